@@ -81,6 +81,16 @@ pub struct TransferArgs {
         default_value = crate::cli::DEFAULT_SEQUENCER_RPC
     )]
     pub(crate) sequencer_url: String,
+    /// The last sequencer block height at which the transfer can be executed
+    #[clap(long)]
+    pub(crate) valid_until_height: Option<u64>,
+    /// The last block time, in seconds since the unix epoch, at which the transfer can be
+    /// executed
+    #[clap(long)]
+    pub(crate) valid_until_timestamp: Option<u64>,
+    /// The tip in the native asset paid to the block proposer for prioritized inclusion
+    #[clap(long)]
+    pub(crate) tip: Option<u128>,
 }
 
 #[derive(Clone, Debug, PartialEq)]
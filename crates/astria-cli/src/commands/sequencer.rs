@@ -1,3 +1,8 @@
+use std::time::{
+    Duration,
+    SystemTime,
+};
+
 use astria_core::sequencer::v1alpha1::{
     transaction::action::{
         Action,
@@ -188,6 +193,12 @@ pub(crate) async fn send_transfer(args: &TransferArgs) -> eyre::Result<()> {
             asset_id: default_native_asset_id(),
             fee_asset_id: default_native_asset_id(),
         })],
+        valid_until_height: args.valid_until_height,
+        valid_until_timestamp: args
+            .valid_until_timestamp
+            .map(|seconds| (SystemTime::UNIX_EPOCH + Duration::from_secs(seconds)).into()),
+        tip: args.tip,
+        fee_payer: None,
     }
     .into_signed(&sequencer_key);
     let res = sequencer_client
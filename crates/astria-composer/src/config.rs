@@ -37,6 +37,11 @@ pub struct Config {
     /// Max bytes to encode into a single sequencer `SignedTransaction`, not including signature,
     /// public key, nonce. This is the sum of the sizes of all the `SequenceAction`s
     pub max_bytes_per_bundle: usize,
+
+    /// Duration in milliseconds after which a submitted bundle expires if it was not included
+    /// in a sequencer block. Set to 0 to submit bundles without an expiry.
+    pub bundle_validity_ms: u64,
+
     /// Forces writing trace data to stdout no matter if connected to a tty or not.
     pub force_stdout: bool,
 
@@ -6,7 +6,10 @@
 use std::{
     pin::Pin,
     task::Poll,
-    time::Duration,
+    time::{
+        Duration,
+        SystemTime,
+    },
 };
 
 use astria_core::sequencer::v1alpha1::{
@@ -99,6 +102,8 @@ pub(super) struct Executor {
     block_time: tokio::time::Duration,
     // Max bytes in a sequencer action bundle
     max_bytes_per_bundle: usize,
+    // How long a submitted bundle remains valid for inclusion in a sequencer block, if at all.
+    bundle_validity: Option<Duration>,
 }
 
 impl Drop for Executor {
@@ -131,6 +136,7 @@ impl Executor {
         serialized_rollup_transactions_rx: mpsc::Receiver<SequenceAction>,
         block_time: u64,
         max_bytes_per_bundle: usize,
+        bundle_validity_ms: u64,
     ) -> eyre::Result<Self> {
         let sequencer_client = sequencer_client::HttpClient::new(sequencer_url)
             .wrap_err("failed constructing sequencer client")?;
@@ -155,6 +161,8 @@ impl Executor {
             address: sequencer_address,
             block_time: Duration::from_millis(block_time),
             max_bytes_per_bundle,
+            bundle_validity: (bundle_validity_ms != 0)
+                .then(|| Duration::from_millis(bundle_validity_ms)),
         })
     }
 
@@ -173,6 +181,7 @@ impl Executor {
             signing_key: self.sequencer_key.clone(),
             state: SubmitState::NotStarted,
             bundle,
+            bundle_validity: self.bundle_validity,
         }
         .in_current_span()
         .fuse()
@@ -287,6 +296,11 @@ async fn get_latest_nonce(
     .wrap_err("failed getting latest nonce from sequencer after 1024 attempts")
 }
 
+/// Returns the timestamp until which a bundle signed now remains valid, if it expires at all.
+fn valid_until_timestamp(bundle_validity: Option<Duration>) -> Option<prost_types::Timestamp> {
+    bundle_validity.map(|validity| (SystemTime::now() + validity).into())
+}
+
 /// Queries the sequencer for the latest nonce with an exponential backoff
 #[instrument(
     name = "submit signed transaction",
@@ -353,6 +367,7 @@ pin_project! {
         #[pin]
         state: SubmitState,
         bundle: Vec<Action>,
+        bundle_validity: Option<Duration>,
     }
 
     impl PinnedDrop for SubmitFut {
@@ -389,6 +404,10 @@ impl Future for SubmitFut {
                     let tx = UnsignedTransaction {
                         nonce: *this.nonce,
                         actions: this.bundle.clone(),
+                        valid_until_height: None,
+                        valid_until_timestamp: valid_until_timestamp(*this.bundle_validity),
+                        tip: None,
+                        fee_payer: None,
                     }
                     .into_signed(this.signing_key);
                     SubmitState::WaitingForSend {
@@ -442,6 +461,10 @@ impl Future for SubmitFut {
                         let tx = UnsignedTransaction {
                             nonce: *this.nonce,
                             actions: this.bundle.clone(),
+                            valid_until_height: None,
+                            valid_until_timestamp: valid_until_timestamp(*this.bundle_validity),
+                            tip: None,
+                            fee_payer: None,
                         }
                         .into_signed(this.signing_key);
                         SubmitState::WaitingForSend {
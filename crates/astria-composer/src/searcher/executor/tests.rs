@@ -88,6 +88,7 @@ async fn setup() -> (MockServer, MockGuard, Config) {
             .into(),
         block_time_ms: 2000,
         max_bytes_per_bundle: 1000,
+        bundle_validity_ms: 0,
         no_otel: false,
         force_stdout: false,
     };
@@ -201,6 +202,7 @@ async fn full_bundle() {
         seq_actions_rx,
         cfg.block_time_ms,
         cfg.max_bytes_per_bundle,
+        cfg.bundle_validity_ms,
     )
     .unwrap();
     let status = executor.subscribe();
@@ -282,6 +284,7 @@ async fn bundle_triggered_by_block_timer() {
         seq_actions_rx,
         cfg.block_time_ms,
         cfg.max_bytes_per_bundle,
+        cfg.bundle_validity_ms,
     )
     .unwrap();
     let status = executor.subscribe();
@@ -358,6 +361,7 @@ async fn two_seq_actions_single_bundle() {
         seq_actions_rx,
         cfg.block_time_ms,
         cfg.max_bytes_per_bundle,
+        cfg.bundle_validity_ms,
     )
     .unwrap();
     let status = executor.subscribe();
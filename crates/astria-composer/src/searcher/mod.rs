@@ -114,6 +114,7 @@ impl Searcher {
             serialized_rollup_transactions_rx,
             cfg.block_time_ms,
             cfg.max_bytes_per_bundle,
+            cfg.bundle_validity_ms,
         )
         .wrap_err("executor construction from config failed")?;
 
@@ -76,6 +76,7 @@ pub async fn spawn_composer(rollup_ids: &[&str]) -> TestComposer {
             .into(),
         block_time_ms: 2000,
         max_bytes_per_bundle: 200_000,
+        bundle_validity_ms: 0,
         no_otel: false,
         force_stdout: false,
     };
@@ -9,8 +9,12 @@
 //!
 //! When the deposit transactions are executed on the rollup, it mints the corresponding
 //! funds which were deposited (locked) on the L1 on the L2 to the specified address.
+//!
+//! Like all other rollup transactions, the deposit transactions are passed to the rollup
+//! as encoded [`RollupData::SequencedData`].
 use std::sync::Arc;
 
+use astria_core::sequencer::v1alpha1::RollupData;
 use ethers::{
     prelude::*,
     types::transaction::eip2718::TypedTransaction,
@@ -24,6 +28,7 @@ use optimism::{
     },
     deposit::convert_deposit_event_to_deposit_tx,
 };
+use prost::Message as _;
 use tracing::debug;
 
 use super::eyre::Result;
@@ -100,7 +105,8 @@ pub(crate) fn convert_deposit_events_to_encoded_txs(
         .map(|(event, meta)| {
             let tx = convert_deposit_event_to_deposit_tx(event, meta.block_hash, meta.log_index)
                 .wrap_err("failed to convert deposit event to transaction")?;
-            Ok(TypedTransaction::DepositTransaction(tx).rlp().to_vec())
+            let rlp = TypedTransaction::DepositTransaction(tx).rlp().to_vec();
+            Ok(RollupData::SequencedData(rlp).into_raw().encode_to_vec())
         })
         .collect::<Result<Vec<Vec<u8>>>>()?;
 
@@ -90,7 +90,8 @@ pub struct ExecuteBlockRequest {
     /// The hash of previous block, which new block will be created on top of.
     #[prost(bytes = "bytes", tag = "1")]
     pub prev_block_hash: ::prost::bytes::Bytes,
-    /// List of transactions to include in the new block.
+    /// List of transactions to include in the new block. Each transaction
+    /// is an encoded `astria.sequencer.v1alpha1.RollupData`.
     #[prost(bytes = "bytes", repeated, tag = "2")]
     pub transactions: ::prost::alloc::vec::Vec<::prost::bytes::Bytes>,
     /// Timestamp to be used for new block.
@@ -7,6 +7,8 @@ pub enum AbciErrorCode {
     InternalError = 3,
     InvalidNonce = 4,
     TransactionTooLarge = 5,
+    HeightPruned = 6,
+    HeightNotCached = 7,
 }
 impl AbciErrorCode {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -21,6 +23,8 @@ impl AbciErrorCode {
             AbciErrorCode::InternalError => "ABCI_ERROR_CODE_INTERNAL_ERROR",
             AbciErrorCode::InvalidNonce => "ABCI_ERROR_CODE_INVALID_NONCE",
             AbciErrorCode::TransactionTooLarge => "ABCI_ERROR_CODE_TRANSACTION_TOO_LARGE",
+            AbciErrorCode::HeightPruned => "ABCI_ERROR_CODE_HEIGHT_PRUNED",
+            AbciErrorCode::HeightNotCached => "ABCI_ERROR_CODE_HEIGHT_NOT_CACHED",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -32,6 +36,8 @@ impl AbciErrorCode {
             "ABCI_ERROR_CODE_INTERNAL_ERROR" => Some(Self::InternalError),
             "ABCI_ERROR_CODE_INVALID_NONCE" => Some(Self::InvalidNonce),
             "ABCI_ERROR_CODE_TRANSACTION_TOO_LARGE" => Some(Self::TransactionTooLarge),
+            "ABCI_ERROR_CODE_HEIGHT_PRUNED" => Some(Self::HeightPruned),
+            "ABCI_ERROR_CODE_HEIGHT_NOT_CACHED" => Some(Self::HeightNotCached),
             _ => None,
         }
     }
@@ -72,6 +78,79 @@ pub struct Denom {
     #[prost(string, tag = "2")]
     pub base_denom: ::prost::alloc::string::String,
 }
+/// A response containing the full denomination of an asset.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DenomResponse {
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    #[prost(string, tag = "2")]
+    pub denom: ::prost::alloc::string::String,
+}
+/// The metadata used to display amounts of an asset.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AssetMetadata {
+    /// The number of decimal places between the base denomination and the display denomination.
+    #[prost(uint32, tag = "1")]
+    pub decimals: u32,
+    /// The ticker symbol of the asset, eg. `RIA`.
+    #[prost(string, tag = "2")]
+    pub symbol: ::prost::alloc::string::String,
+    /// The denomination amounts are displayed in, eg. `ria`.
+    #[prost(string, tag = "3")]
+    pub display_denom: ::prost::alloc::string::String,
+}
+/// A response containing the metadata of an asset.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AssetMetadataResponse {
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    #[prost(bytes = "vec", tag = "2")]
+    pub asset_id: ::prost::alloc::vec::Vec<u8>,
+    /// The metadata of the asset, if it was set.
+    #[prost(message, optional, tag = "3")]
+    pub metadata: ::core::option::Option<AssetMetadata>,
+}
+/// A response containing the total supply of an asset on the sequencer.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AssetSupplyResponse {
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    #[prost(bytes = "vec", tag = "2")]
+    pub asset_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag = "3")]
+    pub supply: ::core::option::Option<super::super::primitive::v1::Uint128>,
+}
+/// A response containing the current validator set.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidatorSetResponse {
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    #[prost(message, repeated, tag = "2")]
+    pub validators: ::prost::alloc::vec::Vec<::tendermint_proto::abci::ValidatorUpdate>,
+}
+/// A response containing a sudo address.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SudoAddressResponse {
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    #[prost(bytes = "vec", tag = "2")]
+    pub address: ::prost::alloc::vec::Vec<u8>,
+}
+/// A response containing the addresses allowed to relay IBC packets.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct IbcRelayerAddressesResponse {
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    #[prost(bytes = "vec", repeated, tag = "2")]
+    pub addresses: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+}
 /// A proof for a tree of the given size containing the audit path from a leaf to the root.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -97,10 +176,75 @@ pub struct RollupTransactions {
     /// The 32 bytes identifying a rollup. Usually the sha256 hash of a plain rollup name.
     #[prost(bytes = "vec", tag = "1")]
     pub id: ::prost::alloc::vec::Vec<u8>,
-    /// The serialized opaque bytes of the rollup transactions.
+    /// The serialized bytes of the rollup data, each of which is an encoded
+    /// `astria.sequencer.v1alpha1.RollupData`.
     #[prost(bytes = "vec", repeated, tag = "2")]
     pub transactions: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
 }
+/// `RollupData` is a single item of data destined for a rollup: either opaque
+/// data sequenced by a `SequenceAction`, a deposit resulting from a
+/// `BridgeLockAction`, or a deposit resulting from an incoming ICS20 transfer
+/// routed to the rollup.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RollupData {
+    #[prost(oneof = "rollup_data::Value", tags = "1, 2, 3")]
+    pub value: ::core::option::Option<rollup_data::Value>,
+}
+/// Nested message and enum types in `RollupData`.
+pub mod rollup_data {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Value {
+        #[prost(bytes, tag = "1")]
+        SequencedData(::prost::alloc::vec::Vec<u8>),
+        #[prost(message, tag = "2")]
+        Deposit(super::Deposit),
+        #[prost(message, tag = "3")]
+        Ics20Deposit(super::Ics20Deposit),
+    }
+}
+/// `Deposit` represents funds locked in a bridge account on the sequencer,
+/// which are to be credited on the rollup the bridge account is registered for.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Deposit {
+    /// the bridge account the funds were locked in
+    #[prost(bytes = "vec", tag = "1")]
+    pub bridge_address: ::prost::alloc::vec::Vec<u8>,
+    /// the rollup ID the bridge account is registered for
+    #[prost(bytes = "vec", tag = "2")]
+    pub rollup_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag = "3")]
+    pub amount: ::core::option::Option<super::super::primitive::v1::Uint128>,
+    /// the asset that was locked
+    #[prost(bytes = "vec", tag = "4")]
+    pub asset_id: ::prost::alloc::vec::Vec<u8>,
+    /// the address on the destination chain to credit the deposit to
+    #[prost(string, tag = "5")]
+    pub destination_chain_address: ::prost::alloc::string::String,
+}
+/// `Ics20Deposit` represents funds received by an ICS20 transfer whose memo
+/// routes them to a rollup. The funds are held by the rollup's bridge account on
+/// the sequencer and are to be credited on the rollup.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Ics20Deposit {
+    /// the rollup ID named in the memo of the transfer
+    #[prost(bytes = "vec", tag = "1")]
+    pub rollup_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag = "2")]
+    pub amount: ::core::option::Option<super::super::primitive::v1::Uint128>,
+    /// the asset that was received, as it is known on the sequencer
+    #[prost(bytes = "vec", tag = "3")]
+    pub asset_id: ::prost::alloc::vec::Vec<u8>,
+    /// the sender of the transfer on the counterparty chain
+    #[prost(string, tag = "4")]
+    pub sender: ::prost::alloc::string::String,
+    /// the address on the destination chain to credit the deposit to
+    #[prost(string, tag = "5")]
+    pub destination_chain_address: ::prost::alloc::string::String,
+}
 /// `SequencerBlock` is constructed from a tendermint/cometbft block by
 /// converting its opaque `data` bytes into sequencer specific types.
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -131,6 +275,15 @@ pub struct SequencerBlock {
     #[prost(message, optional, tag = "4")]
     pub rollup_ids_proof: ::core::option::Option<Proof>,
 }
+/// A response containing the chain id of the sequencer.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ChainIdResponse {
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    #[prost(string, tag = "2")]
+    pub chain_id: ::prost::alloc::string::String,
+}
 /// A collection of transactions belonging to a specific rollup that are submitted to celestia.
 ///
 /// The transactions contained in the item belong to a rollup identified
@@ -230,10 +383,565 @@ pub struct SequencerNamespaceData {
     #[prost(message, repeated, tag = "4")]
     pub rollup_namespaces: ::prost::alloc::vec::Vec<RollupNamespace>,
 }
+/// A response containing the fee schedule of the sequencer.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FeeScheduleResponse {
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    /// the fee charged for a `TransferAction`
+    #[prost(message, optional, tag = "2")]
+    pub transfer_base_fee: ::core::option::Option<super::super::primitive::v1::Uint128>,
+    /// the fixed part of the fee charged for a `SequenceAction`
+    #[prost(message, optional, tag = "3")]
+    pub sequence_base_fee: ::core::option::Option<super::super::primitive::v1::Uint128>,
+    /// the fee charged per byte of `data` in a `SequenceAction`
+    #[prost(message, optional, tag = "4")]
+    pub sequence_byte_cost_multiplier: ::core::option::Option<
+        super::super::primitive::v1::Uint128,
+    >,
+    /// the fee charged for an `Ics20Withdrawal`
+    #[prost(message, optional, tag = "5")]
+    pub ics20_withdrawal_base_fee: ::core::option::Option<
+        super::super::primitive::v1::Uint128,
+    >,
+}
+/// The fees collected in a single asset.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AssetFee {
+    #[prost(bytes = "vec", tag = "1")]
+    pub asset_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag = "2")]
+    pub amount: ::core::option::Option<super::super::primitive::v1::Uint128>,
+}
+/// A response containing the fees collected in the block at the given height.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BlockFeesResponse {
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    #[prost(message, repeated, tag = "2")]
+    pub fees: ::prost::alloc::vec::Vec<AssetFee>,
+}
+/// A response containing the assets that fees can be paid in.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AllowedFeeAssetsResponse {
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    #[prost(bytes = "vec", repeated, tag = "2")]
+    pub fee_asset_ids: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+}
+/// A rate limit on the ICS20 transfers of an asset over a channel.
+///
+/// The net amount transferred in either direction is limited within fixed windows
+/// of `window_blocks` blocks.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Ics20RateLimit {
+    /// the maximum net amount transferred into the sequencer in a window
+    #[prost(message, optional, tag = "1")]
+    pub max_inflow: ::core::option::Option<super::super::primitive::v1::Uint128>,
+    /// the maximum net amount transferred out of the sequencer in a window
+    #[prost(message, optional, tag = "2")]
+    pub max_outflow: ::core::option::Option<super::super::primitive::v1::Uint128>,
+    /// the length of a window in blocks
+    #[prost(uint64, tag = "3")]
+    pub window_blocks: u64,
+}
+/// A response containing the rate limit of the ICS20 transfers of an asset over a
+/// channel and its usage in the current window.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Ics20RateLimitResponse {
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    #[prost(string, tag = "2")]
+    pub channel: ::prost::alloc::string::String,
+    #[prost(bytes = "vec", tag = "3")]
+    pub asset_id: ::prost::alloc::vec::Vec<u8>,
+    /// unset if the transfers are not rate limited
+    #[prost(message, optional, tag = "4")]
+    pub rate_limit: ::core::option::Option<Ics20RateLimit>,
+    /// the height of the first block of the current window
+    #[prost(uint64, tag = "5")]
+    pub window_start_height: u64,
+    /// the amount transferred into the sequencer in the current window
+    #[prost(message, optional, tag = "6")]
+    pub inflow: ::core::option::Option<super::super::primitive::v1::Uint128>,
+    /// the amount transferred out of the sequencer in the current window
+    #[prost(message, optional, tag = "7")]
+    pub outflow: ::core::option::Option<super::super::primitive::v1::Uint128>,
+}
+/// A rollup registered in the sequencer's rollup registry.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RollupRecord {
+    /// the name of the rollup; its rollup ID is the sha256 hash of the name
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    /// the address authorized to change the rollup's owner and metadata
+    #[prost(bytes = "vec", tag = "2")]
+    pub owner: ::prost::alloc::vec::Vec<u8>,
+    /// the 10 byte ID of the celestia v0 namespace the rollup's data is posted to
+    #[prost(bytes = "vec", tag = "3")]
+    pub celestia_namespace: ::prost::alloc::vec::Vec<u8>,
+    /// free-form metadata describing the rollup
+    #[prost(string, tag = "4")]
+    pub metadata: ::prost::alloc::string::String,
+}
+/// A response containing the registry record of a rollup.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RollupResponse {
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    #[prost(bytes = "vec", tag = "2")]
+    pub rollup_id: ::prost::alloc::vec::Vec<u8>,
+    /// unset if the rollup is not registered
+    #[prost(message, optional, tag = "3")]
+    pub rollup: ::core::option::Option<RollupRecord>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetSequencerBlockRequest {
+    /// The height of the block to retrieve. Height must be greater than 0.
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetRollupTransactionsRequest {
+    /// The height of the block to retrieve the rollup transactions from.
+    /// Height must be greater than 0.
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    /// The 32 bytes identifying the rollup whose transactions to retrieve.
+    #[prost(bytes = "vec", tag = "2")]
+    pub rollup_id: ::prost::alloc::vec::Vec<u8>,
+}
+/// The transactions belonging to a single rollup in a sequencer block,
+/// together with the proof that they are included in that block.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RollupTransactionsWithProof {
+    /// The hash of the sequencer block the transactions were included in. Must be 32 bytes.
+    #[prost(bytes = "vec", tag = "1")]
+    pub block_hash: ::prost::alloc::vec::Vec<u8>,
+    /// The 32 bytes identifying the rollup the transactions belong to.
+    #[prost(bytes = "vec", tag = "2")]
+    pub rollup_id: ::prost::alloc::vec::Vec<u8>,
+    /// The serialized bytes of the rollup data, each of which is an encoded
+    /// `astria.sequencer.v1alpha1.RollupData`.
+    #[prost(bytes = "vec", repeated, tag = "3")]
+    pub transactions: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    /// The Merkle Tree Hash of all rollup transactions in the sequencer block.
+    /// Its inclusion in the block is proven by
+    /// `astria.sequencer.v1alpha1.SequencerBlock.rollup_transactions_proof`.
+    /// Always 32 bytes.
+    #[prost(bytes = "vec", tag = "4")]
+    pub rollup_transactions_root: ::prost::alloc::vec::Vec<u8>,
+    /// The proof that `transactions` are included in `rollup_transactions_root`.
+    #[prost(message, optional, tag = "5")]
+    pub proof: ::core::option::Option<Proof>,
+}
+/// Generated client implementations.
+#[cfg(feature = "client")]
+pub mod sequencer_service_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    /// `SequencerService` exposes the data of sequencer blocks to rollup nodes
+    /// without them having to convert CometBFT blocks.
+    #[derive(Debug, Clone)]
+    pub struct SequencerServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl SequencerServiceClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> SequencerServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> SequencerServiceClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+            >>::Error: Into<StdError> + Send + Sync,
+        {
+            SequencerServiceClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        /// Returns the sequencer block at the given height.
+        pub async fn get_sequencer_block(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetSequencerBlockRequest>,
+        ) -> std::result::Result<tonic::Response<super::SequencerBlock>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/astria.sequencer.v1alpha1.SequencerService/GetSequencerBlock",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "astria.sequencer.v1alpha1.SequencerService",
+                        "GetSequencerBlock",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Returns the transactions of a rollup at the given height together with
+        /// their proof of inclusion.
+        pub async fn get_rollup_transactions(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetRollupTransactionsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RollupTransactionsWithProof>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/astria.sequencer.v1alpha1.SequencerService/GetRollupTransactions",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "astria.sequencer.v1alpha1.SequencerService",
+                        "GetRollupTransactions",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+#[cfg(feature = "server")]
+pub mod sequencer_service_server {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with SequencerServiceServer.
+    #[async_trait]
+    pub trait SequencerService: Send + Sync + 'static {
+        /// Returns the sequencer block at the given height.
+        async fn get_sequencer_block(
+            &self,
+            request: tonic::Request<super::GetSequencerBlockRequest>,
+        ) -> std::result::Result<tonic::Response<super::SequencerBlock>, tonic::Status>;
+        /// Returns the transactions of a rollup at the given height together with
+        /// their proof of inclusion.
+        async fn get_rollup_transactions(
+            &self,
+            request: tonic::Request<super::GetRollupTransactionsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RollupTransactionsWithProof>,
+            tonic::Status,
+        >;
+    }
+    /// `SequencerService` exposes the data of sequencer blocks to rollup nodes
+    /// without them having to convert CometBFT blocks.
+    #[derive(Debug)]
+    pub struct SequencerServiceServer<T: SequencerService> {
+        inner: _Inner<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    struct _Inner<T>(Arc<T>);
+    impl<T: SequencerService> SequencerServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            let inner = _Inner(inner);
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for SequencerServiceServer<T>
+    where
+        T: SequencerService,
+        B: Body + Send + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            let inner = self.inner.clone();
+            match req.uri().path() {
+                "/astria.sequencer.v1alpha1.SequencerService/GetSequencerBlock" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetSequencerBlockSvc<T: SequencerService>(pub Arc<T>);
+                    impl<
+                        T: SequencerService,
+                    > tonic::server::UnaryService<super::GetSequencerBlockRequest>
+                    for GetSequencerBlockSvc<T> {
+                        type Response = super::SequencerBlock;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetSequencerBlockRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as SequencerService>::get_sequencer_block(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetSequencerBlockSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/astria.sequencer.v1alpha1.SequencerService/GetRollupTransactions" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetRollupTransactionsSvc<T: SequencerService>(pub Arc<T>);
+                    impl<
+                        T: SequencerService,
+                    > tonic::server::UnaryService<super::GetRollupTransactionsRequest>
+                    for GetRollupTransactionsSvc<T> {
+                        type Response = super::RollupTransactionsWithProof;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetRollupTransactionsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as SequencerService>::get_rollup_transactions(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetRollupTransactionsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        Ok(
+                            http::Response::builder()
+                                .status(200)
+                                .header("grpc-status", "12")
+                                .header("content-type", "application/grpc")
+                                .body(empty_body())
+                                .unwrap(),
+                        )
+                    })
+                }
+            }
+        }
+    }
+    impl<T: SequencerService> Clone for SequencerServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    impl<T: SequencerService> Clone for _Inner<T> {
+        fn clone(&self) -> Self {
+            Self(Arc::clone(&self.0))
+        }
+    }
+    impl<T: std::fmt::Debug> std::fmt::Debug for _Inner<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{:?}", self.0)
+        }
+    }
+    impl<T: SequencerService> tonic::server::NamedService for SequencerServiceServer<T> {
+        const NAME: &'static str = "astria.sequencer.v1alpha1.SequencerService";
+    }
+}
 /// `SignedTransaction` is a transaction that has
 /// been signed by the given public key.
 /// It wraps an `UnsignedTransaction` with a
 /// signature and public key.
+///
+/// If the transaction is signed by a multisig account,
+/// `multisig` is set instead of `signature` and `public_key`.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SignedTransaction {
@@ -243,6 +951,47 @@ pub struct SignedTransaction {
     pub public_key: ::prost::alloc::vec::Vec<u8>,
     #[prost(message, optional, tag = "3")]
     pub transaction: ::core::option::Option<UnsignedTransaction>,
+    #[prost(message, optional, tag = "4")]
+    pub multisig: ::core::option::Option<MultisigSignature>,
+    /// the signature of the fee payer named in `transaction.fee_payer` over the
+    /// signer's address followed by the encoded transaction, authorizing it to pay the
+    /// fees of the transaction. must be empty if no fee payer is named.
+    #[prost(bytes = "vec", tag = "5")]
+    pub fee_payer_signature: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "6")]
+    pub fee_payer_public_key: ::prost::alloc::vec::Vec<u8>,
+}
+/// `MultisigPublicKey` defines a threshold multisig account.
+/// The account's address is derived from this definition.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MultisigPublicKey {
+    /// the number of signatures required to authorize a transaction.
+    #[prost(uint32, tag = "1")]
+    pub threshold: u32,
+    /// the ed25519 public keys of the account's members.
+    #[prost(bytes = "vec", repeated, tag = "2")]
+    pub public_keys: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+}
+/// `MultisigSignature` is the set of signatures over an `UnsignedTransaction`
+/// made by members of a multisig account.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MultisigSignature {
+    #[prost(message, optional, tag = "1")]
+    pub public_key: ::core::option::Option<MultisigPublicKey>,
+    #[prost(message, repeated, tag = "2")]
+    pub signatures: ::prost::alloc::vec::Vec<IndexedSignature>,
+}
+/// `IndexedSignature` is a signature made by one member of a multisig account.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct IndexedSignature {
+    /// the index of the signing key in `MultisigPublicKey.public_keys`.
+    #[prost(uint32, tag = "1")]
+    pub index: u32,
+    #[prost(bytes = "vec", tag = "2")]
+    pub signature: ::prost::alloc::vec::Vec<u8>,
 }
 /// `UnsignedTransaction` is a transaction that does
 /// not have an attached signature.
@@ -254,11 +1003,41 @@ pub struct UnsignedTransaction {
     pub nonce: u32,
     #[prost(message, repeated, tag = "2")]
     pub actions: ::prost::alloc::vec::Vec<Action>,
+    /// the last block height at which the transaction can be executed.
+    /// 0 means the transaction does not expire by height.
+    #[prost(uint64, tag = "3")]
+    pub valid_until_height: u64,
+    /// the last block timestamp at which the transaction can be executed.
+    /// if unset, the transaction does not expire by time.
+    #[prost(message, optional, tag = "4")]
+    pub valid_until_timestamp: ::core::option::Option<::prost_types::Timestamp>,
+    /// an optional tip in the native asset, paid to the block proposer for
+    /// prioritized inclusion of the transaction.
+    #[prost(message, optional, tag = "5")]
+    pub tip: ::core::option::Option<super::super::primitive::v1::Uint128>,
+    /// an optional account paying the fees and tip of the transaction instead of
+    /// the signer. if set, the fee payer must also sign the transaction.
+    #[prost(message, optional, tag = "6")]
+    pub fee_payer: ::core::option::Option<FeePayer>,
+}
+/// `FeePayer` is the account paying the fees of a sponsored transaction.
+///
+/// The fee payer signs the address of the transaction's signer followed by the
+/// encoded transaction, so its signature is only valid for the signer's account at
+/// the transaction's nonce.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FeePayer {
+    #[prost(bytes = "vec", tag = "1")]
+    pub address: ::prost::alloc::vec::Vec<u8>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Action {
-    #[prost(oneof = "action::Value", tags = "1, 2, 3, 4, 5, 6, 7")]
+    #[prost(
+        oneof = "action::Value",
+        tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21"
+    )]
     pub value: ::core::option::Option<action::Value>,
 }
 /// Nested message and enum types in `Action`.
@@ -280,6 +1059,38 @@ pub mod action {
         IbcAction(::penumbra_proto::core::component::ibc::v1alpha1::IbcRelay),
         #[prost(message, tag = "7")]
         Ics20Withdrawal(super::Ics20Withdrawal),
+        #[prost(message, tag = "8")]
+        FeeChangeAction(super::FeeChangeAction),
+        #[prost(message, tag = "9")]
+        FeeAssetChangeAction(super::FeeAssetChangeAction),
+        #[prost(message, tag = "10")]
+        InitBridgeAccountAction(super::InitBridgeAccountAction),
+        #[prost(message, tag = "11")]
+        BridgeLockAction(super::BridgeLockAction),
+        #[prost(message, tag = "12")]
+        BridgeUnlockAction(super::BridgeUnlockAction),
+        #[prost(message, tag = "13")]
+        IbcRelayerChangeAction(super::IbcRelayerChangeAction),
+        #[prost(message, tag = "14")]
+        IbcSudoChangeAction(super::IbcSudoChangeAction),
+        #[prost(message, tag = "15")]
+        ScheduleUpgradeAction(super::ScheduleUpgradeAction),
+        #[prost(message, tag = "16")]
+        RegisterRollupAction(super::RegisterRollupAction),
+        #[prost(message, tag = "17")]
+        RollupOwnerChangeAction(super::RollupOwnerChangeAction),
+        #[prost(message, tag = "18")]
+        RollupMetadataChangeAction(super::RollupMetadataChangeAction),
+        #[prost(message, tag = "19")]
+        AssetMetadataChangeAction(super::AssetMetadataChangeAction),
+        #[prost(message, tag = "20")]
+        AuthorizeSessionKeyAction(super::AuthorizeSessionKeyAction),
+        #[prost(message, tag = "21")]
+        RevokeSessionKeyAction(super::RevokeSessionKeyAction),
+        #[prost(message, tag = "22")]
+        Ics20ChannelChangeAction(super::Ics20ChannelChangeAction),
+        #[prost(message, tag = "23")]
+        Ics20RateLimitChangeAction(super::Ics20RateLimitChangeAction),
     }
 }
 /// `TransferAction` represents a value transfer transaction.
@@ -365,6 +1176,302 @@ pub struct Ics20Withdrawal {
     /// the source channel used for the withdrawal.
     #[prost(string, tag = "7")]
     pub source_channel: ::prost::alloc::string::String,
+    /// the asset used to pay the transaction fee
+    #[prost(bytes = "vec", tag = "8")]
+    pub fee_asset_id: ::prost::alloc::vec::Vec<u8>,
+}
+/// `FeeChangeAction` represents a transaction that changes one of the
+/// fees in the sequencer's fee schedule.
+/// It can only be executed by the chain's sudo address.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FeeChangeAction {
+    /// the fee to change and its new value
+    #[prost(oneof = "fee_change_action::Value", tags = "1, 2, 3, 4")]
+    pub value: ::core::option::Option<fee_change_action::Value>,
+}
+/// Nested message and enum types in `FeeChangeAction`.
+pub mod fee_change_action {
+    /// the fee to change and its new value
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Value {
+        #[prost(message, tag = "1")]
+        TransferBaseFee(super::super::super::primitive::v1::Uint128),
+        #[prost(message, tag = "2")]
+        SequenceBaseFee(super::super::super::primitive::v1::Uint128),
+        #[prost(message, tag = "3")]
+        SequenceByteCostMultiplier(super::super::super::primitive::v1::Uint128),
+        #[prost(message, tag = "4")]
+        Ics20WithdrawalBaseFee(super::super::super::primitive::v1::Uint128),
+    }
+}
+/// `FeeAssetChangeAction` represents a transaction that adds an asset to or
+/// removes an asset from the set of assets allowed to pay fees in.
+/// It can only be executed by the chain's sudo address.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FeeAssetChangeAction {
+    /// the id of the asset to add or remove
+    #[prost(oneof = "fee_asset_change_action::Value", tags = "1, 2")]
+    pub value: ::core::option::Option<fee_asset_change_action::Value>,
+}
+/// Nested message and enum types in `FeeAssetChangeAction`.
+pub mod fee_asset_change_action {
+    /// the id of the asset to add or remove
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Value {
+        #[prost(bytes, tag = "1")]
+        Addition(::prost::alloc::vec::Vec<u8>),
+        #[prost(bytes, tag = "2")]
+        Removal(::prost::alloc::vec::Vec<u8>),
+    }
+}
+/// `InitBridgeAccountAction` registers the signer's account as a bridge account
+/// for the given rollup.
+///
+/// Funds locked in a bridge account with a `BridgeLockAction` are credited to the
+/// rollup as deposits, and can only be released with a `BridgeUnlockAction` signed
+/// by the bridge account's withdrawer.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InitBridgeAccountAction {
+    /// the rollup ID to register the account for
+    #[prost(bytes = "vec", tag = "1")]
+    pub rollup_id: ::prost::alloc::vec::Vec<u8>,
+    /// the asset that can be locked in the bridge account
+    #[prost(bytes = "vec", tag = "2")]
+    pub asset_id: ::prost::alloc::vec::Vec<u8>,
+    /// the asset used to pay the transaction fee
+    #[prost(bytes = "vec", tag = "3")]
+    pub fee_asset_id: ::prost::alloc::vec::Vec<u8>,
+    /// the address authorized to unlock funds from the bridge account.
+    /// if unset, the bridge account itself is the withdrawer.
+    #[prost(bytes = "vec", tag = "4")]
+    pub withdrawer_address: ::prost::alloc::vec::Vec<u8>,
+}
+/// `BridgeLockAction` locks funds in a bridge account, which are then
+/// deposited into the rollup the bridge account is registered for.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BridgeLockAction {
+    /// the bridge account to lock the funds in
+    #[prost(bytes = "vec", tag = "1")]
+    pub to: ::prost::alloc::vec::Vec<u8>,
+    /// the rollup ID the bridge account is registered for
+    #[prost(bytes = "vec", tag = "2")]
+    pub rollup_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag = "3")]
+    pub amount: ::core::option::Option<super::super::primitive::v1::Uint128>,
+    /// the asset to be locked
+    #[prost(bytes = "vec", tag = "4")]
+    pub asset_id: ::prost::alloc::vec::Vec<u8>,
+    /// the asset used to pay the transaction fee
+    #[prost(bytes = "vec", tag = "5")]
+    pub fee_asset_id: ::prost::alloc::vec::Vec<u8>,
+    /// the address on the destination chain to credit the deposit to
+    #[prost(string, tag = "6")]
+    pub destination_chain_address: ::prost::alloc::string::String,
+}
+/// `BridgeUnlockAction` releases funds from a bridge account.
+/// It can only be executed by the bridge account's withdrawer.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BridgeUnlockAction {
+    /// the address to release the funds to
+    #[prost(bytes = "vec", tag = "1")]
+    pub to: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag = "2")]
+    pub amount: ::core::option::Option<super::super::primitive::v1::Uint128>,
+    /// the asset used to pay the transaction fee
+    #[prost(bytes = "vec", tag = "3")]
+    pub fee_asset_id: ::prost::alloc::vec::Vec<u8>,
+    /// memo for the unlock, e.g. the rollup transaction that initiated the withdrawal
+    #[prost(bytes = "vec", tag = "4")]
+    pub memo: ::prost::alloc::vec::Vec<u8>,
+    /// the bridge account to release the funds from
+    #[prost(bytes = "vec", tag = "5")]
+    pub bridge_address: ::prost::alloc::vec::Vec<u8>,
+}
+/// `IbcRelayerChangeAction` represents a transaction that adds an address to or
+/// removes an address from the set of addresses allowed to relay IBC packets.
+/// It can only be executed by the chain's IBC sudo address.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct IbcRelayerChangeAction {
+    /// the address to add or remove
+    #[prost(oneof = "ibc_relayer_change_action::Value", tags = "1, 2")]
+    pub value: ::core::option::Option<ibc_relayer_change_action::Value>,
+}
+/// Nested message and enum types in `IbcRelayerChangeAction`.
+pub mod ibc_relayer_change_action {
+    /// the address to add or remove
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Value {
+        #[prost(bytes, tag = "1")]
+        Addition(::prost::alloc::vec::Vec<u8>),
+        #[prost(bytes, tag = "2")]
+        Removal(::prost::alloc::vec::Vec<u8>),
+    }
+}
+/// `IbcSudoChangeAction` represents a transaction that changes the chain's
+/// IBC sudo address.
+/// It can only be executed by the current IBC sudo address.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct IbcSudoChangeAction {
+    #[prost(bytes = "vec", tag = "1")]
+    pub new_address: ::prost::alloc::vec::Vec<u8>,
+}
+/// `ScheduleUpgradeAction` schedules a chain upgrade at the given height.
+/// At that height, nodes halt unless the binary they are running knows the
+/// upgrade, in which case the upgrade's state migration is run.
+/// It can only be executed by the chain's sudo address.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ScheduleUpgradeAction {
+    /// the name of the upgrade
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    /// the height at which the upgrade is applied
+    #[prost(uint64, tag = "2")]
+    pub height: u64,
+}
+/// `RegisterRollupAction` registers a rollup in the rollup registry.
+/// The rollup's ID is the sha256 hash of its name, and each name can only be
+/// registered once.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RegisterRollupAction {
+    /// the name of the rollup
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    /// the address authorized to change the rollup's owner and metadata
+    #[prost(bytes = "vec", tag = "2")]
+    pub owner: ::prost::alloc::vec::Vec<u8>,
+    /// the 10 byte ID of the celestia v0 namespace the rollup's data is posted to
+    #[prost(bytes = "vec", tag = "3")]
+    pub celestia_namespace: ::prost::alloc::vec::Vec<u8>,
+    /// free-form metadata describing the rollup
+    #[prost(string, tag = "4")]
+    pub metadata: ::prost::alloc::string::String,
+    /// the asset used to pay the transaction fee
+    #[prost(bytes = "vec", tag = "5")]
+    pub fee_asset_id: ::prost::alloc::vec::Vec<u8>,
+}
+/// `RollupOwnerChangeAction` transfers the ownership of a registered rollup.
+/// It can only be executed by the rollup's current owner.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RollupOwnerChangeAction {
+    #[prost(bytes = "vec", tag = "1")]
+    pub rollup_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub new_owner: ::prost::alloc::vec::Vec<u8>,
+}
+/// `RollupMetadataChangeAction` replaces the metadata of a registered rollup.
+/// It can only be executed by the rollup's owner.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RollupMetadataChangeAction {
+    #[prost(bytes = "vec", tag = "1")]
+    pub rollup_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag = "2")]
+    pub metadata: ::prost::alloc::string::String,
+}
+/// `AssetMetadataChangeAction` sets the display metadata of the native asset
+/// or of a registered IBC asset.
+/// It can only be executed by the chain's sudo address.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AssetMetadataChangeAction {
+    #[prost(bytes = "vec", tag = "1")]
+    pub asset_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag = "2")]
+    pub metadata: ::core::option::Option<AssetMetadata>,
+}
+/// `AuthorizeSessionKeyAction` authorizes a session key to submit transactions on
+/// behalf of the signer's account.
+///
+/// Transactions signed by the session key can only contain sequence actions for the
+/// listed rollups, and pay at most `fee_spend_limit` of fees and tips in the native
+/// asset from the account until `expiry_height`.
+/// Authorizing a session key again replaces its scope and resets its spent fees.
+///
+/// The session key must sign the address of the signer's account, proving that its
+/// holder agrees to act for the account.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AuthorizeSessionKeyAction {
+    /// the address of the session key
+    #[prost(bytes = "vec", tag = "1")]
+    pub session_key: ::prost::alloc::vec::Vec<u8>,
+    /// the rollups the session key can submit sequence data for
+    #[prost(bytes = "vec", repeated, tag = "2")]
+    pub rollup_ids: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    /// the maximum amount of fees and tips the session key can spend
+    #[prost(message, optional, tag = "3")]
+    pub fee_spend_limit: ::core::option::Option<super::super::primitive::v1::Uint128>,
+    /// the last block height at which the session key can be used
+    #[prost(uint64, tag = "4")]
+    pub expiry_height: u64,
+    /// the asset used to pay the transaction fee
+    #[prost(bytes = "vec", tag = "5")]
+    pub fee_asset_id: ::prost::alloc::vec::Vec<u8>,
+    /// the public key of the session key
+    #[prost(bytes = "vec", tag = "6")]
+    pub session_key_public_key: ::prost::alloc::vec::Vec<u8>,
+    /// the session key's signature over the address of the signer's account
+    #[prost(bytes = "vec", tag = "7")]
+    pub session_key_signature: ::prost::alloc::vec::Vec<u8>,
+}
+/// `RevokeSessionKeyAction` revokes a session key authorized by the signer's account.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RevokeSessionKeyAction {
+    /// the address of the session key
+    #[prost(bytes = "vec", tag = "1")]
+    pub session_key: ::prost::alloc::vec::Vec<u8>,
+}
+/// `Ics20ChannelChangeAction` represents a transaction that adds a channel to or
+/// removes a channel from the set of channels ICS20 transfers can be made over.
+/// It can only be executed by the chain's IBC sudo address.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Ics20ChannelChangeAction {
+    /// the sequencer-side ID of the channel to add or remove
+    #[prost(oneof = "ics20_channel_change_action::Value", tags = "1, 2")]
+    pub value: ::core::option::Option<ics20_channel_change_action::Value>,
+}
+/// Nested message and enum types in `Ics20ChannelChangeAction`.
+pub mod ics20_channel_change_action {
+    /// the sequencer-side ID of the channel to add or remove
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Value {
+        #[prost(string, tag = "1")]
+        Addition(::prost::alloc::string::String),
+        #[prost(string, tag = "2")]
+        Removal(::prost::alloc::string::String),
+    }
+}
+/// `Ics20RateLimitChangeAction` represents a transaction that sets or removes the
+/// rate limit on the ICS20 transfers of an asset over a channel.
+/// It can only be executed by the chain's IBC sudo address.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Ics20RateLimitChangeAction {
+    /// the sequencer-side ID of the channel
+    #[prost(string, tag = "1")]
+    pub channel: ::prost::alloc::string::String,
+    /// the denomination trace of the asset as it is known on the sequencer
+    #[prost(string, tag = "2")]
+    pub denom: ::prost::alloc::string::String,
+    /// the new rate limit; unset to remove the rate limit
+    #[prost(message, optional, tag = "3")]
+    pub rate_limit: ::core::option::Option<Ics20RateLimit>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
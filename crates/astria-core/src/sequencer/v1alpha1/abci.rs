@@ -17,6 +17,8 @@ impl AbciErrorCode {
     pub const INTERNAL_ERROR: Self = Self(3);
     pub const INVALID_NONCE: Self = Self(4);
     pub const TRANSACTION_TOO_LARGE: Self = Self(5);
+    pub const HEIGHT_PRUNED: Self = Self(6);
+    pub const HEIGHT_NOT_CACHED: Self = Self(7);
 }
 
 impl AbciErrorCode {
@@ -29,6 +31,8 @@ impl AbciErrorCode {
             3 => "an internal server error occured".into(),
             4 => "the provided nonce was invalid".into(),
             5 => "the provided transaction was too large".into(),
+            6 => "the state at the requested height has been pruned".into(),
+            7 => "the state at the requested height is not cached for queries".into(),
             other => format!("unknown non-zero abci error code: {other}").into(),
         }
     }
@@ -46,6 +50,8 @@ impl AbciErrorCode {
             raw::AbciErrorCode::InternalError => Self::INTERNAL_ERROR,
             raw::AbciErrorCode::InvalidNonce => Self::INVALID_NONCE,
             raw::AbciErrorCode::TransactionTooLarge => Self::TRANSACTION_TOO_LARGE,
+            raw::AbciErrorCode::HeightPruned => Self::HEIGHT_PRUNED,
+            raw::AbciErrorCode::HeightNotCached => Self::HEIGHT_NOT_CACHED,
         };
         Some(code)
     }
@@ -71,6 +77,8 @@ impl From<NonZeroU32> for AbciErrorCode {
             3 => Self::INTERNAL_ERROR,
             4 => Self::INVALID_NONCE,
             5 => Self::TRANSACTION_TOO_LARGE,
+            6 => Self::HEIGHT_PRUNED,
+            7 => Self::HEIGHT_NOT_CACHED,
             other => Self(other),
         }
     }
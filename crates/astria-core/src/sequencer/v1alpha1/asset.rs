@@ -6,6 +6,8 @@ use std::{
     },
 };
 
+use super::raw;
+
 /// The default sequencer asset base denomination.
 pub const DEFAULT_NATIVE_ASSET_DENOM: &str = "nria";
 
@@ -175,3 +177,277 @@ impl Display for Id {
 pub struct IncorrectAssetIdLength {
     received: usize,
 }
+
+impl raw::DenomResponse {
+    /// Converts an astria native [`DenomResponse`] to a
+    /// protobuf [`raw::DenomResponse`].
+    #[must_use]
+    pub fn from_native(native: DenomResponse) -> Self {
+        let DenomResponse {
+            height,
+            denom,
+        } = native;
+        Self {
+            height,
+            denom: denom.to_string(),
+        }
+    }
+
+    /// Converts a protobuf [`raw::DenomResponse`] to an astria
+    /// native [`DenomResponse`].
+    #[must_use]
+    pub fn into_native(self) -> DenomResponse {
+        DenomResponse::from_raw(&self)
+    }
+
+    /// Converts a protobuf [`raw::DenomResponse`] to an astria
+    /// native [`DenomResponse`] by allocating a new [`DenomResponse`].
+    #[must_use]
+    pub fn to_native(&self) -> DenomResponse {
+        self.clone().into_native()
+    }
+}
+
+/// The sequencer response to a request for the denomination of an asset at a given height.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DenomResponse {
+    pub height: u64,
+    pub denom: Denom,
+}
+
+impl DenomResponse {
+    /// Converts a protobuf [`raw::DenomResponse`] to an astria
+    /// native [`DenomResponse`].
+    #[must_use]
+    pub fn from_raw(proto: &raw::DenomResponse) -> Self {
+        let raw::DenomResponse {
+            height,
+            denom,
+        } = proto;
+        Self {
+            height: *height,
+            denom: denom.as_str().into(),
+        }
+    }
+
+    /// Converts an astria native [`DenomResponse`] to a
+    /// protobuf [`raw::DenomResponse`].
+    #[must_use]
+    pub fn into_raw(self) -> raw::DenomResponse {
+        raw::DenomResponse::from_native(self)
+    }
+}
+
+/// The metadata used to display amounts of an asset.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Metadata {
+    /// The number of decimal places between the base denomination and the display
+    /// denomination.
+    pub decimals: u32,
+    /// The ticker symbol of the asset, eg. `RIA`.
+    pub symbol: String,
+    /// The denomination amounts are displayed in, eg. `ria`.
+    pub display_denom: String,
+}
+
+impl Metadata {
+    /// Converts a protobuf [`raw::AssetMetadata`] to an astria native [`Metadata`].
+    #[must_use]
+    pub fn from_raw(proto: raw::AssetMetadata) -> Self {
+        let raw::AssetMetadata {
+            decimals,
+            symbol,
+            display_denom,
+        } = proto;
+        Self {
+            decimals,
+            symbol,
+            display_denom,
+        }
+    }
+
+    /// Converts an astria native [`Metadata`] to a protobuf [`raw::AssetMetadata`].
+    #[must_use]
+    pub fn into_raw(self) -> raw::AssetMetadata {
+        let Self {
+            decimals,
+            symbol,
+            display_denom,
+        } = self;
+        raw::AssetMetadata {
+            decimals,
+            symbol,
+            display_denom,
+        }
+    }
+}
+
+impl raw::AssetMetadataResponse {
+    /// Converts an astria native [`AssetMetadataResponse`] to a
+    /// protobuf [`raw::AssetMetadataResponse`].
+    #[must_use]
+    pub fn from_native(native: AssetMetadataResponse) -> Self {
+        let AssetMetadataResponse {
+            height,
+            asset_id,
+            metadata,
+        } = native;
+        Self {
+            height,
+            asset_id: asset_id.as_ref().to_vec(),
+            metadata: metadata.map(Metadata::into_raw),
+        }
+    }
+
+    /// Converts a protobuf [`raw::AssetMetadataResponse`] to an astria
+    /// native [`AssetMetadataResponse`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the asset ID is not 32 bytes.
+    pub fn try_into_native(self) -> Result<AssetMetadataResponse, IncorrectAssetIdLength> {
+        AssetMetadataResponse::try_from_raw(self)
+    }
+}
+
+/// The sequencer response to a request for the metadata of an asset at a given height.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AssetMetadataResponse {
+    pub height: u64,
+    pub asset_id: Id,
+    /// The metadata of the asset, or `None` if it was never set.
+    pub metadata: Option<Metadata>,
+}
+
+impl AssetMetadataResponse {
+    /// Converts a protobuf [`raw::AssetMetadataResponse`] to an astria
+    /// native [`AssetMetadataResponse`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the asset ID is not 32 bytes.
+    pub fn try_from_raw(proto: raw::AssetMetadataResponse) -> Result<Self, IncorrectAssetIdLength> {
+        let raw::AssetMetadataResponse {
+            height,
+            asset_id,
+            metadata,
+        } = proto;
+        Ok(Self {
+            height,
+            asset_id: Id::try_from_slice(&asset_id)?,
+            metadata: metadata.map(Metadata::from_raw),
+        })
+    }
+
+    /// Converts an astria native [`AssetMetadataResponse`] to a
+    /// protobuf [`raw::AssetMetadataResponse`].
+    #[must_use]
+    pub fn into_raw(self) -> raw::AssetMetadataResponse {
+        raw::AssetMetadataResponse::from_native(self)
+    }
+}
+
+impl raw::AssetSupplyResponse {
+    /// Converts an astria native [`AssetSupplyResponse`] to a
+    /// protobuf [`raw::AssetSupplyResponse`].
+    #[must_use]
+    pub fn from_native(native: AssetSupplyResponse) -> Self {
+        let AssetSupplyResponse {
+            height,
+            asset_id,
+            supply,
+        } = native;
+        Self {
+            height,
+            asset_id: asset_id.as_ref().to_vec(),
+            supply: Some(supply.into()),
+        }
+    }
+
+    /// Converts a protobuf [`raw::AssetSupplyResponse`] to an astria
+    /// native [`AssetSupplyResponse`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the asset ID is not 32 bytes.
+    pub fn try_into_native(self) -> Result<AssetSupplyResponse, IncorrectAssetIdLength> {
+        AssetSupplyResponse::try_from_raw(self)
+    }
+}
+
+/// The sequencer response to a request for the total supply of an asset at a given height.
+///
+/// The total supply is the amount of the asset held by accounts on the sequencer,
+/// including the amounts escrowed in IBC channels.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AssetSupplyResponse {
+    pub height: u64,
+    pub asset_id: Id,
+    pub supply: u128,
+}
+
+impl AssetSupplyResponse {
+    /// Converts a protobuf [`raw::AssetSupplyResponse`] to an astria
+    /// native [`AssetSupplyResponse`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the asset ID is not 32 bytes.
+    pub fn try_from_raw(proto: raw::AssetSupplyResponse) -> Result<Self, IncorrectAssetIdLength> {
+        let raw::AssetSupplyResponse {
+            height,
+            asset_id,
+            supply,
+        } = proto;
+        Ok(Self {
+            height,
+            asset_id: Id::try_from_slice(&asset_id)?,
+            supply: supply.map_or(0, Into::into),
+        })
+    }
+
+    /// Converts an astria native [`AssetSupplyResponse`] to a
+    /// protobuf [`raw::AssetSupplyResponse`].
+    #[must_use]
+    pub fn into_raw(self) -> raw::AssetSupplyResponse {
+        raw::AssetSupplyResponse::from_native(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        AssetMetadataResponse,
+        AssetSupplyResponse,
+        Id,
+        Metadata,
+    };
+
+    #[test]
+    fn asset_metadata_response_roundtrip_is_correct() {
+        let expected = AssetMetadataResponse {
+            height: 42,
+            asset_id: Id::from_denom("nria"),
+            metadata: Some(Metadata {
+                decimals: 9,
+                symbol: "RIA".to_string(),
+                display_denom: "ria".to_string(),
+            }),
+        };
+        let actual = expected.clone().into_raw().try_into_native().unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn asset_supply_response_roundtrip_is_correct() {
+        let expected = AssetSupplyResponse {
+            height: 42,
+            asset_id: Id::from_denom("nria"),
+            supply: u128::MAX,
+        };
+        let actual = expected.clone().into_raw().try_into_native().unwrap();
+        assert_eq!(expected, actual);
+    }
+}
@@ -0,0 +1,257 @@
+use super::{
+    raw,
+    Address,
+    IncorrectAddressLength,
+};
+
+impl raw::ValidatorSetResponse {
+    /// Converts an astria native [`ValidatorSetResponse`] to a
+    /// protobuf [`raw::ValidatorSetResponse`].
+    #[must_use]
+    pub fn from_native(native: ValidatorSetResponse) -> Self {
+        let ValidatorSetResponse {
+            height,
+            validators,
+        } = native;
+        Self {
+            height,
+            validators: validators.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Converts a protobuf [`raw::ValidatorSetResponse`] to an astria
+    /// native [`ValidatorSetResponse`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the validator updates is invalid.
+    pub fn try_into_native(self) -> Result<ValidatorSetResponse, ValidatorSetResponseError> {
+        ValidatorSetResponse::try_from_raw(self)
+    }
+}
+
+/// The sequencer response to a validator set request at a given height.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ValidatorSetResponse {
+    pub height: u64,
+    pub validators: Vec<tendermint::validator::Update>,
+}
+
+impl ValidatorSetResponse {
+    /// Converts a protobuf [`raw::ValidatorSetResponse`] to an astria
+    /// native [`ValidatorSetResponse`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the validator updates is invalid.
+    pub fn try_from_raw(
+        proto: raw::ValidatorSetResponse,
+    ) -> Result<Self, ValidatorSetResponseError> {
+        let raw::ValidatorSetResponse {
+            height,
+            validators,
+        } = proto;
+        let validators = validators
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<_, _>>()
+            .map_err(ValidatorSetResponseError)?;
+        Ok(Self {
+            height,
+            validators,
+        })
+    }
+
+    /// Converts an astria native [`ValidatorSetResponse`] to a
+    /// protobuf [`raw::ValidatorSetResponse`].
+    #[must_use]
+    pub fn into_raw(self) -> raw::ValidatorSetResponse {
+        raw::ValidatorSetResponse::from_native(self)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("`validators` field contained an invalid validator update")]
+pub struct ValidatorSetResponseError(#[source] tendermint::error::Error);
+
+impl raw::SudoAddressResponse {
+    /// Converts an astria native [`SudoAddressResponse`] to a
+    /// protobuf [`raw::SudoAddressResponse`].
+    #[must_use]
+    pub fn from_native(native: SudoAddressResponse) -> Self {
+        let SudoAddressResponse {
+            height,
+            address,
+        } = native;
+        Self {
+            height,
+            address: address.to_vec(),
+        }
+    }
+
+    /// Converts a protobuf [`raw::SudoAddressResponse`] to an astria
+    /// native [`SudoAddressResponse`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the address is not 20 bytes long.
+    pub fn try_into_native(self) -> Result<SudoAddressResponse, SudoAddressResponseError> {
+        SudoAddressResponse::try_from_raw(&self)
+    }
+}
+
+/// The sequencer response to a sudo address request at a given height.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SudoAddressResponse {
+    pub height: u64,
+    pub address: Address,
+}
+
+impl SudoAddressResponse {
+    /// Converts a protobuf [`raw::SudoAddressResponse`] to an astria
+    /// native [`SudoAddressResponse`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the address is not 20 bytes long.
+    pub fn try_from_raw(
+        proto: &raw::SudoAddressResponse,
+    ) -> Result<Self, SudoAddressResponseError> {
+        let raw::SudoAddressResponse {
+            height,
+            address,
+        } = proto;
+        Ok(Self {
+            height: *height,
+            address: Address::try_from_slice(address).map_err(SudoAddressResponseError)?,
+        })
+    }
+
+    /// Converts an astria native [`SudoAddressResponse`] to a
+    /// protobuf [`raw::SudoAddressResponse`].
+    #[must_use]
+    pub fn into_raw(self) -> raw::SudoAddressResponse {
+        raw::SudoAddressResponse::from_native(self)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("`address` field did not contain a valid address")]
+pub struct SudoAddressResponseError(#[source] IncorrectAddressLength);
+
+impl raw::IbcRelayerAddressesResponse {
+    /// Converts an astria native [`IbcRelayerAddressesResponse`] to a
+    /// protobuf [`raw::IbcRelayerAddressesResponse`].
+    #[must_use]
+    pub fn from_native(native: IbcRelayerAddressesResponse) -> Self {
+        let IbcRelayerAddressesResponse {
+            height,
+            addresses,
+        } = native;
+        Self {
+            height,
+            addresses: addresses.iter().map(Address::to_vec).collect(),
+        }
+    }
+
+    /// Converts a protobuf [`raw::IbcRelayerAddressesResponse`] to an astria
+    /// native [`IbcRelayerAddressesResponse`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the addresses is not 20 bytes long.
+    pub fn try_into_native(
+        self,
+    ) -> Result<IbcRelayerAddressesResponse, IbcRelayerAddressesResponseError> {
+        IbcRelayerAddressesResponse::try_from_raw(&self)
+    }
+}
+
+/// The sequencer response to an IBC relayer addresses request at a given height.
+#[derive(Clone, Debug, PartialEq)]
+pub struct IbcRelayerAddressesResponse {
+    pub height: u64,
+    pub addresses: Vec<Address>,
+}
+
+impl IbcRelayerAddressesResponse {
+    /// Converts a protobuf [`raw::IbcRelayerAddressesResponse`] to an astria
+    /// native [`IbcRelayerAddressesResponse`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the addresses is not 20 bytes long.
+    pub fn try_from_raw(
+        proto: &raw::IbcRelayerAddressesResponse,
+    ) -> Result<Self, IbcRelayerAddressesResponseError> {
+        let raw::IbcRelayerAddressesResponse {
+            height,
+            addresses,
+        } = proto;
+        let addresses = addresses
+            .iter()
+            .map(|address| Address::try_from_slice(address))
+            .collect::<Result<_, _>>()
+            .map_err(IbcRelayerAddressesResponseError)?;
+        Ok(Self {
+            height: *height,
+            addresses,
+        })
+    }
+
+    /// Converts an astria native [`IbcRelayerAddressesResponse`] to a
+    /// protobuf [`raw::IbcRelayerAddressesResponse`].
+    #[must_use]
+    pub fn into_raw(self) -> raw::IbcRelayerAddressesResponse {
+        raw::IbcRelayerAddressesResponse::from_native(self)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("`addresses` field contained an invalid address")]
+pub struct IbcRelayerAddressesResponseError(#[source] IncorrectAddressLength);
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        Address,
+        IbcRelayerAddressesResponse,
+        SudoAddressResponse,
+        ValidatorSetResponse,
+    };
+
+    #[test]
+    fn validator_set_roundtrip_is_correct() {
+        let verification_key = ed25519_consensus::SigningKey::from([1; 32]).verification_key();
+        let expected = ValidatorSetResponse {
+            height: 42,
+            validators: vec![tendermint::validator::Update {
+                pub_key: tendermint::PublicKey::from_raw_ed25519(verification_key.as_bytes())
+                    .unwrap(),
+                power: 10u32.into(),
+            }],
+        };
+        let actual = expected.clone().into_raw().try_into_native().unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn sudo_address_roundtrip_is_correct() {
+        let expected = SudoAddressResponse {
+            height: 42,
+            address: Address::from([1; 20]),
+        };
+        let actual = expected.into_raw().try_into_native().unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn ibc_relayer_addresses_roundtrip_is_correct() {
+        let expected = IbcRelayerAddressesResponse {
+            height: 42,
+            addresses: vec![Address::from([1; 20]), Address::from([2; 20])],
+        };
+        let actual = expected.clone().into_raw().try_into_native().unwrap();
+        assert_eq!(expected, actual);
+    }
+}
@@ -1,12 +1,27 @@
+use ibc_types::core::channel::{
+    msgs::MsgRecvPacket,
+    ChannelId,
+    PortId,
+};
 use indexmap::IndexMap;
 use transaction::SignedTransaction;
 
 use super::{
+    asset::{
+        self,
+        Denom,
+    },
     celestia,
+    ibc::{
+        Ics20DepositMemo,
+        Ics20DepositMemoError,
+    },
     raw,
     transaction,
+    Address,
     CelestiaRollupBlob,
     CelestiaSequencerBlob,
+    IncorrectAddressLength,
     IncorrectRollupIdLength,
     RollupId,
 };
@@ -15,6 +30,7 @@ use crate::{
         are_rollup_ids_included,
         are_rollup_txs_included,
         derive_merkle_tree_from_rollup_txs,
+        group_rollup_data_in_signed_transactions_by_rollup_id,
         transaction::action,
     },
     Protobuf as _,
@@ -36,12 +52,13 @@ pub enum RollupTransactionsErrorKind {
     RollupId(#[source] IncorrectRollupIdLength),
 }
 
-/// The opaque transactions belonging to a rollup identified by its rollup ID.
+/// The transactions belonging to a rollup identified by its rollup ID.
 #[derive(Clone)]
 pub struct RollupTransactions {
     /// The 32 bytes identifying a rollup. Usually the sha256 hash of a plain rollup name.
     id: RollupId,
-    /// The serialized opaque bytes of the rollup transactions.
+    /// The serialized bytes of the rollup data, each of which is an encoded
+    /// [`raw::RollupData`].
     transactions: Vec<Vec<u8>>,
 }
 
@@ -52,7 +69,7 @@ impl RollupTransactions {
         self.id
     }
 
-    /// Returns the opaque transactions bytes.
+    /// Returns the transactions bytes, each of which is an encoded [`raw::RollupData`].
     #[must_use]
     pub fn transactions(&self) -> &[Vec<u8>] {
         &self.transactions
@@ -366,6 +383,30 @@ impl SequencerBlock {
         }
     }
 
+    /// Returns the transactions of the rollup identified by `rollup_id` together with the
+    /// proof that they are included in this block.
+    ///
+    /// Returns `None` if the block does not contain any transactions for the rollup.
+    #[must_use]
+    #[allow(clippy::missing_panics_doc)] // the proof is guaranteed to exist; revisit if refactoring
+    pub fn rollup_transactions_with_proof(
+        &self,
+        rollup_id: RollupId,
+    ) -> Option<RollupTransactionsWithProof> {
+        let (index, _, transactions) = self.rollup_transactions.get_full(&rollup_id)?;
+        let tree = derive_merkle_tree_from_rollup_txs(&self.rollup_transactions);
+        let proof = tree
+            .construct_proof(index)
+            .expect("the proof must exist because the tree was derived with the same leaf");
+        Some(RollupTransactionsWithProof {
+            block_hash: self.block_hash,
+            rollup_id,
+            transactions: transactions.clone(),
+            rollup_transactions_root: tree.root(),
+            proof,
+        })
+    }
+
     /// Turn the sequencer block into a [`CelestiaSequencerBlob`] and its associated list of
     /// [`CelestiaRollupBlob`]s.
     #[must_use]
@@ -379,13 +420,28 @@ impl SequencerBlock {
     /// TODO(https://github.com/astriaorg/astria/issues/612)
     #[allow(clippy::missing_panics_doc)] // the panic sources are checked before hand; revisit if refactoring
     pub fn try_from_cometbft(block: tendermint::Block) -> Result<Self, SequencerBlockError> {
-        use prost::Message as _;
-
         let tendermint::Block {
             header,
             data,
             ..
         } = block;
+        Self::try_from_cometbft_header_and_data(header, data)
+    }
+
+    /// Converts from a `CometBFT` header and the block data it commits to.
+    ///
+    /// This is useful when the full [`tendermint::Block`] is not available, for example
+    /// while executing a block in the ABCI application, where only the header and the
+    /// delivered transactions are known.
+    ///
+    /// # Errors
+    /// TODO(https://github.com/astriaorg/astria/issues/612)
+    #[allow(clippy::missing_panics_doc)] // the panic sources are checked before hand; revisit if refactoring
+    pub fn try_from_cometbft_header_and_data(
+        header: tendermint::block::Header,
+        data: Vec<Vec<u8>>,
+    ) -> Result<Self, SequencerBlockError> {
+        use prost::Message as _;
 
         let Some(tendermint::Hash::Sha256(data_hash)) = header.data_hash else {
             // header.data_hash is Option<Hash> and Hash itself has
@@ -417,25 +473,16 @@ impl SequencerBlock {
             .try_into()
             .map_err(|e: Vec<_>| SequencerBlockError::incorrect_rollup_ids_root_length(e.len()))?;
 
-        let mut rollup_transactions = IndexMap::new();
-        for elem in data_list {
-            let raw_tx = raw::SignedTransaction::decode(&*elem)
-                .map_err(SequencerBlockError::signed_transaction_prootbof_decode)?;
-            let signed_tx = SignedTransaction::try_from_raw(raw_tx)
-                .map_err(SequencerBlockError::raw_signed_transactin_conversion)?;
-            for action in signed_tx.into_unsigned().actions {
-                if let action::Action::Sequence(action::SequenceAction {
-                    rollup_id,
-                    data,
-                    fee_asset_id: _,
-                }) = action
-                {
-                    let elem = rollup_transactions.entry(rollup_id).or_insert(vec![]);
-                    elem.push(data);
-                }
-            }
-        }
-        rollup_transactions.sort_unstable_keys();
+        let signed_txs = data_list
+            .map(|elem| {
+                let raw_tx = raw::SignedTransaction::decode(&*elem)
+                    .map_err(SequencerBlockError::signed_transaction_prootbof_decode)?;
+                SignedTransaction::try_from_raw(raw_tx)
+                    .map_err(SequencerBlockError::raw_signed_transactin_conversion)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let rollup_transactions =
+            group_rollup_data_in_signed_transactions_by_rollup_id(&signed_txs);
 
         if rollup_transactions_root
             != derive_merkle_tree_from_rollup_txs(&rollup_transactions).root()
@@ -549,6 +596,551 @@ impl SequencerBlock {
     }
 }
 
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct RollupTransactionsWithProofError(RollupTransactionsWithProofErrorKind);
+
+impl RollupTransactionsWithProofError {
+    fn field_not_set(field: &'static str) -> Self {
+        Self(RollupTransactionsWithProofErrorKind::FieldNotSet(field))
+    }
+
+    fn block_hash(len: usize) -> Self {
+        Self(RollupTransactionsWithProofErrorKind::BlockHash(len))
+    }
+
+    fn rollup_id(source: IncorrectRollupIdLength) -> Self {
+        Self(RollupTransactionsWithProofErrorKind::RollupId(source))
+    }
+
+    fn rollup_transactions_root(len: usize) -> Self {
+        Self(RollupTransactionsWithProofErrorKind::RollupTransactionsRoot(len))
+    }
+
+    fn proof(source: merkle::audit::InvalidProof) -> Self {
+        Self(RollupTransactionsWithProofErrorKind::Proof(source))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum RollupTransactionsWithProofErrorKind {
+    #[error("the expected field in the raw source type was not set: `{0}`")]
+    FieldNotSet(&'static str),
+    #[error("the block hash was expected to be 32 bytes long, but was actually `{0}`")]
+    BlockHash(usize),
+    #[error("`rollup_id` field is invalid")]
+    RollupId(#[source] IncorrectRollupIdLength),
+    #[error(
+        "the rollup transactions root was expected to be 32 bytes long, but was actually `{0}`"
+    )]
+    RollupTransactionsRoot(usize),
+    #[error("failed constructing a Merkle Hash Tree Proof from the raw protobuf proof")]
+    Proof(#[source] merkle::audit::InvalidProof),
+}
+
+/// The transactions belonging to a single rollup in a [`SequencerBlock`], together with
+/// the proof that they are included in that block.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RollupTransactionsWithProof {
+    /// The hash of the sequencer block the transactions were included in.
+    block_hash: [u8; 32],
+    /// The 32 bytes identifying the rollup the transactions belong to.
+    rollup_id: RollupId,
+    /// The serialized opaque bytes of the rollup transactions.
+    transactions: Vec<Vec<u8>>,
+    /// The Merkle Tree Hash of all rollup transactions in the sequencer block.
+    rollup_transactions_root: [u8; 32],
+    /// The proof that `transactions` are included in `rollup_transactions_root`.
+    proof: merkle::Proof,
+}
+
+impl RollupTransactionsWithProof {
+    #[must_use]
+    pub fn block_hash(&self) -> [u8; 32] {
+        self.block_hash
+    }
+
+    #[must_use]
+    pub fn rollup_id(&self) -> RollupId {
+        self.rollup_id
+    }
+
+    #[must_use]
+    pub fn transactions(&self) -> &[Vec<u8>] {
+        &self.transactions
+    }
+
+    #[must_use]
+    pub fn rollup_transactions_root(&self) -> [u8; 32] {
+        self.rollup_transactions_root
+    }
+
+    #[must_use]
+    pub fn proof(&self) -> &merkle::Proof {
+        &self.proof
+    }
+
+    /// Returns if the transactions are included in `rollup_transactions_root` using `proof`.
+    #[must_use]
+    pub fn is_included(&self) -> bool {
+        self.proof
+            .audit()
+            .with_root(self.rollup_transactions_root)
+            .with_leaf_builder()
+            .write(&self.rollup_id.get())
+            .write(&merkle::Tree::from_leaves(&self.transactions).root())
+            .finish_leaf()
+            .perform()
+    }
+
+    #[must_use]
+    pub fn into_raw(self) -> raw::RollupTransactionsWithProof {
+        let Self {
+            block_hash,
+            rollup_id,
+            transactions,
+            rollup_transactions_root,
+            proof,
+        } = self;
+        raw::RollupTransactionsWithProof {
+            block_hash: block_hash.to_vec(),
+            rollup_id: rollup_id.to_vec(),
+            transactions,
+            rollup_transactions_root: rollup_transactions_root.to_vec(),
+            proof: Some(proof.into_raw()),
+        }
+    }
+
+    /// Converts from the raw decoded protobuf representation of this type.
+    ///
+    /// # Errors
+    /// Returns an error if any of the fields could not be converted to its native type,
+    /// or if the proof is not set.
+    pub fn try_from_raw(
+        raw: raw::RollupTransactionsWithProof,
+    ) -> Result<Self, RollupTransactionsWithProofError> {
+        let raw::RollupTransactionsWithProof {
+            block_hash,
+            rollup_id,
+            transactions,
+            rollup_transactions_root,
+            proof,
+        } = raw;
+        let block_hash = block_hash
+            .try_into()
+            .map_err(|e: Vec<_>| RollupTransactionsWithProofError::block_hash(e.len()))?;
+        let rollup_id = RollupId::try_from_slice(&rollup_id)
+            .map_err(RollupTransactionsWithProofError::rollup_id)?;
+        let rollup_transactions_root =
+            rollup_transactions_root.try_into().map_err(|e: Vec<_>| {
+                RollupTransactionsWithProofError::rollup_transactions_root(e.len())
+            })?;
+        let proof = 'proof: {
+            let Some(proof) = proof else {
+                break 'proof Err(RollupTransactionsWithProofError::field_not_set("proof"));
+            };
+            merkle::Proof::try_from_raw(proof).map_err(RollupTransactionsWithProofError::proof)
+        }?;
+        Ok(Self {
+            block_hash,
+            rollup_id,
+            transactions,
+            rollup_transactions_root,
+            proof,
+        })
+    }
+}
+
+/// Funds locked in a bridge account on the sequencer by a [`action::BridgeLockAction`],
+/// which are to be credited on the rollup the bridge account is registered for.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Deposit {
+    /// The bridge account the funds were locked in.
+    pub bridge_address: Address,
+    /// The rollup the bridge account is registered for.
+    pub rollup_id: RollupId,
+    pub amount: u128,
+    /// The asset that was locked.
+    pub asset_id: asset::Id,
+    /// The address on the destination chain to credit the deposit to.
+    pub destination_chain_address: String,
+}
+
+impl Deposit {
+    /// Constructs the deposit resulting from executing `action`.
+    #[must_use]
+    pub fn from_bridge_lock(action: &action::BridgeLockAction) -> Self {
+        Self {
+            bridge_address: action.to,
+            rollup_id: action.rollup_id,
+            amount: action.amount,
+            asset_id: action.asset_id,
+            destination_chain_address: action.destination_chain_address.clone(),
+        }
+    }
+
+    #[must_use]
+    pub fn into_raw(self) -> raw::Deposit {
+        let Self {
+            bridge_address,
+            rollup_id,
+            amount,
+            asset_id,
+            destination_chain_address,
+        } = self;
+        raw::Deposit {
+            bridge_address: bridge_address.to_vec(),
+            rollup_id: rollup_id.to_vec(),
+            amount: Some(amount.into()),
+            asset_id: asset_id.as_ref().to_vec(),
+            destination_chain_address,
+        }
+    }
+
+    /// Converts from a raw, unchecked protobuf [`raw::Deposit`].
+    ///
+    /// # Errors
+    ///
+    /// - if the `bridge_address` field is not 20 bytes
+    /// - if the `rollup_id` or `asset_id` fields are not 32 bytes
+    pub fn try_from_raw(raw: raw::Deposit) -> Result<Self, DepositError> {
+        let raw::Deposit {
+            bridge_address,
+            rollup_id,
+            amount,
+            asset_id,
+            destination_chain_address,
+        } = raw;
+        let bridge_address =
+            Address::try_from_slice(&bridge_address).map_err(DepositError::bridge_address)?;
+        let rollup_id = RollupId::try_from_vec(rollup_id).map_err(DepositError::rollup_id)?;
+        let amount = amount.map_or(0, Into::into);
+        let asset_id = asset::Id::try_from_slice(&asset_id).map_err(DepositError::asset_id)?;
+        Ok(Self {
+            bridge_address,
+            rollup_id,
+            amount,
+            asset_id,
+            destination_chain_address,
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct DepositError(DepositErrorKind);
+
+impl DepositError {
+    fn bridge_address(source: IncorrectAddressLength) -> Self {
+        Self(DepositErrorKind::BridgeAddress(source))
+    }
+
+    fn rollup_id(source: IncorrectRollupIdLength) -> Self {
+        Self(DepositErrorKind::RollupId(source))
+    }
+
+    fn asset_id(source: asset::IncorrectAssetIdLength) -> Self {
+        Self(DepositErrorKind::AssetId(source))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum DepositErrorKind {
+    #[error("`bridge_address` field did not contain a valid address")]
+    BridgeAddress(#[source] IncorrectAddressLength),
+    #[error("`rollup_id` field did not contain a valid rollup ID")]
+    RollupId(#[source] IncorrectRollupIdLength),
+    #[error("`asset_id` field did not contain a valid asset ID")]
+    AssetId(#[source] asset::IncorrectAssetIdLength),
+}
+
+/// Funds received by an incoming ICS20 transfer whose memo routes them to a rollup,
+/// which are held by the rollup's bridge account on the sequencer and are to be credited
+/// on the rollup.
+///
+/// See [`Ics20DepositMemo`] for the format of the memo.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Ics20Deposit {
+    /// The rollup named in the memo of the transfer.
+    pub rollup_id: RollupId,
+    pub amount: u128,
+    /// The asset that was received, as it is known on the sequencer.
+    pub asset_id: asset::Id,
+    /// The sender of the transfer on the counterparty chain.
+    pub sender: String,
+    /// The address on the rollup to credit the deposit to.
+    pub destination_chain_address: String,
+}
+
+impl Ics20Deposit {
+    /// Constructs the deposit resulting from receiving `msg`, if it is an ICS20 transfer
+    /// routed to a rollup.
+    ///
+    /// Returns `None` if the packet of `msg` is not a valid ICS20 transfer routed to a
+    /// rollup. The sequencer rejects receiving packets which are routed to a rollup but
+    /// cannot be deposited, so every included packet this returns `None` for was not
+    /// deposited.
+    #[must_use]
+    pub fn from_recv_packet(msg: &MsgRecvPacket) -> Option<Self> {
+        Self::try_from_packet_data(
+            &msg.packet.data,
+            &msg.packet.port_on_a,
+            &msg.packet.chan_on_a,
+            &msg.packet.port_on_b,
+            &msg.packet.chan_on_b,
+        )
+        .ok()
+        .flatten()
+    }
+
+    /// Constructs the deposit resulting from receiving the ICS20 packet data `data`, sent
+    /// from `source_port` and `source_channel` on the counterparty chain to `dest_port` and
+    /// `dest_channel` on the sequencer.
+    ///
+    /// Returns `None` if the memo of the transfer does not route it to a rollup.
+    ///
+    /// # Errors
+    ///
+    /// - if `data` is not JSON encoded
+    /// - if the memo routes the transfer to a rollup, but is invalid
+    /// - if the transfer is routed to a rollup and its amount, denomination or sender are invalid
+    pub fn try_from_packet_data(
+        data: &[u8],
+        source_port: &PortId,
+        source_channel: &ChannelId,
+        dest_port: &PortId,
+        dest_channel: &ChannelId,
+    ) -> Result<Option<Self>, Ics20DepositPacketError> {
+        let packet_data: serde_json::Value =
+            serde_json::from_slice(data).map_err(Ics20DepositPacketError::packet_data)?;
+        let memo = packet_data
+            .get("memo")
+            .and_then(serde_json::Value::as_str)
+            .unwrap_or_default();
+        let Some(memo) = Ics20DepositMemo::parse(memo).map_err(Ics20DepositPacketError::memo)?
+        else {
+            return Ok(None);
+        };
+
+        let field = |name: &'static str| {
+            packet_data
+                .get(name)
+                .and_then(serde_json::Value::as_str)
+                .ok_or(Ics20DepositPacketError::missing_field(name))
+        };
+        let amount = field("amount")?
+            .parse()
+            .map_err(Ics20DepositPacketError::amount)?;
+        let packet_denom = field("denom")?;
+        let sender = field("sender")?;
+
+        // the asset is credited under the same denomination as any other incoming transfer:
+        // unchanged if the sequencer is the source of the asset, or prefixed with the
+        // sequencer-side port and channel otherwise
+        let denom: Denom = packet_denom.into();
+        if denom.prefix_is("ibc") {
+            return Err(Ics20DepositPacketError::ibc_denom());
+        }
+        let asset_id = if denom.prefix_is(&format!("{source_port}/{source_channel}/")) {
+            denom.id()
+        } else {
+            Denom::from(format!("{dest_port}/{dest_channel}/{packet_denom}")).id()
+        };
+
+        Ok(Some(Self {
+            rollup_id: memo.rollup_id,
+            amount,
+            asset_id,
+            sender: sender.to_string(),
+            destination_chain_address: memo.rollup_address,
+        }))
+    }
+
+    #[must_use]
+    pub fn into_raw(self) -> raw::Ics20Deposit {
+        let Self {
+            rollup_id,
+            amount,
+            asset_id,
+            sender,
+            destination_chain_address,
+        } = self;
+        raw::Ics20Deposit {
+            rollup_id: rollup_id.to_vec(),
+            amount: Some(amount.into()),
+            asset_id: asset_id.as_ref().to_vec(),
+            sender,
+            destination_chain_address,
+        }
+    }
+
+    /// Converts from a raw, unchecked protobuf [`raw::Ics20Deposit`].
+    ///
+    /// # Errors
+    ///
+    /// - if the `rollup_id` or `asset_id` fields are not 32 bytes
+    pub fn try_from_raw(raw: raw::Ics20Deposit) -> Result<Self, Ics20DepositError> {
+        let raw::Ics20Deposit {
+            rollup_id,
+            amount,
+            asset_id,
+            sender,
+            destination_chain_address,
+        } = raw;
+        let rollup_id = RollupId::try_from_vec(rollup_id).map_err(Ics20DepositError::rollup_id)?;
+        let amount = amount.map_or(0, Into::into);
+        let asset_id = asset::Id::try_from_slice(&asset_id).map_err(Ics20DepositError::asset_id)?;
+        Ok(Self {
+            rollup_id,
+            amount,
+            asset_id,
+            sender,
+            destination_chain_address,
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct Ics20DepositError(Ics20DepositErrorKind);
+
+impl Ics20DepositError {
+    fn rollup_id(source: IncorrectRollupIdLength) -> Self {
+        Self(Ics20DepositErrorKind::RollupId(source))
+    }
+
+    fn asset_id(source: asset::IncorrectAssetIdLength) -> Self {
+        Self(Ics20DepositErrorKind::AssetId(source))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum Ics20DepositErrorKind {
+    #[error("`rollup_id` field did not contain a valid rollup ID")]
+    RollupId(#[source] IncorrectRollupIdLength),
+    #[error("`asset_id` field did not contain a valid asset ID")]
+    AssetId(#[source] asset::IncorrectAssetIdLength),
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct Ics20DepositPacketError(Ics20DepositPacketErrorKind);
+
+impl Ics20DepositPacketError {
+    fn packet_data(source: serde_json::Error) -> Self {
+        Self(Ics20DepositPacketErrorKind::PacketData(source))
+    }
+
+    fn memo(source: Ics20DepositMemoError) -> Self {
+        Self(Ics20DepositPacketErrorKind::Memo(source))
+    }
+
+    fn missing_field(field: &'static str) -> Self {
+        Self(Ics20DepositPacketErrorKind::MissingField(field))
+    }
+
+    fn amount(source: std::num::ParseIntError) -> Self {
+        Self(Ics20DepositPacketErrorKind::Amount(source))
+    }
+
+    fn ibc_denom() -> Self {
+        Self(Ics20DepositPacketErrorKind::IbcDenom)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum Ics20DepositPacketErrorKind {
+    #[error("packet data was not valid JSON")]
+    PacketData(#[source] serde_json::Error),
+    #[error("memo did not contain a valid rollup deposit")]
+    Memo(#[source] Ics20DepositMemoError),
+    #[error("`{0}` field of the packet data was missing")]
+    MissingField(&'static str),
+    #[error("`amount` field of the packet data was not a valid amount")]
+    Amount(#[source] std::num::ParseIntError),
+    #[error(
+        "`denom` field of the packet data must be a full denomination trace for transfers routed \
+         to a rollup"
+    )]
+    IbcDenom,
+}
+
+/// A single item of data destined for a rollup.
+///
+/// The transactions of a rollup in a [`SequencerBlock`] are encoded [`raw::RollupData`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RollupData {
+    /// Opaque data sequenced by a [`action::SequenceAction`].
+    SequencedData(Vec<u8>),
+    /// A deposit resulting from a [`action::BridgeLockAction`].
+    Deposit(Deposit),
+    /// A deposit resulting from an incoming ICS20 transfer routed to a rollup.
+    Ics20Deposit(Ics20Deposit),
+}
+
+impl RollupData {
+    #[must_use]
+    pub fn into_raw(self) -> raw::RollupData {
+        use raw::rollup_data::Value;
+        let value = match self {
+            Self::SequencedData(data) => Value::SequencedData(data),
+            Self::Deposit(deposit) => Value::Deposit(deposit.into_raw()),
+            Self::Ics20Deposit(deposit) => Value::Ics20Deposit(deposit.into_raw()),
+        };
+        raw::RollupData {
+            value: Some(value),
+        }
+    }
+
+    /// Converts from a raw, unchecked protobuf [`raw::RollupData`].
+    ///
+    /// # Errors
+    ///
+    /// - if the `value` field is not set
+    /// - if the `value` field contains an invalid deposit or ICS20 deposit
+    pub fn try_from_raw(raw: raw::RollupData) -> Result<Self, RollupDataError> {
+        use raw::rollup_data::Value;
+        match raw.value {
+            Some(Value::SequencedData(data)) => Ok(Self::SequencedData(data)),
+            Some(Value::Deposit(deposit)) => Deposit::try_from_raw(deposit)
+                .map(Self::Deposit)
+                .map_err(RollupDataError::deposit),
+            Some(Value::Ics20Deposit(deposit)) => Ics20Deposit::try_from_raw(deposit)
+                .map(Self::Ics20Deposit)
+                .map_err(RollupDataError::ics20_deposit),
+            None => Err(RollupDataError::field_not_set("value")),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct RollupDataError(RollupDataErrorKind);
+
+impl RollupDataError {
+    fn field_not_set(field: &'static str) -> Self {
+        Self(RollupDataErrorKind::FieldNotSet(field))
+    }
+
+    fn deposit(source: DepositError) -> Self {
+        Self(RollupDataErrorKind::Deposit(source))
+    }
+
+    fn ics20_deposit(source: Ics20DepositError) -> Self {
+        Self(RollupDataErrorKind::Ics20Deposit(source))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum RollupDataErrorKind {
+    #[error("the expected field in the raw source type was not set: `{0}`")]
+    FieldNotSet(&'static str),
+    #[error("`deposit` field did not contain a valid deposit")]
+    Deposit(#[source] DepositError),
+    #[error("`ics20_deposit` field did not contain a valid ICS20 deposit")]
+    Ics20Deposit(#[source] Ics20DepositError),
+}
+
 /// Constructs a `[merkle::Tree]` from an iterator yielding byte slices.
 ///
 /// This hashes each item before pushing it into the Merkle Tree, which
@@ -0,0 +1,61 @@
+use super::raw;
+
+impl raw::ChainIdResponse {
+    /// Converts an astria native [`ChainIdResponse`] to a
+    /// protobuf [`raw::ChainIdResponse`].
+    #[must_use]
+    pub fn from_native(native: ChainIdResponse) -> Self {
+        let ChainIdResponse {
+            height,
+            chain_id,
+        } = native;
+        Self {
+            height,
+            chain_id,
+        }
+    }
+
+    /// Converts a protobuf [`raw::ChainIdResponse`] to an astria
+    /// native [`ChainIdResponse`].
+    #[must_use]
+    pub fn into_native(self) -> ChainIdResponse {
+        ChainIdResponse::from_raw(self)
+    }
+
+    /// Converts a protobuf [`raw::ChainIdResponse`] to an astria
+    /// native [`ChainIdResponse`] by allocating a new [`ChainIdResponse`].
+    #[must_use]
+    pub fn to_native(&self) -> ChainIdResponse {
+        self.clone().into_native()
+    }
+}
+
+/// The sequencer response to a chain id request at a given height.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChainIdResponse {
+    pub height: u64,
+    pub chain_id: String,
+}
+
+impl ChainIdResponse {
+    /// Converts a protobuf [`raw::ChainIdResponse`] to an astria
+    /// native [`ChainIdResponse`].
+    #[must_use]
+    pub fn from_raw(proto: raw::ChainIdResponse) -> Self {
+        let raw::ChainIdResponse {
+            height,
+            chain_id,
+        } = proto;
+        Self {
+            height,
+            chain_id,
+        }
+    }
+
+    /// Converts an astria native [`ChainIdResponse`] to a
+    /// protobuf [`raw::ChainIdResponse`].
+    #[must_use]
+    pub fn into_raw(self) -> raw::ChainIdResponse {
+        raw::ChainIdResponse::from_native(self)
+    }
+}
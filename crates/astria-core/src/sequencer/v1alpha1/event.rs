@@ -0,0 +1,693 @@
+//! Typed ABCI events emitted by the sequencer when executing transactions.
+//!
+//! Every event is converted to a [`tendermint::abci::Event`] with string attributes.
+//! Addresses, asset IDs, rollup IDs and public keys are hex encoded, while amounts
+//! are decimal. The attributes identifying accounts, assets and rollups are indexed,
+//! so that transactions can be searched by them with cometbft's transaction indexer,
+//! eg. `astria.transfer.to='<hex address>'`.
+//!
+//! Events received from cometbft can be decoded again with [`Event::try_from_abci`].
+
+use std::{
+    fmt,
+    str::FromStr,
+};
+
+use tendermint::abci::{
+    self,
+    EventAttribute,
+};
+
+use super::{
+    asset,
+    transaction::action::FeeChange,
+    Address,
+    RollupId,
+};
+
+/// An event emitted by the sequencer when executing a transaction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Event {
+    Transfer(TransferEvent),
+    Sequence(SequenceEvent),
+    Mint(MintEvent),
+    Ics20Withdrawal(Ics20WithdrawalEvent),
+    SudoAddressChange(SudoAddressChangeEvent),
+    ValidatorUpdate(ValidatorUpdateEvent),
+    FeePayment(FeePaymentEvent),
+    FeeChange(FeeChangeEvent),
+    IbcRelayerChange(IbcRelayerChangeEvent),
+    InitBridgeAccount(InitBridgeAccountEvent),
+    BridgeUnlock(BridgeUnlockEvent),
+    RegisterRollup(RegisterRollupEvent),
+}
+
+/// Emitted for every executed transfer action.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransferEvent {
+    pub from: Address,
+    pub to: Address,
+    pub asset_id: asset::Id,
+    pub amount: u128,
+}
+
+impl TransferEvent {
+    pub const KIND: &'static str = "astria.transfer";
+}
+
+/// Emitted for every executed sequence action.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SequenceEvent {
+    pub from: Address,
+    pub rollup_id: RollupId,
+    /// The number of bytes of sequenced data.
+    pub data_len: u64,
+}
+
+impl SequenceEvent {
+    pub const KIND: &'static str = "astria.sequence";
+}
+
+/// Emitted for every executed mint action.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MintEvent {
+    /// The sudo address which signed the mint.
+    pub minter: Address,
+    pub to: Address,
+    pub amount: u128,
+    /// The total amount minted since genesis, including this mint.
+    pub minted_supply: u128,
+}
+
+impl MintEvent {
+    pub const KIND: &'static str = "astria.mint";
+}
+
+/// Emitted for every executed ICS20 withdrawal action.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Ics20WithdrawalEvent {
+    pub from: Address,
+    pub asset_id: asset::Id,
+    pub amount: u128,
+    pub destination_chain_address: String,
+    pub source_channel: String,
+}
+
+impl Ics20WithdrawalEvent {
+    pub const KIND: &'static str = "astria.ics20_withdrawal";
+}
+
+/// Emitted for every executed sudo address change action.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SudoAddressChangeEvent {
+    pub new_address: Address,
+}
+
+impl SudoAddressChangeEvent {
+    pub const KIND: &'static str = "astria.sudo_address_change";
+}
+
+/// Emitted for every executed validator update action.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ValidatorUpdateEvent {
+    pub pub_key: tendermint::PublicKey,
+    pub power: u64,
+}
+
+impl ValidatorUpdateEvent {
+    pub const KIND: &'static str = "astria.validator_update";
+}
+
+/// Emitted once per fee asset for every executed transaction, with the total amount
+/// of that asset paid in fees and tips by the transaction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FeePaymentEvent {
+    pub from: Address,
+    pub asset_id: asset::Id,
+    pub amount: u128,
+}
+
+impl FeePaymentEvent {
+    pub const KIND: &'static str = "astria.fee_payment";
+}
+
+/// Emitted for every executed fee change action.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FeeChangeEvent {
+    pub fee_change: FeeChange,
+    pub new_value: u128,
+}
+
+impl FeeChangeEvent {
+    pub const KIND: &'static str = "astria.fee_change";
+}
+
+/// Emitted for every executed IBC relayer change action.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IbcRelayerChangeEvent {
+    pub address: Address,
+    /// `true` if the address was added to the IBC relayers, `false` if it was removed.
+    pub added: bool,
+}
+
+impl IbcRelayerChangeEvent {
+    pub const KIND: &'static str = "astria.ibc_relayer_change";
+}
+
+/// Emitted for every executed init bridge account action.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InitBridgeAccountEvent {
+    pub bridge_address: Address,
+    pub rollup_id: RollupId,
+    pub asset_id: asset::Id,
+    pub withdrawer_address: Address,
+}
+
+impl InitBridgeAccountEvent {
+    pub const KIND: &'static str = "astria.init_bridge_account";
+}
+
+/// Emitted for every executed bridge unlock action.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BridgeUnlockEvent {
+    pub bridge_address: Address,
+    pub to: Address,
+    pub asset_id: asset::Id,
+    pub amount: u128,
+    pub memo: Vec<u8>,
+}
+
+impl BridgeUnlockEvent {
+    pub const KIND: &'static str = "astria.bridge_unlock";
+}
+
+/// Emitted for every executed register rollup action.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RegisterRollupEvent {
+    pub rollup_id: RollupId,
+    pub name: String,
+    pub owner: Address,
+}
+
+impl RegisterRollupEvent {
+    pub const KIND: &'static str = "astria.register_rollup";
+}
+
+impl From<TransferEvent> for Event {
+    fn from(event: TransferEvent) -> Self {
+        Self::Transfer(event)
+    }
+}
+
+impl From<SequenceEvent> for Event {
+    fn from(event: SequenceEvent) -> Self {
+        Self::Sequence(event)
+    }
+}
+
+impl From<MintEvent> for Event {
+    fn from(event: MintEvent) -> Self {
+        Self::Mint(event)
+    }
+}
+
+impl From<Ics20WithdrawalEvent> for Event {
+    fn from(event: Ics20WithdrawalEvent) -> Self {
+        Self::Ics20Withdrawal(event)
+    }
+}
+
+impl From<SudoAddressChangeEvent> for Event {
+    fn from(event: SudoAddressChangeEvent) -> Self {
+        Self::SudoAddressChange(event)
+    }
+}
+
+impl From<ValidatorUpdateEvent> for Event {
+    fn from(event: ValidatorUpdateEvent) -> Self {
+        Self::ValidatorUpdate(event)
+    }
+}
+
+impl From<FeePaymentEvent> for Event {
+    fn from(event: FeePaymentEvent) -> Self {
+        Self::FeePayment(event)
+    }
+}
+
+impl From<FeeChangeEvent> for Event {
+    fn from(event: FeeChangeEvent) -> Self {
+        Self::FeeChange(event)
+    }
+}
+
+impl From<IbcRelayerChangeEvent> for Event {
+    fn from(event: IbcRelayerChangeEvent) -> Self {
+        Self::IbcRelayerChange(event)
+    }
+}
+
+impl From<InitBridgeAccountEvent> for Event {
+    fn from(event: InitBridgeAccountEvent) -> Self {
+        Self::InitBridgeAccount(event)
+    }
+}
+
+impl From<BridgeUnlockEvent> for Event {
+    fn from(event: BridgeUnlockEvent) -> Self {
+        Self::BridgeUnlock(event)
+    }
+}
+
+impl From<RegisterRollupEvent> for Event {
+    fn from(event: RegisterRollupEvent) -> Self {
+        Self::RegisterRollup(event)
+    }
+}
+
+fn attribute(key: &str, value: &dyn fmt::Display, index: bool) -> EventAttribute {
+    EventAttribute {
+        key: key.to_string(),
+        value: value.to_string(),
+        index,
+    }
+}
+
+fn fee_change_name(fee_change: FeeChange) -> &'static str {
+    match fee_change {
+        FeeChange::TransferBaseFee => "transfer_base_fee",
+        FeeChange::SequenceBaseFee => "sequence_base_fee",
+        FeeChange::SequenceByteCostMultiplier => "sequence_byte_cost_multiplier",
+        FeeChange::Ics20WithdrawalBaseFee => "ics20_withdrawal_base_fee",
+    }
+}
+
+impl Event {
+    /// Returns the kind of the ABCI event this event is converted to.
+    #[must_use]
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Transfer(_) => TransferEvent::KIND,
+            Self::Sequence(_) => SequenceEvent::KIND,
+            Self::Mint(_) => MintEvent::KIND,
+            Self::Ics20Withdrawal(_) => Ics20WithdrawalEvent::KIND,
+            Self::SudoAddressChange(_) => SudoAddressChangeEvent::KIND,
+            Self::ValidatorUpdate(_) => ValidatorUpdateEvent::KIND,
+            Self::FeePayment(_) => FeePaymentEvent::KIND,
+            Self::FeeChange(_) => FeeChangeEvent::KIND,
+            Self::IbcRelayerChange(_) => IbcRelayerChangeEvent::KIND,
+            Self::InitBridgeAccount(_) => InitBridgeAccountEvent::KIND,
+            Self::BridgeUnlock(_) => BridgeUnlockEvent::KIND,
+            Self::RegisterRollup(_) => RegisterRollupEvent::KIND,
+        }
+    }
+
+    /// Converts the event to an ABCI event.
+    #[must_use]
+    pub fn into_abci(self) -> abci::Event {
+        let kind = self.kind();
+        let attributes = match self {
+            Self::Transfer(TransferEvent {
+                from,
+                to,
+                asset_id,
+                amount,
+            }) => vec![
+                attribute("from", &from, true),
+                attribute("to", &to, true),
+                attribute("asset_id", &asset_id, true),
+                attribute("amount", &amount, false),
+            ],
+            Self::Sequence(SequenceEvent {
+                from,
+                rollup_id,
+                data_len,
+            }) => vec![
+                attribute("from", &from, true),
+                attribute("rollup_id", &rollup_id, true),
+                attribute("data_len", &data_len, false),
+            ],
+            Self::Mint(MintEvent {
+                minter,
+                to,
+                amount,
+                minted_supply,
+            }) => vec![
+                attribute("minter", &minter, true),
+                attribute("to", &to, true),
+                attribute("amount", &amount, false),
+                attribute("minted_supply", &minted_supply, false),
+            ],
+            Self::Ics20Withdrawal(Ics20WithdrawalEvent {
+                from,
+                asset_id,
+                amount,
+                destination_chain_address,
+                source_channel,
+            }) => vec![
+                attribute("from", &from, true),
+                attribute("asset_id", &asset_id, true),
+                attribute("amount", &amount, false),
+                attribute(
+                    "destination_chain_address",
+                    &destination_chain_address,
+                    true,
+                ),
+                attribute("source_channel", &source_channel, true),
+            ],
+            Self::SudoAddressChange(SudoAddressChangeEvent {
+                new_address,
+            }) => vec![attribute("new_address", &new_address, true)],
+            Self::ValidatorUpdate(ValidatorUpdateEvent {
+                pub_key,
+                power,
+            }) => vec![
+                attribute("pub_key", &hex::encode(pub_key.to_bytes()), true),
+                attribute("power", &power, false),
+            ],
+            Self::FeePayment(FeePaymentEvent {
+                from,
+                asset_id,
+                amount,
+            }) => vec![
+                attribute("from", &from, true),
+                attribute("asset_id", &asset_id, true),
+                attribute("amount", &amount, false),
+            ],
+            Self::FeeChange(FeeChangeEvent {
+                fee_change,
+                new_value,
+            }) => vec![
+                attribute("fee_change", &fee_change_name(fee_change), true),
+                attribute("new_value", &new_value, false),
+            ],
+            Self::IbcRelayerChange(IbcRelayerChangeEvent {
+                address,
+                added,
+            }) => vec![
+                attribute("address", &address, true),
+                attribute("added", &added, false),
+            ],
+            Self::InitBridgeAccount(InitBridgeAccountEvent {
+                bridge_address,
+                rollup_id,
+                asset_id,
+                withdrawer_address,
+            }) => vec![
+                attribute("bridge_address", &bridge_address, true),
+                attribute("rollup_id", &rollup_id, true),
+                attribute("asset_id", &asset_id, true),
+                attribute("withdrawer_address", &withdrawer_address, true),
+            ],
+            Self::BridgeUnlock(BridgeUnlockEvent {
+                bridge_address,
+                to,
+                asset_id,
+                amount,
+                memo,
+            }) => vec![
+                attribute("bridge_address", &bridge_address, true),
+                attribute("to", &to, true),
+                attribute("asset_id", &asset_id, true),
+                attribute("amount", &amount, false),
+                attribute("memo", &hex::encode(memo), false),
+            ],
+            Self::RegisterRollup(RegisterRollupEvent {
+                rollup_id,
+                name,
+                owner,
+            }) => vec![
+                attribute("rollup_id", &rollup_id, true),
+                attribute("name", &name, true),
+                attribute("owner", &owner, true),
+            ],
+        };
+        abci::Event {
+            kind: kind.to_string(),
+            attributes,
+        }
+    }
+
+    /// Decodes an ABCI event emitted by the sequencer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the event is not of a known kind, or if one of its
+    /// attributes is missing or cannot be decoded.
+    pub fn try_from_abci(event: &abci::Event) -> Result<Self, EventError> {
+        let attributes = Attributes(event);
+        let event = match event.kind.as_str() {
+            TransferEvent::KIND => Self::Transfer(TransferEvent {
+                from: attributes.address("from")?,
+                to: attributes.address("to")?,
+                asset_id: attributes.asset_id("asset_id")?,
+                amount: attributes.number("amount")?,
+            }),
+            SequenceEvent::KIND => Self::Sequence(SequenceEvent {
+                from: attributes.address("from")?,
+                rollup_id: attributes.rollup_id("rollup_id")?,
+                data_len: attributes.number("data_len")?,
+            }),
+            MintEvent::KIND => Self::Mint(MintEvent {
+                minter: attributes.address("minter")?,
+                to: attributes.address("to")?,
+                amount: attributes.number("amount")?,
+                minted_supply: attributes.number("minted_supply")?,
+            }),
+            Ics20WithdrawalEvent::KIND => Self::Ics20Withdrawal(Ics20WithdrawalEvent {
+                from: attributes.address("from")?,
+                asset_id: attributes.asset_id("asset_id")?,
+                amount: attributes.number("amount")?,
+                destination_chain_address: attributes.get("destination_chain_address")?.to_string(),
+                source_channel: attributes.get("source_channel")?.to_string(),
+            }),
+            SudoAddressChangeEvent::KIND => Self::SudoAddressChange(SudoAddressChangeEvent {
+                new_address: attributes.address("new_address")?,
+            }),
+            ValidatorUpdateEvent::KIND => Self::ValidatorUpdate(ValidatorUpdateEvent {
+                pub_key: attributes.pub_key("pub_key")?,
+                power: attributes.number("power")?,
+            }),
+            FeePaymentEvent::KIND => Self::FeePayment(FeePaymentEvent {
+                from: attributes.address("from")?,
+                asset_id: attributes.asset_id("asset_id")?,
+                amount: attributes.number("amount")?,
+            }),
+            FeeChangeEvent::KIND => Self::FeeChange(FeeChangeEvent {
+                fee_change: attributes.fee_change("fee_change")?,
+                new_value: attributes.number("new_value")?,
+            }),
+            IbcRelayerChangeEvent::KIND => Self::IbcRelayerChange(IbcRelayerChangeEvent {
+                address: attributes.address("address")?,
+                added: attributes.number("added")?,
+            }),
+            InitBridgeAccountEvent::KIND => Self::InitBridgeAccount(InitBridgeAccountEvent {
+                bridge_address: attributes.address("bridge_address")?,
+                rollup_id: attributes.rollup_id("rollup_id")?,
+                asset_id: attributes.asset_id("asset_id")?,
+                withdrawer_address: attributes.address("withdrawer_address")?,
+            }),
+            BridgeUnlockEvent::KIND => Self::BridgeUnlock(BridgeUnlockEvent {
+                bridge_address: attributes.address("bridge_address")?,
+                to: attributes.address("to")?,
+                asset_id: attributes.asset_id("asset_id")?,
+                amount: attributes.number("amount")?,
+                memo: attributes.hex("memo")?,
+            }),
+            RegisterRollupEvent::KIND => Self::RegisterRollup(RegisterRollupEvent {
+                rollup_id: attributes.rollup_id("rollup_id")?,
+                name: attributes.get("name")?.to_string(),
+                owner: attributes.address("owner")?,
+            }),
+            other => return Err(EventError::unknown_kind(other)),
+        };
+        Ok(event)
+    }
+}
+
+impl From<Event> for abci::Event {
+    fn from(event: Event) -> Self {
+        event.into_abci()
+    }
+}
+
+/// Looks up and decodes the attributes of an ABCI event.
+struct Attributes<'a>(&'a abci::Event);
+
+impl<'a> Attributes<'a> {
+    fn get(&self, key: &'static str) -> Result<&'a str, EventError> {
+        self.0
+            .attributes
+            .iter()
+            .find(|attribute| attribute.key == key)
+            .map(|attribute| attribute.value.as_str())
+            .ok_or(EventError::missing_attribute(key))
+    }
+
+    fn hex(&self, key: &'static str) -> Result<Vec<u8>, EventError> {
+        hex::decode(self.get(key)?).map_err(|_| EventError::invalid_attribute(key))
+    }
+
+    fn address(&self, key: &'static str) -> Result<Address, EventError> {
+        Address::try_from_slice(&self.hex(key)?).map_err(|_| EventError::invalid_attribute(key))
+    }
+
+    fn asset_id(&self, key: &'static str) -> Result<asset::Id, EventError> {
+        asset::Id::try_from_slice(&self.hex(key)?).map_err(|_| EventError::invalid_attribute(key))
+    }
+
+    fn rollup_id(&self, key: &'static str) -> Result<RollupId, EventError> {
+        RollupId::try_from_slice(&self.hex(key)?).map_err(|_| EventError::invalid_attribute(key))
+    }
+
+    fn pub_key(&self, key: &'static str) -> Result<tendermint::PublicKey, EventError> {
+        tendermint::PublicKey::from_raw_ed25519(&self.hex(key)?)
+            .ok_or(EventError::invalid_attribute(key))
+    }
+
+    fn fee_change(&self, key: &'static str) -> Result<FeeChange, EventError> {
+        let name = self.get(key)?;
+        [
+            FeeChange::TransferBaseFee,
+            FeeChange::SequenceBaseFee,
+            FeeChange::SequenceByteCostMultiplier,
+            FeeChange::Ics20WithdrawalBaseFee,
+        ]
+        .into_iter()
+        .find(|fee_change| fee_change_name(*fee_change) == name)
+        .ok_or(EventError::invalid_attribute(key))
+    }
+
+    fn number<T: FromStr>(&self, key: &'static str) -> Result<T, EventError> {
+        self.get(key)?
+            .parse()
+            .map_err(|_| EventError::invalid_attribute(key))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct EventError(EventErrorKind);
+
+impl EventError {
+    fn unknown_kind(kind: &str) -> Self {
+        Self(EventErrorKind::UnknownKind(kind.to_string()))
+    }
+
+    fn missing_attribute(key: &'static str) -> Self {
+        Self(EventErrorKind::MissingAttribute(key))
+    }
+
+    fn invalid_attribute(key: &'static str) -> Self {
+        Self(EventErrorKind::InvalidAttribute(key))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum EventErrorKind {
+    #[error("`{0}` is not a known sequencer event kind")]
+    UnknownKind(String),
+    #[error("attribute `{0}` is missing")]
+    MissingAttribute(&'static str),
+    #[error("attribute `{0}` could not be decoded")]
+    InvalidAttribute(&'static str),
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn events_roundtrip_through_abci_events() {
+        let events: Vec<Event> = vec![
+            TransferEvent {
+                from: Address::from([1; 20]),
+                to: Address::from([2; 20]),
+                asset_id: asset::Id::from_denom("nria"),
+                amount: u128::MAX,
+            }
+            .into(),
+            SequenceEvent {
+                from: Address::from([1; 20]),
+                rollup_id: RollupId::new([3; 32]),
+                data_len: 100,
+            }
+            .into(),
+            MintEvent {
+                minter: Address::from([1; 20]),
+                to: Address::from([2; 20]),
+                amount: 5,
+                minted_supply: 105,
+            }
+            .into(),
+            Ics20WithdrawalEvent {
+                from: Address::from([1; 20]),
+                asset_id: asset::Id::from_denom("transfer/channel-0/utia"),
+                amount: 10,
+                destination_chain_address: "celestia1xyz".to_string(),
+                source_channel: "channel-0".to_string(),
+            }
+            .into(),
+            SudoAddressChangeEvent {
+                new_address: Address::from([4; 20]),
+            }
+            .into(),
+            ValidatorUpdateEvent {
+                pub_key: tendermint::PublicKey::from_raw_ed25519(&[5; 32]).unwrap(),
+                power: 10,
+            }
+            .into(),
+            FeePaymentEvent {
+                from: Address::from([1; 20]),
+                asset_id: asset::Id::from_denom("nria"),
+                amount: 12,
+            }
+            .into(),
+            FeeChangeEvent {
+                fee_change: FeeChange::SequenceByteCostMultiplier,
+                new_value: 3,
+            }
+            .into(),
+            IbcRelayerChangeEvent {
+                address: Address::from([6; 20]),
+                added: false,
+            }
+            .into(),
+            InitBridgeAccountEvent {
+                bridge_address: Address::from([1; 20]),
+                rollup_id: RollupId::new([3; 32]),
+                asset_id: asset::Id::from_denom("nria"),
+                withdrawer_address: Address::from([2; 20]),
+            }
+            .into(),
+            BridgeUnlockEvent {
+                bridge_address: Address::from([1; 20]),
+                to: Address::from([2; 20]),
+                asset_id: asset::Id::from_denom("nria"),
+                amount: 7,
+                memo: b"withdrawal".to_vec(),
+            }
+            .into(),
+            RegisterRollupEvent {
+                rollup_id: RollupId::new([3; 32]),
+                name: "test-rollup".to_string(),
+                owner: Address::from([2; 20]),
+            }
+            .into(),
+        ];
+        for event in events {
+            let abci_event = event.clone().into_abci();
+            assert_eq!(abci_event.kind, event.kind());
+            assert_eq!(Event::try_from_abci(&abci_event).unwrap(), event);
+        }
+    }
+
+    #[test]
+    fn decoding_unknown_event_kind_fails() {
+        let event = abci::Event {
+            kind: "transfer".to_string(),
+            attributes: vec![],
+        };
+        let _ = Event::try_from_abci(&event).unwrap_err();
+    }
+}
@@ -0,0 +1,278 @@
+use super::{
+    asset,
+    raw,
+};
+
+impl raw::FeeScheduleResponse {
+    /// Converts an astria native [`FeeScheduleResponse`] to a
+    /// protobuf [`raw::FeeScheduleResponse`].
+    #[must_use]
+    pub fn from_native(native: FeeScheduleResponse) -> Self {
+        let FeeScheduleResponse {
+            height,
+            transfer_base_fee,
+            sequence_base_fee,
+            sequence_byte_cost_multiplier,
+            ics20_withdrawal_base_fee,
+        } = native;
+        Self {
+            height,
+            transfer_base_fee: Some(transfer_base_fee.into()),
+            sequence_base_fee: Some(sequence_base_fee.into()),
+            sequence_byte_cost_multiplier: Some(sequence_byte_cost_multiplier.into()),
+            ics20_withdrawal_base_fee: Some(ics20_withdrawal_base_fee.into()),
+        }
+    }
+
+    /// Converts a protobuf [`raw::FeeScheduleResponse`] to an astria
+    /// native [`FeeScheduleResponse`].
+    #[must_use]
+    pub fn into_native(self) -> FeeScheduleResponse {
+        FeeScheduleResponse::from_raw(&self)
+    }
+
+    /// Converts a protobuf [`raw::FeeScheduleResponse`] to an astria
+    /// native [`FeeScheduleResponse`] by allocating a new [`FeeScheduleResponse`].
+    #[must_use]
+    pub fn to_native(&self) -> FeeScheduleResponse {
+        self.clone().into_native()
+    }
+}
+
+/// The sequencer response to a fee schedule request at a given height.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FeeScheduleResponse {
+    pub height: u64,
+    pub transfer_base_fee: u128,
+    pub sequence_base_fee: u128,
+    pub sequence_byte_cost_multiplier: u128,
+    pub ics20_withdrawal_base_fee: u128,
+}
+
+impl FeeScheduleResponse {
+    /// Converts a protobuf [`raw::FeeScheduleResponse`] to an astria
+    /// native [`FeeScheduleResponse`].
+    ///
+    /// Unset fees are interpreted as zero.
+    #[must_use]
+    pub fn from_raw(proto: &raw::FeeScheduleResponse) -> Self {
+        let raw::FeeScheduleResponse {
+            height,
+            transfer_base_fee,
+            sequence_base_fee,
+            sequence_byte_cost_multiplier,
+            ics20_withdrawal_base_fee,
+        } = proto;
+        Self {
+            height: *height,
+            transfer_base_fee: transfer_base_fee.map_or(0, Into::into),
+            sequence_base_fee: sequence_base_fee.map_or(0, Into::into),
+            sequence_byte_cost_multiplier: sequence_byte_cost_multiplier.map_or(0, Into::into),
+            ics20_withdrawal_base_fee: ics20_withdrawal_base_fee.map_or(0, Into::into),
+        }
+    }
+
+    /// Converts an astria native [`FeeScheduleResponse`] to a
+    /// protobuf [`raw::FeeScheduleResponse`].
+    #[must_use]
+    pub fn into_raw(self) -> raw::FeeScheduleResponse {
+        raw::FeeScheduleResponse::from_native(self)
+    }
+}
+
+impl raw::BlockFeesResponse {
+    /// Converts an astria native [`BlockFeesResponse`] to a
+    /// protobuf [`raw::BlockFeesResponse`].
+    #[must_use]
+    pub fn from_native(native: BlockFeesResponse) -> Self {
+        let BlockFeesResponse {
+            height,
+            fees,
+        } = native;
+        Self {
+            height,
+            fees: fees
+                .into_iter()
+                .map(|(asset_id, amount)| raw::AssetFee {
+                    asset_id: asset_id.as_ref().to_vec(),
+                    amount: Some(amount.into()),
+                })
+                .collect(),
+        }
+    }
+
+    /// Converts a protobuf [`raw::BlockFeesResponse`] to an astria
+    /// native [`BlockFeesResponse`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the asset IDs is not 32 bytes long.
+    pub fn try_into_native(self) -> Result<BlockFeesResponse, BlockFeesResponseError> {
+        BlockFeesResponse::try_from_raw(&self)
+    }
+}
+
+/// The sequencer response to a block fees request at a given height.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BlockFeesResponse {
+    pub height: u64,
+    pub fees: Vec<(asset::Id, u128)>,
+}
+
+impl BlockFeesResponse {
+    /// Converts a protobuf [`raw::BlockFeesResponse`] to an astria
+    /// native [`BlockFeesResponse`].
+    ///
+    /// Unset amounts are interpreted as zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the asset IDs is not 32 bytes long.
+    pub fn try_from_raw(proto: &raw::BlockFeesResponse) -> Result<Self, BlockFeesResponseError> {
+        let raw::BlockFeesResponse {
+            height,
+            fees,
+        } = proto;
+        let fees = fees
+            .iter()
+            .map(|fee| -> Result<_, BlockFeesResponseError> {
+                let asset_id =
+                    asset::Id::try_from_slice(&fee.asset_id).map_err(BlockFeesResponseError)?;
+                Ok((asset_id, fee.amount.map_or(0, Into::into)))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            height: *height,
+            fees,
+        })
+    }
+
+    /// Converts an astria native [`BlockFeesResponse`] to a
+    /// protobuf [`raw::BlockFeesResponse`].
+    #[must_use]
+    pub fn into_raw(self) -> raw::BlockFeesResponse {
+        raw::BlockFeesResponse::from_native(self)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("`fees` field contained an invalid asset ID")]
+pub struct BlockFeesResponseError(#[source] asset::IncorrectAssetIdLength);
+
+impl raw::AllowedFeeAssetsResponse {
+    /// Converts an astria native [`AllowedFeeAssetsResponse`] to a
+    /// protobuf [`raw::AllowedFeeAssetsResponse`].
+    #[must_use]
+    pub fn from_native(native: AllowedFeeAssetsResponse) -> Self {
+        let AllowedFeeAssetsResponse {
+            height,
+            fee_asset_ids,
+        } = native;
+        Self {
+            height,
+            fee_asset_ids: fee_asset_ids
+                .into_iter()
+                .map(|asset_id| asset_id.as_ref().to_vec())
+                .collect(),
+        }
+    }
+
+    /// Converts a protobuf [`raw::AllowedFeeAssetsResponse`] to an astria
+    /// native [`AllowedFeeAssetsResponse`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the asset IDs is not 32 bytes long.
+    pub fn try_into_native(
+        self,
+    ) -> Result<AllowedFeeAssetsResponse, AllowedFeeAssetsResponseError> {
+        AllowedFeeAssetsResponse::try_from_raw(&self)
+    }
+}
+
+/// The sequencer response to an allowed fee assets request at a given height.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AllowedFeeAssetsResponse {
+    pub height: u64,
+    pub fee_asset_ids: Vec<asset::Id>,
+}
+
+impl AllowedFeeAssetsResponse {
+    /// Converts a protobuf [`raw::AllowedFeeAssetsResponse`] to an astria
+    /// native [`AllowedFeeAssetsResponse`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the asset IDs is not 32 bytes long.
+    pub fn try_from_raw(
+        proto: &raw::AllowedFeeAssetsResponse,
+    ) -> Result<Self, AllowedFeeAssetsResponseError> {
+        let raw::AllowedFeeAssetsResponse {
+            height,
+            fee_asset_ids,
+        } = proto;
+        let fee_asset_ids = fee_asset_ids
+            .iter()
+            .map(|asset_id| asset::Id::try_from_slice(asset_id))
+            .collect::<Result<_, _>>()
+            .map_err(AllowedFeeAssetsResponseError)?;
+        Ok(Self {
+            height: *height,
+            fee_asset_ids,
+        })
+    }
+
+    /// Converts an astria native [`AllowedFeeAssetsResponse`] to a
+    /// protobuf [`raw::AllowedFeeAssetsResponse`].
+    #[must_use]
+    pub fn into_raw(self) -> raw::AllowedFeeAssetsResponse {
+        raw::AllowedFeeAssetsResponse::from_native(self)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("`fee_asset_ids` field contained an invalid asset ID")]
+pub struct AllowedFeeAssetsResponseError(#[source] asset::IncorrectAssetIdLength);
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        asset::default_native_asset_id,
+        AllowedFeeAssetsResponse,
+        BlockFeesResponse,
+        FeeScheduleResponse,
+    };
+
+    #[test]
+    fn fee_schedule_roundtrip_is_correct() {
+        let expected = FeeScheduleResponse {
+            height: 42,
+            transfer_base_fee: 12,
+            sequence_base_fee: 32,
+            sequence_byte_cost_multiplier: 1,
+            ics20_withdrawal_base_fee: 24,
+        };
+        let actual = expected.into_raw().into_native();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn block_fees_roundtrip_is_correct() {
+        let expected = BlockFeesResponse {
+            height: 42,
+            fees: vec![(default_native_asset_id(), 100)],
+        };
+        let actual = expected.clone().into_raw().try_into_native().unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn allowed_fee_assets_roundtrip_is_correct() {
+        let expected = AllowedFeeAssetsResponse {
+            height: 42,
+            fee_asset_ids: vec![default_native_asset_id()],
+        };
+        let actual = expected.clone().into_raw().try_into_native().unwrap();
+        assert_eq!(expected, actual);
+    }
+}
@@ -0,0 +1,327 @@
+use ibc_types::{
+    core::channel::ChannelId,
+    IdentifierError,
+};
+
+use super::{
+    asset,
+    raw,
+    IncorrectRollupIdLength,
+    RollupId,
+};
+
+/// The key in the memo of an ICS20 transfer under which the transfer is routed to a rollup.
+const ROLLUP_DEPOSIT_MEMO_KEY: &str = "rollup_deposit";
+
+/// A rate limit on the ICS20 transfers of an asset over a channel.
+///
+/// The net amount transferred in either direction is limited within fixed windows of
+/// `window_blocks` blocks, where a window starts at every height divisible by
+/// `window_blocks`.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Ics20RateLimit {
+    /// The maximum net amount transferred into the sequencer in a window.
+    pub max_inflow: u128,
+    /// The maximum net amount transferred out of the sequencer in a window.
+    pub max_outflow: u128,
+    /// The length of a window in blocks.
+    pub window_blocks: u64,
+}
+
+impl Ics20RateLimit {
+    /// Converts an astria native [`Ics20RateLimit`] to a protobuf [`raw::Ics20RateLimit`].
+    #[must_use]
+    pub fn into_raw(self) -> raw::Ics20RateLimit {
+        let Self {
+            max_inflow,
+            max_outflow,
+            window_blocks,
+        } = self;
+        raw::Ics20RateLimit {
+            max_inflow: Some(max_inflow.into()),
+            max_outflow: Some(max_outflow.into()),
+            window_blocks,
+        }
+    }
+
+    /// Converts a protobuf [`raw::Ics20RateLimit`] to an astria native [`Ics20RateLimit`].
+    #[must_use]
+    pub fn from_raw(proto: raw::Ics20RateLimit) -> Self {
+        let raw::Ics20RateLimit {
+            max_inflow,
+            max_outflow,
+            window_blocks,
+        } = proto;
+        Self {
+            max_inflow: max_inflow.map_or(0, Into::into),
+            max_outflow: max_outflow.map_or(0, Into::into),
+            window_blocks,
+        }
+    }
+}
+
+impl raw::Ics20RateLimitResponse {
+    /// Converts an astria native [`Ics20RateLimitResponse`] to a
+    /// protobuf [`raw::Ics20RateLimitResponse`].
+    #[must_use]
+    pub fn from_native(native: Ics20RateLimitResponse) -> Self {
+        let Ics20RateLimitResponse {
+            height,
+            channel,
+            asset_id,
+            rate_limit,
+            window_start_height,
+            inflow,
+            outflow,
+        } = native;
+        Self {
+            height,
+            channel: channel.to_string(),
+            asset_id: asset_id.as_ref().to_vec(),
+            rate_limit: rate_limit.map(Ics20RateLimit::into_raw),
+            window_start_height,
+            inflow: Some(inflow.into()),
+            outflow: Some(outflow.into()),
+        }
+    }
+
+    /// Converts a protobuf [`raw::Ics20RateLimitResponse`] to an astria
+    /// native [`Ics20RateLimitResponse`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the channel ID or the asset ID are invalid.
+    pub fn try_into_native(self) -> Result<Ics20RateLimitResponse, Ics20RateLimitResponseError> {
+        Ics20RateLimitResponse::try_from_raw(self)
+    }
+}
+
+/// The sequencer response to a request for the rate limit of the ICS20 transfers of an
+/// asset over a channel at a given height.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Ics20RateLimitResponse {
+    pub height: u64,
+    pub channel: ChannelId,
+    pub asset_id: asset::Id,
+    /// The rate limit, or `None` if the transfers are not rate limited.
+    pub rate_limit: Option<Ics20RateLimit>,
+    /// The height of the first block of the current window.
+    pub window_start_height: u64,
+    /// The amount transferred into the sequencer in the current window.
+    pub inflow: u128,
+    /// The amount transferred out of the sequencer in the current window.
+    pub outflow: u128,
+}
+
+impl Ics20RateLimitResponse {
+    /// Converts a protobuf [`raw::Ics20RateLimitResponse`] to an astria
+    /// native [`Ics20RateLimitResponse`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the channel ID or the asset ID are invalid.
+    pub fn try_from_raw(
+        proto: raw::Ics20RateLimitResponse,
+    ) -> Result<Self, Ics20RateLimitResponseError> {
+        let raw::Ics20RateLimitResponse {
+            height,
+            channel,
+            asset_id,
+            rate_limit,
+            window_start_height,
+            inflow,
+            outflow,
+        } = proto;
+        let channel = channel
+            .parse()
+            .map_err(Ics20RateLimitResponseError::channel)?;
+        let asset_id =
+            asset::Id::try_from_slice(&asset_id).map_err(Ics20RateLimitResponseError::asset_id)?;
+        Ok(Self {
+            height,
+            channel,
+            asset_id,
+            rate_limit: rate_limit.map(Ics20RateLimit::from_raw),
+            window_start_height,
+            inflow: inflow.map_or(0, Into::into),
+            outflow: outflow.map_or(0, Into::into),
+        })
+    }
+
+    /// Converts an astria native [`Ics20RateLimitResponse`] to a
+    /// protobuf [`raw::Ics20RateLimitResponse`].
+    #[must_use]
+    pub fn into_raw(self) -> raw::Ics20RateLimitResponse {
+        raw::Ics20RateLimitResponse::from_native(self)
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct Ics20RateLimitResponseError(Ics20RateLimitResponseErrorKind);
+
+impl Ics20RateLimitResponseError {
+    fn channel(inner: IdentifierError) -> Self {
+        Self(Ics20RateLimitResponseErrorKind::Channel(inner))
+    }
+
+    fn asset_id(inner: asset::IncorrectAssetIdLength) -> Self {
+        Self(Ics20RateLimitResponseErrorKind::AssetId(inner))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum Ics20RateLimitResponseErrorKind {
+    #[error("`channel` field did not contain a valid channel ID")]
+    Channel(#[source] IdentifierError),
+    #[error("`asset_id` field did not contain a valid asset ID")]
+    AssetId(#[source] asset::IncorrectAssetIdLength),
+}
+
+/// The routing of an incoming ICS20 transfer to a rollup.
+///
+/// A transfer is routed to a rollup if its memo is a JSON object of the form
+/// `{"rollup_deposit": {"rollup_id": "<hex encoded rollup ID>", "rollup_address": "<address>"}}`,
+/// where `rollup_address` is the address on the rollup to credit the transfer to. The
+/// receiver of the transfer must be a bridge account registered for the rollup, which
+/// holds the transferred funds on the sequencer.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Ics20DepositMemo {
+    pub rollup_id: RollupId,
+    pub rollup_address: String,
+}
+
+impl Ics20DepositMemo {
+    /// Parses the memo of an ICS20 transfer.
+    ///
+    /// Returns `None` if the memo does not route the transfer to a rollup, which includes
+    /// memos that are not JSON objects.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the memo contains a `rollup_deposit` object that is invalid.
+    pub fn parse(memo: &str) -> Result<Option<Self>, Ics20DepositMemoError> {
+        let Ok(serde_json::Value::Object(mut memo)) = serde_json::from_str(memo) else {
+            return Ok(None);
+        };
+        let Some(deposit) = memo.remove(ROLLUP_DEPOSIT_MEMO_KEY) else {
+            return Ok(None);
+        };
+        let rollup_id = deposit
+            .get("rollup_id")
+            .and_then(serde_json::Value::as_str)
+            .ok_or(Ics20DepositMemoError::missing_field("rollup_id"))?;
+        let rollup_id = hex::decode(rollup_id).map_err(Ics20DepositMemoError::rollup_id_hex)?;
+        let rollup_id =
+            RollupId::try_from_vec(rollup_id).map_err(Ics20DepositMemoError::rollup_id)?;
+        let rollup_address = deposit
+            .get("rollup_address")
+            .and_then(serde_json::Value::as_str)
+            .filter(|address| !address.is_empty())
+            .ok_or(Ics20DepositMemoError::missing_field("rollup_address"))?;
+        Ok(Some(Self {
+            rollup_id,
+            rollup_address: rollup_address.to_string(),
+        }))
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct Ics20DepositMemoError(Ics20DepositMemoErrorKind);
+
+impl Ics20DepositMemoError {
+    fn missing_field(field: &'static str) -> Self {
+        Self(Ics20DepositMemoErrorKind::MissingField(field))
+    }
+
+    fn rollup_id_hex(inner: hex::FromHexError) -> Self {
+        Self(Ics20DepositMemoErrorKind::RollupIdHex(inner))
+    }
+
+    fn rollup_id(inner: IncorrectRollupIdLength) -> Self {
+        Self(Ics20DepositMemoErrorKind::RollupId(inner))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum Ics20DepositMemoErrorKind {
+    #[error("`{0}` field of the rollup deposit memo was missing or empty")]
+    MissingField(&'static str),
+    #[error("`rollup_id` field of the rollup deposit memo was not hex encoded")]
+    RollupIdHex(#[source] hex::FromHexError),
+    #[error("`rollup_id` field of the rollup deposit memo did not contain a valid rollup ID")]
+    RollupId(#[source] IncorrectRollupIdLength),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        asset,
+        Ics20DepositMemo,
+        Ics20RateLimit,
+        Ics20RateLimitResponse,
+        RollupId,
+    };
+
+    #[test]
+    fn ics20_rate_limit_response_roundtrip_is_correct() {
+        let expected = Ics20RateLimitResponse {
+            height: 42,
+            channel: "channel-0".parse().unwrap(),
+            asset_id: asset::Id::from_denom("transfer/channel-0/utia"),
+            rate_limit: Some(Ics20RateLimit {
+                max_inflow: 1_000,
+                max_outflow: 500,
+                window_blocks: 100,
+            }),
+            window_start_height: 0,
+            inflow: 300,
+            outflow: 200,
+        };
+        let actual = expected.clone().into_raw().try_into_native().unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn ics20_deposit_memo_is_parsed() {
+        let rollup_id = RollupId::from_unhashed_bytes("test-rollup");
+        let memo = format!(
+            r#"{{"rollup_deposit": {{"rollup_id": "{rollup_id}", "rollup_address": "0xabc"}}}}"#
+        );
+        assert_eq!(
+            Ics20DepositMemo::parse(&memo).unwrap(),
+            Some(Ics20DepositMemo {
+                rollup_id,
+                rollup_address: "0xabc".to_string(),
+            }),
+        );
+    }
+
+    #[test]
+    fn ics20_memo_without_rollup_deposit_is_not_routed() {
+        assert_eq!(Ics20DepositMemo::parse("").unwrap(), None);
+        assert_eq!(Ics20DepositMemo::parse("a plain memo").unwrap(), None);
+        assert_eq!(
+            Ics20DepositMemo::parse(r#"{"forward": {"receiver": "abc"}}"#).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn invalid_ics20_deposit_memo_is_rejected() {
+        Ics20DepositMemo::parse(r#"{"rollup_deposit": {"rollup_address": "0xabc"}}"#).unwrap_err();
+        Ics20DepositMemo::parse(
+            r#"{"rollup_deposit": {"rollup_id": "abcd", "rollup_address": "0xabc"}}"#,
+        )
+        .unwrap_err();
+        let rollup_id = RollupId::from_unhashed_bytes("test-rollup");
+        let memo = format!(r#"{{"rollup_deposit": {{"rollup_id": "{rollup_id}"}}}}"#);
+        Ics20DepositMemo::parse(&memo).unwrap_err();
+    }
+}
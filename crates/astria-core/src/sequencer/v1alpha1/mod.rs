@@ -12,8 +12,14 @@ use crate::{
 pub mod abci;
 pub mod account;
 pub mod asset;
+pub mod authority;
 pub mod block;
 pub mod celestia;
+pub mod chain;
+pub mod event;
+pub mod fee;
+pub mod ibc;
+pub mod rollup;
 #[cfg(feature = "test-utils")]
 pub mod test_utils;
 pub mod transaction;
@@ -23,7 +29,21 @@ pub use account::{
     BalanceResponse,
     NonceResponse,
 };
+pub use asset::{
+    AssetMetadataResponse,
+    AssetSupplyResponse,
+    DenomResponse,
+};
+pub use authority::{
+    IbcRelayerAddressesResponse,
+    SudoAddressResponse,
+    ValidatorSetResponse,
+};
 pub use block::{
+    Deposit,
+    Ics20Deposit,
+    RollupData,
+    RollupTransactionsWithProof,
     SequencerBlock,
     UncheckedSequencerBlock,
 };
@@ -31,15 +51,31 @@ pub use celestia::{
     CelestiaRollupBlob,
     CelestiaSequencerBlob,
 };
+pub use chain::ChainIdResponse;
+pub use fee::{
+    AllowedFeeAssetsResponse,
+    BlockFeesResponse,
+    FeeScheduleResponse,
+};
+pub use ibc::{
+    Ics20DepositMemo,
+    Ics20RateLimit,
+    Ics20RateLimitResponse,
+};
+pub use rollup::{
+    RollupRecord,
+    RollupResponse,
+};
 pub use transaction::{
     SignedTransaction,
+    TransactionSignature,
     UnsignedTransaction,
 };
 
 pub const ADDRESS_LEN: usize = 20;
 pub const ROLLUP_ID_LEN: usize = 32;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Address(pub [u8; ADDRESS_LEN]);
 
 impl Address {
@@ -320,19 +356,41 @@ where
     tree
 }
 
+/// Groups the data destined for rollups in `signed_transactions` by rollup ID.
+///
+/// The data of every `SequenceAction`, the deposit resulting from every
+/// `BridgeLockAction` and the deposit resulting from every received ICS20 transfer
+/// routed to a rollup are encoded as [`raw::RollupData`], in the order in which
+/// they appear in the transactions. The map is sorted by rollup ID.
 // TODO: This can all be done in-place once https://github.com/rust-lang/rust/issues/80552 is stabilized.
-pub fn group_sequence_actions_in_signed_transaction_transactions_by_rollup_id(
+pub fn group_rollup_data_in_signed_transactions_by_rollup_id(
     signed_transactions: &[SignedTransaction],
 ) -> IndexMap<RollupId, Vec<Vec<u8>>> {
+    use penumbra_ibc::IbcRelay;
+    use prost::Message as _;
+    use transaction::action::Action;
+
     let mut map = IndexMap::new();
     for action in signed_transactions
         .iter()
         .flat_map(SignedTransaction::actions)
     {
-        if let Some(action) = action.as_sequence() {
-            let txs_for_rollup: &mut Vec<Vec<u8>> = map.entry(action.rollup_id).or_insert(vec![]);
-            txs_for_rollup.push(action.data.clone());
-        }
+        let (rollup_id, rollup_data) = match action {
+            Action::Sequence(act) => (act.rollup_id, RollupData::SequencedData(act.data.clone())),
+            Action::BridgeLock(act) => (
+                act.rollup_id,
+                RollupData::Deposit(Deposit::from_bridge_lock(act)),
+            ),
+            Action::Ibc(IbcRelay::RecvPacket(msg)) => {
+                let Some(deposit) = Ics20Deposit::from_recv_packet(msg) else {
+                    continue;
+                };
+                (deposit.rollup_id, RollupData::Ics20Deposit(deposit))
+            }
+            _ => continue,
+        };
+        let txs_for_rollup: &mut Vec<Vec<u8>> = map.entry(rollup_id).or_insert(vec![]);
+        txs_for_rollup.push(rollup_data.into_raw().encode_to_vec());
     }
     map.sort_unstable_keys();
     map
@@ -0,0 +1,280 @@
+use super::{
+    raw,
+    Address,
+    IncorrectAddressLength,
+    IncorrectRollupIdLength,
+    RollupId,
+};
+
+pub const CELESTIA_NAMESPACE_ID_LEN: usize = 10;
+
+/// The ID of a celestia v0 namespace, which are the last 10 bytes of the namespace.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CelestiaNamespaceId([u8; CELESTIA_NAMESPACE_ID_LEN]);
+
+impl CelestiaNamespaceId {
+    #[must_use]
+    pub const fn new(inner: [u8; CELESTIA_NAMESPACE_ID_LEN]) -> Self {
+        Self(inner)
+    }
+
+    /// Returns the namespace ID rollup data is posted to by default, which are the
+    /// first 10 bytes of the rollup ID.
+    #[must_use]
+    pub fn from_rollup_id(rollup_id: &RollupId) -> Self {
+        let mut inner = [0; CELESTIA_NAMESPACE_ID_LEN];
+        inner.copy_from_slice(&rollup_id.get()[..CELESTIA_NAMESPACE_ID_LEN]);
+        Self(inner)
+    }
+
+    #[must_use]
+    pub const fn get(self) -> [u8; CELESTIA_NAMESPACE_ID_LEN] {
+        self.0
+    }
+
+    #[must_use]
+    pub fn to_vec(&self) -> Vec<u8> {
+        self.0.to_vec()
+    }
+
+    /// Converts a byte slice to a namespace ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the byte slice was not 10 bytes long.
+    pub fn try_from_slice(bytes: &[u8]) -> Result<Self, IncorrectNamespaceIdLength> {
+        let inner = <[u8; CELESTIA_NAMESPACE_ID_LEN]>::try_from(bytes).map_err(|_| {
+            IncorrectNamespaceIdLength {
+                received: bytes.len(),
+            }
+        })?;
+        Ok(Self(inner))
+    }
+}
+
+impl AsRef<[u8]> for CelestiaNamespaceId {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+/// Indicates that a protobuf field did not contain a 10 byte namespace ID.
+#[derive(Debug, thiserror::Error)]
+#[error("expected 10 bytes, got {received}")]
+pub struct IncorrectNamespaceIdLength {
+    received: usize,
+}
+
+/// The record of a rollup in the sequencer's rollup registry.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RollupRecord {
+    /// The name of the rollup, from which its rollup ID is derived.
+    pub name: String,
+    /// The address authorized to change the rollup's owner and metadata.
+    pub owner: Address,
+    /// The celestia namespace the rollup's data is posted to.
+    pub celestia_namespace: CelestiaNamespaceId,
+    /// Free-form metadata describing the rollup.
+    pub metadata: String,
+}
+
+impl RollupRecord {
+    /// Converts a protobuf [`raw::RollupRecord`] to an astria native [`RollupRecord`].
+    ///
+    /// # Errors
+    ///
+    /// - if the `owner` field is not 20 bytes
+    /// - if the `celestia_namespace` field is not 10 bytes
+    pub fn try_from_raw(proto: raw::RollupRecord) -> Result<Self, RollupRecordError> {
+        let raw::RollupRecord {
+            name,
+            owner,
+            celestia_namespace,
+            metadata,
+        } = proto;
+        let owner = Address::try_from_slice(&owner).map_err(RollupRecordError::owner)?;
+        let celestia_namespace = CelestiaNamespaceId::try_from_slice(&celestia_namespace)
+            .map_err(RollupRecordError::celestia_namespace)?;
+        Ok(Self {
+            name,
+            owner,
+            celestia_namespace,
+            metadata,
+        })
+    }
+
+    /// Converts an astria native [`RollupRecord`] to a protobuf [`raw::RollupRecord`].
+    #[must_use]
+    pub fn into_raw(self) -> raw::RollupRecord {
+        let Self {
+            name,
+            owner,
+            celestia_namespace,
+            metadata,
+        } = self;
+        raw::RollupRecord {
+            name,
+            owner: owner.to_vec(),
+            celestia_namespace: celestia_namespace.to_vec(),
+            metadata,
+        }
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct RollupRecordError(RollupRecordErrorKind);
+
+impl RollupRecordError {
+    fn owner(inner: IncorrectAddressLength) -> Self {
+        Self(RollupRecordErrorKind::Owner(inner))
+    }
+
+    fn celestia_namespace(inner: IncorrectNamespaceIdLength) -> Self {
+        Self(RollupRecordErrorKind::CelestiaNamespace(inner))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum RollupRecordErrorKind {
+    #[error("`owner` field did not contain a valid address")]
+    Owner(#[source] IncorrectAddressLength),
+    #[error("`celestia_namespace` field did not contain a valid namespace ID")]
+    CelestiaNamespace(#[source] IncorrectNamespaceIdLength),
+}
+
+impl raw::RollupResponse {
+    /// Converts an astria native [`RollupResponse`] to a
+    /// protobuf [`raw::RollupResponse`].
+    #[must_use]
+    pub fn from_native(native: RollupResponse) -> Self {
+        let RollupResponse {
+            height,
+            rollup_id,
+            rollup,
+        } = native;
+        Self {
+            height,
+            rollup_id: rollup_id.to_vec(),
+            rollup: rollup.map(RollupRecord::into_raw),
+        }
+    }
+
+    /// Converts a protobuf [`raw::RollupResponse`] to an astria
+    /// native [`RollupResponse`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the rollup ID or the rollup record are invalid.
+    pub fn try_into_native(self) -> Result<RollupResponse, RollupResponseError> {
+        RollupResponse::try_from_raw(self)
+    }
+}
+
+/// The sequencer response to a rollup registry request at a given height.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RollupResponse {
+    pub height: u64,
+    pub rollup_id: RollupId,
+    /// The rollup's registry record, or `None` if the rollup is not registered.
+    pub rollup: Option<RollupRecord>,
+}
+
+impl RollupResponse {
+    /// Converts a protobuf [`raw::RollupResponse`] to an astria
+    /// native [`RollupResponse`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the rollup ID or the rollup record are invalid.
+    pub fn try_from_raw(proto: raw::RollupResponse) -> Result<Self, RollupResponseError> {
+        let raw::RollupResponse {
+            height,
+            rollup_id,
+            rollup,
+        } = proto;
+        let rollup_id =
+            RollupId::try_from_vec(rollup_id).map_err(RollupResponseError::rollup_id)?;
+        let rollup = rollup
+            .map(RollupRecord::try_from_raw)
+            .transpose()
+            .map_err(RollupResponseError::rollup)?;
+        Ok(Self {
+            height,
+            rollup_id,
+            rollup,
+        })
+    }
+
+    /// Converts an astria native [`RollupResponse`] to a
+    /// protobuf [`raw::RollupResponse`].
+    #[must_use]
+    pub fn into_raw(self) -> raw::RollupResponse {
+        raw::RollupResponse::from_native(self)
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct RollupResponseError(RollupResponseErrorKind);
+
+impl RollupResponseError {
+    fn rollup_id(inner: IncorrectRollupIdLength) -> Self {
+        Self(RollupResponseErrorKind::RollupId(inner))
+    }
+
+    fn rollup(inner: RollupRecordError) -> Self {
+        Self(RollupResponseErrorKind::Rollup(inner))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum RollupResponseErrorKind {
+    #[error("`rollup_id` field did not contain a valid rollup ID")]
+    RollupId(#[source] IncorrectRollupIdLength),
+    #[error("`rollup` field did not contain a valid rollup record")]
+    Rollup(#[source] RollupRecordError),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        Address,
+        CelestiaNamespaceId,
+        RollupId,
+        RollupRecord,
+        RollupResponse,
+    };
+
+    #[test]
+    fn rollup_response_roundtrip_is_correct() {
+        let rollup_id = RollupId::from_unhashed_bytes("test-rollup");
+        let expected = RollupResponse {
+            height: 42,
+            rollup_id,
+            rollup: Some(RollupRecord {
+                name: "test-rollup".to_string(),
+                owner: Address::from([1; 20]),
+                celestia_namespace: CelestiaNamespaceId::from_rollup_id(&rollup_id),
+                metadata: "{\"chain_id\":1337}".to_string(),
+            }),
+        };
+        let actual = expected.clone().into_raw().try_into_native().unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn unregistered_rollup_response_roundtrip_is_correct() {
+        let expected = RollupResponse {
+            height: 42,
+            rollup_id: RollupId::from_unhashed_bytes("test-rollup"),
+            rollup: None,
+        };
+        let actual = expected.clone().into_raw().try_into_native().unwrap();
+        assert_eq!(expected, actual);
+    }
+}
@@ -6,7 +6,7 @@ use super::{
     // FIXME(github....): bring these assets under v1alpha1
     asset::default_native_asset_id,
     derive_merkle_tree_from_rollup_txs,
-    group_sequence_actions_in_signed_transaction_transactions_by_rollup_id,
+    group_rollup_data_in_signed_transactions_by_rollup_id,
     transaction::action::SequenceAction,
     RollupId,
     UnsignedTransaction,
@@ -85,24 +85,18 @@ impl ConfigureCometBftBlock {
         let unsigned_transaction = UnsignedTransaction {
             nonce: 1,
             actions,
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
         };
 
         let signed_transaction = unsigned_transaction.into_signed(&signing_key);
         let rollup_transactions =
-            group_sequence_actions_in_signed_transaction_transactions_by_rollup_id(&[
-                signed_transaction.clone(),
-            ]);
+            group_rollup_data_in_signed_transactions_by_rollup_id(&[signed_transaction.clone()]);
         let rollup_transactions_tree = derive_merkle_tree_from_rollup_txs(&rollup_transactions);
 
-        let rollup_ids_root = merkle::Tree::from_leaves(
-            signed_transaction
-                .unsigned_transaction()
-                .actions
-                .iter()
-                .filter_map(|act| act.as_sequence())
-                .map(|seq| seq.rollup_id),
-        )
-        .root();
+        let rollup_ids_root = merkle::Tree::from_leaves(rollup_transactions.keys()).root();
         let data = vec![
             rollup_transactions_tree.root().to_vec(),
             rollup_ids_root.to_vec(),
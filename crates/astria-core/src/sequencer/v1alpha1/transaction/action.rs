@@ -1,3 +1,8 @@
+use ed25519_consensus::{
+    Signature,
+    SigningKey,
+    VerificationKey,
+};
 use ibc_types::{
     core::{
         channel::ChannelId,
@@ -15,7 +20,12 @@ use crate::{
             self,
             Denom,
         },
+        rollup::{
+            CelestiaNamespaceId,
+            IncorrectNamespaceIdLength,
+        },
         Address,
+        Ics20RateLimit,
         IncorrectAddressLength,
         IncorrectRollupIdLength,
         RollupId,
@@ -32,6 +42,22 @@ pub enum Action {
     Mint(MintAction),
     Ibc(IbcRelay),
     Ics20Withdrawal(Ics20Withdrawal),
+    FeeChange(FeeChangeAction),
+    FeeAssetChange(FeeAssetChangeAction),
+    InitBridgeAccount(InitBridgeAccountAction),
+    BridgeLock(BridgeLockAction),
+    BridgeUnlock(BridgeUnlockAction),
+    IbcRelayerChange(IbcRelayerChangeAction),
+    IbcSudoChange(IbcSudoChangeAction),
+    ScheduleUpgrade(ScheduleUpgradeAction),
+    RegisterRollup(RegisterRollupAction),
+    RollupOwnerChange(RollupOwnerChangeAction),
+    RollupMetadataChange(RollupMetadataChangeAction),
+    AssetMetadataChange(AssetMetadataChangeAction),
+    AuthorizeSessionKey(AuthorizeSessionKeyAction),
+    RevokeSessionKey(RevokeSessionKeyAction),
+    Ics20ChannelChange(Ics20ChannelChangeAction),
+    Ics20RateLimitChange(Ics20RateLimitChangeAction),
 }
 
 impl Action {
@@ -46,6 +72,22 @@ impl Action {
             Action::Mint(act) => Value::MintAction(act.into_raw()),
             Action::Ibc(act) => Value::IbcAction(act.into()),
             Action::Ics20Withdrawal(act) => Value::Ics20Withdrawal(act.into_raw()),
+            Action::FeeChange(act) => Value::FeeChangeAction(act.into_raw()),
+            Action::FeeAssetChange(act) => Value::FeeAssetChangeAction(act.into_raw()),
+            Action::InitBridgeAccount(act) => Value::InitBridgeAccountAction(act.into_raw()),
+            Action::BridgeLock(act) => Value::BridgeLockAction(act.into_raw()),
+            Action::BridgeUnlock(act) => Value::BridgeUnlockAction(act.into_raw()),
+            Action::IbcRelayerChange(act) => Value::IbcRelayerChangeAction(act.into_raw()),
+            Action::IbcSudoChange(act) => Value::IbcSudoChangeAction(act.into_raw()),
+            Action::ScheduleUpgrade(act) => Value::ScheduleUpgradeAction(act.into_raw()),
+            Action::RegisterRollup(act) => Value::RegisterRollupAction(act.into_raw()),
+            Action::RollupOwnerChange(act) => Value::RollupOwnerChangeAction(act.into_raw()),
+            Action::RollupMetadataChange(act) => Value::RollupMetadataChangeAction(act.into_raw()),
+            Action::AssetMetadataChange(act) => Value::AssetMetadataChangeAction(act.into_raw()),
+            Action::AuthorizeSessionKey(act) => Value::AuthorizeSessionKeyAction(act.into_raw()),
+            Action::RevokeSessionKey(act) => Value::RevokeSessionKeyAction(act.into_raw()),
+            Action::Ics20ChannelChange(act) => Value::Ics20ChannelChangeAction(act.into_raw()),
+            Action::Ics20RateLimitChange(act) => Value::Ics20RateLimitChangeAction(act.into_raw()),
         };
         raw::Action {
             value: Some(kind),
@@ -65,6 +107,22 @@ impl Action {
             Action::Mint(act) => Value::MintAction(act.to_raw()),
             Action::Ibc(act) => Value::IbcAction(act.clone().into()),
             Action::Ics20Withdrawal(act) => Value::Ics20Withdrawal(act.to_raw()),
+            Action::FeeChange(act) => Value::FeeChangeAction(act.to_raw()),
+            Action::FeeAssetChange(act) => Value::FeeAssetChangeAction(act.to_raw()),
+            Action::InitBridgeAccount(act) => Value::InitBridgeAccountAction(act.to_raw()),
+            Action::BridgeLock(act) => Value::BridgeLockAction(act.to_raw()),
+            Action::BridgeUnlock(act) => Value::BridgeUnlockAction(act.to_raw()),
+            Action::IbcRelayerChange(act) => Value::IbcRelayerChangeAction(act.to_raw()),
+            Action::IbcSudoChange(act) => Value::IbcSudoChangeAction(act.to_raw()),
+            Action::ScheduleUpgrade(act) => Value::ScheduleUpgradeAction(act.to_raw()),
+            Action::RegisterRollup(act) => Value::RegisterRollupAction(act.to_raw()),
+            Action::RollupOwnerChange(act) => Value::RollupOwnerChangeAction(act.to_raw()),
+            Action::RollupMetadataChange(act) => Value::RollupMetadataChangeAction(act.to_raw()),
+            Action::AssetMetadataChange(act) => Value::AssetMetadataChangeAction(act.to_raw()),
+            Action::AuthorizeSessionKey(act) => Value::AuthorizeSessionKeyAction(act.to_raw()),
+            Action::RevokeSessionKey(act) => Value::RevokeSessionKeyAction(act.to_raw()),
+            Action::Ics20ChannelChange(act) => Value::Ics20ChannelChangeAction(act.to_raw()),
+            Action::Ics20RateLimitChange(act) => Value::Ics20RateLimitChangeAction(act.to_raw()),
         };
         raw::Action {
             value: Some(kind),
@@ -108,6 +166,63 @@ impl Action {
             Value::Ics20Withdrawal(act) => Self::Ics20Withdrawal(
                 Ics20Withdrawal::try_from_raw(act).map_err(ActionError::ics20_withdrawal)?,
             ),
+            Value::FeeChangeAction(act) => Self::FeeChange(
+                FeeChangeAction::try_from_raw(act).map_err(ActionError::fee_change)?,
+            ),
+            Value::FeeAssetChangeAction(act) => Self::FeeAssetChange(
+                FeeAssetChangeAction::try_from_raw(act).map_err(ActionError::fee_asset_change)?,
+            ),
+            Value::InitBridgeAccountAction(act) => Self::InitBridgeAccount(
+                InitBridgeAccountAction::try_from_raw(act)
+                    .map_err(ActionError::init_bridge_account)?,
+            ),
+            Value::BridgeLockAction(act) => Self::BridgeLock(
+                BridgeLockAction::try_from_raw(act).map_err(ActionError::bridge_lock)?,
+            ),
+            Value::BridgeUnlockAction(act) => Self::BridgeUnlock(
+                BridgeUnlockAction::try_from_raw(act).map_err(ActionError::bridge_unlock)?,
+            ),
+            Value::IbcRelayerChangeAction(act) => Self::IbcRelayerChange(
+                IbcRelayerChangeAction::try_from_raw(act)
+                    .map_err(ActionError::ibc_relayer_change)?,
+            ),
+            Value::IbcSudoChangeAction(act) => Self::IbcSudoChange(
+                IbcSudoChangeAction::try_from_raw(act).map_err(ActionError::ibc_sudo_change)?,
+            ),
+            Value::ScheduleUpgradeAction(act) => {
+                Self::ScheduleUpgrade(ScheduleUpgradeAction::from_raw(act))
+            }
+            Value::RegisterRollupAction(act) => Self::RegisterRollup(
+                RegisterRollupAction::try_from_raw(act).map_err(ActionError::register_rollup)?,
+            ),
+            Value::RollupOwnerChangeAction(act) => Self::RollupOwnerChange(
+                RollupOwnerChangeAction::try_from_raw(act)
+                    .map_err(ActionError::rollup_owner_change)?,
+            ),
+            Value::RollupMetadataChangeAction(act) => Self::RollupMetadataChange(
+                RollupMetadataChangeAction::try_from_raw(act)
+                    .map_err(ActionError::rollup_metadata_change)?,
+            ),
+            Value::AssetMetadataChangeAction(act) => Self::AssetMetadataChange(
+                AssetMetadataChangeAction::try_from_raw(act)
+                    .map_err(ActionError::asset_metadata_change)?,
+            ),
+            Value::AuthorizeSessionKeyAction(act) => Self::AuthorizeSessionKey(
+                AuthorizeSessionKeyAction::try_from_raw(act)
+                    .map_err(ActionError::authorize_session_key)?,
+            ),
+            Value::RevokeSessionKeyAction(act) => Self::RevokeSessionKey(
+                RevokeSessionKeyAction::try_from_raw(act)
+                    .map_err(ActionError::revoke_session_key)?,
+            ),
+            Value::Ics20ChannelChangeAction(act) => Self::Ics20ChannelChange(
+                Ics20ChannelChangeAction::try_from_raw(act)
+                    .map_err(ActionError::ics20_channel_change)?,
+            ),
+            Value::Ics20RateLimitChangeAction(act) => Self::Ics20RateLimitChange(
+                Ics20RateLimitChangeAction::try_from_raw(act)
+                    .map_err(ActionError::ics20_rate_limit_change)?,
+            ),
         };
         Ok(action)
     }
@@ -127,6 +242,14 @@ impl Action {
         };
         Some(transfer_action)
     }
+
+    #[must_use]
+    pub fn as_bridge_lock(&self) -> Option<&BridgeLockAction> {
+        let Self::BridgeLock(bridge_lock_action) = self else {
+            return None;
+        };
+        Some(bridge_lock_action)
+    }
 }
 
 impl From<SequenceAction> for Action {
@@ -165,6 +288,102 @@ impl From<Ics20Withdrawal> for Action {
     }
 }
 
+impl From<FeeChangeAction> for Action {
+    fn from(value: FeeChangeAction) -> Self {
+        Self::FeeChange(value)
+    }
+}
+
+impl From<FeeAssetChangeAction> for Action {
+    fn from(value: FeeAssetChangeAction) -> Self {
+        Self::FeeAssetChange(value)
+    }
+}
+
+impl From<InitBridgeAccountAction> for Action {
+    fn from(value: InitBridgeAccountAction) -> Self {
+        Self::InitBridgeAccount(value)
+    }
+}
+
+impl From<BridgeLockAction> for Action {
+    fn from(value: BridgeLockAction) -> Self {
+        Self::BridgeLock(value)
+    }
+}
+
+impl From<BridgeUnlockAction> for Action {
+    fn from(value: BridgeUnlockAction) -> Self {
+        Self::BridgeUnlock(value)
+    }
+}
+
+impl From<IbcRelayerChangeAction> for Action {
+    fn from(value: IbcRelayerChangeAction) -> Self {
+        Self::IbcRelayerChange(value)
+    }
+}
+
+impl From<IbcSudoChangeAction> for Action {
+    fn from(value: IbcSudoChangeAction) -> Self {
+        Self::IbcSudoChange(value)
+    }
+}
+
+impl From<ScheduleUpgradeAction> for Action {
+    fn from(value: ScheduleUpgradeAction) -> Self {
+        Self::ScheduleUpgrade(value)
+    }
+}
+
+impl From<RegisterRollupAction> for Action {
+    fn from(value: RegisterRollupAction) -> Self {
+        Self::RegisterRollup(value)
+    }
+}
+
+impl From<RollupOwnerChangeAction> for Action {
+    fn from(value: RollupOwnerChangeAction) -> Self {
+        Self::RollupOwnerChange(value)
+    }
+}
+
+impl From<RollupMetadataChangeAction> for Action {
+    fn from(value: RollupMetadataChangeAction) -> Self {
+        Self::RollupMetadataChange(value)
+    }
+}
+
+impl From<AssetMetadataChangeAction> for Action {
+    fn from(value: AssetMetadataChangeAction) -> Self {
+        Self::AssetMetadataChange(value)
+    }
+}
+
+impl From<AuthorizeSessionKeyAction> for Action {
+    fn from(value: AuthorizeSessionKeyAction) -> Self {
+        Self::AuthorizeSessionKey(value)
+    }
+}
+
+impl From<RevokeSessionKeyAction> for Action {
+    fn from(value: RevokeSessionKeyAction) -> Self {
+        Self::RevokeSessionKey(value)
+    }
+}
+
+impl From<Ics20ChannelChangeAction> for Action {
+    fn from(value: Ics20ChannelChangeAction) -> Self {
+        Self::Ics20ChannelChange(value)
+    }
+}
+
+impl From<Ics20RateLimitChangeAction> for Action {
+    fn from(value: Ics20RateLimitChangeAction) -> Self {
+        Self::Ics20RateLimitChange(value)
+    }
+}
+
 #[allow(clippy::module_name_repetitions)]
 #[derive(Debug, thiserror::Error)]
 #[error(transparent)]
@@ -202,6 +421,66 @@ impl ActionError {
     fn ics20_withdrawal(inner: Ics20WithdrawalError) -> Self {
         Self(ActionErrorKind::Ics20Withdrawal(inner))
     }
+
+    fn fee_change(inner: FeeChangeActionError) -> Self {
+        Self(ActionErrorKind::FeeChange(inner))
+    }
+
+    fn fee_asset_change(inner: FeeAssetChangeActionError) -> Self {
+        Self(ActionErrorKind::FeeAssetChange(inner))
+    }
+
+    fn init_bridge_account(inner: InitBridgeAccountActionError) -> Self {
+        Self(ActionErrorKind::InitBridgeAccount(inner))
+    }
+
+    fn bridge_lock(inner: BridgeLockActionError) -> Self {
+        Self(ActionErrorKind::BridgeLock(inner))
+    }
+
+    fn bridge_unlock(inner: BridgeUnlockActionError) -> Self {
+        Self(ActionErrorKind::BridgeUnlock(inner))
+    }
+
+    fn ibc_relayer_change(inner: IbcRelayerChangeActionError) -> Self {
+        Self(ActionErrorKind::IbcRelayerChange(inner))
+    }
+
+    fn ibc_sudo_change(inner: IbcSudoChangeActionError) -> Self {
+        Self(ActionErrorKind::IbcSudoChange(inner))
+    }
+
+    fn register_rollup(inner: RegisterRollupActionError) -> Self {
+        Self(ActionErrorKind::RegisterRollup(inner))
+    }
+
+    fn rollup_owner_change(inner: RollupOwnerChangeActionError) -> Self {
+        Self(ActionErrorKind::RollupOwnerChange(inner))
+    }
+
+    fn rollup_metadata_change(inner: RollupMetadataChangeActionError) -> Self {
+        Self(ActionErrorKind::RollupMetadataChange(inner))
+    }
+
+    fn asset_metadata_change(inner: AssetMetadataChangeActionError) -> Self {
+        Self(ActionErrorKind::AssetMetadataChange(inner))
+    }
+
+    fn authorize_session_key(inner: AuthorizeSessionKeyActionError) -> Self {
+        Self(ActionErrorKind::AuthorizeSessionKey(inner))
+    }
+
+    fn revoke_session_key(inner: RevokeSessionKeyActionError) -> Self {
+        Self(ActionErrorKind::RevokeSessionKey(inner))
+    }
+
+    fn ics20_channel_change(inner: Ics20ChannelChangeActionError) -> Self {
+        Self(ActionErrorKind::Ics20ChannelChange(inner))
+    }
+
+    fn ics20_rate_limit_change(inner: Ics20RateLimitChangeActionError) -> Self {
+        Self(ActionErrorKind::Ics20RateLimitChange(inner))
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -222,6 +501,36 @@ enum ActionErrorKind {
     Ibc(#[source] Box<dyn std::error::Error + Send + Sync>),
     #[error("ics20 withdrawal action was not valid")]
     Ics20Withdrawal(#[source] Ics20WithdrawalError),
+    #[error("fee change action was not valid")]
+    FeeChange(#[source] FeeChangeActionError),
+    #[error("fee asset change action was not valid")]
+    FeeAssetChange(#[source] FeeAssetChangeActionError),
+    #[error("init bridge account action was not valid")]
+    InitBridgeAccount(#[source] InitBridgeAccountActionError),
+    #[error("bridge lock action was not valid")]
+    BridgeLock(#[source] BridgeLockActionError),
+    #[error("bridge unlock action was not valid")]
+    BridgeUnlock(#[source] BridgeUnlockActionError),
+    #[error("ibc relayer change action was not valid")]
+    IbcRelayerChange(#[source] IbcRelayerChangeActionError),
+    #[error("ibc sudo change action was not valid")]
+    IbcSudoChange(#[source] IbcSudoChangeActionError),
+    #[error("register rollup action was not valid")]
+    RegisterRollup(#[source] RegisterRollupActionError),
+    #[error("rollup owner change action was not valid")]
+    RollupOwnerChange(#[source] RollupOwnerChangeActionError),
+    #[error("rollup metadata change action was not valid")]
+    RollupMetadataChange(#[source] RollupMetadataChangeActionError),
+    #[error("asset metadata change action was not valid")]
+    AssetMetadataChange(#[source] AssetMetadataChangeActionError),
+    #[error("authorize session key action was not valid")]
+    AuthorizeSessionKey(#[source] AuthorizeSessionKeyActionError),
+    #[error("revoke session key action was not valid")]
+    RevokeSessionKey(#[source] RevokeSessionKeyActionError),
+    #[error("ics20 channel change action was not valid")]
+    Ics20ChannelChange(#[source] Ics20ChannelChangeActionError),
+    #[error("ics20 rate limit change action was not valid")]
+    Ics20RateLimitChange(#[source] Ics20RateLimitChangeActionError),
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -564,6 +873,8 @@ pub struct Ics20Withdrawal {
     timeout_time: u64,
     // the source channel used for the withdrawal.
     source_channel: ChannelId,
+    // the asset to use for fee payment.
+    fee_asset_id: asset::Id,
 }
 
 impl Ics20Withdrawal {
@@ -602,6 +913,11 @@ impl Ics20Withdrawal {
         &self.source_channel
     }
 
+    #[must_use]
+    pub fn fee_asset_id(&self) -> &asset::Id {
+        &self.fee_asset_id
+    }
+
     #[must_use]
     pub fn to_fungible_token_packet_data(&self) -> FungibleTokenPacketData {
         FungibleTokenPacketData {
@@ -622,6 +938,7 @@ impl Ics20Withdrawal {
             timeout_height: Some(self.timeout_height.into_raw()),
             timeout_time: self.timeout_time,
             source_channel: self.source_channel.to_string(),
+            fee_asset_id: self.fee_asset_id.as_ref().to_vec(),
         }
     }
 
@@ -635,6 +952,7 @@ impl Ics20Withdrawal {
             timeout_height: Some(self.timeout_height.into_raw()),
             timeout_time: self.timeout_time,
             source_channel: self.source_channel.to_string(),
+            fee_asset_id: self.fee_asset_id.as_ref().to_vec(),
         }
     }
 
@@ -647,6 +965,7 @@ impl Ics20Withdrawal {
     /// - if the `return_address` field is invalid
     /// - if the `timeout_height` field is missing
     /// - if the `source_channel` field is invalid
+    /// - if the `fee_asset_id` field is invalid
     pub fn try_from_raw(proto: raw::Ics20Withdrawal) -> Result<Self, Ics20WithdrawalError> {
         let amount = proto.amount.ok_or(Ics20WithdrawalError::missing_amount())?;
         let return_address = Address::try_from_slice(&proto.return_address)
@@ -655,6 +974,8 @@ impl Ics20Withdrawal {
             .timeout_height
             .ok_or(Ics20WithdrawalError::missing_timeout_height())?
             .into();
+        let fee_asset_id = asset::Id::try_from_slice(&proto.fee_asset_id)
+            .map_err(Ics20WithdrawalError::invalid_fee_asset_id)?;
 
         Ok(Self {
             amount: amount.into(),
@@ -667,6 +988,7 @@ impl Ics20Withdrawal {
                 .source_channel
                 .parse()
                 .map_err(Ics20WithdrawalError::invalid_source_channel)?,
+            fee_asset_id,
         })
     }
 }
@@ -724,6 +1046,11 @@ impl Ics20WithdrawalError {
     fn invalid_source_channel(err: IdentifierError) -> Self {
         Self(Ics20WithdrawalErrorKind::InvalidSourceChannel(err))
     }
+
+    #[must_use]
+    fn invalid_fee_asset_id(err: asset::IncorrectAssetIdLength) -> Self {
+        Self(Ics20WithdrawalErrorKind::InvalidFeeAssetId(err))
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -736,4 +1063,1342 @@ enum Ics20WithdrawalErrorKind {
     MissingTimeoutHeight,
     #[error("`source_channel` field was invalid")]
     InvalidSourceChannel(IdentifierError),
+    #[error("`fee_asset_id` field was invalid")]
+    InvalidFeeAssetId(asset::IncorrectAssetIdLength),
+}
+
+/// The fee in the sequencer's fee schedule that is changed by a [`FeeChangeAction`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeeChange {
+    TransferBaseFee,
+    SequenceBaseFee,
+    SequenceByteCostMultiplier,
+    Ics20WithdrawalBaseFee,
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone)]
+pub struct FeeChangeAction {
+    pub fee_change: FeeChange,
+    pub new_value: u128,
+}
+
+impl FeeChangeAction {
+    #[must_use]
+    pub fn into_raw(self) -> raw::FeeChangeAction {
+        self.to_raw()
+    }
+
+    #[must_use]
+    pub fn to_raw(&self) -> raw::FeeChangeAction {
+        use raw::fee_change_action::Value;
+        let new_value = self.new_value.into();
+        let value = match self.fee_change {
+            FeeChange::TransferBaseFee => Value::TransferBaseFee(new_value),
+            FeeChange::SequenceBaseFee => Value::SequenceBaseFee(new_value),
+            FeeChange::SequenceByteCostMultiplier => Value::SequenceByteCostMultiplier(new_value),
+            FeeChange::Ics20WithdrawalBaseFee => Value::Ics20WithdrawalBaseFee(new_value),
+        };
+        raw::FeeChangeAction {
+            value: Some(value),
+        }
+    }
+
+    /// Convert from a raw, unchecked protobuf [`raw::FeeChangeAction`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the raw action's `value` field was not set.
+    pub fn try_from_raw(proto: raw::FeeChangeAction) -> Result<Self, FeeChangeActionError> {
+        use raw::fee_change_action::Value;
+        let (fee_change, new_value) = match proto.value {
+            Some(Value::TransferBaseFee(new_value)) => (FeeChange::TransferBaseFee, new_value),
+            Some(Value::SequenceBaseFee(new_value)) => (FeeChange::SequenceBaseFee, new_value),
+            Some(Value::SequenceByteCostMultiplier(new_value)) => {
+                (FeeChange::SequenceByteCostMultiplier, new_value)
+            }
+            Some(Value::Ics20WithdrawalBaseFee(new_value)) => {
+                (FeeChange::Ics20WithdrawalBaseFee, new_value)
+            }
+            None => return Err(FeeChangeActionError::missing_value()),
+        };
+        Ok(Self {
+            fee_change,
+            new_value: new_value.into(),
+        })
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct FeeChangeActionError(FeeChangeActionErrorKind);
+
+impl FeeChangeActionError {
+    #[must_use]
+    fn missing_value() -> Self {
+        Self(FeeChangeActionErrorKind::MissingValue)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum FeeChangeActionErrorKind {
+    #[error("`value` field was missing")]
+    MissingValue,
+}
+
+/// Adds an asset to or removes an asset from the set of assets that fees can be paid in.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeeAssetChangeAction {
+    Addition(asset::Id),
+    Removal(asset::Id),
+}
+
+impl FeeAssetChangeAction {
+    #[must_use]
+    pub fn into_raw(self) -> raw::FeeAssetChangeAction {
+        self.to_raw()
+    }
+
+    #[must_use]
+    pub fn to_raw(&self) -> raw::FeeAssetChangeAction {
+        use raw::fee_asset_change_action::Value;
+        let value = match self {
+            FeeAssetChangeAction::Addition(asset_id) => Value::Addition(asset_id.as_ref().to_vec()),
+            FeeAssetChangeAction::Removal(asset_id) => Value::Removal(asset_id.as_ref().to_vec()),
+        };
+        raw::FeeAssetChangeAction {
+            value: Some(value),
+        }
+    }
+
+    /// Convert from a raw, unchecked protobuf [`raw::FeeAssetChangeAction`].
+    ///
+    /// # Errors
+    ///
+    /// - if the raw action's `value` field was not set
+    /// - if the asset ID in the `value` field was not 32 bytes
+    pub fn try_from_raw(
+        proto: raw::FeeAssetChangeAction,
+    ) -> Result<Self, FeeAssetChangeActionError> {
+        use raw::fee_asset_change_action::Value;
+        match proto.value {
+            Some(Value::Addition(asset_id)) => Ok(Self::Addition(
+                asset::Id::try_from_slice(&asset_id)
+                    .map_err(FeeAssetChangeActionError::asset_id)?,
+            )),
+            Some(Value::Removal(asset_id)) => Ok(Self::Removal(
+                asset::Id::try_from_slice(&asset_id)
+                    .map_err(FeeAssetChangeActionError::asset_id)?,
+            )),
+            None => Err(FeeAssetChangeActionError::missing_value()),
+        }
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct FeeAssetChangeActionError(FeeAssetChangeActionErrorKind);
+
+impl FeeAssetChangeActionError {
+    #[must_use]
+    fn missing_value() -> Self {
+        Self(FeeAssetChangeActionErrorKind::MissingValue)
+    }
+
+    #[must_use]
+    fn asset_id(inner: asset::IncorrectAssetIdLength) -> Self {
+        Self(FeeAssetChangeActionErrorKind::AssetId(inner))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum FeeAssetChangeActionErrorKind {
+    #[error("`value` field was missing")]
+    MissingValue,
+    #[error("`value` field did not contain a valid asset ID")]
+    AssetId(#[source] asset::IncorrectAssetIdLength),
+}
+
+/// Registers the signer's account as a bridge account for a rollup.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone)]
+pub struct InitBridgeAccountAction {
+    /// the rollup that funds locked in the bridge account are deposited to.
+    pub rollup_id: RollupId,
+    /// the asset that can be locked in the bridge account.
+    pub asset_id: asset::Id,
+    /// asset to use for fee payment.
+    pub fee_asset_id: asset::Id,
+    /// the address authorized to unlock funds from the bridge account.
+    /// if `None`, the bridge account itself is the withdrawer.
+    pub withdrawer_address: Option<Address>,
+}
+
+impl InitBridgeAccountAction {
+    #[must_use]
+    pub fn into_raw(self) -> raw::InitBridgeAccountAction {
+        self.to_raw()
+    }
+
+    #[must_use]
+    pub fn to_raw(&self) -> raw::InitBridgeAccountAction {
+        let Self {
+            rollup_id,
+            asset_id,
+            fee_asset_id,
+            withdrawer_address,
+        } = self;
+        raw::InitBridgeAccountAction {
+            rollup_id: rollup_id.to_vec(),
+            asset_id: asset_id.as_ref().to_vec(),
+            fee_asset_id: fee_asset_id.as_ref().to_vec(),
+            withdrawer_address: withdrawer_address
+                .as_ref()
+                .map(Address::to_vec)
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Convert from a raw, unchecked protobuf [`raw::InitBridgeAccountAction`].
+    ///
+    /// # Errors
+    ///
+    /// - if the `rollup_id` field is not 32 bytes
+    /// - if the `asset_id` or `fee_asset_id` fields are not 32 bytes
+    /// - if the `withdrawer_address` field is set but is not 20 bytes
+    pub fn try_from_raw(
+        proto: raw::InitBridgeAccountAction,
+    ) -> Result<Self, InitBridgeAccountActionError> {
+        let raw::InitBridgeAccountAction {
+            rollup_id,
+            asset_id,
+            fee_asset_id,
+            withdrawer_address,
+        } = proto;
+        let rollup_id =
+            RollupId::try_from_vec(rollup_id).map_err(InitBridgeAccountActionError::rollup_id)?;
+        let asset_id =
+            asset::Id::try_from_slice(&asset_id).map_err(InitBridgeAccountActionError::asset_id)?;
+        let fee_asset_id = asset::Id::try_from_slice(&fee_asset_id)
+            .map_err(InitBridgeAccountActionError::fee_asset_id)?;
+        let withdrawer_address = if withdrawer_address.is_empty() {
+            None
+        } else {
+            Some(
+                Address::try_from_slice(&withdrawer_address)
+                    .map_err(InitBridgeAccountActionError::withdrawer_address)?,
+            )
+        };
+        Ok(Self {
+            rollup_id,
+            asset_id,
+            fee_asset_id,
+            withdrawer_address,
+        })
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct InitBridgeAccountActionError(InitBridgeAccountActionErrorKind);
+
+impl InitBridgeAccountActionError {
+    fn rollup_id(inner: IncorrectRollupIdLength) -> Self {
+        Self(InitBridgeAccountActionErrorKind::RollupId(inner))
+    }
+
+    fn asset_id(inner: asset::IncorrectAssetIdLength) -> Self {
+        Self(InitBridgeAccountActionErrorKind::Asset(inner))
+    }
+
+    fn fee_asset_id(inner: asset::IncorrectAssetIdLength) -> Self {
+        Self(InitBridgeAccountActionErrorKind::FeeAsset(inner))
+    }
+
+    fn withdrawer_address(inner: IncorrectAddressLength) -> Self {
+        Self(InitBridgeAccountActionErrorKind::WithdrawerAddress(inner))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum InitBridgeAccountActionErrorKind {
+    #[error("`rollup_id` field did not contain a valid rollup ID")]
+    RollupId(#[source] IncorrectRollupIdLength),
+    #[error("`asset_id` field did not contain a valid asset ID")]
+    Asset(#[source] asset::IncorrectAssetIdLength),
+    #[error("`fee_asset_id` field did not contain a valid asset ID")]
+    FeeAsset(#[source] asset::IncorrectAssetIdLength),
+    #[error("`withdrawer_address` field did not contain a valid address")]
+    WithdrawerAddress(#[source] IncorrectAddressLength),
+}
+
+/// Locks funds in a bridge account, depositing them into the rollup
+/// the bridge account is registered for.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone)]
+pub struct BridgeLockAction {
+    /// the bridge account to lock the funds in.
+    pub to: Address,
+    /// the rollup the bridge account is registered for.
+    pub rollup_id: RollupId,
+    pub amount: u128,
+    /// asset to be locked.
+    pub asset_id: asset::Id,
+    /// asset to use for fee payment.
+    pub fee_asset_id: asset::Id,
+    /// the address on the destination chain to credit the deposit to.
+    pub destination_chain_address: String,
 }
+
+impl BridgeLockAction {
+    #[must_use]
+    pub fn into_raw(self) -> raw::BridgeLockAction {
+        let Self {
+            to,
+            rollup_id,
+            amount,
+            asset_id,
+            fee_asset_id,
+            destination_chain_address,
+        } = self;
+        raw::BridgeLockAction {
+            to: to.to_vec(),
+            rollup_id: rollup_id.to_vec(),
+            amount: Some(amount.into()),
+            asset_id: asset_id.as_ref().to_vec(),
+            fee_asset_id: fee_asset_id.as_ref().to_vec(),
+            destination_chain_address,
+        }
+    }
+
+    #[must_use]
+    pub fn to_raw(&self) -> raw::BridgeLockAction {
+        self.clone().into_raw()
+    }
+
+    /// Convert from a raw, unchecked protobuf [`raw::BridgeLockAction`].
+    ///
+    /// # Errors
+    ///
+    /// - if the `to` field is not 20 bytes
+    /// - if the `rollup_id` field is not 32 bytes
+    /// - if the `asset_id` or `fee_asset_id` fields are not 32 bytes
+    pub fn try_from_raw(proto: raw::BridgeLockAction) -> Result<Self, BridgeLockActionError> {
+        let raw::BridgeLockAction {
+            to,
+            rollup_id,
+            amount,
+            asset_id,
+            fee_asset_id,
+            destination_chain_address,
+        } = proto;
+        let to = Address::try_from_slice(&to).map_err(BridgeLockActionError::address)?;
+        let rollup_id =
+            RollupId::try_from_vec(rollup_id).map_err(BridgeLockActionError::rollup_id)?;
+        let amount = amount.map_or(0, Into::into);
+        let asset_id =
+            asset::Id::try_from_slice(&asset_id).map_err(BridgeLockActionError::asset_id)?;
+        let fee_asset_id = asset::Id::try_from_slice(&fee_asset_id)
+            .map_err(BridgeLockActionError::fee_asset_id)?;
+        Ok(Self {
+            to,
+            rollup_id,
+            amount,
+            asset_id,
+            fee_asset_id,
+            destination_chain_address,
+        })
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct BridgeLockActionError(BridgeLockActionErrorKind);
+
+impl BridgeLockActionError {
+    fn address(inner: IncorrectAddressLength) -> Self {
+        Self(BridgeLockActionErrorKind::Address(inner))
+    }
+
+    fn rollup_id(inner: IncorrectRollupIdLength) -> Self {
+        Self(BridgeLockActionErrorKind::RollupId(inner))
+    }
+
+    fn asset_id(inner: asset::IncorrectAssetIdLength) -> Self {
+        Self(BridgeLockActionErrorKind::Asset(inner))
+    }
+
+    fn fee_asset_id(inner: asset::IncorrectAssetIdLength) -> Self {
+        Self(BridgeLockActionErrorKind::FeeAsset(inner))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum BridgeLockActionErrorKind {
+    #[error("`to` field did not contain a valid address")]
+    Address(#[source] IncorrectAddressLength),
+    #[error("`rollup_id` field did not contain a valid rollup ID")]
+    RollupId(#[source] IncorrectRollupIdLength),
+    #[error("`asset_id` field did not contain a valid asset ID")]
+    Asset(#[source] asset::IncorrectAssetIdLength),
+    #[error("`fee_asset_id` field did not contain a valid asset ID")]
+    FeeAsset(#[source] asset::IncorrectAssetIdLength),
+}
+
+/// Releases funds from a bridge account. Must be signed by the bridge account's withdrawer.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone)]
+pub struct BridgeUnlockAction {
+    /// the address to release the funds to.
+    pub to: Address,
+    pub amount: u128,
+    /// asset to use for fee payment.
+    pub fee_asset_id: asset::Id,
+    /// memo for the unlock, e.g. the rollup transaction that initiated the withdrawal.
+    pub memo: Vec<u8>,
+    /// the bridge account to release the funds from.
+    pub bridge_address: Address,
+}
+
+impl BridgeUnlockAction {
+    #[must_use]
+    pub fn into_raw(self) -> raw::BridgeUnlockAction {
+        let Self {
+            to,
+            amount,
+            fee_asset_id,
+            memo,
+            bridge_address,
+        } = self;
+        raw::BridgeUnlockAction {
+            to: to.to_vec(),
+            amount: Some(amount.into()),
+            fee_asset_id: fee_asset_id.as_ref().to_vec(),
+            memo,
+            bridge_address: bridge_address.to_vec(),
+        }
+    }
+
+    #[must_use]
+    pub fn to_raw(&self) -> raw::BridgeUnlockAction {
+        self.clone().into_raw()
+    }
+
+    /// Convert from a raw, unchecked protobuf [`raw::BridgeUnlockAction`].
+    ///
+    /// # Errors
+    ///
+    /// - if the `to` or `bridge_address` fields are not 20 bytes
+    /// - if the `fee_asset_id` field is not 32 bytes
+    pub fn try_from_raw(proto: raw::BridgeUnlockAction) -> Result<Self, BridgeUnlockActionError> {
+        let raw::BridgeUnlockAction {
+            to,
+            amount,
+            fee_asset_id,
+            memo,
+            bridge_address,
+        } = proto;
+        let to = Address::try_from_slice(&to).map_err(BridgeUnlockActionError::address)?;
+        let amount = amount.map_or(0, Into::into);
+        let fee_asset_id = asset::Id::try_from_slice(&fee_asset_id)
+            .map_err(BridgeUnlockActionError::fee_asset_id)?;
+        let bridge_address = Address::try_from_slice(&bridge_address)
+            .map_err(BridgeUnlockActionError::bridge_address)?;
+        Ok(Self {
+            to,
+            amount,
+            fee_asset_id,
+            memo,
+            bridge_address,
+        })
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct BridgeUnlockActionError(BridgeUnlockActionErrorKind);
+
+impl BridgeUnlockActionError {
+    fn address(inner: IncorrectAddressLength) -> Self {
+        Self(BridgeUnlockActionErrorKind::Address(inner))
+    }
+
+    fn fee_asset_id(inner: asset::IncorrectAssetIdLength) -> Self {
+        Self(BridgeUnlockActionErrorKind::FeeAsset(inner))
+    }
+
+    fn bridge_address(inner: IncorrectAddressLength) -> Self {
+        Self(BridgeUnlockActionErrorKind::BridgeAddress(inner))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum BridgeUnlockActionErrorKind {
+    #[error("`to` field did not contain a valid address")]
+    Address(#[source] IncorrectAddressLength),
+    #[error("`fee_asset_id` field did not contain a valid asset ID")]
+    FeeAsset(#[source] asset::IncorrectAssetIdLength),
+    #[error("`bridge_address` field did not contain a valid address")]
+    BridgeAddress(#[source] IncorrectAddressLength),
+}
+
+/// Adds an address to or removes an address from the set of addresses allowed to relay
+/// IBC packets.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IbcRelayerChangeAction {
+    Addition(Address),
+    Removal(Address),
+}
+
+impl IbcRelayerChangeAction {
+    #[must_use]
+    pub fn into_raw(self) -> raw::IbcRelayerChangeAction {
+        self.to_raw()
+    }
+
+    #[must_use]
+    pub fn to_raw(&self) -> raw::IbcRelayerChangeAction {
+        use raw::ibc_relayer_change_action::Value;
+        let value = match self {
+            IbcRelayerChangeAction::Addition(address) => Value::Addition(address.to_vec()),
+            IbcRelayerChangeAction::Removal(address) => Value::Removal(address.to_vec()),
+        };
+        raw::IbcRelayerChangeAction {
+            value: Some(value),
+        }
+    }
+
+    /// Convert from a raw, unchecked protobuf [`raw::IbcRelayerChangeAction`].
+    ///
+    /// # Errors
+    ///
+    /// - if the raw action's `value` field was not set
+    /// - if the address in the `value` field did not have the expected length
+    pub fn try_from_raw(
+        proto: raw::IbcRelayerChangeAction,
+    ) -> Result<Self, IbcRelayerChangeActionError> {
+        use raw::ibc_relayer_change_action::Value;
+        match proto.value {
+            Some(Value::Addition(address)) => Ok(Self::Addition(
+                Address::try_from_slice(&address).map_err(IbcRelayerChangeActionError::address)?,
+            )),
+            Some(Value::Removal(address)) => Ok(Self::Removal(
+                Address::try_from_slice(&address).map_err(IbcRelayerChangeActionError::address)?,
+            )),
+            None => Err(IbcRelayerChangeActionError::missing_value()),
+        }
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct IbcRelayerChangeActionError(IbcRelayerChangeActionErrorKind);
+
+impl IbcRelayerChangeActionError {
+    #[must_use]
+    fn missing_value() -> Self {
+        Self(IbcRelayerChangeActionErrorKind::MissingValue)
+    }
+
+    #[must_use]
+    fn address(inner: IncorrectAddressLength) -> Self {
+        Self(IbcRelayerChangeActionErrorKind::Address(inner))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum IbcRelayerChangeActionErrorKind {
+    #[error("`value` field was missing")]
+    MissingValue,
+    #[error("`value` field did not contain a valid address")]
+    Address(#[source] IncorrectAddressLength),
+}
+
+/// Changes the chain's IBC sudo address.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Debug)]
+pub struct IbcSudoChangeAction {
+    pub new_address: Address,
+}
+
+impl IbcSudoChangeAction {
+    #[must_use]
+    pub fn into_raw(self) -> raw::IbcSudoChangeAction {
+        self.to_raw()
+    }
+
+    #[must_use]
+    pub fn to_raw(&self) -> raw::IbcSudoChangeAction {
+        let Self {
+            new_address,
+        } = self;
+        raw::IbcSudoChangeAction {
+            new_address: new_address.to_vec(),
+        }
+    }
+
+    /// Convert from a raw, unchecked protobuf [`raw::IbcSudoChangeAction`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the raw action's `new_address` did not have the expected
+    /// length.
+    pub fn try_from_raw(proto: raw::IbcSudoChangeAction) -> Result<Self, IbcSudoChangeActionError> {
+        let raw::IbcSudoChangeAction {
+            new_address,
+        } = proto;
+        let new_address =
+            Address::try_from_slice(&new_address).map_err(IbcSudoChangeActionError::address)?;
+        Ok(Self {
+            new_address,
+        })
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct IbcSudoChangeActionError(IbcSudoChangeActionErrorKind);
+
+impl IbcSudoChangeActionError {
+    fn address(inner: IncorrectAddressLength) -> Self {
+        Self(IbcSudoChangeActionErrorKind::Address(inner))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum IbcSudoChangeActionErrorKind {
+    #[error("`new_address` field did not contain a valid address")]
+    Address(#[source] IncorrectAddressLength),
+}
+
+/// Schedules a chain upgrade with the given name at the given height.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScheduleUpgradeAction {
+    pub name: String,
+    pub height: u64,
+}
+
+impl ScheduleUpgradeAction {
+    #[must_use]
+    pub fn into_raw(self) -> raw::ScheduleUpgradeAction {
+        let Self {
+            name,
+            height,
+        } = self;
+        raw::ScheduleUpgradeAction {
+            name,
+            height,
+        }
+    }
+
+    #[must_use]
+    pub fn to_raw(&self) -> raw::ScheduleUpgradeAction {
+        self.clone().into_raw()
+    }
+
+    /// Convert from a raw protobuf [`raw::ScheduleUpgradeAction`].
+    #[must_use]
+    pub fn from_raw(proto: raw::ScheduleUpgradeAction) -> Self {
+        let raw::ScheduleUpgradeAction {
+            name,
+            height,
+        } = proto;
+        Self {
+            name,
+            height,
+        }
+    }
+}
+
+/// Registers a rollup in the rollup registry.
+///
+/// The registered rollup's ID is derived from its name with [`RollupId::from_unhashed_bytes`].
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Debug)]
+pub struct RegisterRollupAction {
+    pub name: String,
+    /// the address authorized to change the rollup's owner and metadata.
+    pub owner: Address,
+    /// the celestia namespace the rollup's data is posted to.
+    pub celestia_namespace: CelestiaNamespaceId,
+    /// free-form metadata describing the rollup.
+    pub metadata: String,
+    /// asset to use for fee payment.
+    pub fee_asset_id: asset::Id,
+}
+
+impl RegisterRollupAction {
+    /// Returns the ID of the rollup registered by this action.
+    #[must_use]
+    pub fn rollup_id(&self) -> RollupId {
+        RollupId::from_unhashed_bytes(&self.name)
+    }
+
+    #[must_use]
+    pub fn into_raw(self) -> raw::RegisterRollupAction {
+        let Self {
+            name,
+            owner,
+            celestia_namespace,
+            metadata,
+            fee_asset_id,
+        } = self;
+        raw::RegisterRollupAction {
+            name,
+            owner: owner.to_vec(),
+            celestia_namespace: celestia_namespace.to_vec(),
+            metadata,
+            fee_asset_id: fee_asset_id.as_ref().to_vec(),
+        }
+    }
+
+    #[must_use]
+    pub fn to_raw(&self) -> raw::RegisterRollupAction {
+        self.clone().into_raw()
+    }
+
+    /// Convert from a raw, unchecked protobuf [`raw::RegisterRollupAction`].
+    ///
+    /// # Errors
+    ///
+    /// - if the `owner` field is not 20 bytes
+    /// - if the `celestia_namespace` field is not 10 bytes
+    /// - if the `fee_asset_id` field is not 32 bytes
+    pub fn try_from_raw(
+        proto: raw::RegisterRollupAction,
+    ) -> Result<Self, RegisterRollupActionError> {
+        let raw::RegisterRollupAction {
+            name,
+            owner,
+            celestia_namespace,
+            metadata,
+            fee_asset_id,
+        } = proto;
+        let owner = Address::try_from_slice(&owner).map_err(RegisterRollupActionError::owner)?;
+        let celestia_namespace = CelestiaNamespaceId::try_from_slice(&celestia_namespace)
+            .map_err(RegisterRollupActionError::celestia_namespace)?;
+        let fee_asset_id = asset::Id::try_from_slice(&fee_asset_id)
+            .map_err(RegisterRollupActionError::fee_asset_id)?;
+        Ok(Self {
+            name,
+            owner,
+            celestia_namespace,
+            metadata,
+            fee_asset_id,
+        })
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct RegisterRollupActionError(RegisterRollupActionErrorKind);
+
+impl RegisterRollupActionError {
+    fn owner(inner: IncorrectAddressLength) -> Self {
+        Self(RegisterRollupActionErrorKind::Owner(inner))
+    }
+
+    fn celestia_namespace(inner: IncorrectNamespaceIdLength) -> Self {
+        Self(RegisterRollupActionErrorKind::CelestiaNamespace(inner))
+    }
+
+    fn fee_asset_id(inner: asset::IncorrectAssetIdLength) -> Self {
+        Self(RegisterRollupActionErrorKind::FeeAsset(inner))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum RegisterRollupActionErrorKind {
+    #[error("`owner` field did not contain a valid address")]
+    Owner(#[source] IncorrectAddressLength),
+    #[error("`celestia_namespace` field did not contain a valid namespace ID")]
+    CelestiaNamespace(#[source] IncorrectNamespaceIdLength),
+    #[error("`fee_asset_id` field did not contain a valid asset ID")]
+    FeeAsset(#[source] asset::IncorrectAssetIdLength),
+}
+
+/// Transfers the ownership of a registered rollup to a new owner.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Debug)]
+pub struct RollupOwnerChangeAction {
+    pub rollup_id: RollupId,
+    pub new_owner: Address,
+}
+
+impl RollupOwnerChangeAction {
+    #[must_use]
+    pub fn into_raw(self) -> raw::RollupOwnerChangeAction {
+        self.to_raw()
+    }
+
+    #[must_use]
+    pub fn to_raw(&self) -> raw::RollupOwnerChangeAction {
+        let Self {
+            rollup_id,
+            new_owner,
+        } = self;
+        raw::RollupOwnerChangeAction {
+            rollup_id: rollup_id.to_vec(),
+            new_owner: new_owner.to_vec(),
+        }
+    }
+
+    /// Convert from a raw, unchecked protobuf [`raw::RollupOwnerChangeAction`].
+    ///
+    /// # Errors
+    ///
+    /// - if the `rollup_id` field is not 32 bytes
+    /// - if the `new_owner` field is not 20 bytes
+    pub fn try_from_raw(
+        proto: raw::RollupOwnerChangeAction,
+    ) -> Result<Self, RollupOwnerChangeActionError> {
+        let raw::RollupOwnerChangeAction {
+            rollup_id,
+            new_owner,
+        } = proto;
+        let rollup_id =
+            RollupId::try_from_vec(rollup_id).map_err(RollupOwnerChangeActionError::rollup_id)?;
+        let new_owner =
+            Address::try_from_slice(&new_owner).map_err(RollupOwnerChangeActionError::new_owner)?;
+        Ok(Self {
+            rollup_id,
+            new_owner,
+        })
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct RollupOwnerChangeActionError(RollupOwnerChangeActionErrorKind);
+
+impl RollupOwnerChangeActionError {
+    fn rollup_id(inner: IncorrectRollupIdLength) -> Self {
+        Self(RollupOwnerChangeActionErrorKind::RollupId(inner))
+    }
+
+    fn new_owner(inner: IncorrectAddressLength) -> Self {
+        Self(RollupOwnerChangeActionErrorKind::NewOwner(inner))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum RollupOwnerChangeActionErrorKind {
+    #[error("`rollup_id` field did not contain a valid rollup ID")]
+    RollupId(#[source] IncorrectRollupIdLength),
+    #[error("`new_owner` field did not contain a valid address")]
+    NewOwner(#[source] IncorrectAddressLength),
+}
+
+/// Replaces the metadata of a registered rollup.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Debug)]
+pub struct RollupMetadataChangeAction {
+    pub rollup_id: RollupId,
+    pub metadata: String,
+}
+
+impl RollupMetadataChangeAction {
+    #[must_use]
+    pub fn into_raw(self) -> raw::RollupMetadataChangeAction {
+        let Self {
+            rollup_id,
+            metadata,
+        } = self;
+        raw::RollupMetadataChangeAction {
+            rollup_id: rollup_id.to_vec(),
+            metadata,
+        }
+    }
+
+    #[must_use]
+    pub fn to_raw(&self) -> raw::RollupMetadataChangeAction {
+        self.clone().into_raw()
+    }
+
+    /// Convert from a raw, unchecked protobuf [`raw::RollupMetadataChangeAction`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the `rollup_id` field is not 32 bytes.
+    pub fn try_from_raw(
+        proto: raw::RollupMetadataChangeAction,
+    ) -> Result<Self, RollupMetadataChangeActionError> {
+        let raw::RollupMetadataChangeAction {
+            rollup_id,
+            metadata,
+        } = proto;
+        let rollup_id =
+            RollupId::try_from_vec(rollup_id).map_err(RollupMetadataChangeActionError)?;
+        Ok(Self {
+            rollup_id,
+            metadata,
+        })
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, thiserror::Error)]
+#[error("`rollup_id` field did not contain a valid rollup ID")]
+pub struct RollupMetadataChangeActionError(#[source] IncorrectRollupIdLength);
+
+/// Sets the display metadata of the native asset or of a registered IBC asset.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Debug)]
+pub struct AssetMetadataChangeAction {
+    pub asset_id: asset::Id,
+    pub metadata: asset::Metadata,
+}
+
+impl AssetMetadataChangeAction {
+    #[must_use]
+    pub fn into_raw(self) -> raw::AssetMetadataChangeAction {
+        let Self {
+            asset_id,
+            metadata,
+        } = self;
+        raw::AssetMetadataChangeAction {
+            asset_id: asset_id.as_ref().to_vec(),
+            metadata: Some(metadata.into_raw()),
+        }
+    }
+
+    #[must_use]
+    pub fn to_raw(&self) -> raw::AssetMetadataChangeAction {
+        self.clone().into_raw()
+    }
+
+    /// Convert from a raw, unchecked protobuf [`raw::AssetMetadataChangeAction`].
+    ///
+    /// # Errors
+    ///
+    /// - if the `asset_id` field is not 32 bytes
+    /// - if the `metadata` field is missing
+    pub fn try_from_raw(
+        proto: raw::AssetMetadataChangeAction,
+    ) -> Result<Self, AssetMetadataChangeActionError> {
+        let raw::AssetMetadataChangeAction {
+            asset_id,
+            metadata,
+        } = proto;
+        let asset_id = asset::Id::try_from_slice(&asset_id)
+            .map_err(AssetMetadataChangeActionError::asset_id)?;
+        let metadata = metadata.ok_or(AssetMetadataChangeActionError::missing_metadata())?;
+        Ok(Self {
+            asset_id,
+            metadata: asset::Metadata::from_raw(metadata),
+        })
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct AssetMetadataChangeActionError(AssetMetadataChangeActionErrorKind);
+
+impl AssetMetadataChangeActionError {
+    fn asset_id(inner: asset::IncorrectAssetIdLength) -> Self {
+        Self(AssetMetadataChangeActionErrorKind::AssetId(inner))
+    }
+
+    #[must_use]
+    fn missing_metadata() -> Self {
+        Self(AssetMetadataChangeActionErrorKind::MissingMetadata)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum AssetMetadataChangeActionErrorKind {
+    #[error("`asset_id` field did not contain a valid asset ID")]
+    AssetId(#[source] asset::IncorrectAssetIdLength),
+    #[error("`metadata` field was missing")]
+    MissingMetadata,
+}
+
+/// Authorizes a session key to submit transactions on behalf of the signer's account.
+///
+/// Transactions signed by the session key can only contain sequence actions for
+/// `rollup_ids`, and pay at most `fee_spend_limit` of fees and tips in the native
+/// asset from the account up to and including `expiry_height`.
+///
+/// The session key must sign the address of the signer's account with
+/// [`SessionKeySignature::new`], proving that its holder agrees to act for the account.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Debug)]
+pub struct AuthorizeSessionKeyAction {
+    /// the address of the session key.
+    pub session_key: Address,
+    /// the rollups the session key can submit sequence data for.
+    pub rollup_ids: Vec<RollupId>,
+    /// the maximum amount of fees and tips the session key can spend.
+    pub fee_spend_limit: u128,
+    /// the last block height at which the session key can be used.
+    pub expiry_height: u64,
+    /// asset to use for fee payment.
+    pub fee_asset_id: asset::Id,
+    /// the session key's signature over the address of the authorizing account.
+    pub session_key_signature: SessionKeySignature,
+}
+
+impl AuthorizeSessionKeyAction {
+    #[must_use]
+    pub fn into_raw(self) -> raw::AuthorizeSessionKeyAction {
+        let Self {
+            session_key,
+            rollup_ids,
+            fee_spend_limit,
+            expiry_height,
+            fee_asset_id,
+            session_key_signature,
+        } = self;
+        raw::AuthorizeSessionKeyAction {
+            session_key: session_key.to_vec(),
+            rollup_ids: rollup_ids.into_iter().map(RollupId::to_vec).collect(),
+            fee_spend_limit: Some(fee_spend_limit.into()),
+            expiry_height,
+            fee_asset_id: fee_asset_id.as_ref().to_vec(),
+            session_key_public_key: session_key_signature.verification_key.to_bytes().to_vec(),
+            session_key_signature: session_key_signature.signature.to_bytes().to_vec(),
+        }
+    }
+
+    #[must_use]
+    pub fn to_raw(&self) -> raw::AuthorizeSessionKeyAction {
+        self.clone().into_raw()
+    }
+
+    /// Convert from a raw, unchecked protobuf [`raw::AuthorizeSessionKeyAction`].
+    ///
+    /// Note that the session key's signature can only be verified against the account
+    /// authorizing it, which is not part of the action. Use [`SessionKeySignature::verify`]
+    /// for this.
+    ///
+    /// # Errors
+    ///
+    /// - if the `session_key` field is not 20 bytes
+    /// - if any of the `rollup_ids` is not 32 bytes
+    /// - if the `fee_asset_id` field is not 32 bytes
+    /// - if the `session_key_public_key` or `session_key_signature` fields are invalid
+    /// - if the `session_key_public_key` field does not belong to `session_key`
+    pub fn try_from_raw(
+        proto: raw::AuthorizeSessionKeyAction,
+    ) -> Result<Self, AuthorizeSessionKeyActionError> {
+        let raw::AuthorizeSessionKeyAction {
+            session_key,
+            rollup_ids,
+            fee_spend_limit,
+            expiry_height,
+            fee_asset_id,
+            session_key_public_key,
+            session_key_signature,
+        } = proto;
+        let session_key = Address::try_from_slice(&session_key)
+            .map_err(AuthorizeSessionKeyActionError::session_key)?;
+        let rollup_ids = rollup_ids
+            .into_iter()
+            .map(RollupId::try_from_vec)
+            .collect::<Result<_, _>>()
+            .map_err(AuthorizeSessionKeyActionError::rollup_id)?;
+        let fee_spend_limit = fee_spend_limit.map_or(0, Into::into);
+        let fee_asset_id = asset::Id::try_from_slice(&fee_asset_id)
+            .map_err(AuthorizeSessionKeyActionError::fee_asset_id)?;
+        let verification_key = VerificationKey::try_from(&*session_key_public_key)
+            .map_err(AuthorizeSessionKeyActionError::session_key_public_key)?;
+        if Address::from_verification_key(verification_key) != session_key {
+            return Err(AuthorizeSessionKeyActionError::session_key_mismatch());
+        }
+        let signature = Signature::try_from(&*session_key_signature)
+            .map_err(AuthorizeSessionKeyActionError::session_key_signature)?;
+        Ok(Self {
+            session_key,
+            rollup_ids,
+            fee_spend_limit,
+            expiry_height,
+            fee_asset_id,
+            session_key_signature: SessionKeySignature {
+                signature,
+                verification_key,
+            },
+        })
+    }
+}
+
+/// The signature of a session key over the address of the account authorizing it.
+#[derive(Clone, Debug)]
+pub struct SessionKeySignature {
+    pub signature: Signature,
+    pub verification_key: VerificationKey,
+}
+
+impl SessionKeySignature {
+    /// Signs the address of `account` with the session key `signing_key`.
+    #[must_use]
+    pub fn new(signing_key: &SigningKey, account: Address) -> Self {
+        Self {
+            signature: signing_key.sign(&session_key_signing_bytes(account)),
+            verification_key: signing_key.verification_key(),
+        }
+    }
+
+    /// Verifies that the session key signed the address of `account`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the signature is not over the address of `account`.
+    pub fn verify(&self, account: Address) -> Result<(), ed25519_consensus::Error> {
+        self.verification_key
+            .verify(&self.signature, &session_key_signing_bytes(account))
+    }
+}
+
+/// Returns the bytes a session key signs to be authorized by `account`.
+///
+/// The address is prefixed so that the signature cannot be mistaken for a transaction
+/// signature.
+fn session_key_signing_bytes(account: Address) -> Vec<u8> {
+    const PREFIX: &[u8] = b"astria/session_key_authorization/";
+    [PREFIX, &account.to_vec()].concat()
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct AuthorizeSessionKeyActionError(AuthorizeSessionKeyActionErrorKind);
+
+impl AuthorizeSessionKeyActionError {
+    fn session_key(inner: IncorrectAddressLength) -> Self {
+        Self(AuthorizeSessionKeyActionErrorKind::SessionKey(inner))
+    }
+
+    fn rollup_id(inner: IncorrectRollupIdLength) -> Self {
+        Self(AuthorizeSessionKeyActionErrorKind::RollupId(inner))
+    }
+
+    fn fee_asset_id(inner: asset::IncorrectAssetIdLength) -> Self {
+        Self(AuthorizeSessionKeyActionErrorKind::FeeAsset(inner))
+    }
+
+    fn session_key_public_key(inner: ed25519_consensus::Error) -> Self {
+        Self(AuthorizeSessionKeyActionErrorKind::SessionKeyPublicKey(
+            inner,
+        ))
+    }
+
+    fn session_key_signature(inner: ed25519_consensus::Error) -> Self {
+        Self(AuthorizeSessionKeyActionErrorKind::SessionKeySignature(
+            inner,
+        ))
+    }
+
+    fn session_key_mismatch() -> Self {
+        Self(AuthorizeSessionKeyActionErrorKind::SessionKeyMismatch)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum AuthorizeSessionKeyActionErrorKind {
+    #[error("`session_key` field did not contain a valid address")]
+    SessionKey(#[source] IncorrectAddressLength),
+    #[error("`rollup_ids` field contained an invalid rollup ID")]
+    RollupId(#[source] IncorrectRollupIdLength),
+    #[error("`fee_asset_id` field did not contain a valid asset ID")]
+    FeeAsset(#[source] asset::IncorrectAssetIdLength),
+    #[error("`session_key_public_key` field invalid")]
+    SessionKeyPublicKey(#[source] ed25519_consensus::Error),
+    #[error("`session_key_signature` field invalid")]
+    SessionKeySignature(#[source] ed25519_consensus::Error),
+    #[error("`session_key_public_key` field does not belong to the `session_key` address")]
+    SessionKeyMismatch,
+}
+
+/// Revokes a session key authorized by the signer's account.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Debug)]
+pub struct RevokeSessionKeyAction {
+    pub session_key: Address,
+}
+
+impl RevokeSessionKeyAction {
+    #[must_use]
+    pub fn into_raw(self) -> raw::RevokeSessionKeyAction {
+        self.to_raw()
+    }
+
+    #[must_use]
+    pub fn to_raw(&self) -> raw::RevokeSessionKeyAction {
+        raw::RevokeSessionKeyAction {
+            session_key: self.session_key.to_vec(),
+        }
+    }
+
+    /// Convert from a raw, unchecked protobuf [`raw::RevokeSessionKeyAction`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the `session_key` field is not 20 bytes.
+    pub fn try_from_raw(
+        proto: raw::RevokeSessionKeyAction,
+    ) -> Result<Self, RevokeSessionKeyActionError> {
+        let raw::RevokeSessionKeyAction {
+            session_key,
+        } = proto;
+        let session_key =
+            Address::try_from_slice(&session_key).map_err(RevokeSessionKeyActionError)?;
+        Ok(Self {
+            session_key,
+        })
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, thiserror::Error)]
+#[error("`session_key` field did not contain a valid address")]
+pub struct RevokeSessionKeyActionError(#[source] IncorrectAddressLength);
+
+/// Adds a channel to or removes a channel from the set of channels ICS20 transfers
+/// can be made over.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone)]
+pub enum Ics20ChannelChangeAction {
+    Addition(ChannelId),
+    Removal(ChannelId),
+}
+
+impl Ics20ChannelChangeAction {
+    #[must_use]
+    pub fn into_raw(self) -> raw::Ics20ChannelChangeAction {
+        self.to_raw()
+    }
+
+    #[must_use]
+    pub fn to_raw(&self) -> raw::Ics20ChannelChangeAction {
+        use raw::ics20_channel_change_action::Value;
+        let value = match self {
+            Ics20ChannelChangeAction::Addition(channel) => Value::Addition(channel.to_string()),
+            Ics20ChannelChangeAction::Removal(channel) => Value::Removal(channel.to_string()),
+        };
+        raw::Ics20ChannelChangeAction {
+            value: Some(value),
+        }
+    }
+
+    /// Convert from a raw, unchecked protobuf [`raw::Ics20ChannelChangeAction`].
+    ///
+    /// # Errors
+    ///
+    /// - if the raw action's `value` field was not set
+    /// - if the `value` field did not contain a valid channel ID
+    pub fn try_from_raw(
+        proto: raw::Ics20ChannelChangeAction,
+    ) -> Result<Self, Ics20ChannelChangeActionError> {
+        use raw::ics20_channel_change_action::Value;
+        match proto.value {
+            Some(Value::Addition(channel)) => Ok(Self::Addition(
+                channel
+                    .parse()
+                    .map_err(Ics20ChannelChangeActionError::channel)?,
+            )),
+            Some(Value::Removal(channel)) => Ok(Self::Removal(
+                channel
+                    .parse()
+                    .map_err(Ics20ChannelChangeActionError::channel)?,
+            )),
+            None => Err(Ics20ChannelChangeActionError::missing_value()),
+        }
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct Ics20ChannelChangeActionError(Ics20ChannelChangeActionErrorKind);
+
+impl Ics20ChannelChangeActionError {
+    #[must_use]
+    fn missing_value() -> Self {
+        Self(Ics20ChannelChangeActionErrorKind::MissingValue)
+    }
+
+    #[must_use]
+    fn channel(inner: IdentifierError) -> Self {
+        Self(Ics20ChannelChangeActionErrorKind::Channel(inner))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum Ics20ChannelChangeActionErrorKind {
+    #[error("`value` field was missing")]
+    MissingValue,
+    #[error("`value` field did not contain a valid channel ID")]
+    Channel(#[source] IdentifierError),
+}
+
+/// Sets or removes the rate limit on the ICS20 transfers of an asset over a channel.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone)]
+pub struct Ics20RateLimitChangeAction {
+    /// The sequencer-side ID of the channel.
+    pub channel: ChannelId,
+    /// The asset as it is known on the sequencer.
+    pub denom: Denom,
+    /// The new rate limit, or `None` to remove the rate limit.
+    pub rate_limit: Option<Ics20RateLimit>,
+}
+
+impl Ics20RateLimitChangeAction {
+    #[must_use]
+    pub fn into_raw(self) -> raw::Ics20RateLimitChangeAction {
+        let Self {
+            channel,
+            denom,
+            rate_limit,
+        } = self;
+        raw::Ics20RateLimitChangeAction {
+            channel: channel.to_string(),
+            denom: denom.denomination_trace(),
+            rate_limit: rate_limit.map(Ics20RateLimit::into_raw),
+        }
+    }
+
+    #[must_use]
+    pub fn to_raw(&self) -> raw::Ics20RateLimitChangeAction {
+        self.clone().into_raw()
+    }
+
+    /// Convert from a raw, unchecked protobuf [`raw::Ics20RateLimitChangeAction`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the `channel` field did not contain a valid channel ID.
+    pub fn try_from_raw(
+        proto: raw::Ics20RateLimitChangeAction,
+    ) -> Result<Self, Ics20RateLimitChangeActionError> {
+        let raw::Ics20RateLimitChangeAction {
+            channel,
+            denom,
+            rate_limit,
+        } = proto;
+        let channel = channel.parse().map_err(Ics20RateLimitChangeActionError)?;
+        Ok(Self {
+            channel,
+            denom: denom.as_str().into(),
+            rate_limit: rate_limit.map(Ics20RateLimit::from_raw),
+        })
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, thiserror::Error)]
+#[error("`channel` field did not contain a valid channel ID")]
+pub struct Ics20RateLimitChangeActionError(#[source] IdentifierError);
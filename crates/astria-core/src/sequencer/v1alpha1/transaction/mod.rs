@@ -5,10 +5,19 @@ use ed25519_consensus::{
 };
 use prost::Message as _;
 
-use super::raw;
+use super::{
+    raw,
+    Address,
+    IncorrectAddressLength,
+};
 
 pub mod action;
+pub mod multisig;
 pub use action::Action;
+pub use multisig::{
+    MultisigKey,
+    MultisigSignature,
+};
 
 #[derive(Debug, thiserror::Error)]
 #[error(transparent)]
@@ -34,6 +43,38 @@ impl SignedTransactionError {
     fn unset_transaction() -> Self {
         Self(SignedTransactionErrorKind::UnsetTransaction)
     }
+
+    fn multisig(inner: multisig::MultisigSignatureError) -> Self {
+        Self(SignedTransactionErrorKind::Multisig(inner))
+    }
+
+    fn multisig_with_single_signature() -> Self {
+        Self(SignedTransactionErrorKind::MultisigWithSingleSignature)
+    }
+
+    fn fee_payer_signature(inner: ed25519_consensus::Error) -> Self {
+        Self(SignedTransactionErrorKind::FeePayerSignature(inner))
+    }
+
+    fn fee_payer_verification_key(inner: ed25519_consensus::Error) -> Self {
+        Self(SignedTransactionErrorKind::FeePayerVerificationKey(inner))
+    }
+
+    fn fee_payer_verification(inner: ed25519_consensus::Error) -> Self {
+        Self(SignedTransactionErrorKind::FeePayerVerification(inner))
+    }
+
+    fn fee_payer_mismatch() -> Self {
+        Self(SignedTransactionErrorKind::FeePayerMismatch)
+    }
+
+    fn missing_fee_payer_signature() -> Self {
+        Self(SignedTransactionErrorKind::MissingFeePayerSignature)
+    }
+
+    fn unexpected_fee_payer_signature() -> Self {
+        Self(SignedTransactionErrorKind::UnexpectedFeePayerSignature)
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -48,18 +89,78 @@ enum SignedTransactionErrorKind {
     VerificationKey(#[source] ed25519_consensus::Error),
     #[error("transaction could not be verified given the signature and verification key")]
     Verification(ed25519_consensus::Error),
+    #[error("`multisig` field invalid or its signatures could not be verified")]
+    Multisig(#[source] multisig::MultisigSignatureError),
+    #[error("`signature` and `public_key` fields must be empty if `multisig` is set")]
+    MultisigWithSingleSignature,
+    #[error("`fee_payer_signature` field invalid")]
+    FeePayerSignature(#[source] ed25519_consensus::Error),
+    #[error("`fee_payer_public_key` field invalid")]
+    FeePayerVerificationKey(#[source] ed25519_consensus::Error),
+    #[error(
+        "transaction could not be verified given the fee payer's signature and verification key"
+    )]
+    FeePayerVerification(ed25519_consensus::Error),
+    #[error("`fee_payer_public_key` field does not belong to the fee payer of the transaction")]
+    FeePayerMismatch,
+    #[error("transaction names a fee payer but `fee_payer_signature` field is empty")]
+    MissingFeePayerSignature,
+    #[error("`fee_payer_signature` field must be empty if the transaction names no fee payer")]
+    UnexpectedFeePayerSignature,
+}
+
+/// The signature authorizing a [`SignedTransaction`].
+#[derive(Clone, Debug)]
+pub enum TransactionSignature {
+    /// A signature by a single ed25519 key.
+    Single {
+        signature: Signature,
+        verification_key: VerificationKey,
+    },
+    /// Signatures by members of a threshold multisig account.
+    Multisig(MultisigSignature),
+}
+
+impl TransactionSignature {
+    /// Returns the address of the account that signed the transaction.
+    #[must_use]
+    pub fn address(&self) -> Address {
+        match self {
+            Self::Single {
+                verification_key, ..
+            } => Address::from_verification_key(*verification_key),
+            Self::Multisig(multisig) => multisig.key().address(),
+        }
+    }
+}
+
+/// The signature of the fee payer of a sponsored [`SignedTransaction`], authorizing it
+/// to pay the transaction's fees.
+#[derive(Clone, Debug)]
+pub struct FeePayerSignature {
+    pub signature: Signature,
+    pub verification_key: VerificationKey,
+}
+
+/// Returns the bytes the fee payer of a transaction signs: the address of the transaction's
+/// signer followed by the encoded [`raw::UnsignedTransaction`].
+///
+/// Including the signer means that the fee payer's signature cannot be attached to the same
+/// transaction signed by another account.
+fn fee_payer_signing_bytes(signer: Address, transaction_bytes: &[u8]) -> Vec<u8> {
+    [&signer.to_vec(), transaction_bytes].concat()
 }
 
 /// A signed transaction.
 ///
 /// [`SignedTransaction`] contains an [`UnsignedTransaction`] together
-/// with its signature and public key.
+/// with the signature or signatures authorizing it.
 #[derive(Clone, Debug)]
 #[allow(clippy::module_name_repetitions)]
 pub struct SignedTransaction {
-    signature: Signature,
-    verification_key: VerificationKey,
+    signature: TransactionSignature,
     transaction: UnsignedTransaction,
+    fee_payer_signature: Option<FeePayerSignature>,
 }
 
 impl SignedTransaction {
@@ -81,28 +182,24 @@ impl SignedTransaction {
     pub fn into_raw(self) -> raw::SignedTransaction {
         let Self {
             signature,
-            verification_key,
             transaction,
+            fee_payer_signature,
         } = self;
-        raw::SignedTransaction {
-            signature: signature.to_bytes().to_vec(),
-            public_key: verification_key.to_bytes().to_vec(),
-            transaction: Some(transaction.into_raw()),
-        }
+        let mut raw = raw_signature(&signature, fee_payer_signature.as_ref());
+        raw.transaction = Some(transaction.into_raw());
+        raw
     }
 
     #[must_use]
     pub fn to_raw(&self) -> raw::SignedTransaction {
         let Self {
             signature,
-            verification_key,
             transaction,
+            fee_payer_signature,
         } = self;
-        raw::SignedTransaction {
-            signature: signature.to_bytes().to_vec(),
-            public_key: verification_key.to_bytes().to_vec(),
-            transaction: Some(transaction.to_raw()),
-        }
+        let mut raw = raw_signature(signature, fee_payer_signature.as_ref());
+        raw.transaction = Some(transaction.to_raw());
+        raw
     }
 
     /// Attempt to convert from a raw, unchecked protobuf [`raw::SignedTransaction`].
@@ -114,40 +211,114 @@ impl SignedTransaction {
     /// `None`), if the inner transaction could not be verified given the key and signature, or
     /// if the native [`UnsignedTransaction`] could not be created from the inner raw
     /// [`raw::UnsignedTransaction`].
+    ///
+    /// If the transaction is signed by a multisig account, returns an error if fewer valid
+    /// signatures than the account's threshold were provided.
+    ///
+    /// If the transaction names a fee payer, returns an error if the fee payer did not sign
+    /// the transaction together with the address of its signer.
     pub fn try_from_raw(proto: raw::SignedTransaction) -> Result<Self, SignedTransactionError> {
         let raw::SignedTransaction {
             signature,
             public_key,
             transaction,
+            multisig,
+            fee_payer_signature,
+            fee_payer_public_key,
         } = proto;
-        let signature =
-            Signature::try_from(&*signature).map_err(SignedTransactionError::signature)?;
-        let verification_key = VerificationKey::try_from(&*public_key)
-            .map_err(SignedTransactionError::verification_key)?;
         let Some(transaction) = transaction else {
             return Err(SignedTransactionError::unset_transaction());
         };
         let bytes = transaction.encode_to_vec();
-        verification_key
-            .verify(&signature, &bytes)
-            .map_err(SignedTransactionError::verification)?;
+        let signature = if let Some(multisig) = multisig {
+            if !signature.is_empty() || !public_key.is_empty() {
+                return Err(SignedTransactionError::multisig_with_single_signature());
+            }
+            let multisig = MultisigSignature::try_from_raw(multisig)
+                .map_err(SignedTransactionError::multisig)?;
+            multisig
+                .verify(&bytes)
+                .map_err(SignedTransactionError::multisig)?;
+            TransactionSignature::Multisig(multisig)
+        } else {
+            let signature =
+                Signature::try_from(&*signature).map_err(SignedTransactionError::signature)?;
+            let verification_key = VerificationKey::try_from(&*public_key)
+                .map_err(SignedTransactionError::verification_key)?;
+            verification_key
+                .verify(&signature, &bytes)
+                .map_err(SignedTransactionError::verification)?;
+            TransactionSignature::Single {
+                signature,
+                verification_key,
+            }
+        };
+        let signer = signature.address();
+        let fee_payer_signature =
+            if fee_payer_signature.is_empty() && fee_payer_public_key.is_empty() {
+                None
+            } else {
+                let signature = Signature::try_from(&*fee_payer_signature)
+                    .map_err(SignedTransactionError::fee_payer_signature)?;
+                let verification_key = VerificationKey::try_from(&*fee_payer_public_key)
+                    .map_err(SignedTransactionError::fee_payer_verification_key)?;
+                verification_key
+                    .verify(&signature, &fee_payer_signing_bytes(signer, &bytes))
+                    .map_err(SignedTransactionError::fee_payer_verification)?;
+                Some(FeePayerSignature {
+                    signature,
+                    verification_key,
+                })
+            };
         let transaction = UnsignedTransaction::try_from_raw(transaction)
             .map_err(SignedTransactionError::transaction)?;
+        match (&transaction.fee_payer, &fee_payer_signature) {
+            (Some(fee_payer), Some(fee_payer_signature)) => {
+                if Address::from_verification_key(fee_payer_signature.verification_key)
+                    != fee_payer.address
+                {
+                    return Err(SignedTransactionError::fee_payer_mismatch());
+                }
+            }
+            (Some(_), None) => return Err(SignedTransactionError::missing_fee_payer_signature()),
+            (None, Some(_)) => {
+                return Err(SignedTransactionError::unexpected_fee_payer_signature());
+            }
+            (None, None) => {}
+        }
         Ok(Self {
             signature,
-            verification_key,
             transaction,
+            fee_payer_signature,
         })
     }
 
+    /// Adds the signature of the fee payer named in the transaction, authorizing it to
+    /// pay the transaction's fees.
+    ///
+    /// Note that the resulting transaction is only valid if `signing_key` belongs to the
+    /// transaction's fee payer.
+    #[must_use]
+    pub fn with_fee_payer_signature(self, signing_key: &SigningKey) -> Self {
+        let bytes =
+            fee_payer_signing_bytes(self.address(), &self.transaction.to_raw().encode_to_vec());
+        Self {
+            fee_payer_signature: Some(FeePayerSignature {
+                signature: signing_key.sign(&bytes),
+                verification_key: signing_key.verification_key(),
+            }),
+            ..self
+        }
+    }
+
     #[must_use]
-    pub fn into_parts(self) -> (Signature, VerificationKey, UnsignedTransaction) {
+    pub fn into_parts(self) -> (TransactionSignature, UnsignedTransaction) {
         let Self {
             signature,
-            verification_key,
             transaction,
+            ..
         } = self;
-        (signature, verification_key, transaction)
+        (signature, transaction)
     }
 
     #[must_use]
@@ -161,13 +332,19 @@ impl SignedTransaction {
     }
 
     #[must_use]
-    pub fn signature(&self) -> Signature {
-        self.signature
+    pub fn signature(&self) -> &TransactionSignature {
+        &self.signature
     }
 
     #[must_use]
-    pub fn verification_key(&self) -> VerificationKey {
-        self.verification_key
+    pub fn fee_payer_signature(&self) -> Option<&FeePayerSignature> {
+        self.fee_payer_signature.as_ref()
+    }
+
+    /// Returns the address of the account that signed the transaction.
+    #[must_use]
+    pub fn address(&self) -> Address {
+        self.signature.address()
     }
 
     #[must_use]
@@ -176,11 +353,94 @@ impl SignedTransaction {
     }
 }
 
+fn raw_signature(
+    signature: &TransactionSignature,
+    fee_payer_signature: Option<&FeePayerSignature>,
+) -> raw::SignedTransaction {
+    let (fee_payer_signature, fee_payer_public_key) =
+        fee_payer_signature.map_or((vec![], vec![]), |fee_payer_signature| {
+            (
+                fee_payer_signature.signature.to_bytes().to_vec(),
+                fee_payer_signature.verification_key.to_bytes().to_vec(),
+            )
+        });
+    match signature {
+        TransactionSignature::Single {
+            signature,
+            verification_key,
+        } => raw::SignedTransaction {
+            signature: signature.to_bytes().to_vec(),
+            public_key: verification_key.to_bytes().to_vec(),
+            transaction: None,
+            multisig: None,
+            fee_payer_signature,
+            fee_payer_public_key,
+        },
+        TransactionSignature::Multisig(multisig) => raw::SignedTransaction {
+            signature: vec![],
+            public_key: vec![],
+            transaction: None,
+            multisig: Some(multisig.to_raw()),
+            fee_payer_signature,
+            fee_payer_public_key,
+        },
+    }
+}
+
+/// The account paying the fees and tip of a sponsored transaction instead of its signer.
+///
+/// The fee payer signs the transaction body together with the address of its signer, see
+/// [`SignedTransaction::with_fee_payer_signature`]. Its signature is only valid for the
+/// signer's account at the transaction's nonce, so it cannot be replayed once the signer's
+/// nonce is used, nor attached to the same body signed by another account.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FeePayer {
+    pub address: Address,
+}
+
+impl FeePayer {
+    #[must_use]
+    pub fn into_raw(self) -> raw::FeePayer {
+        let Self {
+            address,
+        } = self;
+        raw::FeePayer {
+            address: address.to_vec(),
+        }
+    }
+
+    /// Convert from a raw, unchecked protobuf [`raw::FeePayer`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the `address` field is not 20 bytes.
+    pub fn try_from_raw(proto: raw::FeePayer) -> Result<Self, IncorrectAddressLength> {
+        let raw::FeePayer {
+            address,
+        } = proto;
+        Ok(Self {
+            address: Address::try_from_slice(&address)?,
+        })
+    }
+}
+
 #[derive(Clone, Debug)]
 #[allow(clippy::module_name_repetitions)]
 pub struct UnsignedTransaction {
     pub nonce: u32,
     pub actions: Vec<Action>,
+    /// The last block height at which the transaction can be executed.
+    /// If `None`, the transaction does not expire by height.
+    pub valid_until_height: Option<u64>,
+    /// The last block timestamp at which the transaction can be executed.
+    /// If `None`, the transaction does not expire by time.
+    pub valid_until_timestamp: Option<prost_types::Timestamp>,
+    /// The tip in the native asset paid to the block proposer for prioritized inclusion.
+    /// If `None`, the transaction pays no tip.
+    pub tip: Option<u128>,
+    /// The account paying the fees and tip of the transaction, which must also sign it.
+    /// If `None`, the fees and tip are paid by the signer.
+    pub fee_payer: Option<FeePayer>,
 }
 
 impl UnsignedTransaction {
@@ -190,21 +450,55 @@ impl UnsignedTransaction {
         let signature = signing_key.sign(&bytes);
         let verification_key = signing_key.verification_key();
         SignedTransaction {
-            signature,
-            verification_key,
+            signature: TransactionSignature::Single {
+                signature,
+                verification_key,
+            },
             transaction: self,
+            fee_payer_signature: None,
         }
     }
 
+    /// Signs the transaction on behalf of the multisig account `key` with each of
+    /// `signing_keys`.
+    ///
+    /// Note that the resulting transaction is only valid if at least `key.threshold()`
+    /// signing keys were provided.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if one of the signing keys is not a member of the multisig account.
+    pub fn into_multisig_signed(
+        self,
+        key: MultisigKey,
+        signing_keys: &[SigningKey],
+    ) -> Result<SignedTransaction, multisig::MultisigSignatureError> {
+        let bytes = self.to_raw().encode_to_vec();
+        let multisig = MultisigSignature::sign(key, signing_keys, &bytes)?;
+        Ok(SignedTransaction {
+            signature: TransactionSignature::Multisig(multisig),
+            transaction: self,
+            fee_payer_signature: None,
+        })
+    }
+
     pub fn into_raw(self) -> raw::UnsignedTransaction {
         let Self {
             nonce,
             actions,
+            valid_until_height,
+            valid_until_timestamp,
+            tip,
+            fee_payer,
         } = self;
         let actions = actions.into_iter().map(Action::into_raw).collect();
         raw::UnsignedTransaction {
             nonce,
             actions,
+            valid_until_height: valid_until_height.unwrap_or_default(),
+            valid_until_timestamp,
+            tip: tip.map(Into::into),
+            fee_payer: fee_payer.map(FeePayer::into_raw),
         }
     }
 
@@ -212,11 +506,19 @@ impl UnsignedTransaction {
         let Self {
             nonce,
             actions,
+            valid_until_height,
+            valid_until_timestamp,
+            tip,
+            fee_payer,
         } = self;
         let actions = actions.iter().map(Action::to_raw).collect();
         raw::UnsignedTransaction {
             nonce: *nonce,
             actions,
+            valid_until_height: valid_until_height.unwrap_or_default(),
+            valid_until_timestamp: valid_until_timestamp.clone(),
+            tip: tip.map(Into::into),
+            fee_payer: fee_payer.map(FeePayer::into_raw),
         }
     }
 
@@ -225,21 +527,34 @@ impl UnsignedTransaction {
     /// # Errors
     ///
     /// Returns an error if one of the inner raw actions could not be converted to a native
-    /// [`Action`].
+    /// [`Action`], or if the fee payer's address is invalid.
     pub fn try_from_raw(proto: raw::UnsignedTransaction) -> Result<Self, UnsignedTransactionError> {
         let raw::UnsignedTransaction {
             nonce,
             actions,
+            valid_until_height,
+            valid_until_timestamp,
+            tip,
+            fee_payer,
         } = proto;
         let actions: Vec<_> = actions
             .into_iter()
             .map(Action::try_from_raw)
             .collect::<Result<_, _>>()
             .map_err(UnsignedTransactionError::action)?;
+        let fee_payer = fee_payer
+            .map(FeePayer::try_from_raw)
+            .transpose()
+            .map_err(UnsignedTransactionError::fee_payer)?;
 
         Ok(Self {
             nonce,
             actions,
+            // a height of 0 means the transaction does not expire by height
+            valid_until_height: (valid_until_height != 0).then_some(valid_until_height),
+            valid_until_timestamp,
+            tip: tip.map(Into::into),
+            fee_payer,
         })
     }
 }
@@ -252,12 +567,18 @@ impl UnsignedTransactionError {
     fn action(inner: action::ActionError) -> Self {
         Self(UnsignedTransactionErrorKind::Action(inner))
     }
+
+    fn fee_payer(inner: IncorrectAddressLength) -> Self {
+        Self(UnsignedTransactionErrorKind::FeePayer(inner))
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
 enum UnsignedTransactionErrorKind {
     #[error("`actions` field is invalid")]
     Action(#[source] action::ActionError),
+    #[error("`fee_payer` field did not contain a valid address")]
+    FeePayer(#[source] IncorrectAddressLength),
 }
 
 #[cfg(test)]
@@ -298,14 +619,104 @@ mod test {
         let unsigned = UnsignedTransaction {
             nonce: 0,
             actions: vec![transfer.into()],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
         };
 
         let tx = SignedTransaction {
-            signature,
-            verification_key,
+            signature: TransactionSignature::Single {
+                signature,
+                verification_key,
+            },
             transaction: unsigned,
+            fee_payer_signature: None,
         };
 
         assert_eq!(tx.sha256_of_proto_encoding(), expected_hash);
     }
+
+    #[test]
+    fn multisig_signed_transaction_roundtrip() {
+        let signing_keys: Vec<_> = (1..=3).map(|i| SigningKey::from([i; 32])).collect();
+        let key = MultisigKey::new(
+            2,
+            signing_keys
+                .iter()
+                .map(SigningKey::verification_key)
+                .collect(),
+        )
+        .unwrap();
+        let address = key.address();
+
+        let unsigned = UnsignedTransaction {
+            nonce: 0,
+            actions: vec![
+                TransferAction {
+                    to: Address::from([0; 20]),
+                    amount: 0,
+                    asset_id: default_native_asset_id(),
+                    fee_asset_id: default_native_asset_id(),
+                }
+                .into(),
+            ],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
+        };
+
+        let signed = unsigned
+            .clone()
+            .into_multisig_signed(key.clone(), &signing_keys[..2])
+            .unwrap();
+        let roundtripped = SignedTransaction::try_from_raw(signed.into_raw()).unwrap();
+        assert_eq!(roundtripped.address(), address);
+
+        // a single signature does not meet the threshold
+        let signed = unsigned
+            .into_multisig_signed(key, &signing_keys[..1])
+            .unwrap();
+        SignedTransaction::try_from_raw(signed.into_raw()).unwrap_err();
+    }
+
+    #[test]
+    fn fee_payer_signature_is_bound_to_signer() {
+        let signer_key = SigningKey::from([1; 32]);
+        let fee_payer_key = SigningKey::from([2; 32]);
+        let unsigned = UnsignedTransaction {
+            nonce: 0,
+            actions: vec![
+                TransferAction {
+                    to: Address::from([0; 20]),
+                    amount: 0,
+                    asset_id: default_native_asset_id(),
+                    fee_asset_id: default_native_asset_id(),
+                }
+                .into(),
+            ],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: Some(FeePayer {
+                address: Address::from_verification_key(fee_payer_key.verification_key()),
+            }),
+        };
+
+        let signed = unsigned
+            .clone()
+            .into_signed(&signer_key)
+            .with_fee_payer_signature(&fee_payer_key);
+        let fee_payer_signature = signed.fee_payer_signature().cloned();
+        SignedTransaction::try_from_raw(signed.into_raw()).unwrap();
+
+        // the fee payer's signature cannot be attached to the same body signed by
+        // another account
+        let replayed = SignedTransaction {
+            fee_payer_signature,
+            ..unsigned.into_signed(&SigningKey::from([3; 32]))
+        };
+        SignedTransaction::try_from_raw(replayed.into_raw()).unwrap_err();
+    }
 }
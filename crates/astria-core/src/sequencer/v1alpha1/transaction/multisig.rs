@@ -0,0 +1,463 @@
+use std::collections::HashSet;
+
+use ed25519_consensus::{
+    Signature,
+    SigningKey,
+    VerificationKey,
+};
+use sha2::{
+    Digest as _,
+    Sha256,
+};
+
+use crate::sequencer::v1alpha1::{
+    raw,
+    Address,
+    ADDRESS_LEN,
+};
+
+/// The maximum number of keys that can make up a multisig account.
+///
+/// This bounds the number of signature verifications a single transaction can require.
+pub const MAX_MULTISIG_KEYS: usize = 16;
+
+/// Domain separator used when deriving the address of a multisig account, ensuring
+/// it can never collide with the address of a single verification key.
+const MULTISIG_ADDRESS_DOMAIN: &[u8] = b"astria-sequencer-multisig";
+
+/// The definition of a threshold multisig account.
+///
+/// A transaction is authorized by the account if it carries valid signatures from at
+/// least `threshold` of its keys.
+#[derive(Clone, Debug)]
+#[allow(clippy::module_name_repetitions)]
+pub struct MultisigKey {
+    threshold: u32,
+    verification_keys: Vec<VerificationKey>,
+}
+
+impl MultisigKey {
+    /// Creates a new multisig account definition.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no keys or more than [`MAX_MULTISIG_KEYS`] keys are provided,
+    /// if a key is repeated, or if the threshold is zero or larger than the number of keys.
+    pub fn new(
+        threshold: u32,
+        verification_keys: Vec<VerificationKey>,
+    ) -> Result<Self, MultisigKeyError> {
+        if verification_keys.is_empty() || verification_keys.len() > MAX_MULTISIG_KEYS {
+            return Err(MultisigKeyError::number_of_keys(verification_keys.len()));
+        }
+        if threshold == 0 || threshold as usize > verification_keys.len() {
+            return Err(MultisigKeyError::threshold(
+                threshold,
+                verification_keys.len(),
+            ));
+        }
+        let unique_keys: HashSet<_> = verification_keys.iter().map(|key| key.to_bytes()).collect();
+        if unique_keys.len() != verification_keys.len() {
+            return Err(MultisigKeyError::duplicate_key());
+        }
+        Ok(Self {
+            threshold,
+            verification_keys,
+        })
+    }
+
+    #[must_use]
+    pub fn threshold(&self) -> u32 {
+        self.threshold
+    }
+
+    #[must_use]
+    pub fn verification_keys(&self) -> &[VerificationKey] {
+        &self.verification_keys
+    }
+
+    /// Returns the address of the multisig account.
+    ///
+    /// The address is the first 20 bytes of the sha256 hash of a domain separator,
+    /// the big-endian threshold, and the account's keys in order.
+    #[must_use]
+    // Silence the clippy lint because the function body asserts that the panic
+    // cannot happen.
+    #[allow(clippy::missing_panics_doc)]
+    pub fn address(&self) -> Address {
+        let mut hasher = Sha256::new();
+        hasher.update(MULTISIG_ADDRESS_DOMAIN);
+        hasher.update(self.threshold.to_be_bytes());
+        for key in &self.verification_keys {
+            hasher.update(key);
+        }
+        let bytes: [u8; 32] = hasher.finalize().into();
+        Address::try_from_slice(&bytes[..ADDRESS_LEN])
+            .expect("can convert 32 byte hash to 20 byte array")
+    }
+
+    #[must_use]
+    pub fn to_raw(&self) -> raw::MultisigPublicKey {
+        raw::MultisigPublicKey {
+            threshold: self.threshold,
+            public_keys: self
+                .verification_keys
+                .iter()
+                .map(|key| key.to_bytes().to_vec())
+                .collect(),
+        }
+    }
+
+    /// Attempt to convert from a raw, unchecked protobuf [`raw::MultisigPublicKey`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if one of the public keys is not a valid ed25519 verification key,
+    /// or if the keys and threshold do not form a valid multisig account as described in
+    /// [`MultisigKey::new`].
+    pub fn try_from_raw(proto: raw::MultisigPublicKey) -> Result<Self, MultisigKeyError> {
+        let raw::MultisigPublicKey {
+            threshold,
+            public_keys,
+        } = proto;
+        let verification_keys = public_keys
+            .iter()
+            .map(|bytes| VerificationKey::try_from(&**bytes))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(MultisigKeyError::verification_key)?;
+        Self::new(threshold, verification_keys)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+#[allow(clippy::module_name_repetitions)]
+pub struct MultisigKeyError(MultisigKeyErrorKind);
+
+impl MultisigKeyError {
+    fn number_of_keys(received: usize) -> Self {
+        Self(MultisigKeyErrorKind::NumberOfKeys {
+            received,
+        })
+    }
+
+    fn threshold(threshold: u32, num_keys: usize) -> Self {
+        Self(MultisigKeyErrorKind::Threshold {
+            threshold,
+            num_keys,
+        })
+    }
+
+    fn duplicate_key() -> Self {
+        Self(MultisigKeyErrorKind::DuplicateKey)
+    }
+
+    fn verification_key(inner: ed25519_consensus::Error) -> Self {
+        Self(MultisigKeyErrorKind::VerificationKey(inner))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum MultisigKeyErrorKind {
+    #[error(
+        "a multisig account must have between 1 and {MAX_MULTISIG_KEYS} keys, but {received} were \
+         provided"
+    )]
+    NumberOfKeys { received: usize },
+    #[error(
+        "threshold must be between 1 and the number of keys ({num_keys}), but was {threshold}"
+    )]
+    Threshold { threshold: u32, num_keys: usize },
+    #[error("the keys of a multisig account must be unique")]
+    DuplicateKey,
+    #[error("`public_keys` field contained an invalid verification key")]
+    VerificationKey(#[source] ed25519_consensus::Error),
+}
+
+/// Signatures over a transaction made by members of a multisig account.
+#[derive(Clone, Debug)]
+#[allow(clippy::module_name_repetitions)]
+pub struct MultisigSignature {
+    key: MultisigKey,
+    signatures: Vec<(u32, Signature)>,
+}
+
+impl MultisigSignature {
+    /// Signs `message` with each of `signing_keys` on behalf of the multisig account `key`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if one of the signing keys is not a member of the account.
+    // Silence the clippy lint because the number of keys is bounded by `MAX_MULTISIG_KEYS`.
+    #[allow(clippy::missing_panics_doc)]
+    pub fn sign(
+        key: MultisigKey,
+        signing_keys: &[SigningKey],
+        message: &[u8],
+    ) -> Result<Self, MultisigSignatureError> {
+        let signatures = signing_keys
+            .iter()
+            .map(|signing_key| {
+                let verification_key = signing_key.verification_key();
+                let index = key
+                    .verification_keys
+                    .iter()
+                    .position(|key| key.to_bytes() == verification_key.to_bytes())
+                    .ok_or_else(MultisigSignatureError::unknown_signer)?;
+                Ok((
+                    u32::try_from(index).expect("number of keys is bounded by MAX_MULTISIG_KEYS"),
+                    signing_key.sign(message),
+                ))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self {
+            key,
+            signatures,
+        })
+    }
+
+    #[must_use]
+    pub fn key(&self) -> &MultisigKey {
+        &self.key
+    }
+
+    /// Returns the signatures and the indices of the keys that made them.
+    #[must_use]
+    pub fn signatures(&self) -> &[(u32, Signature)] {
+        &self.signatures
+    }
+
+    /// Verifies that at least `threshold` distinct members of the account signed `message`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a signature refers to a key that is not part of the account,
+    /// if a key signed more than once, if fewer signatures than the threshold were provided,
+    /// or if any of the signatures is invalid.
+    pub fn verify(&self, message: &[u8]) -> Result<(), MultisigSignatureError> {
+        let mut signers = HashSet::with_capacity(self.signatures.len());
+        for (index, signature) in &self.signatures {
+            let verification_key = self
+                .key
+                .verification_keys
+                .get(*index as usize)
+                .ok_or_else(|| MultisigSignatureError::index_out_of_range(*index))?;
+            if !signers.insert(*index) {
+                return Err(MultisigSignatureError::duplicate_signer(*index));
+            }
+            verification_key
+                .verify(signature, message)
+                .map_err(|source| MultisigSignatureError::verification(*index, source))?;
+        }
+        if signers.len() < self.key.threshold as usize {
+            return Err(MultisigSignatureError::below_threshold(
+                signers.len(),
+                self.key.threshold,
+            ));
+        }
+        Ok(())
+    }
+
+    #[must_use]
+    pub fn to_raw(&self) -> raw::MultisigSignature {
+        raw::MultisigSignature {
+            public_key: Some(self.key.to_raw()),
+            signatures: self
+                .signatures
+                .iter()
+                .map(|(index, signature)| raw::IndexedSignature {
+                    index: *index,
+                    signature: signature.to_bytes().to_vec(),
+                })
+                .collect(),
+        }
+    }
+
+    /// Attempt to convert from a raw, unchecked protobuf [`raw::MultisigSignature`].
+    ///
+    /// Note that this does not verify the signatures; use [`MultisigSignature::verify`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the public key is not set or invalid, or if one of the signatures
+    /// cannot be reconstructed from its bytes.
+    pub fn try_from_raw(proto: raw::MultisigSignature) -> Result<Self, MultisigSignatureError> {
+        let raw::MultisigSignature {
+            public_key,
+            signatures,
+        } = proto;
+        let Some(public_key) = public_key else {
+            return Err(MultisigSignatureError::unset_public_key());
+        };
+        let key = MultisigKey::try_from_raw(public_key).map_err(MultisigSignatureError::key)?;
+        let signatures = signatures
+            .into_iter()
+            .map(
+                |raw::IndexedSignature {
+                     index,
+                     signature,
+                 }| {
+                    Signature::try_from(&*signature)
+                        .map(|signature| (index, signature))
+                        .map_err(MultisigSignatureError::signature)
+                },
+            )
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            key,
+            signatures,
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+#[allow(clippy::module_name_repetitions)]
+pub struct MultisigSignatureError(MultisigSignatureErrorKind);
+
+impl MultisigSignatureError {
+    fn unset_public_key() -> Self {
+        Self(MultisigSignatureErrorKind::UnsetPublicKey)
+    }
+
+    fn key(inner: MultisigKeyError) -> Self {
+        Self(MultisigSignatureErrorKind::Key(inner))
+    }
+
+    fn signature(inner: ed25519_consensus::Error) -> Self {
+        Self(MultisigSignatureErrorKind::Signature(inner))
+    }
+
+    fn unknown_signer() -> Self {
+        Self(MultisigSignatureErrorKind::UnknownSigner)
+    }
+
+    fn index_out_of_range(index: u32) -> Self {
+        Self(MultisigSignatureErrorKind::IndexOutOfRange {
+            index,
+        })
+    }
+
+    fn duplicate_signer(index: u32) -> Self {
+        Self(MultisigSignatureErrorKind::DuplicateSigner {
+            index,
+        })
+    }
+
+    fn below_threshold(received: usize, threshold: u32) -> Self {
+        Self(MultisigSignatureErrorKind::BelowThreshold {
+            received,
+            threshold,
+        })
+    }
+
+    fn verification(index: u32, source: ed25519_consensus::Error) -> Self {
+        Self(MultisigSignatureErrorKind::Verification {
+            index,
+            source,
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum MultisigSignatureErrorKind {
+    #[error("`public_key` field not set")]
+    UnsetPublicKey,
+    #[error("`public_key` field invalid")]
+    Key(#[source] MultisigKeyError),
+    #[error("`signatures` field contained an invalid signature")]
+    Signature(#[source] ed25519_consensus::Error),
+    #[error("signing key is not a member of the multisig account")]
+    UnknownSigner,
+    #[error("signature refers to key at index {index}, which is not part of the account")]
+    IndexOutOfRange { index: u32 },
+    #[error("key at index {index} signed more than once")]
+    DuplicateSigner { index: u32 },
+    #[error("received {received} signatures, but the account requires {threshold}")]
+    BelowThreshold { received: usize, threshold: u32 },
+    #[error("signature by key at index {index} could not be verified")]
+    Verification {
+        index: u32,
+        source: ed25519_consensus::Error,
+    },
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn signing_keys() -> Vec<SigningKey> {
+        (1..=3).map(|i| SigningKey::from([i; 32])).collect()
+    }
+
+    fn two_of_three() -> MultisigKey {
+        MultisigKey::new(
+            2,
+            signing_keys()
+                .iter()
+                .map(SigningKey::verification_key)
+                .collect(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn multisig_key_rejects_invalid_threshold() {
+        let keys: Vec<_> = signing_keys()
+            .iter()
+            .map(SigningKey::verification_key)
+            .collect();
+        MultisigKey::new(0, keys.clone()).unwrap_err();
+        MultisigKey::new(4, keys).unwrap_err();
+    }
+
+    #[test]
+    fn multisig_key_rejects_duplicate_keys() {
+        let key = SigningKey::from([1; 32]).verification_key();
+        MultisigKey::new(1, vec![key, key]).unwrap_err();
+    }
+
+    #[test]
+    fn multisig_address_differs_from_single_key_address() {
+        let key = SigningKey::from([1; 32]).verification_key();
+        let multisig = MultisigKey::new(1, vec![key]).unwrap();
+        assert_ne!(multisig.address(), Address::from_verification_key(key));
+    }
+
+    #[test]
+    fn multisig_signature_meets_threshold() {
+        let keys = signing_keys();
+        let signature = MultisigSignature::sign(two_of_three(), &keys[1..], b"message").unwrap();
+        signature.verify(b"message").unwrap();
+
+        let roundtripped = MultisigSignature::try_from_raw(signature.to_raw()).unwrap();
+        roundtripped.verify(b"message").unwrap();
+        assert_eq!(roundtripped.key().address(), two_of_three().address());
+    }
+
+    #[test]
+    fn multisig_signature_below_threshold() {
+        let keys = signing_keys();
+        let signature = MultisigSignature::sign(two_of_three(), &keys[..1], b"message").unwrap();
+        signature.verify(b"message").unwrap_err();
+    }
+
+    #[test]
+    fn multisig_signature_duplicate_signer() {
+        let keys = signing_keys();
+        let signature = MultisigSignature::sign(
+            two_of_three(),
+            &[keys[0].clone(), keys[0].clone()],
+            b"message",
+        )
+        .unwrap();
+        signature.verify(b"message").unwrap_err();
+    }
+
+    #[test]
+    fn multisig_signature_wrong_message() {
+        let keys = signing_keys();
+        let signature = MultisigSignature::sign(two_of_three(), &keys, b"message").unwrap();
+        signature.verify(b"other message").unwrap_err();
+    }
+}
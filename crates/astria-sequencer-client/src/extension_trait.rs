@@ -27,12 +27,25 @@ use std::{
 };
 
 pub use astria_core::sequencer::v1alpha1::{
+    asset,
     block::SequencerBlockError,
     Address,
+    AllowedFeeAssetsResponse,
+    AssetMetadataResponse,
+    AssetSupplyResponse,
     BalanceResponse,
+    BlockFeesResponse,
+    ChainIdResponse,
+    DenomResponse,
+    IbcRelayerAddressesResponse,
+    Ics20RateLimitResponse,
     NonceResponse,
+    RollupId,
+    RollupResponse,
     SequencerBlock,
     SignedTransaction,
+    SudoAddressResponse,
+    ValidatorSetResponse,
 };
 use async_trait::async_trait;
 use futures::Stream;
@@ -70,7 +83,8 @@ const _: () = {
 /// 1. the RPC call of the underlying tendermint client fails;
 /// 2. the returned bytes contained in an `abci_query` RPC response cannot be deserialized as a
 ///    sequencer query response.
-/// 3. the sequencer query response is not the expected one.
+/// 3. the sequencer query response is not the expected one;
+/// 4. the sequencer query response cannot be converted to its native astria type.
 #[derive(Debug)]
 pub struct Error {
     inner: ErrorKind,
@@ -87,6 +101,7 @@ impl std::error::Error for Error {
         match &self.inner {
             ErrorKind::AbciQueryDeserialization(e) => Some(e),
             ErrorKind::CometBftConversion(e) => Some(e),
+            ErrorKind::NativeConversion(e) => Some(e),
             ErrorKind::TendermintRpc(e) => Some(e),
         }
     }
@@ -118,6 +133,19 @@ impl Error {
         }
     }
 
+    /// Convenience function to construct `Error` containing a `DeserializationError`.
+    fn native_conversion<E>(target: &'static str, inner: E) -> Self
+    where
+        E: std::error::Error + Send + Sync + 'static,
+    {
+        Self {
+            inner: ErrorKind::NativeConversion(DeserializationError {
+                inner: Arc::new(inner),
+                target,
+            }),
+        }
+    }
+
     fn cometbft_conversion(e: SequencerBlockError) -> Self {
         Self {
             inner: ErrorKind::CometBftConversion(e),
@@ -249,6 +277,7 @@ impl std::error::Error for DeserializationError {
 pub enum ErrorKind {
     AbciQueryDeserialization(AbciQueryDeserializationError),
     CometBftConversion(SequencerBlockError),
+    NativeConversion(DeserializationError),
     TendermintRpc(TendermintRpcError),
 }
 
@@ -526,6 +555,544 @@ pub trait SequencerClientExt: Client {
         self.get_nonce(address, 0u32).await
     }
 
+    /// Returns the validator set at the given height.
+    ///
+    /// # Errors
+    ///
+    /// - If calling tendermint `abci_query` RPC fails.
+    /// - If the bytes contained in the abci query response cannot be read as an
+    ///   `astria.sequencer.v1alpha1.ValidatorSetResponse`.
+    /// - If the validator updates contained in the response are invalid.
+    async fn get_validator_set<HeightT>(
+        &self,
+        height: HeightT,
+    ) -> Result<ValidatorSetResponse, Error>
+    where
+        HeightT: Into<tendermint::block::Height> + Send,
+    {
+        let response = self
+            .abci_query(
+                Some("authority/validator_set".to_string()),
+                vec![],
+                Some(height.into()),
+                false,
+            )
+            .await
+            .map_err(|e| Error::tendermint_rpc("abci_query", e))?;
+
+        let proto_response =
+            astria_core::generated::sequencer::v1alpha1::ValidatorSetResponse::decode(
+                &*response.value,
+            )
+            .map_err(|e| {
+                Error::abci_query_deserialization(
+                    "astria.sequencer.v1alpha1.ValidatorSetResponse",
+                    response,
+                    e,
+                )
+            })?;
+        proto_response.try_into_native().map_err(|e| {
+            Error::native_conversion("astria.sequencer.v1alpha1.ValidatorSetResponse", e)
+        })
+    }
+
+    /// Returns the sudo address of the authority component at the given height.
+    ///
+    /// # Errors
+    ///
+    /// - If calling tendermint `abci_query` RPC fails.
+    /// - If the bytes contained in the abci query response cannot be read as an
+    ///   `astria.sequencer.v1alpha1.SudoAddressResponse`.
+    /// - If the address contained in the response is not 20 bytes long.
+    async fn get_sudo_address<HeightT>(&self, height: HeightT) -> Result<SudoAddressResponse, Error>
+    where
+        HeightT: Into<tendermint::block::Height> + Send,
+    {
+        let response = self
+            .abci_query(
+                Some("authority/sudo_address".to_string()),
+                vec![],
+                Some(height.into()),
+                false,
+            )
+            .await
+            .map_err(|e| Error::tendermint_rpc("abci_query", e))?;
+
+        let proto_response =
+            astria_core::generated::sequencer::v1alpha1::SudoAddressResponse::decode(
+                &*response.value,
+            )
+            .map_err(|e| {
+                Error::abci_query_deserialization(
+                    "astria.sequencer.v1alpha1.SudoAddressResponse",
+                    response,
+                    e,
+                )
+            })?;
+        proto_response.try_into_native().map_err(|e| {
+            Error::native_conversion("astria.sequencer.v1alpha1.SudoAddressResponse", e)
+        })
+    }
+
+    /// Returns the IBC sudo address at the given height.
+    ///
+    /// # Errors
+    ///
+    /// - If calling tendermint `abci_query` RPC fails.
+    /// - If the bytes contained in the abci query response cannot be read as an
+    ///   `astria.sequencer.v1alpha1.SudoAddressResponse`.
+    /// - If the address contained in the response is not 20 bytes long.
+    async fn get_ibc_sudo_address<HeightT>(
+        &self,
+        height: HeightT,
+    ) -> Result<SudoAddressResponse, Error>
+    where
+        HeightT: Into<tendermint::block::Height> + Send,
+    {
+        let response = self
+            .abci_query(
+                Some("ibc/sudo_address".to_string()),
+                vec![],
+                Some(height.into()),
+                false,
+            )
+            .await
+            .map_err(|e| Error::tendermint_rpc("abci_query", e))?;
+
+        let proto_response =
+            astria_core::generated::sequencer::v1alpha1::SudoAddressResponse::decode(
+                &*response.value,
+            )
+            .map_err(|e| {
+                Error::abci_query_deserialization(
+                    "astria.sequencer.v1alpha1.SudoAddressResponse",
+                    response,
+                    e,
+                )
+            })?;
+        proto_response.try_into_native().map_err(|e| {
+            Error::native_conversion("astria.sequencer.v1alpha1.SudoAddressResponse", e)
+        })
+    }
+
+    /// Returns the addresses allowed to relay IBC packets at the given height.
+    ///
+    /// # Errors
+    ///
+    /// - If calling tendermint `abci_query` RPC fails.
+    /// - If the bytes contained in the abci query response cannot be read as an
+    ///   `astria.sequencer.v1alpha1.IbcRelayerAddressesResponse`.
+    /// - If any of the addresses contained in the response is not 20 bytes long.
+    async fn get_ibc_relayer_addresses<HeightT>(
+        &self,
+        height: HeightT,
+    ) -> Result<IbcRelayerAddressesResponse, Error>
+    where
+        HeightT: Into<tendermint::block::Height> + Send,
+    {
+        let response = self
+            .abci_query(
+                Some("ibc/relayer_addresses".to_string()),
+                vec![],
+                Some(height.into()),
+                false,
+            )
+            .await
+            .map_err(|e| Error::tendermint_rpc("abci_query", e))?;
+
+        let proto_response =
+            astria_core::generated::sequencer::v1alpha1::IbcRelayerAddressesResponse::decode(
+                &*response.value,
+            )
+            .map_err(|e| {
+                Error::abci_query_deserialization(
+                    "astria.sequencer.v1alpha1.IbcRelayerAddressesResponse",
+                    response,
+                    e,
+                )
+            })?;
+        proto_response.try_into_native().map_err(|e| {
+            Error::native_conversion("astria.sequencer.v1alpha1.IbcRelayerAddressesResponse", e)
+        })
+    }
+
+    /// Returns the rate limit on the ICS20 transfers of the given asset over the given
+    /// channel, and its usage in the window containing the given height.
+    ///
+    /// # Errors
+    ///
+    /// - If calling tendermint `abci_query` RPC fails.
+    /// - If the bytes contained in the abci query response cannot be read as an
+    ///   `astria.sequencer.v1alpha1.Ics20RateLimitResponse`.
+    /// - If the channel ID or the asset ID contained in the response are invalid.
+    async fn get_ics20_rate_limit<HeightT>(
+        &self,
+        channel: &str,
+        asset_id: asset::Id,
+        height: HeightT,
+    ) -> Result<Ics20RateLimitResponse, Error>
+    where
+        HeightT: Into<tendermint::block::Height> + Send,
+    {
+        let path = format!(
+            "ibc/ics20_rate_limit/{channel}/{}",
+            hex::encode(asset_id.as_bytes())
+        );
+
+        let response = self
+            .abci_query(Some(path), vec![], Some(height.into()), false)
+            .await
+            .map_err(|e| Error::tendermint_rpc("abci_query", e))?;
+
+        let proto_response =
+            astria_core::generated::sequencer::v1alpha1::Ics20RateLimitResponse::decode(
+                &*response.value,
+            )
+            .map_err(|e| {
+                Error::abci_query_deserialization(
+                    "astria.sequencer.v1alpha1.Ics20RateLimitResponse",
+                    response,
+                    e,
+                )
+            })?;
+        proto_response.try_into_native().map_err(|e| {
+            Error::native_conversion("astria.sequencer.v1alpha1.Ics20RateLimitResponse", e)
+        })
+    }
+
+    /// Returns the denomination of the native asset at the given height.
+    ///
+    /// # Errors
+    ///
+    /// - If calling tendermint `abci_query` RPC fails.
+    /// - If the bytes contained in the abci query response cannot be read as an
+    ///   `astria.sequencer.v1alpha1.DenomResponse`.
+    async fn get_native_asset_denom<HeightT>(&self, height: HeightT) -> Result<DenomResponse, Error>
+    where
+        HeightT: Into<tendermint::block::Height> + Send,
+    {
+        let response = self
+            .abci_query(
+                Some("asset/native_denom".to_string()),
+                vec![],
+                Some(height.into()),
+                false,
+            )
+            .await
+            .map_err(|e| Error::tendermint_rpc("abci_query", e))?;
+
+        let proto_response =
+            astria_core::generated::sequencer::v1alpha1::DenomResponse::decode(&*response.value)
+                .map_err(|e| {
+                    Error::abci_query_deserialization(
+                        "astria.sequencer.v1alpha1.DenomResponse",
+                        response,
+                        e,
+                    )
+                })?;
+        Ok(proto_response.into_native())
+    }
+
+    /// Returns the denomination of the asset with the given ID at the given height.
+    ///
+    /// # Errors
+    ///
+    /// - If calling tendermint `abci_query` RPC fails.
+    /// - If the bytes contained in the abci query response cannot be read as an
+    ///   `astria.sequencer.v1alpha1.DenomResponse`.
+    async fn get_asset_denom<HeightT>(
+        &self,
+        asset_id: asset::Id,
+        height: HeightT,
+    ) -> Result<DenomResponse, Error>
+    where
+        HeightT: Into<tendermint::block::Height> + Send,
+    {
+        let path = format!("asset/denom/{}", hex::encode(asset_id.as_bytes()));
+
+        let response = self
+            .abci_query(Some(path), vec![], Some(height.into()), false)
+            .await
+            .map_err(|e| Error::tendermint_rpc("abci_query", e))?;
+
+        let proto_response =
+            astria_core::generated::sequencer::v1alpha1::DenomResponse::decode(&*response.value)
+                .map_err(|e| {
+                    Error::abci_query_deserialization(
+                        "astria.sequencer.v1alpha1.DenomResponse",
+                        response,
+                        e,
+                    )
+                })?;
+        Ok(proto_response.into_native())
+    }
+
+    /// Returns the display metadata of the asset with the given ID at the given height.
+    ///
+    /// # Errors
+    ///
+    /// - If calling tendermint `abci_query` RPC fails.
+    /// - If the bytes contained in the abci query response cannot be read as an
+    ///   `astria.sequencer.v1alpha1.AssetMetadataResponse`.
+    /// - If the asset ID contained in the response is not 32 bytes long.
+    async fn get_asset_metadata<HeightT>(
+        &self,
+        asset_id: asset::Id,
+        height: HeightT,
+    ) -> Result<AssetMetadataResponse, Error>
+    where
+        HeightT: Into<tendermint::block::Height> + Send,
+    {
+        let path = format!("asset/metadata/{}", hex::encode(asset_id.as_bytes()));
+
+        let response = self
+            .abci_query(Some(path), vec![], Some(height.into()), false)
+            .await
+            .map_err(|e| Error::tendermint_rpc("abci_query", e))?;
+
+        let proto_response =
+            astria_core::generated::sequencer::v1alpha1::AssetMetadataResponse::decode(
+                &*response.value,
+            )
+            .map_err(|e| {
+                Error::abci_query_deserialization(
+                    "astria.sequencer.v1alpha1.AssetMetadataResponse",
+                    response,
+                    e,
+                )
+            })?;
+        proto_response.try_into_native().map_err(|e| {
+            Error::native_conversion("astria.sequencer.v1alpha1.AssetMetadataResponse", e)
+        })
+    }
+
+    /// Returns the total supply of the asset with the given ID at the given height.
+    ///
+    /// # Errors
+    ///
+    /// - If calling tendermint `abci_query` RPC fails.
+    /// - If the bytes contained in the abci query response cannot be read as an
+    ///   `astria.sequencer.v1alpha1.AssetSupplyResponse`.
+    /// - If the asset ID contained in the response is not 32 bytes long.
+    async fn get_asset_supply<HeightT>(
+        &self,
+        asset_id: asset::Id,
+        height: HeightT,
+    ) -> Result<AssetSupplyResponse, Error>
+    where
+        HeightT: Into<tendermint::block::Height> + Send,
+    {
+        let path = format!("asset/supply/{}", hex::encode(asset_id.as_bytes()));
+
+        let response = self
+            .abci_query(Some(path), vec![], Some(height.into()), false)
+            .await
+            .map_err(|e| Error::tendermint_rpc("abci_query", e))?;
+
+        let proto_response =
+            astria_core::generated::sequencer::v1alpha1::AssetSupplyResponse::decode(
+                &*response.value,
+            )
+            .map_err(|e| {
+                Error::abci_query_deserialization(
+                    "astria.sequencer.v1alpha1.AssetSupplyResponse",
+                    response,
+                    e,
+                )
+            })?;
+        proto_response.try_into_native().map_err(|e| {
+            Error::native_conversion("astria.sequencer.v1alpha1.AssetSupplyResponse", e)
+        })
+    }
+
+    /// Returns the chain ID of the sequencer at the given height.
+    ///
+    /// # Errors
+    ///
+    /// - If calling tendermint `abci_query` RPC fails.
+    /// - If the bytes contained in the abci query response cannot be read as an
+    ///   `astria.sequencer.v1alpha1.ChainIdResponse`.
+    async fn get_chain_id<HeightT>(&self, height: HeightT) -> Result<ChainIdResponse, Error>
+    where
+        HeightT: Into<tendermint::block::Height> + Send,
+    {
+        let response = self
+            .abci_query(
+                Some("chain/id".to_string()),
+                vec![],
+                Some(height.into()),
+                false,
+            )
+            .await
+            .map_err(|e| Error::tendermint_rpc("abci_query", e))?;
+
+        let proto_response =
+            astria_core::generated::sequencer::v1alpha1::ChainIdResponse::decode(&*response.value)
+                .map_err(|e| {
+                    Error::abci_query_deserialization(
+                        "astria.sequencer.v1alpha1.ChainIdResponse",
+                        response,
+                        e,
+                    )
+                })?;
+        Ok(proto_response.into_native())
+    }
+
+    /// Returns the fees collected in the block at the given height.
+    ///
+    /// # Errors
+    ///
+    /// - If calling tendermint `abci_query` RPC fails.
+    /// - If the bytes contained in the abci query response cannot be read as an
+    ///   `astria.sequencer.v1alpha1.BlockFeesResponse`.
+    /// - If any of the asset IDs contained in the response is not 32 bytes long.
+    async fn get_block_fees<HeightT>(&self, height: HeightT) -> Result<BlockFeesResponse, Error>
+    where
+        HeightT: Into<tendermint::block::Height> + Send,
+    {
+        let response = self
+            .abci_query(
+                Some("fees/block".to_string()),
+                vec![],
+                Some(height.into()),
+                false,
+            )
+            .await
+            .map_err(|e| Error::tendermint_rpc("abci_query", e))?;
+
+        let proto_response =
+            astria_core::generated::sequencer::v1alpha1::BlockFeesResponse::decode(
+                &*response.value,
+            )
+            .map_err(|e| {
+                Error::abci_query_deserialization(
+                    "astria.sequencer.v1alpha1.BlockFeesResponse",
+                    response,
+                    e,
+                )
+            })?;
+        proto_response
+            .try_into_native()
+            .map_err(|e| Error::native_conversion("astria.sequencer.v1alpha1.BlockFeesResponse", e))
+    }
+
+    /// Returns the assets that fees can be paid in at the given height.
+    ///
+    /// # Errors
+    ///
+    /// - If calling tendermint `abci_query` RPC fails.
+    /// - If the bytes contained in the abci query response cannot be read as an
+    ///   `astria.sequencer.v1alpha1.AllowedFeeAssetsResponse`.
+    /// - If any of the asset IDs contained in the response is not 32 bytes long.
+    async fn get_allowed_fee_assets<HeightT>(
+        &self,
+        height: HeightT,
+    ) -> Result<AllowedFeeAssetsResponse, Error>
+    where
+        HeightT: Into<tendermint::block::Height> + Send,
+    {
+        let response = self
+            .abci_query(
+                Some("fees/allowed_assets".to_string()),
+                vec![],
+                Some(height.into()),
+                false,
+            )
+            .await
+            .map_err(|e| Error::tendermint_rpc("abci_query", e))?;
+
+        let proto_response =
+            astria_core::generated::sequencer::v1alpha1::AllowedFeeAssetsResponse::decode(
+                &*response.value,
+            )
+            .map_err(|e| {
+                Error::abci_query_deserialization(
+                    "astria.sequencer.v1alpha1.AllowedFeeAssetsResponse",
+                    response,
+                    e,
+                )
+            })?;
+        proto_response.try_into_native().map_err(|e| {
+            Error::native_conversion("astria.sequencer.v1alpha1.AllowedFeeAssetsResponse", e)
+        })
+    }
+
+    /// Returns the registry record of the rollup with the given ID at the given height.
+    ///
+    /// # Errors
+    ///
+    /// - If calling tendermint `abci_query` RPC fails.
+    /// - If the bytes contained in the abci query response cannot be read as an
+    ///   `astria.sequencer.v1alpha1.RollupResponse`.
+    /// - If the rollup ID or the rollup record contained in the response are invalid.
+    async fn get_rollup<HeightT>(
+        &self,
+        rollup_id: RollupId,
+        height: HeightT,
+    ) -> Result<RollupResponse, Error>
+    where
+        HeightT: Into<tendermint::block::Height> + Send,
+    {
+        let path = format!("rollup/id/{}", hex::encode(rollup_id.get()));
+
+        let response = self
+            .abci_query(Some(path), vec![], Some(height.into()), false)
+            .await
+            .map_err(|e| Error::tendermint_rpc("abci_query", e))?;
+
+        let proto_response =
+            astria_core::generated::sequencer::v1alpha1::RollupResponse::decode(&*response.value)
+                .map_err(|e| {
+                Error::abci_query_deserialization(
+                    "astria.sequencer.v1alpha1.RollupResponse",
+                    response,
+                    e,
+                )
+            })?;
+        proto_response
+            .try_into_native()
+            .map_err(|e| Error::native_conversion("astria.sequencer.v1alpha1.RollupResponse", e))
+    }
+
+    /// Returns the registry record of the rollup with the given name at the given height.
+    ///
+    /// The rollup ID is derived from the name by the sequencer.
+    ///
+    /// # Errors
+    ///
+    /// - If calling tendermint `abci_query` RPC fails.
+    /// - If the bytes contained in the abci query response cannot be read as an
+    ///   `astria.sequencer.v1alpha1.RollupResponse`.
+    /// - If the rollup ID or the rollup record contained in the response are invalid.
+    async fn get_rollup_by_name<HeightT>(
+        &self,
+        name: &str,
+        height: HeightT,
+    ) -> Result<RollupResponse, Error>
+    where
+        HeightT: Into<tendermint::block::Height> + Send,
+    {
+        let path = format!("rollup/name/{name}");
+
+        let response = self
+            .abci_query(Some(path), vec![], Some(height.into()), false)
+            .await
+            .map_err(|e| Error::tendermint_rpc("abci_query", e))?;
+
+        let proto_response =
+            astria_core::generated::sequencer::v1alpha1::RollupResponse::decode(&*response.value)
+                .map_err(|e| {
+                Error::abci_query_deserialization(
+                    "astria.sequencer.v1alpha1.RollupResponse",
+                    response,
+                    e,
+                )
+            })?;
+        proto_response
+            .try_into_native()
+            .map_err(|e| Error::native_conversion("astria.sequencer.v1alpha1.RollupResponse", e))
+    }
+
     /// Get the latest sequencer block.
     ///
     /// This is a convenience method that converts the result [`Client::latest_block`]
@@ -2,6 +2,7 @@ use astria_core::sequencer::v1alpha1::{
     asset::default_native_asset_id,
     transaction::action::TransferAction,
     Address,
+    RollupId,
     SignedTransaction,
     UnsignedTransaction,
 };
@@ -135,6 +136,10 @@ fn create_signed_transaction() -> SignedTransaction {
     UnsignedTransaction {
         nonce: 1,
         actions,
+        valid_until_height: None,
+        valid_until_timestamp: None,
+        tip: None,
+        fee_payer: None,
     }
     .into_signed(&alice_key)
 }
@@ -192,6 +197,279 @@ async fn get_latest_balance() {
     assert_eq!(expected_response, actual_response);
 }
 
+#[tokio::test]
+async fn get_chain_id() {
+    use astria_core::generated::sequencer::v1alpha1::ChainIdResponse;
+
+    let MockSequencer {
+        server,
+        client,
+    } = MockSequencer::start().await;
+
+    let expected_response = ChainIdResponse {
+        height: 10,
+        chain_id: "test".to_string(),
+    };
+    let _guard = register_abci_query_response(&server, "chain/id", expected_response.clone()).await;
+
+    let actual_response = client.get_chain_id(0u32).await.unwrap().into_raw();
+    assert_eq!(expected_response, actual_response);
+}
+
+#[tokio::test]
+async fn get_block_fees() {
+    use astria_core::generated::sequencer::v1alpha1::{
+        AssetFee,
+        BlockFeesResponse,
+    };
+
+    let MockSequencer {
+        server,
+        client,
+    } = MockSequencer::start().await;
+
+    let expected_response = BlockFeesResponse {
+        height: 10,
+        fees: vec![AssetFee {
+            asset_id: vec![1; 32],
+            amount: Some(12u128.into()),
+        }],
+    };
+    let _guard =
+        register_abci_query_response(&server, "fees/block", expected_response.clone()).await;
+
+    let actual_response = client.get_block_fees(10u32).await.unwrap().into_raw();
+    assert_eq!(expected_response, actual_response);
+}
+
+#[tokio::test]
+async fn get_allowed_fee_assets() {
+    use astria_core::generated::sequencer::v1alpha1::AllowedFeeAssetsResponse;
+
+    let MockSequencer {
+        server,
+        client,
+    } = MockSequencer::start().await;
+
+    let expected_response = AllowedFeeAssetsResponse {
+        height: 10,
+        fee_asset_ids: vec![vec![1; 32]],
+    };
+    let _guard =
+        register_abci_query_response(&server, "fees/allowed_assets", expected_response.clone())
+            .await;
+
+    let actual_response = client
+        .get_allowed_fee_assets(10u32)
+        .await
+        .unwrap()
+        .into_raw();
+    assert_eq!(expected_response, actual_response);
+}
+
+#[tokio::test]
+async fn get_ibc_relayer_addresses() {
+    use astria_core::generated::sequencer::v1alpha1::IbcRelayerAddressesResponse;
+
+    let MockSequencer {
+        server,
+        client,
+    } = MockSequencer::start().await;
+
+    let expected_response = IbcRelayerAddressesResponse {
+        height: 10,
+        addresses: vec![vec![1; 20]],
+    };
+    let _guard =
+        register_abci_query_response(&server, "ibc/relayer_addresses", expected_response.clone())
+            .await;
+
+    let actual_response = client
+        .get_ibc_relayer_addresses(10u32)
+        .await
+        .unwrap()
+        .into_raw();
+    assert_eq!(expected_response, actual_response);
+}
+
+#[tokio::test]
+async fn get_ics20_rate_limit() {
+    use astria_core::generated::sequencer::v1alpha1::{
+        Ics20RateLimit,
+        Ics20RateLimitResponse,
+    };
+
+    let MockSequencer {
+        server,
+        client,
+    } = MockSequencer::start().await;
+
+    let asset_id = default_native_asset_id();
+    let expected_response = Ics20RateLimitResponse {
+        height: 10,
+        channel: "channel-0".to_string(),
+        asset_id: asset_id.as_bytes().to_vec(),
+        rate_limit: Some(Ics20RateLimit {
+            max_inflow: Some(1_000u128.into()),
+            max_outflow: Some(500u128.into()),
+            window_blocks: 100,
+        }),
+        window_start_height: 0,
+        inflow: Some(300u128.into()),
+        outflow: Some(200u128.into()),
+    };
+    let _guard = register_abci_query_response(
+        &server,
+        &format!(
+            "ibc/ics20_rate_limit/channel-0/{}",
+            hex::encode(asset_id.as_bytes())
+        ),
+        expected_response.clone(),
+    )
+    .await;
+
+    let actual_response = client
+        .get_ics20_rate_limit("channel-0", asset_id, 10u32)
+        .await
+        .unwrap()
+        .into_raw();
+    assert_eq!(expected_response, actual_response);
+}
+
+#[tokio::test]
+async fn get_rollup() {
+    use astria_core::generated::sequencer::v1alpha1::{
+        RollupRecord,
+        RollupResponse,
+    };
+
+    let MockSequencer {
+        server,
+        client,
+    } = MockSequencer::start().await;
+
+    let rollup_id = RollupId::from_unhashed_bytes("test-rollup");
+    let expected_response = RollupResponse {
+        height: 10,
+        rollup_id: rollup_id.to_vec(),
+        rollup: Some(RollupRecord {
+            name: "test-rollup".to_string(),
+            owner: ALICE_ADDRESS.to_vec(),
+            celestia_namespace: vec![1; 10],
+            metadata: "test metadata".to_string(),
+        }),
+    };
+    let _guard = register_abci_query_response(
+        &server,
+        &format!("rollup/id/{}", hex::encode(rollup_id.get())),
+        expected_response.clone(),
+    )
+    .await;
+
+    let actual_response = client
+        .get_rollup(rollup_id, 10u32)
+        .await
+        .unwrap()
+        .into_raw();
+    assert_eq!(expected_response, actual_response);
+}
+
+#[tokio::test]
+async fn get_rollup_by_name() {
+    use astria_core::generated::sequencer::v1alpha1::RollupResponse;
+
+    let MockSequencer {
+        server,
+        client,
+    } = MockSequencer::start().await;
+
+    let expected_response = RollupResponse {
+        height: 10,
+        rollup_id: RollupId::from_unhashed_bytes("test-rollup").to_vec(),
+        rollup: None,
+    };
+    let _guard = register_abci_query_response(
+        &server,
+        "rollup/name/test-rollup",
+        expected_response.clone(),
+    )
+    .await;
+
+    let actual_response = client
+        .get_rollup_by_name("test-rollup", 10u32)
+        .await
+        .unwrap()
+        .into_raw();
+    assert_eq!(expected_response, actual_response);
+}
+
+#[tokio::test]
+async fn get_asset_metadata() {
+    use astria_core::generated::sequencer::v1alpha1::{
+        AssetMetadata,
+        AssetMetadataResponse,
+    };
+
+    let MockSequencer {
+        server,
+        client,
+    } = MockSequencer::start().await;
+
+    let asset_id = default_native_asset_id();
+    let expected_response = AssetMetadataResponse {
+        height: 10,
+        asset_id: asset_id.as_bytes().to_vec(),
+        metadata: Some(AssetMetadata {
+            decimals: 9,
+            symbol: "RIA".to_string(),
+            display_denom: "ria".to_string(),
+        }),
+    };
+    let _guard = register_abci_query_response(
+        &server,
+        &format!("asset/metadata/{}", hex::encode(asset_id.as_bytes())),
+        expected_response.clone(),
+    )
+    .await;
+
+    let actual_response = client
+        .get_asset_metadata(asset_id, 10u32)
+        .await
+        .unwrap()
+        .into_raw();
+    assert_eq!(expected_response, actual_response);
+}
+
+#[tokio::test]
+async fn get_asset_supply() {
+    use astria_core::generated::sequencer::v1alpha1::AssetSupplyResponse;
+
+    let MockSequencer {
+        server,
+        client,
+    } = MockSequencer::start().await;
+
+    let asset_id = default_native_asset_id();
+    let expected_response = AssetSupplyResponse {
+        height: 10,
+        asset_id: asset_id.as_bytes().to_vec(),
+        supply: Some(10u128.pow(19).into()),
+    };
+    let _guard = register_abci_query_response(
+        &server,
+        &format!("asset/supply/{}", hex::encode(asset_id.as_bytes())),
+        expected_response.clone(),
+    )
+    .await;
+
+    let actual_response = client
+        .get_asset_supply(asset_id, 10u32)
+        .await
+        .unwrap()
+        .into_raw();
+    assert_eq!(expected_response, actual_response);
+}
+
 #[tokio::test]
 async fn submit_tx_sync() {
     let MockSequencer {
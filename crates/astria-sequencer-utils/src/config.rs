@@ -1,6 +1,32 @@
-use clap::Parser;
+use std::path::PathBuf;
+
+use clap::{
+    Parser,
+    Subcommand,
+};
 
 #[derive(Debug, Parser)]
+pub struct Cli {
+    #[clap(subcommand)]
+    pub command: Command,
+}
+
+impl Cli {
+    #[must_use]
+    pub fn get() -> Self {
+        Cli::parse()
+    }
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Copy a genesis app state into a cometbft genesis file
+    CopyGenesisState(Config),
+    /// Export the state of a sequencer database as a genesis app state
+    ExportGenesisState(ExportConfig),
+}
+
+#[derive(Debug, clap::Args)]
 pub struct Config {
     #[clap(long)]
     pub genesis_app_state_file: String,
@@ -12,9 +38,17 @@ pub struct Config {
     pub chain_id: String,
 }
 
-impl Config {
-    #[must_use]
-    pub fn get() -> Self {
-        Config::parse()
-    }
+#[derive(Debug, clap::Args)]
+pub struct ExportConfig {
+    /// The path to the sequencer's database
+    #[clap(long)]
+    pub db_path: PathBuf,
+
+    /// The height to export the state at; the latest state is exported if not set
+    #[clap(long)]
+    pub height: Option<u64>,
+
+    /// The file to write the genesis app state to
+    #[clap(long)]
+    pub destination_genesis_app_state_file: PathBuf,
 }
@@ -0,0 +1,44 @@
+use eyre::{
+    eyre,
+    Result,
+    WrapErr,
+};
+
+use crate::config::ExportConfig;
+
+pub struct GenesisExporter;
+
+impl GenesisExporter {
+    /// Exports the state of a sequencer database as a genesis app state, writing it
+    /// to a JSON file.
+    ///
+    /// The written file can be used as the `--genesis-app-state-file` of the
+    /// `copy-genesis-state` command.
+    ///
+    /// # Errors
+    ///
+    /// An `eyre::Result` is returned if the database cannot be opened or read,
+    /// or if the destination file cannot be written.
+    pub async fn export_genesis_state(data: ExportConfig) -> Result<()> {
+        println!("exporting sequencer state as genesis app state:");
+        println!("\tsource database: {}", data.db_path.display());
+        match data.height {
+            Some(height) => println!("\theight: {height}"),
+            None => println!("\theight: latest"),
+        }
+        println!(
+            "\tdestination genesis app state file: {}",
+            data.destination_genesis_app_state_file.display()
+        );
+
+        let genesis_state = astria_sequencer::export_genesis_state(data.db_path, data.height)
+            .await
+            .map_err(|e| eyre!("{e:#}"))
+            .wrap_err("failed to export genesis state from database")?;
+
+        std::fs::write(&data.destination_genesis_app_state_file, genesis_state)
+            .wrap_err("failed to write genesis app state file")?;
+
+        Ok(())
+    }
+}
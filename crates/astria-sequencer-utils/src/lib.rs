@@ -1,2 +1,3 @@
 pub mod config;
+pub mod genesis_exporter;
 pub mod genesis_parser;
@@ -1,12 +1,26 @@
 use astria_sequencer_utils::{
-    config::Config,
+    config::{
+        Cli,
+        Command,
+    },
+    genesis_exporter::GenesisExporter,
     genesis_parser::GenesisParser,
 };
 
-fn main() {
-    let config = Config::get();
-
-    println!("running genesis parser");
-    GenesisParser::propagate_app_state(config).expect("failed to propagate data");
-    println!("genesis parsing complete");
+#[tokio::main]
+async fn main() {
+    match Cli::get().command {
+        Command::CopyGenesisState(config) => {
+            println!("running genesis parser");
+            GenesisParser::propagate_app_state(config).expect("failed to propagate data");
+            println!("genesis parsing complete");
+        }
+        Command::ExportGenesisState(config) => {
+            println!("running genesis exporter");
+            GenesisExporter::export_genesis_state(config)
+                .await
+                .expect("failed to export genesis state");
+            println!("genesis export complete");
+        }
+    }
 }
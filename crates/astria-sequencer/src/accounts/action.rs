@@ -1,10 +1,11 @@
 use anyhow::{
-    anyhow,
     ensure,
     Context,
     Result,
 };
 use astria_core::sequencer::v1alpha1::{
+    asset,
+    event::TransferEvent,
     transaction::action::TransferAction,
     Address,
 };
@@ -15,13 +16,11 @@ use crate::{
         StateReadExt,
         StateWriteExt,
     },
-    state_ext::StateWriteExt as _,
+    bridge::state_ext::StateReadExt as _,
+    fees::state_ext::StateReadExt as _,
     transaction::action_handler::ActionHandler,
 };
 
-/// Fee charged for a `Transfer` action.
-pub(crate) const TRANSFER_FEE: u128 = 12;
-
 #[async_trait::async_trait]
 impl ActionHandler for TransferAction {
     async fn check_stateful<S: StateReadExt + 'static>(
@@ -29,44 +28,8 @@ impl ActionHandler for TransferAction {
         state: &S,
         from: Address,
     ) -> Result<()> {
-        let transfer_asset_id = self.asset_id;
-
-        let from_fee_balance = state
-            .get_account_balance(from, self.fee_asset_id)
-            .await
-            .context("failed getting `from` account balance for fee payment")?;
-
-        // if fee asset is same as transfer asset, ensure accounts has enough funds
-        // to cover both the fee and the amount transferred
-        if self.fee_asset_id == transfer_asset_id {
-            let payment_amount = self
-                .amount
-                .checked_add(TRANSFER_FEE)
-                .ok_or(anyhow!("transfer amount plus fee overflowed"))?;
-
-            ensure!(
-                from_fee_balance >= payment_amount,
-                "insufficient funds for transfer and fee payment"
-            );
-        } else {
-            // otherwise, check the fee asset account has enough to cover the fees,
-            // and the transfer asset account has enough to cover the transfer
-            ensure!(
-                from_fee_balance >= TRANSFER_FEE,
-                "insufficient funds for fee payment"
-            );
-
-            let from_transfer_balance = state
-                .get_account_balance(from, transfer_asset_id)
-                .await
-                .context("failed to get account balance in transfer check")?;
-            ensure!(
-                from_transfer_balance >= self.amount,
-                "insufficient funds for transfer"
-            );
-        }
-
-        Ok(())
+        check_not_transfer_to_bridge_account(state, &self.to).await?;
+        check_transfer(state, from, self.asset_id, self.amount, self.fee_asset_id).await
     }
 
     #[instrument(
@@ -77,87 +40,94 @@ impl ActionHandler for TransferAction {
         )
     )]
     async fn execute<S: StateWriteExt>(&self, state: &mut S, from: Address) -> Result<()> {
+        // an earlier action of the transaction may have turned the recipient into a
+        // bridge account
+        check_not_transfer_to_bridge_account(&*state, &self.to).await?;
+        execute_transfer(state, from, self.to, self.asset_id, self.amount).await
+    }
+}
+
+/// Funds sent to a bridge account must be deposited with a `BridgeLockAction`, which
+/// is picked up by the bridge account's rollup. A plain transfer would not be.
+async fn check_not_transfer_to_bridge_account<S: StateReadExt>(
+    state: &S,
+    to: &Address,
+) -> Result<()> {
+    ensure!(
         state
-            .get_and_increase_block_fees(self.fee_asset_id, TRANSFER_FEE)
+            .get_bridge_account_rollup_id(to)
             .await
-            .context("failed to add to block fees")?;
+            .context("failed to get bridge account rollup ID")?
+            .is_none(),
+        "cannot transfer to bridge accounts; use a bridge lock instead"
+    );
+    Ok(())
+}
 
-        let transfer_asset_id = self.asset_id;
+/// Checks that `from` can transfer `amount` of `asset_id`, paying the fee in
+/// `fee_asset_id`.
+///
+/// This is shared by transfers and bridge locks, which only differ in their recipient.
+pub(crate) async fn check_transfer<S: StateReadExt>(
+    state: &S,
+    from: Address,
+    asset_id: asset::Id,
+    amount: u128,
+    fee_asset_id: asset::Id,
+) -> Result<()> {
+    ensure!(
+        state.is_allowed_fee_asset(fee_asset_id).await?,
+        "invalid fee asset"
+    );
 
-        let from_balance = state
-            .get_account_balance(from, transfer_asset_id)
-            .await
-            .context("failed getting `from` account balance")?;
-        let to_balance = state
-            .get_account_balance(self.to, transfer_asset_id)
+    // bridge accounts can only release funds through a `BridgeUnlockAction`
+    ensure!(
+        state
+            .get_bridge_account_rollup_id(&from)
             .await
-            .context("failed getting `to` account balance")?;
+            .context("failed to get bridge account rollup ID")?
+            .is_none(),
+        "cannot transfer out of bridge accounts"
+    );
 
-        // if fee payment asset is same asset as transfer asset, deduct fee
-        // from same balance as asset transferred
-        if transfer_asset_id == self.fee_asset_id {
-            // check_stateful should have already checked this arithmetic
-            let payment_amount = self
-                .amount
-                .checked_add(TRANSFER_FEE)
-                .expect("transfer amount plus fee should not overflow");
+    // the fee is paid by the transaction's fee payer, so only the transferred
+    // amount is checked here
+    let from_balance = state
+        .get_account_balance(from, asset_id)
+        .await
+        .context("failed getting `from` account balance")?;
+    ensure!(from_balance >= amount, "insufficient funds for transfer");
 
-            state
-                .put_account_balance(
-                    from,
-                    transfer_asset_id,
-                    from_balance
-                        .checked_sub(payment_amount)
-                        .ok_or(anyhow!("insufficient funds for transfer and fee payment"))?,
-                )
-                .context("failed updating `from` account balance")?;
-            state
-                .put_account_balance(
-                    self.to,
-                    transfer_asset_id,
-                    to_balance
-                        .checked_add(self.amount)
-                        .ok_or(anyhow!("recipient balance overflowed"))?,
-                )
-                .context("failed updating `to` account balance")?;
-        } else {
-            // otherwise, just transfer the transfer asset and deduct fee from fee asset balance
-            // later
-            state
-                .put_account_balance(
-                    from,
-                    transfer_asset_id,
-                    from_balance
-                        .checked_sub(self.amount)
-                        .ok_or(anyhow!("insufficient funds for transfer"))?,
-                )
-                .context("failed updating `from` account balance")?;
-            state
-                .put_account_balance(
-                    self.to,
-                    transfer_asset_id,
-                    to_balance
-                        .checked_add(self.amount)
-                        .ok_or(anyhow!("recipient balance overflowed"))?,
-                )
-                .context("failed updating `to` account balance")?;
+    Ok(())
+}
 
-            // deduct fee from fee asset balance
-            let from_fee_balance = state
-                .get_account_balance(from, self.fee_asset_id)
-                .await
-                .context("failed getting `from` account balance for fee payment")?;
-            state
-                .put_account_balance(
-                    from,
-                    self.fee_asset_id,
-                    from_fee_balance
-                        .checked_sub(TRANSFER_FEE)
-                        .ok_or(anyhow!("insufficient funds for fee payment"))?,
-                )
-                .context("failed updating `from` account balance for fee payment")?;
-        }
+/// Moves `amount` of `asset_id` from `from` to `to`.
+///
+/// This is shared by transfers and bridge locks, which only differ in their recipient.
+pub(crate) async fn execute_transfer<S: StateWriteExt>(
+    state: &mut S,
+    from: Address,
+    to: Address,
+    asset_id: asset::Id,
+    amount: u128,
+) -> Result<()> {
+    state
+        .decrease_balance(from, asset_id, amount)
+        .await
+        .context("failed to deduct transferred amount from `from` account balance")?;
+    state
+        .increase_balance(to, asset_id, amount)
+        .await
+        .context("failed to credit transferred amount to `to` account balance")?;
 
-        Ok(())
-    }
+    state.record(
+        TransferEvent {
+            from,
+            to,
+            asset_id,
+            amount,
+        }
+        .into_abci(),
+    );
+    Ok(())
 }
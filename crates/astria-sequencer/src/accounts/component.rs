@@ -4,6 +4,7 @@ use anyhow::{
     Context,
     Result,
 };
+use astria_core::sequencer::v1alpha1::asset::Denom;
 use tendermint::abci::request::{
     BeginBlock,
     EndBlock,
@@ -12,9 +13,13 @@ use tracing::instrument;
 
 use super::state_ext::StateWriteExt;
 use crate::{
-    asset::get_native_asset,
+    asset::{
+        get_native_asset,
+        state_ext::StateWriteExt as _,
+    },
     component::Component,
     genesis::GenesisState,
+    ibc::state_ext::StateWriteExt as _,
 };
 
 #[derive(Default)]
@@ -31,10 +36,36 @@ impl Component for AccountsComponent {
             state
                 .put_account_balance(account.address, native_asset.id(), account.balance)
                 .context("failed writing account balance to state")?;
+            state
+                .increase_total_supply(native_asset.id(), account.balance)
+                .await
+                .context("failed to increase total supply")?;
+            // accounts only have a nonce in state once they have sent a transaction
+            if account.nonce != 0 {
+                state
+                    .put_account_nonce(account.address, account.nonce)
+                    .context("failed writing account nonce to state")?;
+            }
+        }
+        for balance in &app_state.asset_balances {
+            let asset_id = Denom::from(balance.denom.as_str()).id();
+            state
+                .put_account_balance(balance.address, asset_id, balance.balance)
+                .context("failed writing account asset balance to state")?;
+            state
+                .increase_total_supply(asset_id, balance.balance)
+                .await
+                .context("failed to increase total supply")?;
         }
         state
             .put_ibc_sudo_address(app_state.ibc_sudo_address)
             .context("failed to set IBC sudo key")?;
+        for address in &app_state.ibc_relayer_addresses {
+            state.put_ibc_relayer_address(address);
+        }
+        state
+            .put_ibc_params(&app_state.ibc_params)
+            .context("failed to set IBC params")?;
         Ok(())
     }
 
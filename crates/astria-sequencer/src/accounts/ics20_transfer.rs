@@ -9,16 +9,29 @@
 //! [`AppHandler`] consists of two traits: [`AppHandlerCheck`] and [`AppHandlerExecute`].
 //! [`AppHandlerCheck`] is used for stateless and stateful checks, while
 //! [`AppHandlerExecute`] is used for execution.
+//!
+//! Incoming transfers whose memo routes them to a rollup (see
+//! [`astria_core::sequencer::v1alpha1::Ics20DepositMemo`]) are included in the rollup's
+//! data as an [`Ics20Deposit`]. Like the recipient of a `BridgeLockAction`, their receiver
+//! must be a bridge account registered for the rollup, which holds the deposited funds
+//! until the rollup's withdrawer releases them with a `BridgeUnlockAction`.
 
 use anyhow::{
+    ensure,
     Context as _,
     Result,
 };
 use astria_core::sequencer::v1alpha1::{
-    asset::Denom,
+    asset::{
+        self,
+        Denom,
+    },
     Address,
+    Ics20Deposit,
+    RollupId,
 };
 use cnidarium::{
+    StateDelta,
     StateRead,
     StateWrite,
 };
@@ -52,9 +65,19 @@ use super::state_ext::{
     StateReadExt as _,
     StateWriteExt,
 };
-use crate::asset::state_ext::{
-    StateReadExt as _,
-    StateWriteExt as _,
+use crate::{
+    asset::state_ext::{
+        StateReadExt as _,
+        StateWriteExt as _,
+    },
+    bridge::state_ext::StateReadExt as _,
+    ibc::{
+        ics20_rate_limit::{
+            record_ics20_flow,
+            Ics20Flow,
+        },
+        state_ext::StateReadExt as _,
+    },
 };
 
 /// The ICS20 transfer handler.
@@ -90,18 +113,20 @@ impl AppHandlerCheck for Ics20Transfer {
         Ok(())
     }
 
-    async fn chan_open_ack_check<S: StateRead>(_: S, msg: &MsgChannelOpenAck) -> Result<()> {
+    async fn chan_open_ack_check<S: StateRead>(state: S, msg: &MsgChannelOpenAck) -> Result<()> {
         if msg.version_on_b.as_str() != "ics20-1" {
             anyhow::bail!("counterparty version must be ics20-1 for Ics20 transfer");
         }
 
-        Ok(())
+        ensure_channel_allowed(&state, &msg.chan_id_on_a).await
     }
 
-    async fn chan_open_confirm_check<S: StateRead>(_: S, _: &MsgChannelOpenConfirm) -> Result<()> {
-        // accept channel confirmations, port has already been validated, version has already been
-        // validated
-        Ok(())
+    async fn chan_open_confirm_check<S: StateRead>(
+        state: S,
+        msg: &MsgChannelOpenConfirm,
+    ) -> Result<()> {
+        // port has already been validated, version has already been validated
+        ensure_channel_allowed(&state, &msg.chan_id_on_b).await
     }
 
     async fn chan_close_init_check<S: StateRead>(_: S, _: &MsgChannelCloseInit) -> Result<()> {
@@ -116,9 +141,28 @@ impl AppHandlerCheck for Ics20Transfer {
         Ok(())
     }
 
-    async fn recv_packet_check<S: StateRead>(_: S, _: &MsgRecvPacket) -> Result<()> {
-        // checks performed in `execute`
-        Ok(())
+    async fn recv_packet_check<S: StateRead>(state: S, msg: &MsgRecvPacket) -> Result<()> {
+        // transfers routed to a rollup are included in the rollup's data as deposits, so
+        // they must not fail in `execute`, where failures are only acknowledged with an
+        // error. instead they are executed against a fork of the state here, which rejects
+        // the transaction if the deposit fails.
+        //
+        // all other checks are performed in `execute`.
+        if Ics20Deposit::from_recv_packet(msg).is_none() {
+            return Ok(());
+        }
+        let mut state = StateDelta::new(state);
+        execute_ics20_transfer(
+            &mut state,
+            &msg.packet.data,
+            &msg.packet.port_on_a,
+            &msg.packet.chan_on_a,
+            &msg.packet.port_on_b,
+            &msg.packet.chan_on_b,
+            false,
+        )
+        .await
+        .context("failed to deposit ICS20 transfer to rollup")
     }
 
     async fn timeout_packet_check<S: StateRead>(state: S, msg: &MsgTimeout) -> Result<()> {
@@ -154,6 +198,21 @@ impl AppHandlerCheck for Ics20Transfer {
     }
 }
 
+/// Ensures that `channel` was added to the ICS20 channel allowlist.
+///
+/// Channels are only opened if they are allowed, so the IBC sudo address must add the
+/// sequencer-side ID of a channel before its handshake completes.
+async fn ensure_channel_allowed<S: StateRead>(state: &S, channel: &ChannelId) -> Result<()> {
+    ensure!(
+        state
+            .is_ics20_channel_allowed(channel)
+            .await
+            .context("failed to check if channel is allowed for ICS20 transfers")?,
+        "channel `{channel}` is not allowed for ICS20 transfers"
+    );
+    Ok(())
+}
+
 async fn refund_tokens_check<S: StateRead>(
     state: S,
     data: &[u8],
@@ -226,7 +285,7 @@ impl AppHandlerExecute for Ics20Transfer {
     async fn recv_packet_execute<S: StateWrite>(mut state: S, msg: &MsgRecvPacket) {
         use penumbra_ibc::component::packet::WriteAcknowledgement as _;
 
-        let ack = match execute_ics20_transfer(
+        let ack = match execute_ics20_transfer_atomically(
             &mut state,
             &msg.packet.data,
             &msg.packet.port_on_a,
@@ -252,7 +311,7 @@ impl AppHandlerExecute for Ics20Transfer {
     async fn timeout_packet_execute<S: StateWrite>(mut state: S, msg: &MsgTimeout) {
         // we put source and dest as chain_a (the source) as we're refunding tokens,
         // and the destination chain of the refund is the source.
-        if let Err(e) = execute_ics20_transfer(
+        if let Err(e) = execute_ics20_transfer_atomically(
             &mut state,
             &msg.packet.data,
             &msg.packet.port_on_a,
@@ -282,7 +341,7 @@ impl AppHandlerExecute for Ics20Transfer {
 
         // we put source and dest as chain_a (the source) as we're refunding tokens,
         // and the destination chain of the refund is the source.
-        if let Err(e) = execute_ics20_transfer(
+        if let Err(e) = execute_ics20_transfer_atomically(
             &mut state,
             &msg.packet.data,
             &msg.packet.port_on_a,
@@ -305,7 +364,112 @@ impl AppHandlerExecute for Ics20Transfer {
 #[async_trait::async_trait]
 impl AppHandler for Ics20Transfer {}
 
-async fn execute_ics20_transfer<S: StateWriteExt>(
+/// The recipient of the tokens of an incoming ICS20 transfer.
+#[derive(Clone, Copy, Debug)]
+enum Recipient {
+    Account(Address),
+    /// The bridge account of a rollup, for transfers routed to the rollup.
+    BridgeAccount {
+        address: Address,
+        rollup_id: RollupId,
+    },
+}
+
+async fn credit_recipient<S: StateWriteExt>(
+    state: &mut S,
+    recipient: Recipient,
+    asset: asset::Id,
+    amount: u128,
+) -> Result<()> {
+    let address = match recipient {
+        Recipient::Account(address) => {
+            // funds sent to a bridge account must be routed to its rollup by the memo, or
+            // they would not be deposited
+            ensure!(
+                state
+                    .get_bridge_account_rollup_id(&address)
+                    .await
+                    .context("failed to get bridge account rollup ID")?
+                    .is_none(),
+                "ICS20 transfers to bridge accounts must be routed to their rollup"
+            );
+            address
+        }
+        Recipient::BridgeAccount {
+            address,
+            rollup_id,
+        } => {
+            // the same checks as for a bridge lock, so that the deposit is picked up by
+            // the rollup and can be released by its withdrawer
+            let bridge_rollup_id = state
+                .get_bridge_account_rollup_id(&address)
+                .await
+                .context("failed to get bridge account rollup ID")?
+                .context("receiver of a rollup deposit must be a bridge account")?;
+            ensure!(
+                bridge_rollup_id == rollup_id,
+                "bridge account is registered for a different rollup"
+            );
+            let allowed_asset_id = state
+                .get_bridge_account_asset_id(&address)
+                .await
+                .context("failed to get bridge account asset ID")?;
+            ensure!(
+                allowed_asset_id == asset,
+                "asset ID is not authorized for transfer to bridge account"
+            );
+            address
+        }
+    };
+    let user_balance = state
+        .get_account_balance(address, asset)
+        .await
+        .context("failed to get user account balance in execute_ics20_transfer")?;
+    state
+        .put_account_balance(
+            address,
+            asset,
+            user_balance
+                .checked_add(amount)
+                .ok_or(anyhow::anyhow!("overflow when adding to user balance"))?,
+        )
+        .context("failed to update user account balance in execute_ics20_transfer")?;
+    Ok(())
+}
+
+/// Executes an ICS20 transfer against a fork of `state`, which is only applied if the
+/// transfer succeeds.
+///
+/// Failed transfers are not rejected in `execute`, but acknowledged with an error or
+/// logged, so this ensures they leave no partial writes behind.
+async fn execute_ics20_transfer_atomically<S: StateWrite>(
+    state: &mut S,
+    data: &[u8],
+    source_port: &PortId,
+    source_channel: &ChannelId,
+    dest_port: &PortId,
+    dest_channel: &ChannelId,
+    is_refund: bool,
+) -> Result<()> {
+    let mut fork = StateDelta::new(state);
+    execute_ics20_transfer(
+        &mut fork,
+        data,
+        source_port,
+        source_channel,
+        dest_port,
+        dest_channel,
+        is_refund,
+    )
+    .await?;
+    let (state, events) = fork.apply();
+    for event in events {
+        state.record(event);
+    }
+    Ok(())
+}
+
+pub(crate) async fn execute_ics20_transfer<S: StateWriteExt>(
     state: &mut S,
     data: &[u8],
     source_port: &PortId,
@@ -314,16 +478,56 @@ async fn execute_ics20_transfer<S: StateWriteExt>(
     dest_channel: &ChannelId,
     is_refund: bool,
 ) -> Result<()> {
+    // refunds return tokens that left the sequencer, so they are not inbound transfers
+    // and are let through even if their channel was removed from the allowlist since
+    if !is_refund {
+        ensure!(
+            state
+                .get_ibc_params()
+                .await
+                .context("failed to get IBC params")?
+                .inbound_ics20_transfers_enabled,
+            "inbound ICS20 transfers are disabled"
+        );
+        ensure_channel_allowed(&*state, dest_channel).await?;
+    }
+    // refunds are counted against the rate limit, but never rejected by it
+    let flow = if is_refund {
+        Ics20Flow::Refund
+    } else {
+        Ics20Flow::Inflow
+    };
+
     let packet_data: FungibleTokenPacketData =
         serde_json::from_slice(data).context("failed to decode FungibleTokenPacketData")?;
     let packet_amount: u128 = packet_data
         .amount
         .parse()
         .context("failed to parse packet data amount to u128")?;
-    let recipient = Address::try_from_slice(
+    // refunds return tokens to their sender, so they are never routed to a rollup
+    let deposit = if is_refund {
+        None
+    } else {
+        Ics20Deposit::try_from_packet_data(
+            data,
+            source_port,
+            source_channel,
+            dest_port,
+            dest_channel,
+        )
+        .context("invalid rollup deposit in ICS20 transfer")?
+    };
+    let receiver = Address::try_from_slice(
         &hex::decode(packet_data.receiver).context("failed to decode receiver as hex string")?,
     )
     .context("invalid receiver address")?;
+    let recipient = match deposit {
+        Some(deposit) => Recipient::BridgeAccount {
+            address: receiver,
+            rollup_id: deposit.rollup_id,
+        },
+        None => Recipient::Account(receiver),
+    };
     let mut denom: Denom = packet_data.denom.as_str().into();
 
     // if the asset is prefixed with `ibc`, the rest of the denomination string is the asset ID,
@@ -340,12 +544,15 @@ async fn execute_ics20_transfer<S: StateWriteExt>(
         // sender of packet (us) was the source chain
         // subtract balance from escrow account and transfer to user
 
+        record_ics20_flow(state, dest_channel, denom.id(), packet_amount, flow)
+            .await
+            .context("transfer exceeds the ICS20 rate limit")?;
+
         let escrow_balance = state
             .get_ibc_channel_balance(source_channel, denom.id())
             .await
             .context("failed to get IBC channel balance in execute_ics20_transfer")?;
 
-        let user_balance = state.get_account_balance(recipient, denom.id()).await?;
         state
             .put_ibc_channel_balance(
                 source_channel,
@@ -357,15 +564,7 @@ async fn execute_ics20_transfer<S: StateWriteExt>(
                     ))?,
             )
             .context("failed to update escrow account balance in execute_ics20_transfer")?;
-        state
-            .put_account_balance(
-                recipient,
-                denom.id(),
-                user_balance
-                    .checked_add(packet_amount)
-                    .ok_or(anyhow::anyhow!("overflow when adding to user balance"))?,
-            )
-            .context("failed to update user account balance in execute_ics20_transfer")?;
+        credit_recipient(state, recipient, denom.id(), packet_amount).await?;
     } else {
         let prefixed_denomination = if is_refund {
             // we're refunding a token we issued and tried to bridge, but failed
@@ -378,6 +577,10 @@ async fn execute_ics20_transfer<S: StateWriteExt>(
 
         let denom: Denom = prefixed_denomination.as_str().into();
 
+        record_ics20_flow(state, dest_channel, denom.id(), packet_amount, flow)
+            .await
+            .context("transfer exceeds the ICS20 rate limit")?;
+
         // register denomination in global ID -> denom map if it's not already there
         if !state
             .has_ibc_asset(denom.id())
@@ -389,20 +592,79 @@ async fn execute_ics20_transfer<S: StateWriteExt>(
                 .context("failed to put IBC asset in storage")?;
         }
 
-        let user_balance = state
-            .get_account_balance(recipient, denom.id())
-            .await
-            .context("failed to get user account balance in execute_ics20_transfer")?;
+        credit_recipient(state, recipient, denom.id(), packet_amount).await?;
         state
-            .put_account_balance(
-                recipient,
-                denom.id(),
-                user_balance
-                    .checked_add(packet_amount)
-                    .ok_or(anyhow::anyhow!("overflow when adding to user balance"))?,
-            )
-            .context("failed to update user account balance in execute_ics20_transfer")?;
+            .increase_total_supply(denom.id(), packet_amount)
+            .await
+            .context("failed to increase total supply in execute_ics20_transfer")?;
     }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod test {
+    use astria_core::sequencer::v1alpha1::{
+        asset,
+        RollupId,
+    };
+    use cnidarium::StateDelta;
+    use ibc_types::core::channel::{
+        ChannelId,
+        PortId,
+    };
+
+    use super::execute_ics20_transfer_atomically;
+    use crate::{
+        asset::state_ext::StateReadExt as _,
+        genesis::IbcParameters,
+        ibc::state_ext::StateWriteExt as _,
+    };
+
+    #[tokio::test]
+    async fn failed_transfer_leaves_no_partial_writes() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state
+            .put_ibc_params(&IbcParameters {
+                ibc_enabled: true,
+                inbound_ics20_transfers_enabled: true,
+                outbound_ics20_transfers_enabled: true,
+            })
+            .unwrap();
+        let dest_channel: ChannelId = "channel-0".parse().unwrap();
+        state.put_ics20_channel(&dest_channel);
+
+        // the deposit fails only after the asset is registered, as its receiver is not a
+        // bridge account of the rollup
+        let rollup_id = RollupId::from_unhashed_bytes("test-rollup");
+        let data = serde_json::json!({
+            "amount": "100",
+            "denom": "uatom",
+            "sender": "cosmos1sender",
+            "receiver": hex::encode([1; 20]),
+            "memo": format!(
+                r#"{{"rollup_deposit": {{"rollup_id": "{rollup_id}", "rollup_address": "0xabc"}}}}"#
+            ),
+        })
+        .to_string()
+        .into_bytes();
+        let port = PortId::transfer();
+        let source_channel: ChannelId = "channel-7".parse().unwrap();
+        execute_ics20_transfer_atomically(
+            &mut state,
+            &data,
+            &port,
+            &source_channel,
+            &port,
+            &dest_channel,
+            false,
+        )
+        .await
+        .unwrap_err();
+
+        let asset_id = asset::Id::from_denom("transfer/channel-0/uatom");
+        assert!(!state.has_ibc_asset(asset_id).await.unwrap());
+        assert_eq!(state.get_total_supply(asset_id).await.unwrap(), 0);
+    }
+}
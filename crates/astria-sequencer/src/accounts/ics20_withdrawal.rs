@@ -5,6 +5,7 @@ use anyhow::{
 };
 use astria_core::sequencer::v1alpha1::{
     asset::Denom,
+    event::Ics20WithdrawalEvent,
     transaction::action,
     Address,
 };
@@ -25,6 +26,17 @@ use crate::{
         StateReadExt,
         StateWriteExt,
     },
+    asset::state_ext::StateWriteExt as _,
+    bridge::state_ext::StateReadExt as _,
+    fees::state_ext::StateReadExt as _,
+    ibc::{
+        ics20_rate_limit::{
+            check_ics20_flow,
+            record_ics20_flow,
+            Ics20Flow,
+        },
+        state_ext::StateReadExt as _,
+    },
     transaction::action_handler::ActionHandler,
 };
 
@@ -62,12 +74,53 @@ impl ActionHandler for action::Ics20Withdrawal {
         state: &S,
         from: Address,
     ) -> Result<()> {
+        ensure!(
+            state
+                .get_ibc_params()
+                .await
+                .context("failed to get IBC params")?
+                .outbound_ics20_transfers_enabled,
+            "outbound ICS20 transfers are disabled"
+        );
+        ensure!(
+            state
+                .is_ics20_channel_allowed(self.source_channel())
+                .await
+                .context("failed to check if channel is allowed for ICS20 transfers")?,
+            "channel `{}` is not allowed for ICS20 transfers",
+            self.source_channel(),
+        );
+        check_ics20_flow(
+            state,
+            self.source_channel(),
+            self.denom().id(),
+            self.amount(),
+            Ics20Flow::Outflow,
+        )
+        .await
+        .context("withdrawal exceeds the ICS20 rate limit")?;
+
         let packet: IBCPacket<Unchecked> = withdrawal_to_unchecked_ibc_packet(self);
         state
             .send_packet_check(packet)
             .await
             .context("packet failed send check")?;
 
+        ensure!(
+            state.is_allowed_fee_asset(*self.fee_asset_id()).await?,
+            "invalid fee asset"
+        );
+
+        // bridge accounts can only release funds through a `BridgeUnlockAction`
+        ensure!(
+            state
+                .get_bridge_account_rollup_id(&from)
+                .await
+                .context("failed to get bridge account rollup ID")?
+                .is_none(),
+            "cannot withdraw out of bridge accounts"
+        );
+
         let from_transfer_balance = state
             .get_account_balance(from, self.denom().id())
             .await
@@ -84,6 +137,17 @@ impl ActionHandler for action::Ics20Withdrawal {
     async fn execute<S: StateWriteExt>(&self, state: &mut S, from: Address) -> Result<()> {
         let checked_packet = withdrawal_to_unchecked_ibc_packet(self).assume_checked();
 
+        // the rate limit is checked again, as a transaction can contain several withdrawals
+        record_ics20_flow(
+            state,
+            self.source_channel(),
+            self.denom().id(),
+            self.amount(),
+            Ics20Flow::Outflow,
+        )
+        .await
+        .context("withdrawal exceeds the ICS20 rate limit")?;
+
         let from_transfer_balance = state
             .get_account_balance(from, self.denom().id())
             .await
@@ -120,9 +184,25 @@ impl ActionHandler for action::Ics20Withdrawal {
                         .context("overflow when adding to channel balance")?,
                 )
                 .context("failed to update channel balance")?;
+        } else {
+            state
+                .decrease_total_supply(self.denom().id(), self.amount())
+                .await
+                .context("failed to decrease total supply")?;
         }
 
         state.send_packet_execute(checked_packet).await;
+
+        state.record(
+            Ics20WithdrawalEvent {
+                from,
+                asset_id: self.denom().id(),
+                amount: self.amount(),
+                destination_chain_address: self.destination_chain_address().to_string(),
+                source_channel: self.source_channel().to_string(),
+            }
+            .into_abci(),
+        );
         Ok(())
     }
 }
@@ -98,32 +98,90 @@ pub(crate) async fn nonce_request(
     }
 }
 
-async fn get_snapshot_and_height(
+/// Opens the snapshot of the state at `height`, or of the latest state if `height` is 0.
+///
+/// Returns the snapshot together with the height it was taken at. If there is no state
+/// for `height`, the returned error response explains why: either the chain has not
+/// reached `height` yet, or its state was pruned, i.e. never stored by this node as it
+/// was restored from a state sync snapshot of a later height, or it is stored but no
+/// longer among the recent snapshots cached for queries.
+pub(crate) async fn get_snapshot_and_height(
     storage: &Storage,
     height: Height,
-) -> anyhow::Result<(Snapshot, Height)> {
+) -> Result<(Snapshot, Height), response::Query> {
     let snapshot = match height.value() {
         0 => storage.latest_snapshot(),
-        other => {
-            let version = storage
-                .latest_snapshot()
-                .get_storage_version_by_height(other)
+        requested => {
+            let latest_snapshot = storage.latest_snapshot();
+            let latest_height = latest_snapshot
+                .get_block_height()
                 .await
-                .context("failed to get storage version from height")?;
-            storage
-                .snapshot(version)
-                .context("failed to get storage at version")?
+                .context("failed to get latest block height")
+                .map_err(internal_error_response)?;
+            if requested > latest_height {
+                return Err(response::Query {
+                    code: AbciErrorCode::INVALID_PARAMETER.into(),
+                    info: AbciErrorCode::INVALID_PARAMETER.to_string(),
+                    log: format!(
+                        "requested height {requested} is above the latest height {latest_height}"
+                    ),
+                    ..response::Query::default()
+                });
+            }
+            let earliest_height = latest_snapshot
+                .get_earliest_retained_height()
+                .await
+                .context("failed to get earliest retained height")
+                .map_err(internal_error_response)?;
+            if requested < earliest_height {
+                return Err(response::Query {
+                    code: AbciErrorCode::HEIGHT_PRUNED.into(),
+                    info: AbciErrorCode::HEIGHT_PRUNED.to_string(),
+                    log: format!(
+                        "the state at height {requested} has been pruned; the earliest height is \
+                         {earliest_height}"
+                    ),
+                    ..response::Query::default()
+                });
+            }
+            let version = latest_snapshot
+                .get_storage_version_by_height(requested)
+                .await
+                .context("failed to get storage version from height")
+                .map_err(internal_error_response)?;
+            storage.snapshot(version).ok_or_else(|| response::Query {
+                code: AbciErrorCode::HEIGHT_NOT_CACHED.into(),
+                info: AbciErrorCode::HEIGHT_NOT_CACHED.to_string(),
+                log: format!(
+                    "the state at height {requested} is not among the recent snapshots cached for \
+                     queries; the latest height is {latest_height}"
+                ),
+                ..response::Query::default()
+            })?
         }
     };
     let height: Height = snapshot
         .get_block_height()
         .await
-        .context("failed to get block height from snapshot")?
-        .try_into()
-        .context("internal u64 block height does not fit into tendermint i64 `Height`")?;
+        .context("failed to get block height from snapshot")
+        .and_then(|height| {
+            height
+                .try_into()
+                .context("internal u64 block height does not fit into tendermint i64 `Height`")
+        })
+        .map_err(internal_error_response)?;
     Ok((snapshot, height))
 }
 
+fn internal_error_response(err: anyhow::Error) -> response::Query {
+    response::Query {
+        code: AbciErrorCode::INTERNAL_ERROR.into(),
+        info: AbciErrorCode::INTERNAL_ERROR.to_string(),
+        log: format!("failed to query internal storage for snapshot and height: {err:?}"),
+        ..response::Query::default()
+    }
+}
+
 async fn preprocess_request(
     storage: &Storage,
     request: &request::Query,
@@ -151,16 +209,6 @@ async fn preprocess_request(
             log: format!("address could not be constructed from provided parameter: {err:?}"),
             ..response::Query::default()
         })?;
-    let (snapshot, height) = match get_snapshot_and_height(storage, request.height).await {
-        Ok(tup) => tup,
-        Err(err) => {
-            return Err(response::Query {
-                code: AbciErrorCode::INTERNAL_ERROR.into(),
-                info: AbciErrorCode::INTERNAL_ERROR.to_string(),
-                log: format!("failed to query internal storage for snapshot and height: {err:?}"),
-                ..response::Query::default()
-            });
-        }
-    };
+    let (snapshot, height) = get_snapshot_and_height(storage, request.height).await?;
     Ok((address, snapshot, height))
 }
@@ -1,4 +1,7 @@
+use std::collections::BTreeMap;
+
 use anyhow::{
+    bail,
     Context,
     Result,
 };
@@ -33,13 +36,14 @@ struct Nonce(u32);
 #[derive(BorshSerialize, BorshDeserialize, Debug)]
 struct Balance(u128);
 
-/// Newtype wrapper to read and write an address from rocksdb.
-#[derive(BorshSerialize, BorshDeserialize, Debug)]
-struct SudoAddress([u8; ADDRESS_LEN]);
-
 const ACCOUNTS_PREFIX: &str = "accounts";
 
-const IBC_SUDO_STORAGE_KEY: &str = "ibcsudo";
+/// The nonce and balances of an account, as found in state.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(crate) struct StoredAccount {
+    pub(crate) nonce: u32,
+    pub(crate) balances: BTreeMap<asset::Id, u128>,
+}
 
 fn storage_key(address: &str) -> String {
     format!("{ACCOUNTS_PREFIX}/{address}")
@@ -159,19 +163,43 @@ pub(crate) trait StateReadExt: StateRead {
         Ok(balance)
     }
 
+    /// Returns every account with a nonce or a balance in state, ordered by address.
     #[instrument(skip(self))]
-    async fn get_ibc_sudo_address(&self) -> Result<Address> {
-        let Some(bytes) = self
-            .get_raw(IBC_SUDO_STORAGE_KEY)
-            .await
-            .context("failed reading raw ibc sudo key from state")?
-        else {
-            // ibc sudo key must be set
-            return Err(anyhow::anyhow!("ibc sudo key not found"));
-        };
-        let SudoAddress(address) =
-            SudoAddress::try_from_slice(&bytes).context("invalid ibc sudo key bytes")?;
-        Ok(Address(address))
+    async fn get_accounts(&self) -> Result<Vec<(Address, StoredAccount)>> {
+        let prefix = format!("{ACCOUNTS_PREFIX}/");
+        let mut accounts: BTreeMap<[u8; ADDRESS_LEN], StoredAccount> = BTreeMap::new();
+
+        let mut stream = std::pin::pin!(self.prefix_raw(&prefix));
+        while let Some(entry) = stream.next().await {
+            let (key, value) = entry.context("failed reading raw account entry from state")?;
+            let (address_str, field) = key
+                .strip_prefix(&prefix)
+                .and_then(|rest| rest.split_once('/'))
+                .context("invalid account key")?;
+            let address_bytes = hex::decode(address_str).context("invalid address bytes")?;
+            let address = Address::try_from_slice(&address_bytes)
+                .context("failed to parse address from account key")?;
+            let account = accounts.entry(address.0).or_default();
+
+            if field == "nonce" {
+                let Nonce(nonce) = Nonce::try_from_slice(&value).context("invalid nonce bytes")?;
+                account.nonce = nonce;
+            } else if let Some(asset_id_str) = field.strip_prefix("balance/") {
+                let asset_id_bytes = hex::decode(asset_id_str).context("invalid asset id bytes")?;
+                let asset_id = asset::Id::try_from_slice(&asset_id_bytes)
+                    .context("failed to parse asset id from account balance key")?;
+                let Balance(balance) =
+                    Balance::try_from_slice(&value).context("invalid balance bytes")?;
+                account.balances.insert(asset_id, balance);
+            } else {
+                bail!("unknown account key `{key}`");
+            }
+        }
+
+        Ok(accounts
+            .into_iter()
+            .map(|(address, account)| (Address(address), account))
+            .collect())
     }
 }
 
@@ -193,6 +221,66 @@ pub(crate) trait StateWriteExt: StateWrite {
         Ok(())
     }
 
+    #[instrument(skip(self))]
+    async fn increase_balance(
+        &mut self,
+        address: Address,
+        asset: asset::Id,
+        amount: u128,
+    ) -> Result<()> {
+        let balance = match self
+            .get_raw(&balance_storage_key(address, asset))
+            .await
+            .context("failed reading raw account balance from state")?
+        {
+            Some(bytes) => {
+                let Balance(balance) =
+                    Balance::try_from_slice(&bytes).context("invalid balance bytes")?;
+                balance
+            }
+            None => 0,
+        };
+        self.put_account_balance(
+            address,
+            asset,
+            balance
+                .checked_add(amount)
+                .context("failed to update account balance due to overflow")?,
+        )
+        .context("failed to store updated account balance in database")?;
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn decrease_balance(
+        &mut self,
+        address: Address,
+        asset: asset::Id,
+        amount: u128,
+    ) -> Result<()> {
+        let balance = match self
+            .get_raw(&balance_storage_key(address, asset))
+            .await
+            .context("failed reading raw account balance from state")?
+        {
+            Some(bytes) => {
+                let Balance(balance) =
+                    Balance::try_from_slice(&bytes).context("invalid balance bytes")?;
+                balance
+            }
+            None => 0,
+        };
+        self.put_account_balance(
+            address,
+            asset,
+            balance
+                .checked_sub(amount)
+                .context("subtracting from account balance failed due to insufficient funds")?,
+        )
+        .context("failed to store updated account balance in database")?;
+        Ok(())
+    }
+
     #[instrument(skip(self))]
     fn put_account_nonce(&mut self, address: Address, nonce: u32) -> Result<()> {
         let bytes = Nonce(nonce)
@@ -215,17 +303,6 @@ pub(crate) trait StateWriteExt: StateWrite {
         self.put_raw(channel_balance_storage_key(channel, asset), bytes);
         Ok(())
     }
-
-    #[instrument(skip(self))]
-    fn put_ibc_sudo_address(&mut self, address: Address) -> Result<()> {
-        self.put_raw(
-            IBC_SUDO_STORAGE_KEY.to_string(),
-            SudoAddress(address.0)
-                .try_to_vec()
-                .context("failed to convert sudo address to vec")?,
-        );
-        Ok(())
-    }
 }
 
 impl<T: StateWrite> StateWriteExt for T {}
@@ -1,6 +1,7 @@
 use std::{
     collections::{
         HashMap,
+        HashSet,
         VecDeque,
     },
     sync::Arc,
@@ -14,8 +15,9 @@ use anyhow::{
 use astria_core::{
     generated::sequencer::v1alpha1 as raw,
     sequencer::v1alpha1::{
-        transaction::Action,
+        asset,
         Address,
+        SequencerBlock,
         SignedTransaction,
     },
 };
@@ -38,12 +40,18 @@ use tendermint::{
         Event,
     },
     account,
+    consensus::{
+        self,
+        params::VersionParams,
+    },
     Hash,
+    Time,
 };
 use tracing::{
     debug,
     info,
     instrument,
+    warn,
 };
 
 use crate::{
@@ -54,6 +62,7 @@ use crate::{
             StateWriteExt as _,
         },
     },
+    asset::state_ext::StateWriteExt as _,
     authority::{
         component::{
             AuthorityComponent,
@@ -64,9 +73,24 @@ use crate::{
             StateWriteExt as _,
         },
     },
+    blockspace::{
+        check_proposal_order,
+        proposal_order,
+        sequence_data_bytes,
+        state_ext::StateWriteExt as _,
+        BlockspaceBudget,
+        RollupQuotas,
+    },
     component::Component as _,
+    fees::{
+        component::FeesComponent,
+        state_ext::StateWriteExt as _,
+    },
     genesis::GenesisState,
+    grpc::state_ext::StateWriteExt as _,
     host_interface::AstriaHost,
+    mempool::Mempool,
+    mint::state_ext::StateWriteExt as _,
     proposal::commitment::{
         generate_sequence_actions_commitment,
         GeneratedCommitments,
@@ -76,14 +100,19 @@ use crate::{
         StateWriteExt as _,
     },
     transaction,
+    upgrade::{
+        apply_scheduled_upgrade,
+        consensus_params_update,
+        state_ext::{
+            StateWriteExt as _,
+            GENESIS_APP_VERSION,
+        },
+    },
 };
 
 /// The inter-block state being written to by the application.
 type InterBlockState = Arc<StateDelta<Snapshot>>;
 
-/// The maximum number of bytes allowed in sequencer action data.
-const MAX_SEQUENCE_DATA_BYTES_PER_BLOCK: usize = 256_000;
-
 /// The Sequencer application, written as a bundle of [`Component`]s.
 ///
 /// Note: this is called `App` because this is a Tendermint ABCI application,
@@ -134,10 +163,29 @@ pub(crate) struct App {
     // this is used only to determine who to transfer the block fees to
     // at the end of the block.
     current_proposer: Option<account::Id>,
+
+    // header and data of the block being currently executed; the header is set in
+    // begin_block and every delivered tx is appended to the data.
+    // these are used to construct the sequencer block that is stored at the
+    // end of the block and served over grpc.
+    current_header: Option<tendermint::block::Header>,
+    current_block_data: Vec<Vec<u8>>,
+
+    // the app-side mempool, which is shared with the mempool service.
+    // transactions for a block are taken from it in `prepare_proposal`,
+    // and stale transactions are evicted from it after every commit.
+    mempool: Mempool,
+
+    // the number of most recent sequencer blocks kept in storage, or 0 to keep all.
+    sequencer_block_retention: u64,
 }
 
 impl App {
-    pub(crate) fn new(snapshot: Snapshot) -> Self {
+    pub(crate) fn new(
+        snapshot: Snapshot,
+        mempool: Mempool,
+        sequencer_block_retention: u64,
+    ) -> Self {
         tracing::debug!("initializing App instance");
 
         // We perform the `Arc` wrapping of `State` here to ensure
@@ -151,16 +199,30 @@ impl App {
             execution_result: HashMap::new(),
             processed_txs: 0,
             current_proposer: None,
+            current_header: None,
+            current_block_data: Vec::new(),
+            mempool,
+            sequencer_block_retention,
         }
     }
 
+    /// Initializes the chain from its genesis state, returning the consensus params
+    /// to respond to cometbft with.
+    ///
+    /// The app version in the returned params is always [`GENESIS_APP_VERSION`].
     #[instrument(name = "App:init_chain", skip_all)]
     pub(crate) async fn init_chain(
         &mut self,
         genesis_state: GenesisState,
         genesis_validators: Vec<tendermint::validator::Update>,
         chain_id: String,
-    ) -> anyhow::Result<()> {
+        mut consensus_params: consensus::Params,
+    ) -> anyhow::Result<consensus::Params> {
+        // check the whole genesis state before writing any of it
+        genesis_state
+            .validate()
+            .context("failed to validate genesis state")?;
+
         let mut state_tx = self
             .state
             .try_begin_transaction()
@@ -169,7 +231,44 @@ impl App {
         crate::asset::initialize_native_asset(&genesis_state.native_asset_base_denomination);
         state_tx.put_native_asset_denom(&genesis_state.native_asset_base_denomination);
         state_tx.put_chain_id(chain_id);
+        for denom in &genesis_state.allowed_fee_asset_denominations {
+            state_tx.put_allowed_fee_asset(asset::Id::from_denom(denom));
+        }
+        for trace in &genesis_state.ibc_assets {
+            let denom = asset::Denom::from(trace.as_str());
+            state_tx
+                .put_ibc_asset(denom.id(), &denom)
+                .context("failed to put genesis IBC asset")?;
+        }
+        for metadata in &genesis_state.asset_metadata {
+            state_tx
+                .put_asset_metadata(
+                    asset::Id::from_denom(&metadata.denom),
+                    &metadata.to_metadata(),
+                )
+                .context("failed to put genesis asset metadata")?;
+        }
+        if let Some(quota) = genesis_state.blockspace.default_rollup_quota {
+            state_tx
+                .put_default_rollup_quota(quota)
+                .context("failed to put default rollup quota")?;
+        }
+        for quota in &genesis_state.blockspace.rollup_quotas {
+            state_tx
+                .put_rollup_quota(&quota.rollup_id, quota.max_sequence_data_bytes)
+                .context("failed to put rollup quota")?;
+        }
+        state_tx
+            .put_mint_caps(&genesis_state.mint_caps)
+            .context("failed to put mint caps")?;
+        state_tx
+            .put_minted_supply(genesis_state.minted_supply)
+            .context("failed to put minted supply")?;
         state_tx.put_block_height(0);
+        consensus_params.version = Some(VersionParams {
+            app: GENESIS_APP_VERSION,
+        });
+        state_tx.put_consensus_params(consensus_params.clone());
 
         // call init_chain on all components
         AccountsComponent::init_chain(&mut state_tx, &genesis_state)
@@ -184,10 +283,13 @@ impl App {
         )
         .await
         .context("failed to call init_chain on AuthorityComponent")?;
+        FeesComponent::init_chain(&mut state_tx, &genesis_state.fees)
+            .await
+            .context("failed to call init_chain on FeesComponent")?;
         IBCComponent::init_chain(&mut state_tx, Some(&())).await;
 
         state_tx.apply();
-        Ok(())
+        Ok(consensus_params)
     }
 
     fn update_state_for_new_round(&mut self, storage: &Storage) {
@@ -203,7 +305,33 @@ impl App {
         self.executed_proposal_hash = Hash::default();
     }
 
-    /// Generates a commitment to the `sequence::Actions` in the block's transactions.
+    /// Stores the height and time of the block being proposed, and applies the upgrade
+    /// scheduled for it, if any.
+    ///
+    /// Transactions are executed during the proposal phase, before `begin_block` is called,
+    /// so this ensures they are executed against the same state as when they are executed
+    /// after `begin_block`, eg. when checking their expiry.
+    async fn prepare_state_for_proposed_block(
+        &mut self,
+        height: tendermint::block::Height,
+        time: Time,
+    ) -> anyhow::Result<()> {
+        let mut state_tx = StateDelta::new(self.state.clone());
+        state_tx.put_block_height(height.into());
+        state_tx.put_block_timestamp(time);
+        apply_scheduled_upgrade(&mut state_tx, height.value())
+            .await
+            .context("failed to apply scheduled upgrade")?;
+        self.apply(state_tx);
+        Ok(())
+    }
+
+    /// Builds a block from the transactions in the app-side mempool which are
+    /// ready for execution, and generates a commitment to the `sequence::Actions`
+    /// in the block's transactions.
+    ///
+    /// The transactions proposed by cometbft are ignored, as cometbft's mempool
+    /// also contains transactions with gapped nonces, which cannot be executed.
     ///
     /// This is required so that a rollup can easily verify that the transactions it
     /// receives are correct (ie. we actually included in a sequencer block, and none
@@ -219,8 +347,43 @@ impl App {
     ) -> abci::response::PrepareProposal {
         self.is_proposer = true;
         self.update_state_for_new_round(&storage);
+        if let Err(e) = self
+            .prepare_state_for_proposed_block(prepare_proposal.height, prepare_proposal.time)
+            .await
+        {
+            // the same error is returned from `begin_block`, halting the chain at this height
+            warn!(
+                error = AsRef::<dyn std::error::Error>::as_ref(&e),
+                "failed to prepare state for proposed block; proposing empty block"
+            );
+            return abci::response::PrepareProposal {
+                txs: generate_sequence_actions_commitment(&[]).into_transactions(vec![]),
+            };
+        }
 
-        let (signed_txs, txs_to_include) = self.execute_block_data(prepare_proposal.txs).await;
+        let txs = match self
+            .select_mempool_transactions(prepare_proposal.max_tx_bytes)
+            .await
+        {
+            Ok(txs) => txs,
+            Err(e) => {
+                warn!(
+                    error = AsRef::<dyn std::error::Error>::as_ref(&e),
+                    "failed to select transactions from mempool; proposing empty block"
+                );
+                vec![]
+            }
+        };
+        let (signed_txs, txs_to_include) = match self.execute_block_data(txs).await {
+            Ok(executed) => executed,
+            Err(e) => {
+                warn!(
+                    error = AsRef::<dyn std::error::Error>::as_ref(&e),
+                    "failed to execute block data; proposing empty block"
+                );
+                (vec![], vec![])
+            }
+        };
 
         // generate commitment to sequence::Actions and commitment to the chain IDs included in the
         // sequence::Actions
@@ -231,6 +394,59 @@ impl App {
         }
     }
 
+    /// Returns the transactions in the mempool which are ready for execution against
+    /// the current state, up to the `max_tx_bytes` limit of the block.
+    ///
+    /// The ready transactions are put in [`proposal_order`], and transactions which
+    /// would exceed a rollup's quota of sequence data are skipped, together with all
+    /// later transactions of the same signer as their nonces would be gapped.
+    ///
+    /// Space is reserved for the two commitments which are prepended to the block's
+    /// transactions.
+    async fn select_mempool_transactions(
+        &self,
+        max_tx_bytes: i64,
+    ) -> anyhow::Result<Vec<bytes::Bytes>> {
+        let max_tx_bytes = usize::try_from(max_tx_bytes).unwrap_or(0);
+        let mut block_tx_bytes = 2 * proto_encoded_tx_len(32);
+
+        let ready_txs = self
+            .mempool
+            .ready_transactions(&self.state)
+            .await
+            .context("failed to get ready transactions from mempool")?;
+        let quotas = RollupQuotas::from_state(&self.state)
+            .await
+            .context("failed to get rollup quotas")?;
+        let mut budget = BlockspaceBudget::new(quotas);
+        let mut skipped_signers = HashSet::new();
+        let mut txs = Vec::with_capacity(ready_txs.len());
+        for tx in proposal_order(ready_txs) {
+            let tx_len = proto_encoded_tx_len(tx.tx_bytes().len());
+            if block_tx_bytes + tx_len > max_tx_bytes {
+                break;
+            }
+            let signer = tx.signed_tx().address();
+            if skipped_signers.contains(&signer) {
+                continue;
+            }
+            let tx_sequence_data_bytes = sequence_data_bytes(tx.signed_tx());
+            if budget.check(&tx_sequence_data_bytes).is_err() {
+                skipped_signers.insert(signer);
+                continue;
+            }
+            budget.consume(&tx_sequence_data_bytes);
+            block_tx_bytes += tx_len;
+            txs.push(tx.tx_bytes().clone());
+        }
+        debug!(
+            included_tx_count = txs.len(),
+            mempool_len = self.mempool.len().await,
+            "selected transactions from mempool"
+        );
+        Ok(txs)
+    }
+
     /// Generates a commitment to the `sequence::Actions` in the block's transactions
     /// and ensures it matches the commitment created by the proposer, which
     /// should be the first transaction in the block.
@@ -254,6 +470,9 @@ impl App {
 
         self.is_proposer = false;
         self.update_state_for_new_round(&storage);
+        self.prepare_state_for_proposed_block(process_proposal.height, process_proposal.time)
+            .await
+            .context("failed to prepare state for proposed block")?;
 
         let mut txs = VecDeque::from(process_proposal.txs);
         let received_sequence_actions_root: [u8; 32] = txs
@@ -272,17 +491,31 @@ impl App {
 
         let expected_txs_len = txs.len();
 
-        let (signed_txs, txs_to_include) = self.execute_block_data(txs.into()).await;
+        let (signed_txs, txs_to_include) = self
+            .execute_block_data(txs.into())
+            .await
+            .context("failed to execute block data")?;
 
         // all txs in the proposal should be deserializable and executable
         // if any txs were not deserializeable or executable, they would not have been
         // returned by `execute_block_data`, thus the length of `txs_to_include`
         // will be shorter than that of `txs`.
+        //
         ensure!(
             txs_to_include.len() == expected_txs_len,
             "transactions to be included do not match expected",
         );
 
+        // the full `proposal_order` depends on the proposer's mempool, which other
+        // validators do not share, so only the ordering by tip per byte across signers
+        // is checked. the rollup quotas are enforced while executing the block data.
+        check_proposal_order(
+            signed_txs
+                .iter()
+                .zip(txs_to_include.iter().map(bytes::Bytes::len)),
+        )
+        .context("transactions are not in proposal order")?;
+
         let GeneratedCommitments {
             sequence_actions_root: expected_sequence_actions_root,
             rollup_ids_root: expected_rollup_ids_root,
@@ -307,6 +540,9 @@ impl App {
     /// The result of execution of every transaction which is successfully decoded
     /// is stored in `self.execution_result`.
     ///
+    /// Transactions which would exceed the block's limit of sequence data or the
+    /// quota of one of their rollups are not executed.
+    ///
     /// Returns the transactions which were successfully decoded and executed
     /// in both their [`SignedTransaction`] and raw bytes form.
     #[instrument(name = "App::execute_block_data", skip_all, fields(
@@ -315,10 +551,13 @@ impl App {
     async fn execute_block_data(
         &mut self,
         txs: Vec<bytes::Bytes>,
-    ) -> (Vec<SignedTransaction>, Vec<bytes::Bytes>) {
+    ) -> anyhow::Result<(Vec<SignedTransaction>, Vec<bytes::Bytes>)> {
+        let quotas = RollupQuotas::from_state(&self.state)
+            .await
+            .context("failed to get rollup quotas")?;
+        let mut budget = BlockspaceBudget::new(quotas);
         let mut signed_txs = Vec::with_capacity(txs.len());
         let mut validated_txs = Vec::with_capacity(txs.len());
-        let mut block_sequence_data_bytes: usize = 0;
         let mut excluded_tx_count: usize = 0;
 
         for tx in txs {
@@ -336,23 +575,15 @@ impl App {
 
             let tx_hash = Sha256::digest(&tx);
 
-            let tx_sequence_data_bytes = signed_tx
-                .unsigned_transaction()
-                .actions
-                .iter()
-                .filter_map(Action::as_sequence)
-                .fold(0usize, |acc, seq| acc + seq.data.len());
+            let tx_sequence_data_bytes = sequence_data_bytes(&signed_tx);
 
-            // Don't include tx if it would make the sequenced block data too large.
-            if block_sequence_data_bytes + tx_sequence_data_bytes
-                > MAX_SEQUENCE_DATA_BYTES_PER_BLOCK
-            {
+            // Don't include tx if it would make the sequenced block data too large,
+            // either in total or for one of its rollups.
+            if let Err(e) = budget.check(&tx_sequence_data_bytes) {
                 debug!(
                     transaction_hash = %telemetry::display::hex(&tx_hash),
-                    included_data_bytes = block_sequence_data_bytes,
-                    tx_data_bytes = tx_sequence_data_bytes,
-                    max_data_bytes = MAX_SEQUENCE_DATA_BYTES_PER_BLOCK,
-                    "excluding transaction: max block sequenced data limit reached"
+                    error = &e as &dyn std::error::Error,
+                    "excluding transaction: sequence data limit reached"
                 );
                 excluded_tx_count += 1;
                 continue;
@@ -364,7 +595,7 @@ impl App {
                     self.execution_result.insert(tx_hash.into(), Ok(events));
                     signed_txs.push(signed_tx);
                     validated_txs.push(tx);
-                    block_sequence_data_bytes += tx_sequence_data_bytes;
+                    budget.consume(&tx_sequence_data_bytes);
                 }
                 Err(e) => {
                     debug!(
@@ -386,7 +617,7 @@ impl App {
             );
         }
 
-        (signed_txs, validated_txs)
+        Ok((signed_txs, validated_txs))
     }
 
     #[instrument(name = "App::begin_block", skip_all)]
@@ -399,6 +630,9 @@ impl App {
         self.processed_txs = 0;
         // set the current proposer
         self.current_proposer = Some(begin_block.header.proposer_address);
+        // set the current header and reset the block data
+        self.current_header = Some(begin_block.header.clone());
+        self.current_block_data.clear();
 
         // If we previously executed txs in a different proposal than is being processed reset
         // cached state changes.
@@ -413,6 +647,12 @@ impl App {
         // store the block time
         state_tx.put_block_timestamp(begin_block.header.time);
 
+        // apply the upgrade scheduled for this block, if any. this is a no-op if the
+        // upgrade was already applied while executing the proposal.
+        apply_scheduled_upgrade(&mut state_tx, begin_block.header.height.value())
+            .await
+            .context("failed to apply scheduled upgrade")?;
+
         // call begin_block on all components
         let mut arc_state_tx = Arc::new(state_tx);
         AccountsComponent::begin_block(&mut arc_state_tx, begin_block)
@@ -450,6 +690,8 @@ impl App {
         &mut self,
         tx: abci::request::DeliverTx,
     ) -> Option<anyhow::Result<Vec<abci::Event>>> {
+        self.current_block_data.push(tx.tx.to_vec());
+
         if self.processed_txs < 2 {
             self.processed_txs += 1;
             return Some(Ok(vec![]));
@@ -491,7 +733,7 @@ impl App {
     /// Note that `begin_block` is now called *after* transaction execution.
     #[instrument(name = "App::deliver_tx", skip_all, fields(
         signed_transaction_hash = %telemetry::display::hex(&signed_tx.sha256_of_proto_encoding()),
-        sender = %Address::from_verification_key(signed_tx.verification_key()),
+        sender = %signed_tx.address(),
     ))]
     pub(crate) async fn deliver_tx(
         &mut self,
@@ -573,29 +815,66 @@ impl App {
         // the same address, as they are both ed25519 keys
         let proposer_address = Address::try_from_slice(proposer.as_bytes())
             .context("failed to convert proposer tendermint id to astria address")?;
-        for (asset, amount) in fees {
+        for (asset, amount) in &fees {
             let balance = state_tx
-                .get_account_balance(proposer_address, asset)
+                .get_account_balance(proposer_address, *asset)
                 .await
                 .context("failed to get proposer account balance")?;
             let new_balance = balance
-                .checked_add(amount)
+                .checked_add(*amount)
                 .context("account balance overflowed u128")?;
             state_tx
-                .put_account_balance(proposer_address, asset, new_balance)
+                .put_account_balance(proposer_address, *asset, new_balance)
                 .context("failed to put proposer account balance")?;
         }
 
-        // clear block fees
+        // record the fees paid out in this block so they remain queryable,
+        // then clear block fees
+        state_tx
+            .put_collected_block_fees(&fees)
+            .context("failed to put collected block fees")?;
         state_tx.clear_block_fees().await;
         self.current_proposer = None;
 
+        // store the sequencer block derived from the executed block so it can be served
+        // over grpc. failing to do so must not halt the chain.
+        let header = self
+            .current_header
+            .take()
+            .expect("current header must be set in `begin_block`");
+        let height = header.height.value();
+        match SequencerBlock::try_from_cometbft_header_and_data(
+            header,
+            std::mem::take(&mut self.current_block_data),
+        ) {
+            Ok(block) => state_tx.put_sequencer_block(block),
+            Err(e) => warn!(
+                error = &e as &dyn std::error::Error,
+                "failed to construct sequencer block from executed block; it will not be served \
+                 over grpc"
+            ),
+        }
+        if let Err(e) = state_tx
+            .prune_sequencer_blocks(height, self.sequencer_block_retention)
+            .await
+        {
+            warn!(
+                error = AsRef::<dyn std::error::Error>::as_ref(&e),
+                "failed to prune stored sequencer blocks"
+            );
+        }
+
+        // send the new app version to cometbft if an upgrade was applied in this block
+        let consensus_param_updates = consensus_params_update(&mut state_tx)
+            .await
+            .context("failed to get consensus params update")?;
+
         let events = self.apply(state_tx);
 
         Ok(abci::response::EndBlock {
             validator_updates: validator_updates.into_tendermint_validator_updates(),
+            consensus_param_updates,
             events,
-            ..Default::default()
         })
     }
 
@@ -633,6 +912,15 @@ impl App {
         // Get the latest version of the state, now that we've committed it.
         self.state = Arc::new(StateDelta::new(storage.latest_snapshot()));
 
+        // evict the transactions which were included in the block, or can otherwise
+        // no longer be executed.
+        if let Err(e) = self.mempool.remove_stale(&self.state).await {
+            warn!(
+                error = AsRef::<dyn std::error::Error>::as_ref(&e),
+                "failed to remove stale transactions from mempool"
+            );
+        }
+
         app_hash
     }
 
@@ -658,6 +946,12 @@ impl App {
     }
 }
 
+/// Returns the number of bytes a transaction of `len` bytes takes up in the
+/// protobuf-encoded transactions of a block, as counted against `max_tx_bytes`.
+fn proto_encoded_tx_len(len: usize) -> usize {
+    1 + prost::encoding::encoded_len_varint(len as u64) + len
+}
+
 fn signed_transaction_from_bytes(bytes: &[u8]) -> anyhow::Result<SignedTransaction> {
     let raw = raw::SignedTransaction::decode(bytes)
         .context("failed to decode protobuf to signed transaction")?;
@@ -674,17 +968,51 @@ mod test {
     use astria_core::sequencer::v1alpha1::{
         asset,
         asset::DEFAULT_NATIVE_ASSET_DENOM,
-        transaction::action::{
-            Action,
-            SequenceAction,
-            SudoAddressChangeAction,
-            TransferAction,
+        event::{
+            BridgeUnlockEvent,
+            FeeChangeEvent,
+            IbcRelayerChangeEvent,
+            InitBridgeAccountEvent,
+            RegisterRollupEvent,
+        },
+        transaction::{
+            action::{
+                Action,
+                AssetMetadataChangeAction,
+                AuthorizeSessionKeyAction,
+                BridgeLockAction,
+                BridgeUnlockAction,
+                FeeAssetChangeAction,
+                FeeChange,
+                FeeChangeAction,
+                IbcRelayerChangeAction,
+                IbcSudoChangeAction,
+                Ics20ChannelChangeAction,
+                Ics20RateLimitChangeAction,
+                Ics20Withdrawal,
+                InitBridgeAccountAction,
+                RegisterRollupAction,
+                RevokeSessionKeyAction,
+                RollupMetadataChangeAction,
+                RollupOwnerChangeAction,
+                ScheduleUpgradeAction,
+                SequenceAction,
+                SessionKeySignature,
+                SudoAddressChangeAction,
+                TransferAction,
+            },
+            FeePayer,
         },
         Address,
+        Ics20RateLimit,
         RollupId,
         UnsignedTransaction,
         ADDRESS_LEN,
     };
+    use cnidarium::{
+        StateRead as _,
+        StateWrite as _,
+    };
     use ed25519_consensus::SigningKey;
     use tendermint::{
         abci::types::CommitInfo,
@@ -702,12 +1030,33 @@ mod test {
 
     use super::*;
     use crate::{
-        accounts::action::TRANSFER_FEE,
-        asset::get_native_asset,
+        asset::{
+            get_native_asset,
+            state_ext::StateReadExt as _,
+        },
         authority::state_ext::ValidatorSet,
-        genesis::Account,
-        sequence::calculate_fee,
+        fees::state_ext::StateReadExt as _,
+        genesis::{
+            Account,
+            AssetBalance,
+            AssetMetadata,
+            BlockspaceQuotas,
+            Fees,
+            IbcParameters,
+            MintCaps,
+            RollupQuota,
+        },
+        ibc::state_ext::StateReadExt as _,
+        sequence::calculate_fee_from_state,
         transaction::InvalidNonce,
+        upgrade::{
+            state_ext::{
+                ScheduledUpgrade,
+                StateReadExt as _,
+                StateWriteExt as _,
+            },
+            test_consensus_params,
+        },
     };
 
     /// attempts to decode the given hex string into an address.
@@ -726,18 +1075,30 @@ mod test {
             Account {
                 address: address_from_hex_string(ALICE_ADDRESS),
                 balance: 10u128.pow(19),
+                nonce: 0,
             },
             Account {
                 address: address_from_hex_string(BOB_ADDRESS),
                 balance: 10u128.pow(19),
+                nonce: 0,
             },
             Account {
                 address: address_from_hex_string(CAROL_ADDRESS),
                 balance: 10u128.pow(19),
+                nonce: 0,
             },
         ]
     }
 
+    fn default_fees() -> Fees {
+        Fees {
+            transfer_base_fee: 12,
+            sequence_base_fee: 0,
+            sequence_byte_cost_multiplier: 1,
+            ics20_withdrawal_base_fee: 1,
+        }
+    }
+
     fn default_header() -> Header {
         Header {
             app_hash: AppHash::try_from(vec![]).unwrap(),
@@ -768,18 +1129,34 @@ mod test {
             .await
             .expect("failed to create temp storage backing chain state");
         let snapshot = storage.latest_snapshot();
-        let mut app = App::new(snapshot);
+        let mut app = App::new(snapshot, Mempool::new(), 0);
 
         let genesis_state = genesis_state.unwrap_or_else(|| GenesisState {
             accounts: default_genesis_accounts(),
             authority_sudo_address: Address::from([0; 20]),
             ibc_sudo_address: Address::from([0; 20]),
+            ibc_relayer_addresses: vec![],
             native_asset_base_denomination: DEFAULT_NATIVE_ASSET_DENOM.to_string(),
+            fees: default_fees(),
+            allowed_fee_asset_denominations: vec![DEFAULT_NATIVE_ASSET_DENOM.to_string()],
+            ibc_assets: vec![],
+            asset_balances: vec![],
+            asset_metadata: vec![],
+            ibc_params: IbcParameters::default(),
+            validators: vec![],
+            blockspace: BlockspaceQuotas::default(),
+            mint_caps: MintCaps::default(),
+            minted_supply: 0,
         });
 
-        app.init_chain(genesis_state, genesis_validators, "test".to_string())
-            .await
-            .unwrap();
+        app.init_chain(
+            genesis_state,
+            genesis_validators,
+            "test".to_string(),
+            test_consensus_params(),
+        )
+        .await
+        .unwrap();
 
         (app, storage.clone())
     }
@@ -813,6 +1190,7 @@ mod test {
         for Account {
             address,
             balance,
+            ..
         } in default_genesis_accounts()
         {
             assert_eq!(
@@ -828,6 +1206,139 @@ mod test {
             app.state.get_native_asset_denom().await.unwrap(),
             DEFAULT_NATIVE_ASSET_DENOM
         );
+        assert_eq!(
+            app.state
+                .get_total_supply(get_native_asset().id())
+                .await
+                .unwrap(),
+            default_genesis_accounts()
+                .iter()
+                .map(|account| account.balance)
+                .sum::<u128>(),
+        );
+    }
+
+    #[tokio::test]
+    async fn app_init_chain_with_ibc_assets_and_params() {
+        let alice_address = address_from_hex_string(ALICE_ADDRESS);
+        let ibc_denom = asset::Denom::from("transfer/channel-0/utia");
+        let ibc_params = IbcParameters {
+            ibc_enabled: true,
+            inbound_ics20_transfers_enabled: true,
+            outbound_ics20_transfers_enabled: false,
+        };
+
+        let genesis_state = GenesisState {
+            accounts: default_genesis_accounts(),
+            authority_sudo_address: Address::from([0; 20]),
+            ibc_sudo_address: Address::from([0; 20]),
+            ibc_relayer_addresses: vec![],
+            native_asset_base_denomination: DEFAULT_NATIVE_ASSET_DENOM.to_string(),
+            fees: default_fees(),
+            allowed_fee_asset_denominations: vec![
+                DEFAULT_NATIVE_ASSET_DENOM.to_string(),
+                ibc_denom.denomination_trace(),
+            ],
+            ibc_assets: vec![ibc_denom.denomination_trace()],
+            asset_balances: vec![AssetBalance {
+                address: alice_address,
+                denom: ibc_denom.denomination_trace(),
+                balance: 100,
+            }],
+            asset_metadata: vec![AssetMetadata {
+                denom: ibc_denom.denomination_trace(),
+                decimals: 6,
+                symbol: "TIA".to_string(),
+                display_denom: "tia".to_string(),
+            }],
+            ibc_params,
+            validators: vec![],
+            blockspace: BlockspaceQuotas::default(),
+            mint_caps: MintCaps::default(),
+            minted_supply: 0,
+        };
+        let app = initialize_app(Some(genesis_state), vec![]).await;
+
+        assert_eq!(
+            app.state.get_ibc_asset(ibc_denom.id()).await.unwrap(),
+            ibc_denom
+        );
+        assert_eq!(
+            app.state
+                .get_account_balance(alice_address, ibc_denom.id())
+                .await
+                .unwrap(),
+            100
+        );
+        assert!(app
+            .state
+            .is_allowed_fee_asset(ibc_denom.id())
+            .await
+            .unwrap());
+        assert_eq!(app.state.get_ibc_params().await.unwrap(), ibc_params);
+        assert_eq!(
+            app.state.get_asset_metadata(ibc_denom.id()).await.unwrap(),
+            Some(asset::Metadata {
+                decimals: 6,
+                symbol: "TIA".to_string(),
+                display_denom: "tia".to_string(),
+            })
+        );
+        assert_eq!(
+            app.state.get_total_supply(ibc_denom.id()).await.unwrap(),
+            100
+        );
+    }
+
+    #[tokio::test]
+    async fn app_init_chain_invalid_genesis_reports_all_problems() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut app = App::new(storage.latest_snapshot(), Mempool::new(), 0);
+
+        let mut accounts = default_genesis_accounts();
+        accounts.push(Account {
+            address: address_from_hex_string(ALICE_ADDRESS),
+            balance: 1,
+            nonce: 0,
+        });
+        let genesis_state = GenesisState {
+            accounts,
+            authority_sudo_address: Address::from([0; 20]),
+            ibc_sudo_address: Address::from([0; 20]),
+            ibc_relayer_addresses: vec![],
+            native_asset_base_denomination: DEFAULT_NATIVE_ASSET_DENOM.to_string(),
+            fees: default_fees(),
+            allowed_fee_asset_denominations: vec!["unknown".to_string()],
+            ibc_assets: vec![],
+            asset_balances: vec![AssetBalance {
+                address: address_from_hex_string(BOB_ADDRESS),
+                denom: "transfer/channel-0/utia".to_string(),
+                balance: 100,
+            }],
+            asset_metadata: vec![],
+            ibc_params: IbcParameters::default(),
+            validators: vec![],
+            blockspace: BlockspaceQuotas::default(),
+            mint_caps: MintCaps::default(),
+            minted_supply: 0,
+        };
+
+        let err = app
+            .init_chain(
+                genesis_state,
+                vec![],
+                "test".to_string(),
+                test_consensus_params(),
+            )
+            .await
+            .unwrap_err()
+            .root_cause()
+            .to_string();
+        assert!(err.contains("found 3 problem(s)"));
+        assert!(err.contains(&format!("account {ALICE_ADDRESS} is listed more than once")));
+        assert!(err.contains("not a genesis IBC asset"));
+        assert!(err.contains("allowed fee asset `unknown`"));
+        assert!(app.state.get_chain_id().await.is_err());
     }
 
     #[tokio::test]
@@ -932,12 +1443,17 @@ mod test {
                 }
                 .into(),
             ],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
         };
 
         let signed_tx = tx.into_signed(&alice_signing_key);
         app.deliver_tx(signed_tx).await.unwrap();
 
         let native_asset = get_native_asset().id();
+        let transfer_fee = app.state.get_transfer_base_fee().await.unwrap();
         assert_eq!(
             app.state
                 .get_account_balance(bob_address, native_asset)
@@ -950,159 +1466,330 @@ mod test {
                 .get_account_balance(alice_address, native_asset)
                 .await
                 .unwrap(),
-            10u128.pow(19) - (value + TRANSFER_FEE),
+            10u128.pow(19) - (value + transfer_fee),
         );
         assert_eq!(app.state.get_account_nonce(bob_address).await.unwrap(), 0);
         assert_eq!(app.state.get_account_nonce(alice_address).await.unwrap(), 1);
     }
 
     #[tokio::test]
-    async fn app_deliver_tx_transfer_not_native_token() {
+    async fn app_deliver_tx_transfer_from_multisig_account() {
+        use astria_core::sequencer::v1alpha1::transaction::MultisigKey;
+
         use crate::accounts::state_ext::StateWriteExt as _;
 
         let mut app = initialize_app(None, vec![]).await;
 
-        // create some asset to be transferred and update Alice's balance of it
-        let asset = asset::Id::from_denom("test");
-        let value = 333_333;
-        let (alice_signing_key, alice_address) = get_alice_signing_key_and_address();
+        // fund a 2-of-3 multisig account
+        let signing_keys: Vec<_> = (1..=3).map(|i| SigningKey::from([i; 32])).collect();
+        let multisig_key = MultisigKey::new(
+            2,
+            signing_keys
+                .iter()
+                .map(SigningKey::verification_key)
+                .collect(),
+        )
+        .unwrap();
+        let multisig_address = multisig_key.address();
+        let native_asset = get_native_asset().id();
         let mut state_tx = StateDelta::new(app.state.clone());
         state_tx
-            .put_account_balance(alice_address, asset, value)
+            .put_account_balance(multisig_address, native_asset, 10u128.pow(19))
             .unwrap();
         app.apply(state_tx);
 
-        // transfer funds from Alice to Bob; use native token for fee payment
         let bob_address = address_from_hex_string(BOB_ADDRESS);
+        let value = 333_333;
         let tx = UnsignedTransaction {
             nonce: 0,
             actions: vec![
                 TransferAction {
                     to: bob_address,
                     amount: value,
-                    asset_id: asset,
-                    fee_asset_id: get_native_asset().id(),
+                    asset_id: native_asset,
+                    fee_asset_id: native_asset,
                 }
                 .into(),
             ],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
         };
 
-        let signed_tx = tx.into_signed(&alice_signing_key);
+        let signed_tx = tx
+            .into_multisig_signed(multisig_key, &signing_keys[1..])
+            .unwrap();
+        assert_eq!(signed_tx.address(), multisig_address);
         app.deliver_tx(signed_tx).await.unwrap();
 
-        let native_asset = get_native_asset().id();
+        let transfer_fee = app.state.get_transfer_base_fee().await.unwrap();
         assert_eq!(
             app.state
                 .get_account_balance(bob_address, native_asset)
                 .await
                 .unwrap(),
-            10u128.pow(19), // genesis balance
-        );
-        assert_eq!(
-            app.state
-                .get_account_balance(bob_address, asset)
-                .await
-                .unwrap(),
-            value, // transferred amount
+            value + 10u128.pow(19)
         );
-
         assert_eq!(
             app.state
-                .get_account_balance(alice_address, native_asset)
+                .get_account_balance(multisig_address, native_asset)
                 .await
                 .unwrap(),
-            10u128.pow(19) - TRANSFER_FEE, // genesis balance - fee
+            10u128.pow(19) - (value + transfer_fee),
         );
         assert_eq!(
-            app.state
-                .get_account_balance(alice_address, asset)
-                .await
-                .unwrap(),
-            0, // 0 since all funds of `asset` were transferred
+            app.state.get_account_nonce(multisig_address).await.unwrap(),
+            1
         );
-
-        assert_eq!(app.state.get_account_nonce(bob_address).await.unwrap(), 0);
-        assert_eq!(app.state.get_account_nonce(alice_address).await.unwrap(), 1);
     }
 
     #[tokio::test]
-    async fn app_deliver_tx_transfer_balance_too_low_for_fee() {
-        use rand::rngs::OsRng;
-
+    async fn app_deliver_tx_expired_at_height() {
         let mut app = initialize_app(None, vec![]).await;
+        let mut state_tx = StateDelta::new(app.state.clone());
+        state_tx.put_block_height(10);
+        app.apply(state_tx);
 
-        // create a new key; will have 0 balance
-        let keypair = SigningKey::new(OsRng);
-        let bob = address_from_hex_string(BOB_ADDRESS);
-
-        // 0-value transfer; only fee is deducted from sender
+        let (alice_signing_key, _) = get_alice_signing_key_and_address();
         let tx = UnsignedTransaction {
             nonce: 0,
             actions: vec![
                 TransferAction {
-                    to: bob,
-                    amount: 0,
+                    to: address_from_hex_string(BOB_ADDRESS),
+                    amount: 333_333,
                     asset_id: get_native_asset().id(),
                     fee_asset_id: get_native_asset().id(),
                 }
                 .into(),
             ],
+            valid_until_height: Some(9),
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
         };
 
-        let signed_tx = tx.into_signed(&keypair);
+        let signed_tx = tx.into_signed(&alice_signing_key);
         let res = app
             .deliver_tx(signed_tx)
             .await
             .unwrap_err()
             .root_cause()
             .to_string();
-        assert!(res.contains("insufficient funds"));
+        assert!(res.contains("transaction expired at height 9"));
     }
 
     #[tokio::test]
-    async fn app_deliver_tx_sequence() {
-        let mut app = initialize_app(None, vec![]).await;
+    async fn app_deliver_tx_expired_at_timestamp() {
+        use std::time::{
+            Duration,
+            SystemTime,
+        };
 
-        let (alice_signing_key, alice_address) = get_alice_signing_key_and_address();
-        let data = b"hello world".to_vec();
-        let fee = calculate_fee(&data).unwrap();
+        let mut app = initialize_app(None, vec![]).await;
+        let mut state_tx = StateDelta::new(app.state.clone());
+        state_tx.put_block_timestamp(Time::from_unix_timestamp(100, 0).unwrap());
+        app.apply(state_tx);
 
+        let (alice_signing_key, _) = get_alice_signing_key_and_address();
         let tx = UnsignedTransaction {
             nonce: 0,
             actions: vec![
-                SequenceAction {
-                    rollup_id: RollupId::from_unhashed_bytes(b"testchainid"),
-                    data,
+                TransferAction {
+                    to: address_from_hex_string(BOB_ADDRESS),
+                    amount: 333_333,
+                    asset_id: get_native_asset().id(),
                     fee_asset_id: get_native_asset().id(),
                 }
                 .into(),
             ],
+            valid_until_height: None,
+            valid_until_timestamp: Some((SystemTime::UNIX_EPOCH + Duration::from_secs(99)).into()),
+            tip: None,
+            fee_payer: None,
         };
 
         let signed_tx = tx.into_signed(&alice_signing_key);
-        app.deliver_tx(signed_tx).await.unwrap();
-        assert_eq!(app.state.get_account_nonce(alice_address).await.unwrap(), 1);
-
-        assert_eq!(
-            app.state
-                .get_account_balance(alice_address, get_native_asset().id())
-                .await
-                .unwrap(),
-            10u128.pow(19) - fee,
-        );
+        let res = app
+            .deliver_tx(signed_tx)
+            .await
+            .unwrap_err()
+            .root_cause()
+            .to_string();
+        assert!(res.contains("transaction expired at time"));
     }
 
     #[tokio::test]
-    async fn app_deliver_tx_validator_update() {
-        let (alice_signing_key, alice_address) = get_alice_signing_key_and_address();
+    async fn app_deliver_tx_transfer_not_native_token() {
+        use crate::accounts::state_ext::StateWriteExt as _;
 
-        let genesis_state = GenesisState {
-            accounts: default_genesis_accounts(),
-            authority_sudo_address: alice_address,
-            ibc_sudo_address: alice_address,
-            native_asset_base_denomination: DEFAULT_NATIVE_ASSET_DENOM.to_string(),
-        };
-        let mut app = initialize_app(Some(genesis_state), vec![]).await;
+        let mut app = initialize_app(None, vec![]).await;
+
+        // create some asset to be transferred and update Alice's balance of it
+        let asset = asset::Id::from_denom("test");
+        let value = 333_333;
+        let (alice_signing_key, alice_address) = get_alice_signing_key_and_address();
+        let mut state_tx = StateDelta::new(app.state.clone());
+        state_tx
+            .put_account_balance(alice_address, asset, value)
+            .unwrap();
+        app.apply(state_tx);
+
+        // transfer funds from Alice to Bob; use native token for fee payment
+        let bob_address = address_from_hex_string(BOB_ADDRESS);
+        let tx = UnsignedTransaction {
+            nonce: 0,
+            actions: vec![
+                TransferAction {
+                    to: bob_address,
+                    amount: value,
+                    asset_id: asset,
+                    fee_asset_id: get_native_asset().id(),
+                }
+                .into(),
+            ],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
+        };
+
+        let signed_tx = tx.into_signed(&alice_signing_key);
+        app.deliver_tx(signed_tx).await.unwrap();
+
+        let native_asset = get_native_asset().id();
+        let transfer_fee = app.state.get_transfer_base_fee().await.unwrap();
+        assert_eq!(
+            app.state
+                .get_account_balance(bob_address, native_asset)
+                .await
+                .unwrap(),
+            10u128.pow(19), // genesis balance
+        );
+        assert_eq!(
+            app.state
+                .get_account_balance(bob_address, asset)
+                .await
+                .unwrap(),
+            value, // transferred amount
+        );
+
+        assert_eq!(
+            app.state
+                .get_account_balance(alice_address, native_asset)
+                .await
+                .unwrap(),
+            10u128.pow(19) - transfer_fee, // genesis balance - fee
+        );
+        assert_eq!(
+            app.state
+                .get_account_balance(alice_address, asset)
+                .await
+                .unwrap(),
+            0, // 0 since all funds of `asset` were transferred
+        );
+
+        assert_eq!(app.state.get_account_nonce(bob_address).await.unwrap(), 0);
+        assert_eq!(app.state.get_account_nonce(alice_address).await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn app_deliver_tx_transfer_balance_too_low_for_fee() {
+        use rand::rngs::OsRng;
+
+        let mut app = initialize_app(None, vec![]).await;
+
+        // create a new key; will have 0 balance
+        let keypair = SigningKey::new(OsRng);
+        let bob = address_from_hex_string(BOB_ADDRESS);
+
+        // 0-value transfer; only fee is deducted from sender
+        let tx = UnsignedTransaction {
+            nonce: 0,
+            actions: vec![
+                TransferAction {
+                    to: bob,
+                    amount: 0,
+                    asset_id: get_native_asset().id(),
+                    fee_asset_id: get_native_asset().id(),
+                }
+                .into(),
+            ],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
+        };
+
+        let signed_tx = tx.into_signed(&keypair);
+        let res = app
+            .deliver_tx(signed_tx)
+            .await
+            .unwrap_err()
+            .root_cause()
+            .to_string();
+        assert!(res.contains("insufficient funds"));
+    }
+
+    #[tokio::test]
+    async fn app_deliver_tx_sequence() {
+        let mut app = initialize_app(None, vec![]).await;
+
+        let (alice_signing_key, alice_address) = get_alice_signing_key_and_address();
+        let data = b"hello world".to_vec();
+        let fee = calculate_fee_from_state(&data, &app.state).await.unwrap();
+
+        let tx = UnsignedTransaction {
+            nonce: 0,
+            actions: vec![
+                SequenceAction {
+                    rollup_id: RollupId::from_unhashed_bytes(b"testchainid"),
+                    data,
+                    fee_asset_id: get_native_asset().id(),
+                }
+                .into(),
+            ],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
+        };
+
+        let signed_tx = tx.into_signed(&alice_signing_key);
+        app.deliver_tx(signed_tx).await.unwrap();
+        assert_eq!(app.state.get_account_nonce(alice_address).await.unwrap(), 1);
+
+        assert_eq!(
+            app.state
+                .get_account_balance(alice_address, get_native_asset().id())
+                .await
+                .unwrap(),
+            10u128.pow(19) - fee,
+        );
+    }
+
+    #[tokio::test]
+    async fn app_deliver_tx_validator_update() {
+        let (alice_signing_key, alice_address) = get_alice_signing_key_and_address();
+
+        let genesis_state = GenesisState {
+            accounts: default_genesis_accounts(),
+            authority_sudo_address: alice_address,
+            ibc_sudo_address: alice_address,
+            ibc_relayer_addresses: vec![],
+            native_asset_base_denomination: DEFAULT_NATIVE_ASSET_DENOM.to_string(),
+            fees: default_fees(),
+            allowed_fee_asset_denominations: vec![DEFAULT_NATIVE_ASSET_DENOM.to_string()],
+            ibc_assets: vec![],
+            asset_balances: vec![],
+            asset_metadata: vec![],
+            ibc_params: IbcParameters::default(),
+            validators: vec![],
+            blockspace: BlockspaceQuotas::default(),
+            mint_caps: MintCaps::default(),
+            minted_supply: 0,
+        };
+        let mut app = initialize_app(Some(genesis_state), vec![]).await;
 
         let pub_key = tendermint::public_key::PublicKey::from_raw_ed25519(&[1u8; 32]).unwrap();
         let update = tendermint::validator::Update {
@@ -1113,6 +1800,10 @@ mod test {
         let tx = UnsignedTransaction {
             nonce: 0,
             actions: vec![Action::ValidatorUpdate(update.clone())],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
         };
 
         let signed_tx = tx.into_signed(&alice_signing_key);
@@ -1132,7 +1823,18 @@ mod test {
             accounts: default_genesis_accounts(),
             authority_sudo_address: alice_address,
             ibc_sudo_address: alice_address,
+            ibc_relayer_addresses: vec![],
             native_asset_base_denomination: DEFAULT_NATIVE_ASSET_DENOM.to_string(),
+            fees: default_fees(),
+            allowed_fee_asset_denominations: vec![DEFAULT_NATIVE_ASSET_DENOM.to_string()],
+            ibc_assets: vec![],
+            asset_balances: vec![],
+            asset_metadata: vec![],
+            ibc_params: IbcParameters::default(),
+            validators: vec![],
+            blockspace: BlockspaceQuotas::default(),
+            mint_caps: MintCaps::default(),
+            minted_supply: 0,
         };
         let mut app = initialize_app(Some(genesis_state), vec![]).await;
 
@@ -1143,6 +1845,10 @@ mod test {
             actions: vec![Action::SudoAddressChange(SudoAddressChangeAction {
                 new_address,
             })],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
         };
 
         let signed_tx = tx.into_signed(&alice_signing_key);
@@ -1162,7 +1868,18 @@ mod test {
             accounts: default_genesis_accounts(),
             authority_sudo_address: sudo_address,
             ibc_sudo_address: [0u8; 20].into(),
+            ibc_relayer_addresses: vec![],
             native_asset_base_denomination: DEFAULT_NATIVE_ASSET_DENOM.to_string(),
+            fees: default_fees(),
+            allowed_fee_asset_denominations: vec![DEFAULT_NATIVE_ASSET_DENOM.to_string()],
+            ibc_assets: vec![],
+            asset_balances: vec![],
+            asset_metadata: vec![],
+            ibc_params: IbcParameters::default(),
+            validators: vec![],
+            blockspace: BlockspaceQuotas::default(),
+            mint_caps: MintCaps::default(),
+            minted_supply: 0,
         };
         let mut app = initialize_app(Some(genesis_state), vec![]).await;
 
@@ -1171,6 +1888,10 @@ mod test {
             actions: vec![Action::SudoAddressChange(SudoAddressChangeAction {
                 new_address: alice_address,
             })],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
         };
 
         let signed_tx = tx.into_signed(&alice_signing_key);
@@ -1183,266 +1904,3321 @@ mod test {
         assert!(res.contains("signer is not the sudo key"));
     }
 
-    #[cfg(feature = "mint")]
     #[tokio::test]
-    async fn app_deliver_tx_mint() {
+    async fn app_deliver_tx_fee_change() {
         let (alice_signing_key, alice_address) = get_alice_signing_key_and_address();
 
         let genesis_state = GenesisState {
             accounts: default_genesis_accounts(),
             authority_sudo_address: alice_address,
-            ibc_sudo_address: [0u8; 20].into(),
+            ibc_sudo_address: alice_address,
+            ibc_relayer_addresses: vec![],
             native_asset_base_denomination: DEFAULT_NATIVE_ASSET_DENOM.to_string(),
+            fees: default_fees(),
+            allowed_fee_asset_denominations: vec![DEFAULT_NATIVE_ASSET_DENOM.to_string()],
+            ibc_assets: vec![],
+            asset_balances: vec![],
+            asset_metadata: vec![],
+            ibc_params: IbcParameters::default(),
+            validators: vec![],
+            blockspace: BlockspaceQuotas::default(),
+            mint_caps: MintCaps::default(),
+            minted_supply: 0,
         };
         let mut app = initialize_app(Some(genesis_state), vec![]).await;
 
-        let bob_address = address_from_hex_string(BOB_ADDRESS);
-        let value = 333_333;
         let tx = UnsignedTransaction {
             nonce: 0,
+            actions: vec![Action::FeeChange(FeeChangeAction {
+                fee_change: FeeChange::TransferBaseFee,
+                new_value: 10,
+            })],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
+        };
+
+        let signed_tx = tx.into_signed(&alice_signing_key);
+        let events = app.deliver_tx(signed_tx).await.unwrap();
+        assert_eq!(app.state.get_account_nonce(alice_address).await.unwrap(), 1);
+        assert_eq!(app.state.get_transfer_base_fee().await.unwrap(), 10);
+        assert!(events.contains(
+            &FeeChangeEvent {
+                fee_change: FeeChange::TransferBaseFee,
+                new_value: 10,
+            }
+            .into_abci()
+        ));
+
+        // the new fee is charged for subsequent transfers
+        let bob_address = address_from_hex_string(BOB_ADDRESS);
+        let native_asset = get_native_asset().id();
+        let tx = UnsignedTransaction {
+            nonce: 1,
             actions: vec![
-                MintAction {
+                TransferAction {
                     to: bob_address,
-                    amount: value,
+                    amount: 1,
+                    asset_id: native_asset,
+                    fee_asset_id: native_asset,
                 }
                 .into(),
             ],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
         };
 
         let signed_tx = tx.into_signed(&alice_signing_key);
         app.deliver_tx(signed_tx).await.unwrap();
-
         assert_eq!(
             app.state
-                .get_account_balance(bob_address, get_native_asset().id())
+                .get_account_balance(alice_address, native_asset)
                 .await
                 .unwrap(),
-            value + 10u128.pow(19)
+            10u128.pow(19) - (1 + 10),
         );
-        assert_eq!(app.state.get_account_nonce(bob_address).await.unwrap(), 0);
-        assert_eq!(app.state.get_account_nonce(alice_address).await.unwrap(), 1);
     }
 
     #[tokio::test]
-    async fn app_end_block_validator_updates() {
-        use tendermint::validator;
-
-        let pubkey_a = tendermint::public_key::PublicKey::from_raw_ed25519(&[1; 32]).unwrap();
-        let pubkey_b = tendermint::public_key::PublicKey::from_raw_ed25519(&[2; 32]).unwrap();
-        let pubkey_c = tendermint::public_key::PublicKey::from_raw_ed25519(&[3; 32]).unwrap();
-
-        let initial_validator_set = vec![
-            validator::Update {
-                pub_key: pubkey_a,
-                power: 100u32.into(),
-            },
-            validator::Update {
-                pub_key: pubkey_b,
-                power: 1u32.into(),
-            },
-        ];
-
-        let mut app = initialize_app(None, initial_validator_set).await;
-        app.current_proposer = Some(account::Id::try_from([0u8; 20].to_vec()).unwrap());
+    async fn app_deliver_tx_fee_change_error() {
+        let (alice_signing_key, _) = get_alice_signing_key_and_address();
+        let sudo_address = address_from_hex_string(CAROL_ADDRESS);
 
-        let validator_updates = vec![
-            validator::Update {
-                pub_key: pubkey_a,
-                power: 0u32.into(),
-            },
-            validator::Update {
-                pub_key: pubkey_b,
-                power: 100u32.into(),
-            },
-            validator::Update {
-                pub_key: pubkey_c,
-                power: 100u32.into(),
-            },
-        ];
+        let genesis_state = GenesisState {
+            accounts: default_genesis_accounts(),
+            authority_sudo_address: sudo_address,
+            ibc_sudo_address: [0u8; 20].into(),
+            ibc_relayer_addresses: vec![],
+            native_asset_base_denomination: DEFAULT_NATIVE_ASSET_DENOM.to_string(),
+            fees: default_fees(),
+            allowed_fee_asset_denominations: vec![DEFAULT_NATIVE_ASSET_DENOM.to_string()],
+            ibc_assets: vec![],
+            asset_balances: vec![],
+            asset_metadata: vec![],
+            ibc_params: IbcParameters::default(),
+            validators: vec![],
+            blockspace: BlockspaceQuotas::default(),
+            mint_caps: MintCaps::default(),
+            minted_supply: 0,
+        };
+        let mut app = initialize_app(Some(genesis_state), vec![]).await;
 
-        let mut state_tx = StateDelta::new(app.state.clone());
-        state_tx
-            .put_validator_updates(ValidatorSet::new_from_updates(validator_updates.clone()))
-            .unwrap();
-        app.apply(state_tx);
+        let tx = UnsignedTransaction {
+            nonce: 0,
+            actions: vec![Action::FeeChange(FeeChangeAction {
+                fee_change: FeeChange::SequenceByteCostMultiplier,
+                new_value: 10,
+            })],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
+        };
 
-        let resp = app
-            .end_block(&abci::request::EndBlock {
-                height: 1u32.into(),
-            })
+        let signed_tx = tx.into_signed(&alice_signing_key);
+        let res = app
+            .deliver_tx(signed_tx)
             .await
-            .unwrap();
-        // we only assert length here as the ordering of the updates is not guaranteed
-        // and validator::Update does not implement Ord
-        assert_eq!(resp.validator_updates.len(), validator_updates.len());
-
-        // validator with pubkey_a should be removed (power set to 0)
-        // validator with pubkey_b should be updated
-        // validator with pubkey_c should be added
-        let validator_set = app.state.get_validator_set().await.unwrap();
-        assert_eq!(validator_set.len(), 2);
-        let validator_b = validator_set.get(&pubkey_b.into()).unwrap();
-        assert_eq!(validator_b.pub_key, pubkey_b);
-        assert_eq!(validator_b.power, 100u32.into());
-        let validator_c = validator_set.get(&pubkey_c.into()).unwrap();
-        assert_eq!(validator_c.pub_key, pubkey_c);
-        assert_eq!(validator_c.power, 100u32.into());
-        assert_eq!(app.state.get_validator_updates().await.unwrap().len(), 0);
+            .unwrap_err()
+            .root_cause()
+            .to_string();
+        assert!(res.contains("signer is not the sudo key"));
     }
 
     #[tokio::test]
-    async fn app_deliver_tx_invalid_nonce() {
-        let mut app = initialize_app(None, vec![]).await;
-
+    async fn app_deliver_tx_fee_asset_change_addition() {
         let (alice_signing_key, alice_address) = get_alice_signing_key_and_address();
 
-        // create tx with invalid nonce 1
-        let data = b"hello world".to_vec();
-        let tx = UnsignedTransaction {
-            nonce: 1,
-            actions: vec![
-                SequenceAction {
-                    rollup_id: RollupId::from_unhashed_bytes(b"testchainid"),
-                    data,
-                    fee_asset_id: get_native_asset().id(),
-                }
-                .into(),
+        let genesis_state = GenesisState {
+            accounts: default_genesis_accounts(),
+            authority_sudo_address: alice_address,
+            ibc_sudo_address: alice_address,
+            ibc_relayer_addresses: vec![],
+            native_asset_base_denomination: DEFAULT_NATIVE_ASSET_DENOM.to_string(),
+            fees: default_fees(),
+            allowed_fee_asset_denominations: vec![DEFAULT_NATIVE_ASSET_DENOM.to_string()],
+            ibc_assets: vec![],
+            asset_balances: vec![],
+            asset_metadata: vec![],
+            ibc_params: IbcParameters::default(),
+            validators: vec![],
+            blockspace: BlockspaceQuotas::default(),
+            mint_caps: MintCaps::default(),
+            minted_supply: 0,
+        };
+        let mut app = initialize_app(Some(genesis_state), vec![]).await;
+
+        let test_asset = asset::Id::from_denom("test");
+        let tx = UnsignedTransaction {
+            nonce: 0,
+            actions: vec![Action::FeeAssetChange(FeeAssetChangeAction::Addition(
+                test_asset,
+            ))],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
+        };
+
+        let signed_tx = tx.into_signed(&alice_signing_key);
+        app.deliver_tx(signed_tx).await.unwrap();
+        assert_eq!(app.state.get_account_nonce(alice_address).await.unwrap(), 1);
+        assert!(app.state.is_allowed_fee_asset(test_asset).await.unwrap());
+        assert!(app
+            .state
+            .is_allowed_fee_asset(get_native_asset().id())
+            .await
+            .unwrap());
+    }
+
+    #[tokio::test]
+    async fn app_deliver_tx_fee_asset_change_removal() {
+        let (alice_signing_key, alice_address) = get_alice_signing_key_and_address();
+
+        let genesis_state = GenesisState {
+            accounts: default_genesis_accounts(),
+            authority_sudo_address: alice_address,
+            ibc_sudo_address: alice_address,
+            ibc_relayer_addresses: vec![],
+            native_asset_base_denomination: DEFAULT_NATIVE_ASSET_DENOM.to_string(),
+            fees: default_fees(),
+            allowed_fee_asset_denominations: vec![
+                DEFAULT_NATIVE_ASSET_DENOM.to_string(),
+                "test".to_string(),
             ],
+            ibc_assets: vec![],
+            asset_balances: vec![],
+            asset_metadata: vec![],
+            ibc_params: IbcParameters::default(),
+            validators: vec![],
+            blockspace: BlockspaceQuotas::default(),
+            mint_caps: MintCaps::default(),
+            minted_supply: 0,
+        };
+        let mut app = initialize_app(Some(genesis_state), vec![]).await;
+
+        let test_asset = asset::Id::from_denom("test");
+        let tx = UnsignedTransaction {
+            nonce: 0,
+            actions: vec![Action::FeeAssetChange(FeeAssetChangeAction::Removal(
+                test_asset,
+            ))],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
         };
 
         let signed_tx = tx.into_signed(&alice_signing_key);
-        let response = app.deliver_tx(signed_tx).await;
+        app.deliver_tx(signed_tx).await.unwrap();
+        assert_eq!(app.state.get_account_nonce(alice_address).await.unwrap(), 1);
+        assert!(!app.state.is_allowed_fee_asset(test_asset).await.unwrap());
+        assert_eq!(
+            app.state.get_allowed_fee_assets().await.unwrap(),
+            vec![get_native_asset().id()],
+        );
+    }
 
-        // check that tx was not executed by checking nonce and balance are unchanged
-        assert_eq!(app.state.get_account_nonce(alice_address).await.unwrap(), 0);
+    #[tokio::test]
+    async fn app_deliver_tx_asset_metadata_change() {
+        let (alice_signing_key, alice_address) = get_alice_signing_key_and_address();
+
+        let genesis_state = GenesisState {
+            accounts: default_genesis_accounts(),
+            authority_sudo_address: alice_address,
+            ibc_sudo_address: alice_address,
+            ibc_relayer_addresses: vec![],
+            native_asset_base_denomination: DEFAULT_NATIVE_ASSET_DENOM.to_string(),
+            fees: default_fees(),
+            allowed_fee_asset_denominations: vec![DEFAULT_NATIVE_ASSET_DENOM.to_string()],
+            ibc_assets: vec![],
+            asset_balances: vec![],
+            asset_metadata: vec![],
+            ibc_params: IbcParameters::default(),
+            validators: vec![],
+            blockspace: BlockspaceQuotas::default(),
+            mint_caps: MintCaps::default(),
+            minted_supply: 0,
+        };
+        let mut app = initialize_app(Some(genesis_state), vec![]).await;
+
+        let metadata = asset::Metadata {
+            decimals: 9,
+            symbol: "RIA".to_string(),
+            display_denom: "ria".to_string(),
+        };
+        let tx = UnsignedTransaction {
+            nonce: 0,
+            actions: vec![AssetMetadataChangeAction {
+                asset_id: get_native_asset().id(),
+                metadata: metadata.clone(),
+            }
+            .into()],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
+        };
+        app.deliver_tx(tx.into_signed(&alice_signing_key))
+            .await
+            .unwrap();
         assert_eq!(
             app.state
-                .get_account_balance(alice_address, get_native_asset().id())
+                .get_asset_metadata(get_native_asset().id())
                 .await
                 .unwrap(),
-            10u128.pow(19),
+            Some(metadata.clone())
         );
 
-        assert_eq!(
-            response
-                .unwrap_err()
-                .downcast_ref::<InvalidNonce>()
-                .map(|nonce_err| nonce_err.0)
-                .unwrap(),
-            1
-        );
+        // only the native asset and registered IBC assets have metadata
+        let tx = UnsignedTransaction {
+            nonce: 1,
+            actions: vec![AssetMetadataChangeAction {
+                asset_id: asset::Id::from_denom("test"),
+                metadata,
+            }
+            .into()],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
+        };
+        let res = app
+            .deliver_tx(tx.into_signed(&alice_signing_key))
+            .await
+            .unwrap_err()
+            .root_cause()
+            .to_string();
+        assert!(res.contains("asset is neither the native asset nor a registered IBC asset"));
     }
 
     #[tokio::test]
-    async fn app_commit() {
+    async fn app_deliver_tx_asset_metadata_change_not_sudo() {
+        let mut app = initialize_app(None, vec![]).await;
+        let (alice_signing_key, _) = get_alice_signing_key_and_address();
+
+        let tx = UnsignedTransaction {
+            nonce: 0,
+            actions: vec![AssetMetadataChangeAction {
+                asset_id: get_native_asset().id(),
+                metadata: asset::Metadata {
+                    decimals: 9,
+                    symbol: "RIA".to_string(),
+                    display_denom: "ria".to_string(),
+                },
+            }
+            .into()],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
+        };
+        let res = app
+            .deliver_tx(tx.into_signed(&alice_signing_key))
+            .await
+            .unwrap_err()
+            .root_cause()
+            .to_string();
+        assert!(res.contains("signer is not the sudo key"));
+    }
+
+    #[tokio::test]
+    async fn app_deliver_tx_fee_asset_change_invalid() {
+        let (alice_signing_key, alice_address) = get_alice_signing_key_and_address();
+
         let genesis_state = GenesisState {
             accounts: default_genesis_accounts(),
-            authority_sudo_address: Address::from([0; 20]),
-            ibc_sudo_address: Address::from([0; 20]),
+            authority_sudo_address: alice_address,
+            ibc_sudo_address: alice_address,
+            ibc_relayer_addresses: vec![],
             native_asset_base_denomination: DEFAULT_NATIVE_ASSET_DENOM.to_string(),
+            fees: default_fees(),
+            allowed_fee_asset_denominations: vec![DEFAULT_NATIVE_ASSET_DENOM.to_string()],
+            ibc_assets: vec![],
+            asset_balances: vec![],
+            asset_metadata: vec![],
+            ibc_params: IbcParameters::default(),
+            validators: vec![],
+            blockspace: BlockspaceQuotas::default(),
+            mint_caps: MintCaps::default(),
+            minted_supply: 0,
         };
+        let mut app = initialize_app(Some(genesis_state), vec![]).await;
 
-        let (mut app, storage) = initialize_app_with_storage(Some(genesis_state), vec![]).await;
-        assert_eq!(app.state.get_block_height().await.unwrap(), 0);
-
-        let native_asset = get_native_asset().id();
-        for Account {
-            address,
-            balance,
-        } in default_genesis_accounts()
-        {
-            assert_eq!(
-                balance,
-                app.state
-                    .get_account_balance(address, native_asset)
-                    .await
-                    .unwrap()
-            );
-        }
+        let tx = UnsignedTransaction {
+            nonce: 0,
+            actions: vec![Action::FeeAssetChange(FeeAssetChangeAction::Removal(
+                get_native_asset().id(),
+            ))],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
+        };
 
-        // commit should write the changes to the underlying storage
-        app.commit(storage.clone()).await;
-        let snapshot = storage.latest_snapshot();
-        assert_eq!(snapshot.get_block_height().await.unwrap(), 0);
-        for Account {
-            address,
-            balance,
-        } in default_genesis_accounts()
-        {
-            assert_eq!(
-                snapshot
-                    .get_account_balance(address, native_asset)
-                    .await
-                    .unwrap(),
-                balance
-            );
-        }
+        let signed_tx = tx.into_signed(&alice_signing_key);
+        let res = app
+            .deliver_tx(signed_tx)
+            .await
+            .unwrap_err()
+            .root_cause()
+            .to_string();
+        assert!(res.contains("cannot remove last allowed fee asset"));
     }
 
     #[tokio::test]
-    async fn app_transfer_block_fees_to_proposer() {
-        let (mut app, storage) = initialize_app_with_storage(None, vec![]).await;
+    async fn app_deliver_tx_ibc_relayer_change_addition_and_removal() {
+        use crate::ibc::state_ext::StateReadExt as _;
 
-        let mut begin_block = abci::request::BeginBlock {
-            header: default_header(),
-            hash: Hash::default(),
-            last_commit_info: CommitInfo {
-                votes: vec![],
-                round: Round::default(),
-            },
-            byzantine_validators: vec![],
+        let (alice_signing_key, alice_address) = get_alice_signing_key_and_address();
+        let bob_address = address_from_hex_string(BOB_ADDRESS);
+
+        let genesis_state = GenesisState {
+            accounts: default_genesis_accounts(),
+            authority_sudo_address: alice_address,
+            ibc_sudo_address: alice_address,
+            ibc_relayer_addresses: vec![],
+            native_asset_base_denomination: DEFAULT_NATIVE_ASSET_DENOM.to_string(),
+            fees: default_fees(),
+            allowed_fee_asset_denominations: vec![DEFAULT_NATIVE_ASSET_DENOM.to_string()],
+            ibc_assets: vec![],
+            asset_balances: vec![],
+            asset_metadata: vec![],
+            ibc_params: IbcParameters::default(),
+            validators: vec![],
+            blockspace: BlockspaceQuotas::default(),
+            mint_caps: MintCaps::default(),
+            minted_supply: 0,
         };
-        begin_block.header.height = Height::try_from(1u8).unwrap();
-        let proposer_address =
-            Address::try_from_slice(begin_block.header.proposer_address.as_bytes()).unwrap();
+        let mut app = initialize_app(Some(genesis_state), vec![]).await;
 
-        app.begin_block(&begin_block, storage).await.unwrap();
-        assert_eq!(app.state.get_block_height().await.unwrap(), 1);
-        assert_eq!(
-            app.state.get_block_timestamp().await.unwrap(),
-            begin_block.header.time
-        );
+        let tx = UnsignedTransaction {
+            nonce: 0,
+            actions: vec![IbcRelayerChangeAction::Addition(bob_address).into()],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
+        };
+        let events = app
+            .deliver_tx(tx.into_signed(&alice_signing_key))
+            .await
+            .unwrap();
+        assert!(app.state.is_ibc_relayer(&bob_address).await.unwrap());
+        assert!(events.contains(
+            &IbcRelayerChangeEvent {
+                address: bob_address,
+                added: true,
+            }
+            .into_abci()
+        ));
         assert_eq!(
-            app.current_proposer.unwrap(),
-            begin_block.header.proposer_address
+            app.state.get_ibc_relayer_addresses().await.unwrap(),
+            vec![bob_address]
         );
 
-        let (alice_signing_key, _) = get_alice_signing_key_and_address();
-        let native_asset = get_native_asset().id();
+        let tx = UnsignedTransaction {
+            nonce: 1,
+            actions: vec![IbcRelayerChangeAction::Removal(bob_address).into()],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
+        };
+        let events = app
+            .deliver_tx(tx.into_signed(&alice_signing_key))
+            .await
+            .unwrap();
+        assert!(!app.state.is_ibc_relayer(&bob_address).await.unwrap());
+        assert!(events.contains(
+            &IbcRelayerChangeEvent {
+                address: bob_address,
+                added: false,
+            }
+            .into_abci()
+        ));
+        assert!(app
+            .state
+            .get_ibc_relayer_addresses()
+            .await
+            .unwrap()
+            .is_empty());
+    }
 
-        // transfer funds from Alice to Bob; use native token for fee payment
+    #[tokio::test]
+    async fn app_deliver_tx_ibc_relayer_change_invalid() {
+        let (alice_signing_key, alice_address) = get_alice_signing_key_and_address();
+
+        let genesis_state = GenesisState {
+            accounts: default_genesis_accounts(),
+            authority_sudo_address: alice_address,
+            ibc_sudo_address: [0u8; 20].into(),
+            ibc_relayer_addresses: vec![],
+            native_asset_base_denomination: DEFAULT_NATIVE_ASSET_DENOM.to_string(),
+            fees: default_fees(),
+            allowed_fee_asset_denominations: vec![DEFAULT_NATIVE_ASSET_DENOM.to_string()],
+            ibc_assets: vec![],
+            asset_balances: vec![],
+            asset_metadata: vec![],
+            ibc_params: IbcParameters::default(),
+            validators: vec![],
+            blockspace: BlockspaceQuotas::default(),
+            mint_caps: MintCaps::default(),
+            minted_supply: 0,
+        };
+        let mut app = initialize_app(Some(genesis_state), vec![]).await;
+
+        let tx = UnsignedTransaction {
+            nonce: 0,
+            actions: vec![IbcRelayerChangeAction::Addition(alice_address).into()],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
+        };
+
+        let signed_tx = tx.into_signed(&alice_signing_key);
+        let res = app
+            .deliver_tx(signed_tx)
+            .await
+            .unwrap_err()
+            .root_cause()
+            .to_string();
+        assert!(res.contains("signer is not the IBC sudo key"));
+    }
+
+    #[tokio::test]
+    async fn app_deliver_tx_ibc_sudo_change() {
+        use crate::ibc::state_ext::StateReadExt as _;
+
+        let (alice_signing_key, alice_address) = get_alice_signing_key_and_address();
         let bob_address = address_from_hex_string(BOB_ADDRESS);
-        let amount = 333_333;
+
+        let genesis_state = GenesisState {
+            accounts: default_genesis_accounts(),
+            authority_sudo_address: alice_address,
+            ibc_sudo_address: alice_address,
+            ibc_relayer_addresses: vec![],
+            native_asset_base_denomination: DEFAULT_NATIVE_ASSET_DENOM.to_string(),
+            fees: default_fees(),
+            allowed_fee_asset_denominations: vec![DEFAULT_NATIVE_ASSET_DENOM.to_string()],
+            ibc_assets: vec![],
+            asset_balances: vec![],
+            asset_metadata: vec![],
+            ibc_params: IbcParameters::default(),
+            validators: vec![],
+            blockspace: BlockspaceQuotas::default(),
+            mint_caps: MintCaps::default(),
+            minted_supply: 0,
+        };
+        let mut app = initialize_app(Some(genesis_state), vec![]).await;
+
         let tx = UnsignedTransaction {
             nonce: 0,
             actions: vec![
-                TransferAction {
-                    to: bob_address,
-                    amount,
-                    asset_id: native_asset,
-                    fee_asset_id: get_native_asset().id(),
+                IbcSudoChangeAction {
+                    new_address: bob_address,
                 }
                 .into(),
             ],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
         };
+        app.deliver_tx(tx.into_signed(&alice_signing_key))
+            .await
+            .unwrap();
+        assert_eq!(app.state.get_ibc_sudo_address().await.unwrap(), bob_address);
 
-        let signed_tx = tx.into_signed(&alice_signing_key);
-        app.deliver_tx(signed_tx).await.unwrap();
+        // alice is no longer allowed to change the set of IBC relayers
+        let tx = UnsignedTransaction {
+            nonce: 1,
+            actions: vec![IbcRelayerChangeAction::Addition(alice_address).into()],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
+        };
+        let res = app
+            .deliver_tx(tx.into_signed(&alice_signing_key))
+            .await
+            .unwrap_err()
+            .root_cause()
+            .to_string();
+        assert!(res.contains("signer is not the IBC sudo key"));
+    }
 
-        app.end_block(&abci::request::EndBlock {
-            height: 1u32.into(),
+    fn ics20_rate_limit_change_action(rate_limit: Option<Ics20RateLimit>) -> Action {
+        Ics20RateLimitChangeAction {
+            channel: "channel-0".parse().unwrap(),
+            denom: DEFAULT_NATIVE_ASSET_DENOM.into(),
+            rate_limit,
+        }
+        .into()
+    }
+
+    #[tokio::test]
+    async fn app_deliver_tx_ics20_channel_and_rate_limit_change() {
+        let (alice_signing_key, alice_address) = get_alice_signing_key_and_address();
+        let channel = "channel-0".parse().unwrap();
+        let rate_limit = Ics20RateLimit {
+            max_inflow: 100,
+            max_outflow: 50,
+            window_blocks: 10,
+        };
+
+        let genesis_state = GenesisState {
+            accounts: default_genesis_accounts(),
+            authority_sudo_address: alice_address,
+            ibc_sudo_address: alice_address,
+            ibc_relayer_addresses: vec![],
+            native_asset_base_denomination: DEFAULT_NATIVE_ASSET_DENOM.to_string(),
+            fees: default_fees(),
+            allowed_fee_asset_denominations: vec![DEFAULT_NATIVE_ASSET_DENOM.to_string()],
+            ibc_assets: vec![],
+            asset_balances: vec![],
+            asset_metadata: vec![],
+            ibc_params: IbcParameters::default(),
+            validators: vec![],
+            blockspace: BlockspaceQuotas::default(),
+            mint_caps: MintCaps::default(),
+            minted_supply: 0,
+        };
+        let mut app = initialize_app(Some(genesis_state), vec![]).await;
+
+        let tx = UnsignedTransaction {
+            nonce: 0,
+            actions: vec![
+                Ics20ChannelChangeAction::Addition(channel.clone()).into(),
+                ics20_rate_limit_change_action(Some(rate_limit)),
+            ],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
+        };
+        app.deliver_tx(tx.into_signed(&alice_signing_key))
+            .await
+            .unwrap();
+        assert!(app.state.is_ics20_channel_allowed(&channel).await.unwrap());
+        assert_eq!(
+            app.state
+                .get_ics20_rate_limit(&channel, get_native_asset().id())
+                .await
+                .unwrap(),
+            Some(rate_limit),
+        );
+
+        let tx = UnsignedTransaction {
+            nonce: 1,
+            actions: vec![
+                Ics20ChannelChangeAction::Removal(channel.clone()).into(),
+                ics20_rate_limit_change_action(None),
+            ],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
+        };
+        app.deliver_tx(tx.into_signed(&alice_signing_key))
+            .await
+            .unwrap();
+        assert!(!app.state.is_ics20_channel_allowed(&channel).await.unwrap());
+        assert!(app
+            .state
+            .get_ics20_rate_limit(&channel, get_native_asset().id())
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn app_deliver_tx_ics20_channel_and_rate_limit_change_invalid() {
+        let (alice_signing_key, alice_address) = get_alice_signing_key_and_address();
+
+        let genesis_state = GenesisState {
+            accounts: default_genesis_accounts(),
+            authority_sudo_address: alice_address,
+            ibc_sudo_address: alice_address,
+            ibc_relayer_addresses: vec![],
+            native_asset_base_denomination: DEFAULT_NATIVE_ASSET_DENOM.to_string(),
+            fees: default_fees(),
+            allowed_fee_asset_denominations: vec![DEFAULT_NATIVE_ASSET_DENOM.to_string()],
+            ibc_assets: vec![],
+            asset_balances: vec![],
+            asset_metadata: vec![],
+            ibc_params: IbcParameters::default(),
+            validators: vec![],
+            blockspace: BlockspaceQuotas::default(),
+            mint_caps: MintCaps::default(),
+            minted_supply: 0,
+        };
+        let mut app = initialize_app(Some(genesis_state), vec![]).await;
+
+        // only the IBC sudo address can change the channel allowlist
+        let tx = UnsignedTransaction {
+            nonce: 0,
+            actions: vec![Ics20ChannelChangeAction::Addition("channel-0".parse().unwrap()).into()],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
+        };
+        let res = app
+            .deliver_tx(tx.into_signed(&SigningKey::from([2; 32])))
+            .await
+            .unwrap_err()
+            .root_cause()
+            .to_string();
+        assert!(res.contains("signer is not the IBC sudo key"));
+
+        let tx = UnsignedTransaction {
+            nonce: 0,
+            actions: vec![ics20_rate_limit_change_action(Some(Ics20RateLimit {
+                max_inflow: 100,
+                max_outflow: 50,
+                window_blocks: 0,
+            }))],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
+        };
+        let res = app
+            .deliver_tx(tx.into_signed(&alice_signing_key))
+            .await
+            .unwrap_err()
+            .root_cause()
+            .to_string();
+        assert!(res.contains("rate limit window must be at least one block"));
+    }
+
+    #[tokio::test]
+    async fn app_deliver_tx_ics20_withdrawal_channel_allowlist_and_rate_limit() {
+        use astria_core::generated::sequencer::v1alpha1 as raw;
+
+        use crate::ibc::ics20_rate_limit::{
+            check_ics20_flow,
+            record_ics20_flow,
+            Ics20Flow,
+        };
+
+        let (alice_signing_key, alice_address) = get_alice_signing_key_and_address();
+        let channel = "channel-0".parse().unwrap();
+        let asset_id = get_native_asset().id();
+
+        let genesis_state = GenesisState {
+            accounts: default_genesis_accounts(),
+            authority_sudo_address: alice_address,
+            ibc_sudo_address: alice_address,
+            ibc_relayer_addresses: vec![],
+            native_asset_base_denomination: DEFAULT_NATIVE_ASSET_DENOM.to_string(),
+            fees: default_fees(),
+            allowed_fee_asset_denominations: vec![DEFAULT_NATIVE_ASSET_DENOM.to_string()],
+            ibc_assets: vec![],
+            asset_balances: vec![],
+            asset_metadata: vec![],
+            ibc_params: IbcParameters::default(),
+            validators: vec![],
+            blockspace: BlockspaceQuotas::default(),
+            mint_caps: MintCaps::default(),
+            minted_supply: 0,
+        };
+        let mut app = initialize_app(Some(genesis_state), vec![]).await;
+
+        let withdrawal = |amount: u128| -> Action {
+            Ics20Withdrawal::try_from_raw(raw::Ics20Withdrawal {
+                amount: Some(amount.into()),
+                denom: DEFAULT_NATIVE_ASSET_DENOM.to_string(),
+                destination_chain_address: "destination".to_string(),
+                return_address: alice_address.to_vec(),
+                timeout_height: Some(raw::IbcHeight {
+                    revision_number: 0,
+                    revision_height: 100,
+                }),
+                timeout_time: 1,
+                source_channel: "channel-0".to_string(),
+                fee_asset_id: asset_id.as_bytes().to_vec(),
+            })
+            .unwrap()
+            .into()
+        };
+
+        // channels are not allowed unless they were added to the allowlist
+        let tx = UnsignedTransaction {
+            nonce: 0,
+            actions: vec![withdrawal(100)],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
+        };
+        let res = app
+            .deliver_tx(tx.into_signed(&alice_signing_key))
+            .await
+            .unwrap_err()
+            .root_cause()
+            .to_string();
+        assert!(res.contains("channel `channel-0` is not allowed for ICS20 transfers"));
+
+        let tx = UnsignedTransaction {
+            nonce: 0,
+            actions: vec![
+                Ics20ChannelChangeAction::Addition("channel-0".parse().unwrap()).into(),
+                ics20_rate_limit_change_action(Some(Ics20RateLimit {
+                    max_inflow: 100,
+                    max_outflow: 50,
+                    window_blocks: 10,
+                })),
+            ],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
+        };
+        app.deliver_tx(tx.into_signed(&alice_signing_key))
+            .await
+            .unwrap();
+
+        let tx = UnsignedTransaction {
+            nonce: 1,
+            actions: vec![withdrawal(100)],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
+        };
+        let res = app
+            .deliver_tx(tx.into_signed(&alice_signing_key))
+            .await
+            .unwrap_err()
+            .root_cause()
+            .to_string();
+        assert!(res.contains("exceeds the maximum net outflow of 50"));
+
+        // inflow frees up capacity for outflow, as the limits apply to the net amounts
+        let mut state_tx = StateDelta::new(app.state.clone());
+        record_ics20_flow(&mut state_tx, &channel, asset_id, 30, Ics20Flow::Inflow)
+            .await
+            .unwrap();
+        app.apply(state_tx);
+        check_ics20_flow(&*app.state, &channel, asset_id, 80, Ics20Flow::Outflow)
+            .await
+            .unwrap();
+        assert!(
+            check_ics20_flow(&*app.state, &channel, asset_id, 81, Ics20Flow::Outflow)
+                .await
+                .is_err()
+        );
+        // refunds are recorded but never rejected
+        check_ics20_flow(&*app.state, &channel, asset_id, 1_000, Ics20Flow::Refund)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn app_execute_ics20_transfer_routed_to_rollup() {
+        use astria_core::sequencer::v1alpha1::Ics20Deposit;
+        use ibc_types::core::channel::{
+            ChannelId,
+            PortId,
+        };
+
+        use crate::{
+            accounts::{
+                ics20_transfer::execute_ics20_transfer,
+                state_ext::StateReadExt as _,
+            },
+            bridge::state_ext::StateWriteExt as _,
+            ibc::state_ext::StateWriteExt as _,
+        };
+
+        // the received asset is prefixed with the sequencer-side port and channel
+        let asset_id = asset::Id::from_denom("transfer/channel-0/uatom");
+        let rollup_id = RollupId::from_unhashed_bytes("test-rollup");
+        let bridge_address = address_from_hex_string(BOB_ADDRESS);
+
+        let mut app = initialize_app(None, vec![]).await;
+        let mut state_tx = StateDelta::new(app.state.clone());
+        state_tx
+            .put_ibc_params(&IbcParameters {
+                ibc_enabled: true,
+                inbound_ics20_transfers_enabled: true,
+                outbound_ics20_transfers_enabled: true,
+            })
+            .unwrap();
+        let dest_channel: ChannelId = "channel-0".parse().unwrap();
+        state_tx.put_ics20_channel(&dest_channel);
+        state_tx
+            .put_bridge_account_rollup_id(&bridge_address, &rollup_id)
+            .unwrap();
+        state_tx.put_bridge_account_asset_id(&bridge_address, &asset_id);
+        state_tx
+            .put_bridge_account_withdrawer_address(&bridge_address, &bridge_address)
+            .unwrap();
+        app.apply(state_tx);
+
+        let port = PortId::transfer();
+        let source_channel: ChannelId = "channel-7".parse().unwrap();
+        let packet_data = |receiver: &str, memo: String| {
+            serde_json::json!({
+                "amount": "100",
+                "denom": "uatom",
+                "sender": "cosmos1sender",
+                "receiver": receiver,
+                "memo": memo,
+            })
+            .to_string()
+            .into_bytes()
+        };
+        let deposit_memo = format!(
+            r#"{{"rollup_deposit": {{"rollup_id": "{rollup_id}", "rollup_address": "0xabc"}}}}"#
+        );
+        let data = packet_data(BOB_ADDRESS, deposit_memo.clone());
+        let deposit =
+            Ics20Deposit::try_from_packet_data(&data, &port, &source_channel, &port, &dest_channel)
+                .unwrap();
+        assert_eq!(
+            deposit,
+            Some(Ics20Deposit {
+                rollup_id,
+                amount: 100,
+                asset_id,
+                sender: "cosmos1sender".to_string(),
+                destination_chain_address: "0xabc".to_string(),
+            })
+        );
+
+        let mut state_tx = StateDelta::new(app.state.clone());
+        execute_ics20_transfer(
+            &mut state_tx,
+            &data,
+            &port,
+            &source_channel,
+            &port,
+            &dest_channel,
+            false,
+        )
+        .await
+        .unwrap();
+        app.apply(state_tx);
+
+        // the tokens are held by the rollup's bridge account, from which they can be unlocked
+        assert_eq!(
+            app.state
+                .get_account_balance(bridge_address, asset_id)
+                .await
+                .unwrap(),
+            100
+        );
+        assert_eq!(app.state.get_total_supply(asset_id).await.unwrap(), 100);
+
+        // deposits must be received by a bridge account of the rollup
+        let data = packet_data(ALICE_ADDRESS, deposit_memo);
+        let mut state_tx = StateDelta::new(app.state.clone());
+        let err = execute_ics20_transfer(
+            &mut state_tx,
+            &data,
+            &port,
+            &source_channel,
+            &port,
+            &dest_channel,
+            false,
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(
+            err.root_cause().to_string(),
+            "receiver of a rollup deposit must be a bridge account"
+        );
+
+        // transfers with an invalid rollup deposit memo are rejected
+        let data = packet_data(
+            BOB_ADDRESS,
+            r#"{"rollup_deposit": {"rollup_address": "0xabc"}}"#.to_string(),
+        );
+        let mut state_tx = StateDelta::new(app.state.clone());
+        let res = execute_ics20_transfer(
+            &mut state_tx,
+            &data,
+            &port,
+            &source_channel,
+            &port,
+            &dest_channel,
+            false,
+        )
+        .await;
+        assert!(res.is_err());
+    }
+
+    async fn begin_block_at_height_with_scheduled_upgrade(
+        app: &mut App,
+        storage: Storage,
+        name: &str,
+        height: u8,
+    ) -> anyhow::Result<()> {
+        let mut state_tx = StateDelta::new(app.state.clone());
+        state_tx
+            .put_scheduled_upgrade(&ScheduledUpgrade {
+                name: name.to_string(),
+                height: height.into(),
+            })
+            .unwrap();
+        app.apply(state_tx);
+
+        let mut begin_block = abci::request::BeginBlock {
+            header: default_header(),
+            hash: Hash::default(),
+            last_commit_info: CommitInfo {
+                votes: vec![],
+                round: Round::default(),
+            },
+            byzantine_validators: vec![],
+        };
+        begin_block.header.height = Height::try_from(height).unwrap();
+        app.begin_block(&begin_block, storage).await.map(|_| ())
+    }
+
+    #[tokio::test]
+    async fn app_deliver_tx_schedule_upgrade() {
+        let (alice_signing_key, alice_address) = get_alice_signing_key_and_address();
+
+        let genesis_state = GenesisState {
+            accounts: default_genesis_accounts(),
+            authority_sudo_address: alice_address,
+            ibc_sudo_address: alice_address,
+            ibc_relayer_addresses: vec![],
+            native_asset_base_denomination: DEFAULT_NATIVE_ASSET_DENOM.to_string(),
+            fees: default_fees(),
+            allowed_fee_asset_denominations: vec![DEFAULT_NATIVE_ASSET_DENOM.to_string()],
+            ibc_assets: vec![],
+            asset_balances: vec![],
+            asset_metadata: vec![],
+            ibc_params: IbcParameters::default(),
+            validators: vec![],
+            blockspace: BlockspaceQuotas::default(),
+            mint_caps: MintCaps::default(),
+            minted_supply: 0,
+        };
+        let mut app = initialize_app(Some(genesis_state), vec![]).await;
+
+        let tx = UnsignedTransaction {
+            nonce: 0,
+            actions: vec![
+                ScheduleUpgradeAction {
+                    name: "test-upgrade".to_string(),
+                    height: 10,
+                }
+                .into(),
+            ],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
+        };
+
+        let signed_tx = tx.into_signed(&alice_signing_key);
+        app.deliver_tx(signed_tx).await.unwrap();
+        assert_eq!(app.state.get_account_nonce(alice_address).await.unwrap(), 1);
+        assert_eq!(
+            app.state.get_scheduled_upgrade().await.unwrap(),
+            Some(ScheduledUpgrade {
+                name: "test-upgrade".to_string(),
+                height: 10,
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn app_deliver_tx_schedule_upgrade_not_sudo() {
+        let (alice_signing_key, _) = get_alice_signing_key_and_address();
+        let mut app = initialize_app(None, vec![]).await;
+
+        let tx = UnsignedTransaction {
+            nonce: 0,
+            actions: vec![
+                ScheduleUpgradeAction {
+                    name: "test-upgrade".to_string(),
+                    height: 10,
+                }
+                .into(),
+            ],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
+        };
+
+        let signed_tx = tx.into_signed(&alice_signing_key);
+        let res = app
+            .deliver_tx(signed_tx)
+            .await
+            .unwrap_err()
+            .root_cause()
+            .to_string();
+        assert!(res.contains("signer is not the sudo key"));
+    }
+
+    #[tokio::test]
+    async fn app_deliver_tx_schedule_upgrade_unknown() {
+        let (alice_signing_key, alice_address) = get_alice_signing_key_and_address();
+        let genesis_state = GenesisState {
+            accounts: default_genesis_accounts(),
+            authority_sudo_address: alice_address,
+            ibc_sudo_address: alice_address,
+            ibc_relayer_addresses: vec![],
+            native_asset_base_denomination: DEFAULT_NATIVE_ASSET_DENOM.to_string(),
+            fees: default_fees(),
+            allowed_fee_asset_denominations: vec![DEFAULT_NATIVE_ASSET_DENOM.to_string()],
+            ibc_assets: vec![],
+            asset_balances: vec![],
+            asset_metadata: vec![],
+            ibc_params: IbcParameters::default(),
+            validators: vec![],
+            blockspace: BlockspaceQuotas::default(),
+            mint_caps: MintCaps::default(),
+            minted_supply: 0,
+        };
+        let mut app = initialize_app(Some(genesis_state), vec![]).await;
+
+        let tx = UnsignedTransaction {
+            nonce: 0,
+            actions: vec![
+                ScheduleUpgradeAction {
+                    name: "unknown-upgrade".to_string(),
+                    height: 10,
+                }
+                .into(),
+            ],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
+        };
+
+        // whether the upgrade is known is only checked when entering the mempool, so
+        // that executing the block does not depend on the binary
+        let signed_tx = tx.into_signed(&alice_signing_key);
+        app.deliver_tx(signed_tx).await.unwrap();
+        assert_eq!(
+            app.state.get_scheduled_upgrade().await.unwrap(),
+            Some(ScheduledUpgrade {
+                name: "unknown-upgrade".to_string(),
+                height: 10,
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn app_begin_block_unknown_upgrade_halts() {
+        let (mut app, storage) = initialize_app_with_storage(None, vec![]).await;
+
+        // a node running an older binary than the ones which admitted the upgrade into
+        // their mempools does not know it
+        let err =
+            begin_block_at_height_with_scheduled_upgrade(&mut app, storage, "unknown-upgrade", 1)
+                .await
+                .unwrap_err();
+        assert!(format!("{err:#}").contains("is not known to this binary"));
+        assert_eq!(app.state.get_app_version().await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn app_begin_block_applies_known_upgrade() {
+        let (mut app, storage) = initialize_app_with_storage(None, vec![]).await;
+
+        begin_block_at_height_with_scheduled_upgrade(&mut app, storage, "test-upgrade", 1)
+            .await
+            .unwrap();
+        assert_eq!(app.state.get_app_version().await.unwrap(), 2);
+        assert_eq!(
+            app.state
+                .get_upgrade_applied_height("test-upgrade")
+                .await
+                .unwrap(),
+            Some(1)
+        );
+        assert!(app.state.get_scheduled_upgrade().await.unwrap().is_none());
+        assert_eq!(
+            app.state.get_raw("test/migrated").await.unwrap(),
+            Some(vec![1])
+        );
+
+        // the new app version is sent to cometbft at the end of the block
+        let resp = app
+            .end_block(&abci::request::EndBlock {
+                height: 1u32.into(),
+            })
+            .await
+            .unwrap();
+        let params = resp.consensus_param_updates.unwrap();
+        assert_eq!(params.version.unwrap().app, 2);
+    }
+
+    #[tokio::test]
+    async fn app_end_block_no_consensus_param_updates_without_upgrade() {
+        let (mut app, storage) = initialize_app_with_storage(None, vec![]).await;
+
+        let mut begin_block = abci::request::BeginBlock {
+            header: default_header(),
+            hash: Hash::default(),
+            last_commit_info: CommitInfo {
+                votes: vec![],
+                round: Round::default(),
+            },
+            byzantine_validators: vec![],
+        };
+        begin_block.header.height = Height::try_from(1u8).unwrap();
+        app.begin_block(&begin_block, storage).await.unwrap();
+
+        let resp = app
+            .end_block(&abci::request::EndBlock {
+                height: 1u32.into(),
+            })
+            .await
+            .unwrap();
+        assert!(resp.consensus_param_updates.is_none());
+    }
+
+    #[tokio::test]
+    async fn app_deliver_tx_transfer_invalid_fee_asset() {
+        use crate::accounts::state_ext::StateWriteExt as _;
+
+        let mut app = initialize_app(None, vec![]).await;
+
+        // give Alice a balance of an asset which is not allowed for fee payment
+        let asset = asset::Id::from_denom("test");
+        let (alice_signing_key, alice_address) = get_alice_signing_key_and_address();
+        let mut state_tx = StateDelta::new(app.state.clone());
+        state_tx
+            .put_account_balance(alice_address, asset, 10u128.pow(19))
+            .unwrap();
+        app.apply(state_tx);
+
+        let tx = UnsignedTransaction {
+            nonce: 0,
+            actions: vec![
+                TransferAction {
+                    to: address_from_hex_string(BOB_ADDRESS),
+                    amount: 1,
+                    asset_id: get_native_asset().id(),
+                    fee_asset_id: asset,
+                }
+                .into(),
+            ],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
+        };
+
+        let signed_tx = tx.into_signed(&alice_signing_key);
+        let res = app
+            .deliver_tx(signed_tx)
+            .await
+            .unwrap_err()
+            .root_cause()
+            .to_string();
+        assert!(res.contains("invalid fee asset"));
+    }
+
+    #[tokio::test]
+    async fn app_deliver_tx_init_bridge_account() {
+        use crate::bridge::state_ext::StateReadExt as _;
+
+        let mut app = initialize_app(None, vec![]).await;
+
+        let (alice_signing_key, alice_address) = get_alice_signing_key_and_address();
+        let rollup_id = RollupId::from_unhashed_bytes(b"testchainid");
+        let asset_id = get_native_asset().id();
+        let action = InitBridgeAccountAction {
+            rollup_id,
+            asset_id,
+            fee_asset_id: asset_id,
+            withdrawer_address: None,
+        };
+        let tx = UnsignedTransaction {
+            nonce: 0,
+            actions: vec![action.into()],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
+        };
+
+        let signed_tx = tx.into_signed(&alice_signing_key);
+        let events = app.deliver_tx(signed_tx).await.unwrap();
+        assert!(events.contains(
+            &InitBridgeAccountEvent {
+                bridge_address: alice_address,
+                rollup_id,
+                asset_id,
+                withdrawer_address: alice_address,
+            }
+            .into_abci()
+        ));
+
+        let transfer_fee = app.state.get_transfer_base_fee().await.unwrap();
+        assert_eq!(
+            app.state
+                .get_bridge_account_rollup_id(&alice_address)
+                .await
+                .unwrap()
+                .unwrap(),
+            rollup_id
+        );
+        assert_eq!(
+            app.state
+                .get_bridge_account_asset_id(&alice_address)
+                .await
+                .unwrap(),
+            asset_id
+        );
+        assert_eq!(
+            app.state
+                .get_bridge_account_withdrawer_address(&alice_address)
+                .await
+                .unwrap(),
+            alice_address
+        );
+        assert_eq!(
+            app.state
+                .get_account_balance(alice_address, asset_id)
+                .await
+                .unwrap(),
+            10u128.pow(19) - transfer_fee,
+        );
+    }
+
+    #[tokio::test]
+    async fn app_deliver_tx_init_bridge_account_already_exists() {
+        let mut app = initialize_app(None, vec![]).await;
+
+        let (alice_signing_key, _) = get_alice_signing_key_and_address();
+        let action = InitBridgeAccountAction {
+            rollup_id: RollupId::from_unhashed_bytes(b"testchainid"),
+            asset_id: get_native_asset().id(),
+            fee_asset_id: get_native_asset().id(),
+            withdrawer_address: None,
+        };
+        let tx = UnsignedTransaction {
+            nonce: 0,
+            actions: vec![action.clone().into()],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
+        };
+        app.deliver_tx(tx.into_signed(&alice_signing_key))
+            .await
+            .unwrap();
+
+        let tx = UnsignedTransaction {
+            nonce: 1,
+            actions: vec![action.into()],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
+        };
+        let res = app
+            .deliver_tx(tx.into_signed(&alice_signing_key))
+            .await
+            .unwrap_err()
+            .root_cause()
+            .to_string();
+        assert!(res.contains("bridge account already exists"));
+    }
+
+    #[tokio::test]
+    async fn app_deliver_tx_bridge_lock_action() {
+        use astria_core::sequencer::v1alpha1::{
+            group_rollup_data_in_signed_transactions_by_rollup_id,
+            Deposit,
+            RollupData,
+        };
+        use prost::Message as _;
+
+        use crate::bridge::state_ext::StateWriteExt as _;
+
+        let mut app = initialize_app(None, vec![]).await;
+
+        let (alice_signing_key, alice_address) = get_alice_signing_key_and_address();
+        let bridge_address = address_from_hex_string(BOB_ADDRESS);
+        let rollup_id = RollupId::from_unhashed_bytes(b"testchainid");
+        let asset_id = get_native_asset().id();
+
+        let mut state_tx = StateDelta::new(app.state.clone());
+        state_tx
+            .put_bridge_account_rollup_id(&bridge_address, &rollup_id)
+            .unwrap();
+        state_tx.put_bridge_account_asset_id(&bridge_address, &asset_id);
+        state_tx
+            .put_bridge_account_withdrawer_address(&bridge_address, &bridge_address)
+            .unwrap();
+        app.apply(state_tx);
+
+        let amount = 100;
+        let action = BridgeLockAction {
+            to: bridge_address,
+            rollup_id,
+            amount,
+            asset_id,
+            fee_asset_id: asset_id,
+            destination_chain_address: "nootwashere".to_string(),
+        };
+        let tx = UnsignedTransaction {
+            nonce: 0,
+            actions: vec![action.clone().into()],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
+        };
+
+        let signed_tx = tx.into_signed(&alice_signing_key);
+        app.deliver_tx(signed_tx.clone()).await.unwrap();
+
+        let transfer_fee = app.state.get_transfer_base_fee().await.unwrap();
+        assert_eq!(
+            app.state
+                .get_account_balance(alice_address, asset_id)
+                .await
+                .unwrap(),
+            10u128.pow(19) - (amount + transfer_fee),
+        );
+        assert_eq!(
+            app.state
+                .get_account_balance(bridge_address, asset_id)
+                .await
+                .unwrap(),
+            10u128.pow(19) + amount,
+        );
+
+        // the lock is included in the rollup's data as a deposit
+        let expected_deposit = RollupData::Deposit(Deposit::from_bridge_lock(&action))
+            .into_raw()
+            .encode_to_vec();
+        let rollup_data = group_rollup_data_in_signed_transactions_by_rollup_id(&[signed_tx]);
+        assert_eq!(rollup_data[&rollup_id], vec![expected_deposit]);
+    }
+
+    #[tokio::test]
+    async fn app_deliver_tx_bridge_lock_action_not_bridge_account() {
+        let mut app = initialize_app(None, vec![]).await;
+
+        let (alice_signing_key, _) = get_alice_signing_key_and_address();
+        let action = BridgeLockAction {
+            to: address_from_hex_string(BOB_ADDRESS),
+            rollup_id: RollupId::from_unhashed_bytes(b"testchainid"),
+            amount: 100,
+            asset_id: get_native_asset().id(),
+            fee_asset_id: get_native_asset().id(),
+            destination_chain_address: "nootwashere".to_string(),
+        };
+        let tx = UnsignedTransaction {
+            nonce: 0,
+            actions: vec![action.into()],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
+        };
+
+        let res = app
+            .deliver_tx(tx.into_signed(&alice_signing_key))
+            .await
+            .unwrap_err()
+            .root_cause()
+            .to_string();
+        assert!(res.contains("bridge lock must be sent to a bridge account"));
+    }
+
+    #[tokio::test]
+    async fn app_deliver_tx_bridge_unlock_action() {
+        use crate::bridge::state_ext::StateWriteExt as _;
+
+        let mut app = initialize_app(None, vec![]).await;
+
+        // Bob's account is a bridge account with Alice as the withdrawer
+        let (alice_signing_key, alice_address) = get_alice_signing_key_and_address();
+        let bridge_address = address_from_hex_string(BOB_ADDRESS);
+        let carol_address = address_from_hex_string(CAROL_ADDRESS);
+        let asset_id = get_native_asset().id();
+
+        let mut state_tx = StateDelta::new(app.state.clone());
+        state_tx
+            .put_bridge_account_rollup_id(
+                &bridge_address,
+                &RollupId::from_unhashed_bytes(b"testchainid"),
+            )
+            .unwrap();
+        state_tx.put_bridge_account_asset_id(&bridge_address, &asset_id);
+        state_tx
+            .put_bridge_account_withdrawer_address(&bridge_address, &alice_address)
+            .unwrap();
+        app.apply(state_tx);
+
+        let amount = 100;
+        let action = BridgeUnlockAction {
+            to: carol_address,
+            amount,
+            fee_asset_id: asset_id,
+            memo: b"withdrawal".to_vec(),
+            bridge_address,
+        };
+        let tx = UnsignedTransaction {
+            nonce: 0,
+            actions: vec![action.into()],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
+        };
+
+        let signed_tx = tx.into_signed(&alice_signing_key);
+        let events = app.deliver_tx(signed_tx).await.unwrap();
+        assert!(events.contains(
+            &BridgeUnlockEvent {
+                bridge_address,
+                to: carol_address,
+                asset_id,
+                amount,
+                memo: b"withdrawal".to_vec(),
+            }
+            .into_abci()
+        ));
+
+        let transfer_fee = app.state.get_transfer_base_fee().await.unwrap();
+        assert_eq!(
+            app.state
+                .get_account_balance(bridge_address, asset_id)
+                .await
+                .unwrap(),
+            10u128.pow(19) - amount,
+        );
+        assert_eq!(
+            app.state
+                .get_account_balance(carol_address, asset_id)
+                .await
+                .unwrap(),
+            10u128.pow(19) + amount,
+        );
+        assert_eq!(
+            app.state
+                .get_account_balance(alice_address, asset_id)
+                .await
+                .unwrap(),
+            10u128.pow(19) - transfer_fee,
+        );
+    }
+
+    #[tokio::test]
+    async fn app_deliver_tx_bridge_unlock_action_invalid_withdrawer() {
+        use crate::bridge::state_ext::StateWriteExt as _;
+
+        let mut app = initialize_app(None, vec![]).await;
+
+        // Bob's account is a bridge account with Carol as the withdrawer
+        let (alice_signing_key, _) = get_alice_signing_key_and_address();
+        let bridge_address = address_from_hex_string(BOB_ADDRESS);
+        let carol_address = address_from_hex_string(CAROL_ADDRESS);
+        let asset_id = get_native_asset().id();
+
+        let mut state_tx = StateDelta::new(app.state.clone());
+        state_tx
+            .put_bridge_account_rollup_id(
+                &bridge_address,
+                &RollupId::from_unhashed_bytes(b"testchainid"),
+            )
+            .unwrap();
+        state_tx.put_bridge_account_asset_id(&bridge_address, &asset_id);
+        state_tx
+            .put_bridge_account_withdrawer_address(&bridge_address, &carol_address)
+            .unwrap();
+        app.apply(state_tx);
+
+        let action = BridgeUnlockAction {
+            to: carol_address,
+            amount: 100,
+            fee_asset_id: asset_id,
+            memo: vec![],
+            bridge_address,
+        };
+        let tx = UnsignedTransaction {
+            nonce: 0,
+            actions: vec![action.into()],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
+        };
+
+        let res = app
+            .deliver_tx(tx.into_signed(&alice_signing_key))
+            .await
+            .unwrap_err()
+            .root_cause()
+            .to_string();
+        assert!(res.contains("signer is not the authorized withdrawer for the bridge account"));
+    }
+
+    #[tokio::test]
+    async fn app_deliver_tx_transfer_from_bridge_account_fails() {
+        use crate::bridge::state_ext::StateWriteExt as _;
+
+        let mut app = initialize_app(None, vec![]).await;
+
+        let (alice_signing_key, alice_address) = get_alice_signing_key_and_address();
+        let mut state_tx = StateDelta::new(app.state.clone());
+        state_tx
+            .put_bridge_account_rollup_id(
+                &alice_address,
+                &RollupId::from_unhashed_bytes(b"testchainid"),
+            )
+            .unwrap();
+        app.apply(state_tx);
+
+        let tx = UnsignedTransaction {
+            nonce: 0,
+            actions: vec![
+                TransferAction {
+                    to: address_from_hex_string(BOB_ADDRESS),
+                    amount: 1,
+                    asset_id: get_native_asset().id(),
+                    fee_asset_id: get_native_asset().id(),
+                }
+                .into(),
+            ],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
+        };
+
+        let res = app
+            .deliver_tx(tx.into_signed(&alice_signing_key))
+            .await
+            .unwrap_err()
+            .root_cause()
+            .to_string();
+        assert!(res.contains("cannot transfer out of bridge accounts"));
+    }
+
+    #[tokio::test]
+    async fn app_deliver_tx_transfer_to_bridge_account_fails() {
+        use crate::bridge::state_ext::StateWriteExt as _;
+
+        let mut app = initialize_app(None, vec![]).await;
+
+        let (alice_signing_key, _) = get_alice_signing_key_and_address();
+        let bridge_address = address_from_hex_string(BOB_ADDRESS);
+        let mut state_tx = StateDelta::new(app.state.clone());
+        state_tx
+            .put_bridge_account_rollup_id(
+                &bridge_address,
+                &RollupId::from_unhashed_bytes(b"testchainid"),
+            )
+            .unwrap();
+        app.apply(state_tx);
+
+        // deposits must be made with a bridge lock, which is picked up by the rollup
+        let tx = UnsignedTransaction {
+            nonce: 0,
+            actions: vec![
+                TransferAction {
+                    to: bridge_address,
+                    amount: 1,
+                    asset_id: get_native_asset().id(),
+                    fee_asset_id: get_native_asset().id(),
+                }
+                .into(),
+            ],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
+        };
+
+        let res = app
+            .deliver_tx(tx.into_signed(&alice_signing_key))
+            .await
+            .unwrap_err()
+            .root_cause()
+            .to_string();
+        assert!(res.contains("cannot transfer to bridge accounts"));
+    }
+
+    #[tokio::test]
+    async fn app_deliver_tx_bridge_account_cannot_pay_fees() {
+        use crate::{
+            accounts::state_ext::StateWriteExt as _,
+            bridge::state_ext::StateWriteExt as _,
+        };
+
+        let mut app = initialize_app(None, vec![]).await;
+        let (alice_signing_key, alice_address) = get_alice_signing_key_and_address();
+        let bridge_signing_key = SigningKey::from([2; 32]);
+        let bridge_address = Address::from_verification_key(bridge_signing_key.verification_key());
+        let rollup_id = RollupId::from_unhashed_bytes("test-rollup");
+
+        let mut state_tx = StateDelta::new(app.state.clone());
+        state_tx
+            .put_bridge_account_rollup_id(&bridge_address, &rollup_id)
+            .unwrap();
+        state_tx
+            .put_account_balance(bridge_address, get_native_asset().id(), 10u128.pow(19))
+            .unwrap();
+        app.apply(state_tx);
+
+        // a bridge account cannot pay the fees of its own transactions
+        let res = app
+            .deliver_tx(sequence_tx(0, rollup_id).into_signed(&bridge_signing_key))
+            .await
+            .unwrap_err()
+            .root_cause()
+            .to_string();
+        assert!(res.contains("bridge accounts cannot pay transaction fees or tips"));
+
+        // nor sponsor the transactions of other accounts
+        let tx = UnsignedTransaction {
+            fee_payer: Some(FeePayer {
+                address: bridge_address,
+            }),
+            ..sequence_tx(0, rollup_id)
+        };
+        let res = app
+            .deliver_tx(
+                tx.into_signed(&alice_signing_key)
+                    .with_fee_payer_signature(&bridge_signing_key),
+            )
+            .await
+            .unwrap_err()
+            .root_cause()
+            .to_string();
+        assert!(res.contains("bridge accounts cannot pay transaction fees or tips"));
+
+        // but its transactions can be sponsored
+        let tx = UnsignedTransaction {
+            fee_payer: Some(FeePayer {
+                address: alice_address,
+            }),
+            ..sequence_tx(0, rollup_id)
+        };
+        app.deliver_tx(
+            tx.into_signed(&bridge_signing_key)
+                .with_fee_payer_signature(&alice_signing_key),
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            app.state
+                .get_account_balance(bridge_address, get_native_asset().id())
+                .await
+                .unwrap(),
+            10u128.pow(19),
+        );
+    }
+
+    #[cfg(feature = "mint")]
+    #[tokio::test]
+    async fn app_deliver_tx_mint() {
+        use crate::mint::state_ext::StateReadExt as _;
+
+        let (alice_signing_key, alice_address) = get_alice_signing_key_and_address();
+
+        let genesis_state = GenesisState {
+            accounts: default_genesis_accounts(),
+            authority_sudo_address: alice_address,
+            ibc_sudo_address: [0u8; 20].into(),
+            ibc_relayer_addresses: vec![],
+            native_asset_base_denomination: DEFAULT_NATIVE_ASSET_DENOM.to_string(),
+            fees: default_fees(),
+            allowed_fee_asset_denominations: vec![DEFAULT_NATIVE_ASSET_DENOM.to_string()],
+            ibc_assets: vec![],
+            asset_balances: vec![],
+            asset_metadata: vec![],
+            ibc_params: IbcParameters::default(),
+            validators: vec![],
+            blockspace: BlockspaceQuotas::default(),
+            mint_caps: MintCaps {
+                max_per_block: 333_333,
+                max_total: 333_333,
+            },
+            minted_supply: 0,
+        };
+        let mut app = initialize_app(Some(genesis_state), vec![]).await;
+
+        let bob_address = address_from_hex_string(BOB_ADDRESS);
+        let value = 333_333;
+        let tx = UnsignedTransaction {
+            nonce: 0,
+            actions: vec![
+                MintAction {
+                    to: bob_address,
+                    amount: value,
+                }
+                .into(),
+            ],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
+        };
+
+        let signed_tx = tx.into_signed(&alice_signing_key);
+        app.deliver_tx(signed_tx).await.unwrap();
+
+        assert_eq!(
+            app.state
+                .get_account_balance(bob_address, get_native_asset().id())
+                .await
+                .unwrap(),
+            value + 10u128.pow(19)
+        );
+        assert_eq!(
+            app.state
+                .get_total_supply(get_native_asset().id())
+                .await
+                .unwrap(),
+            value + 3 * 10u128.pow(19)
+        );
+        assert_eq!(app.state.get_account_nonce(bob_address).await.unwrap(), 0);
+        assert_eq!(app.state.get_account_nonce(alice_address).await.unwrap(), 1);
+        assert_eq!(app.state.get_minted_supply().await.unwrap(), value);
+    }
+
+    #[cfg(feature = "mint")]
+    #[tokio::test]
+    async fn app_deliver_tx_mint_without_caps_fails() {
+        let (alice_signing_key, alice_address) = get_alice_signing_key_and_address();
+
+        // caps which are not set at genesis are zero
+        let genesis_state = GenesisState {
+            accounts: default_genesis_accounts(),
+            authority_sudo_address: alice_address,
+            ibc_sudo_address: [0u8; 20].into(),
+            ibc_relayer_addresses: vec![],
+            native_asset_base_denomination: DEFAULT_NATIVE_ASSET_DENOM.to_string(),
+            fees: default_fees(),
+            allowed_fee_asset_denominations: vec![DEFAULT_NATIVE_ASSET_DENOM.to_string()],
+            ibc_assets: vec![],
+            asset_balances: vec![],
+            asset_metadata: vec![],
+            ibc_params: IbcParameters::default(),
+            validators: vec![],
+            blockspace: BlockspaceQuotas::default(),
+            mint_caps: MintCaps::default(),
+            minted_supply: 0,
+        };
+        let mut app = initialize_app(Some(genesis_state), vec![]).await;
+
+        let tx = UnsignedTransaction {
+            nonce: 0,
+            actions: vec![
+                MintAction {
+                    to: address_from_hex_string(BOB_ADDRESS),
+                    amount: 1,
+                }
+                .into(),
+            ],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
+        };
+
+        let res = app
+            .deliver_tx(tx.into_signed(&alice_signing_key))
+            .await
+            .unwrap_err()
+            .root_cause()
+            .to_string();
+        assert!(res.contains("exceeds the per-block mint cap of 0"));
+    }
+
+    #[cfg(feature = "mint")]
+    #[tokio::test]
+    async fn app_deliver_tx_mint_caps() {
+        use crate::mint::state_ext::StateReadExt as _;
+
+        let (alice_signing_key, alice_address) = get_alice_signing_key_and_address();
+
+        let genesis_state = GenesisState {
+            accounts: default_genesis_accounts(),
+            authority_sudo_address: alice_address,
+            ibc_sudo_address: [0u8; 20].into(),
+            ibc_relayer_addresses: vec![],
+            native_asset_base_denomination: DEFAULT_NATIVE_ASSET_DENOM.to_string(),
+            fees: default_fees(),
+            allowed_fee_asset_denominations: vec![DEFAULT_NATIVE_ASSET_DENOM.to_string()],
+            ibc_assets: vec![],
+            asset_balances: vec![],
+            asset_metadata: vec![],
+            ibc_params: IbcParameters::default(),
+            validators: vec![],
+            blockspace: BlockspaceQuotas::default(),
+            mint_caps: MintCaps {
+                max_per_block: 100,
+                max_total: 150,
+            },
+            minted_supply: 0,
+        };
+        let mut app = initialize_app(Some(genesis_state), vec![]).await;
+
+        let bob_address = address_from_hex_string(BOB_ADDRESS);
+        let mint_tx = |nonce, amounts: &[u128]| UnsignedTransaction {
+            nonce,
+            actions: amounts
+                .iter()
+                .map(|&amount| {
+                    MintAction {
+                        to: bob_address,
+                        amount,
+                    }
+                    .into()
+                })
+                .collect(),
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
+        };
+
+        app.deliver_tx(mint_tx(0, &[100]).into_signed(&alice_signing_key))
+            .await
+            .unwrap();
+
+        // the per-block cap is reached
+        let res = app
+            .deliver_tx(mint_tx(1, &[1]).into_signed(&alice_signing_key))
+            .await
+            .unwrap_err()
+            .root_cause()
+            .to_string();
+        assert!(res.contains("exceeds the per-block mint cap of 100"));
+
+        // the total cap is counted across blocks, also for several mints in one transaction
+        let mut state_tx = StateDelta::new(app.state.clone());
+        state_tx.put_block_height(1);
+        app.apply(state_tx);
+        let res = app
+            .deliver_tx(mint_tx(1, &[30, 30]).into_signed(&alice_signing_key))
+            .await
+            .unwrap_err()
+            .root_cause()
+            .to_string();
+        assert!(res.contains("exceeds the total mint cap of 150"));
+
+        app.deliver_tx(mint_tx(1, &[50]).into_signed(&alice_signing_key))
+            .await
+            .unwrap();
+        assert_eq!(app.state.get_minted_supply().await.unwrap(), 150);
+        assert_eq!(
+            app.state
+                .get_account_balance(bob_address, get_native_asset().id())
+                .await
+                .unwrap(),
+            150 + 10u128.pow(19)
+        );
+    }
+
+    #[cfg(feature = "mint")]
+    #[tokio::test]
+    async fn app_deliver_tx_mint_per_block_cap_is_per_block() {
+        use crate::mint::state_ext::StateReadExt as _;
+
+        let (alice_signing_key, alice_address) = get_alice_signing_key_and_address();
+
+        let genesis_state = GenesisState {
+            accounts: default_genesis_accounts(),
+            authority_sudo_address: alice_address,
+            ibc_sudo_address: [0u8; 20].into(),
+            ibc_relayer_addresses: vec![],
+            native_asset_base_denomination: DEFAULT_NATIVE_ASSET_DENOM.to_string(),
+            fees: default_fees(),
+            allowed_fee_asset_denominations: vec![DEFAULT_NATIVE_ASSET_DENOM.to_string()],
+            ibc_assets: vec![],
+            asset_balances: vec![],
+            asset_metadata: vec![],
+            ibc_params: IbcParameters::default(),
+            validators: vec![],
+            blockspace: BlockspaceQuotas::default(),
+            mint_caps: MintCaps {
+                max_per_block: 100,
+                max_total: 1_000,
+            },
+            minted_supply: 0,
+        };
+        let mut app = initialize_app(Some(genesis_state), vec![]).await;
+
+        let bob_address = address_from_hex_string(BOB_ADDRESS);
+        let mint_tx = |nonce, amount| UnsignedTransaction {
+            nonce,
+            actions: vec![
+                MintAction {
+                    to: bob_address,
+                    amount,
+                }
+                .into(),
+            ],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
+        };
+
+        // a second mint in the same block counts towards the same per-block cap
+        let height = app.state.get_block_height().await.unwrap();
+        app.deliver_tx(mint_tx(0, 40).into_signed(&alice_signing_key))
+            .await
+            .unwrap();
+        app.deliver_tx(mint_tx(1, 60).into_signed(&alice_signing_key))
+            .await
+            .unwrap();
+        assert_eq!(app.state.get_block_minted(height).await.unwrap(), 100);
+        let res = app
+            .deliver_tx(mint_tx(2, 1).into_signed(&alice_signing_key))
+            .await
+            .unwrap_err()
+            .root_cause()
+            .to_string();
+        assert!(res.contains("exceeds the per-block mint cap of 100"));
+
+        // the next block can mint up to the per-block cap again
+        let mut state_tx = StateDelta::new(app.state.clone());
+        state_tx.put_block_height(height + 1);
+        app.apply(state_tx);
+        assert_eq!(app.state.get_block_minted(height + 1).await.unwrap(), 0);
+        app.deliver_tx(mint_tx(2, 100).into_signed(&alice_signing_key))
+            .await
+            .unwrap();
+        assert_eq!(app.state.get_block_minted(height + 1).await.unwrap(), 100);
+        assert_eq!(app.state.get_minted_supply().await.unwrap(), 200);
+
+        // the amount minted in the earlier block is no longer known
+        let res = app
+            .state
+            .get_block_minted(height)
+            .await
+            .unwrap_err()
+            .to_string();
+        assert!(res.contains("is no longer known"));
+    }
+
+    #[tokio::test]
+    async fn app_end_block_validator_updates() {
+        use tendermint::validator;
+
+        let pubkey_a = tendermint::public_key::PublicKey::from_raw_ed25519(&[1; 32]).unwrap();
+        let pubkey_b = tendermint::public_key::PublicKey::from_raw_ed25519(&[2; 32]).unwrap();
+        let pubkey_c = tendermint::public_key::PublicKey::from_raw_ed25519(&[3; 32]).unwrap();
+
+        let initial_validator_set = vec![
+            validator::Update {
+                pub_key: pubkey_a,
+                power: 100u32.into(),
+            },
+            validator::Update {
+                pub_key: pubkey_b,
+                power: 1u32.into(),
+            },
+        ];
+
+        let mut app = initialize_app(None, initial_validator_set).await;
+        app.current_proposer = Some(account::Id::try_from([0u8; 20].to_vec()).unwrap());
+        app.current_header = Some(default_header());
+
+        let validator_updates = vec![
+            validator::Update {
+                pub_key: pubkey_a,
+                power: 0u32.into(),
+            },
+            validator::Update {
+                pub_key: pubkey_b,
+                power: 100u32.into(),
+            },
+            validator::Update {
+                pub_key: pubkey_c,
+                power: 100u32.into(),
+            },
+        ];
+
+        let mut state_tx = StateDelta::new(app.state.clone());
+        state_tx
+            .put_validator_updates(ValidatorSet::new_from_updates(validator_updates.clone()))
+            .unwrap();
+        app.apply(state_tx);
+
+        let resp = app
+            .end_block(&abci::request::EndBlock {
+                height: 1u32.into(),
+            })
+            .await
+            .unwrap();
+        // we only assert length here as the ordering of the updates is not guaranteed
+        // and validator::Update does not implement Ord
+        assert_eq!(resp.validator_updates.len(), validator_updates.len());
+
+        // validator with pubkey_a should be removed (power set to 0)
+        // validator with pubkey_b should be updated
+        // validator with pubkey_c should be added
+        let validator_set = app.state.get_validator_set().await.unwrap();
+        assert_eq!(validator_set.len(), 2);
+        let validator_b = validator_set.get(&pubkey_b.into()).unwrap();
+        assert_eq!(validator_b.pub_key, pubkey_b);
+        assert_eq!(validator_b.power, 100u32.into());
+        let validator_c = validator_set.get(&pubkey_c.into()).unwrap();
+        assert_eq!(validator_c.pub_key, pubkey_c);
+        assert_eq!(validator_c.power, 100u32.into());
+        assert_eq!(app.state.get_validator_updates().await.unwrap().len(), 0);
+    }
+
+    #[tokio::test]
+    async fn app_deliver_tx_invalid_nonce() {
+        let mut app = initialize_app(None, vec![]).await;
+
+        let (alice_signing_key, alice_address) = get_alice_signing_key_and_address();
+
+        // create tx with invalid nonce 1
+        let data = b"hello world".to_vec();
+        let tx = UnsignedTransaction {
+            nonce: 1,
+            actions: vec![
+                SequenceAction {
+                    rollup_id: RollupId::from_unhashed_bytes(b"testchainid"),
+                    data,
+                    fee_asset_id: get_native_asset().id(),
+                }
+                .into(),
+            ],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
+        };
+
+        let signed_tx = tx.into_signed(&alice_signing_key);
+        let response = app.deliver_tx(signed_tx).await;
+
+        // check that tx was not executed by checking nonce and balance are unchanged
+        assert_eq!(app.state.get_account_nonce(alice_address).await.unwrap(), 0);
+        assert_eq!(
+            app.state
+                .get_account_balance(alice_address, get_native_asset().id())
+                .await
+                .unwrap(),
+            10u128.pow(19),
+        );
+
+        assert_eq!(
+            response
+                .unwrap_err()
+                .downcast_ref::<InvalidNonce>()
+                .map(|nonce_err| nonce_err.0)
+                .unwrap(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn app_commit() {
+        let genesis_state = GenesisState {
+            accounts: default_genesis_accounts(),
+            authority_sudo_address: Address::from([0; 20]),
+            ibc_sudo_address: Address::from([0; 20]),
+            ibc_relayer_addresses: vec![],
+            native_asset_base_denomination: DEFAULT_NATIVE_ASSET_DENOM.to_string(),
+            fees: default_fees(),
+            allowed_fee_asset_denominations: vec![DEFAULT_NATIVE_ASSET_DENOM.to_string()],
+            ibc_assets: vec![],
+            asset_balances: vec![],
+            asset_metadata: vec![],
+            ibc_params: IbcParameters::default(),
+            validators: vec![],
+            blockspace: BlockspaceQuotas::default(),
+            mint_caps: MintCaps::default(),
+            minted_supply: 0,
+        };
+
+        let (mut app, storage) = initialize_app_with_storage(Some(genesis_state), vec![]).await;
+        assert_eq!(app.state.get_block_height().await.unwrap(), 0);
+
+        let native_asset = get_native_asset().id();
+        for Account {
+            address,
+            balance,
+            ..
+        } in default_genesis_accounts()
+        {
+            assert_eq!(
+                balance,
+                app.state
+                    .get_account_balance(address, native_asset)
+                    .await
+                    .unwrap()
+            );
+        }
+
+        // commit should write the changes to the underlying storage
+        app.commit(storage.clone()).await;
+        let snapshot = storage.latest_snapshot();
+        assert_eq!(snapshot.get_block_height().await.unwrap(), 0);
+        for Account {
+            address,
+            balance,
+            ..
+        } in default_genesis_accounts()
+        {
+            assert_eq!(
+                snapshot
+                    .get_account_balance(address, native_asset)
+                    .await
+                    .unwrap(),
+                balance
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn app_transfer_block_fees_to_proposer() {
+        let (mut app, storage) = initialize_app_with_storage(None, vec![]).await;
+
+        let mut begin_block = abci::request::BeginBlock {
+            header: default_header(),
+            hash: Hash::default(),
+            last_commit_info: CommitInfo {
+                votes: vec![],
+                round: Round::default(),
+            },
+            byzantine_validators: vec![],
+        };
+        begin_block.header.height = Height::try_from(1u8).unwrap();
+        let proposer_address =
+            Address::try_from_slice(begin_block.header.proposer_address.as_bytes()).unwrap();
+
+        app.begin_block(&begin_block, storage).await.unwrap();
+        assert_eq!(app.state.get_block_height().await.unwrap(), 1);
+        assert_eq!(
+            app.state.get_block_timestamp().await.unwrap(),
+            begin_block.header.time
+        );
+        assert_eq!(
+            app.current_proposer.unwrap(),
+            begin_block.header.proposer_address
+        );
+
+        let (alice_signing_key, _) = get_alice_signing_key_and_address();
+        let native_asset = get_native_asset().id();
+
+        // transfer funds from Alice to Bob; use native token for fee payment
+        let bob_address = address_from_hex_string(BOB_ADDRESS);
+        let amount = 333_333;
+        let tx = UnsignedTransaction {
+            nonce: 0,
+            actions: vec![
+                TransferAction {
+                    to: bob_address,
+                    amount,
+                    asset_id: native_asset,
+                    fee_asset_id: get_native_asset().id(),
+                }
+                .into(),
+            ],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
+        };
+
+        let signed_tx = tx.into_signed(&alice_signing_key);
+        app.deliver_tx(signed_tx).await.unwrap();
+
+        app.end_block(&abci::request::EndBlock {
+            height: 1u32.into(),
+        })
+        .await
+        .unwrap();
+
+        // assert that transaction fees were transferred to the block proposer
+        let transfer_fee = app.state.get_transfer_base_fee().await.unwrap();
+        assert_eq!(
+            app.state
+                .get_account_balance(proposer_address, native_asset)
+                .await
+                .unwrap(),
+            transfer_fee,
+        );
+        assert_eq!(app.state.get_block_fees().await.unwrap().len(), 0);
+    }
+
+    #[tokio::test]
+    async fn app_deliver_tx_emits_typed_events() {
+        use astria_core::sequencer::v1alpha1::event::{
+            Event,
+            FeePaymentEvent,
+            SequenceEvent,
+            TransferEvent,
+        };
+
+        let mut app = initialize_app(None, vec![]).await;
+        let (alice_signing_key, alice_address) = get_alice_signing_key_and_address();
+        let native_asset = get_native_asset().id();
+        let rollup_id = RollupId::from_unhashed_bytes(b"testchainid");
+
+        let tx = UnsignedTransaction {
+            nonce: 0,
+            actions: vec![
+                TransferAction {
+                    to: address_from_hex_string(BOB_ADDRESS),
+                    amount: 333_333,
+                    asset_id: native_asset,
+                    fee_asset_id: native_asset,
+                }
+                .into(),
+                SequenceAction {
+                    rollup_id,
+                    data: b"hello world".to_vec(),
+                    fee_asset_id: native_asset,
+                }
+                .into(),
+            ],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: Some(5),
+            fee_payer: None,
+        };
+        let signed_tx = tx.into_signed(&alice_signing_key);
+        let events = app.deliver_tx(signed_tx).await.unwrap();
+
+        let transfer_fee = app.state.get_transfer_base_fee().await.unwrap();
+        let sequence_fee = calculate_fee_from_state(b"hello world", &app.state)
+            .await
+            .unwrap();
+        let events: Vec<Event> = events
+            .iter()
+            .map(|event| Event::try_from_abci(event).unwrap())
+            .collect();
+        assert_eq!(
+            events,
+            vec![
+                FeePaymentEvent {
+                    from: alice_address,
+                    asset_id: native_asset,
+                    amount: transfer_fee + sequence_fee + 5,
+                }
+                .into(),
+                TransferEvent {
+                    from: alice_address,
+                    to: address_from_hex_string(BOB_ADDRESS),
+                    asset_id: native_asset,
+                    amount: 333_333,
+                }
+                .into(),
+                SequenceEvent {
+                    from: alice_address,
+                    rollup_id,
+                    data_len: 11,
+                }
+                .into(),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn app_transfer_tip_to_proposer() {
+        let (mut app, storage) = initialize_app_with_storage(None, vec![]).await;
+
+        let mut begin_block = abci::request::BeginBlock {
+            header: default_header(),
+            hash: Hash::default(),
+            last_commit_info: CommitInfo {
+                votes: vec![],
+                round: Round::default(),
+            },
+            byzantine_validators: vec![],
+        };
+        begin_block.header.height = Height::try_from(1u8).unwrap();
+        let proposer_address =
+            Address::try_from_slice(begin_block.header.proposer_address.as_bytes()).unwrap();
+        app.begin_block(&begin_block, storage).await.unwrap();
+
+        let (alice_signing_key, alice_address) = get_alice_signing_key_and_address();
+        let native_asset = get_native_asset().id();
+        let alice_before_tx = app
+            .state
+            .get_account_balance(alice_address, native_asset)
+            .await
+            .unwrap();
+
+        let amount = 333_333;
+        let tip = 1_000;
+        let tx = UnsignedTransaction {
+            nonce: 0,
+            actions: vec![
+                TransferAction {
+                    to: address_from_hex_string(BOB_ADDRESS),
+                    amount,
+                    asset_id: native_asset,
+                    fee_asset_id: native_asset,
+                }
+                .into(),
+            ],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: Some(tip),
+            fee_payer: None,
+        };
+
+        let signed_tx = tx.into_signed(&alice_signing_key);
+        app.deliver_tx(signed_tx).await.unwrap();
+
+        app.end_block(&abci::request::EndBlock {
+            height: 1u32.into(),
         })
         .await
         .unwrap();
 
-        // assert that transaction fees were transferred to the block proposer
+        // the tip is paid by the signer and transferred to the block proposer with the fees
+        let transfer_fee = app.state.get_transfer_base_fee().await.unwrap();
+        assert_eq!(
+            app.state
+                .get_account_balance(alice_address, native_asset)
+                .await
+                .unwrap(),
+            alice_before_tx - amount - transfer_fee - tip,
+        );
+        assert_eq!(
+            app.state
+                .get_account_balance(proposer_address, native_asset)
+                .await
+                .unwrap(),
+            transfer_fee + tip,
+        );
+    }
+
+    #[tokio::test]
+    async fn app_prepare_proposal_includes_only_contiguous_nonces() {
+        let (mut app, storage) = initialize_app_with_storage(None, vec![]).await;
+        let (alice_signing_key, _) = get_alice_signing_key_and_address();
+
+        let mut expected_txs = vec![];
+        for nonce in [0, 2] {
+            let tx = UnsignedTransaction {
+                nonce,
+                actions: vec![
+                    SequenceAction {
+                        rollup_id: RollupId::from_unhashed_bytes(b"testchainid"),
+                        data: b"hello world".to_vec(),
+                        fee_asset_id: get_native_asset().id(),
+                    }
+                    .into(),
+                ],
+                valid_until_height: None,
+                valid_until_timestamp: None,
+                tip: None,
+                fee_payer: None,
+            };
+            let signed_tx = tx.into_signed(&alice_signing_key);
+            let tx_bytes: bytes::Bytes = signed_tx.to_raw().encode_to_vec().into();
+            if nonce == 0 {
+                expected_txs.push(tx_bytes.clone());
+            }
+            app.mempool.insert(signed_tx, tx_bytes, 0, 0).await.unwrap();
+        }
+
+        let prepare_proposal = abci::request::PrepareProposal {
+            txs: vec![],
+            max_tx_bytes: 1_000_000,
+            local_last_commit: None,
+            misbehavior: vec![],
+            height: 1u32.into(),
+            time: Time::now(),
+            next_validators_hash: Hash::default(),
+            proposer_address: account::Id::new([0; 20]),
+        };
+        let response = app.prepare_proposal(prepare_proposal, storage).await;
+
+        // the first two transactions are the commitments
+        assert_eq!(response.txs.len(), 3);
+        assert_eq!(response.txs[2..], expected_txs[..]);
+    }
+
+    /// Returns the genesis state with an additional funded account for `signing_key`
+    /// and the given blockspace quotas.
+    fn genesis_state_with_blockspace(
+        signing_key: &SigningKey,
+        blockspace: BlockspaceQuotas,
+    ) -> GenesisState {
+        let mut accounts = default_genesis_accounts();
+        accounts.push(Account {
+            address: Address::from_verification_key(signing_key.verification_key()),
+            balance: 10u128.pow(19),
+            nonce: 0,
+        });
+        GenesisState {
+            accounts,
+            authority_sudo_address: Address::from([0; 20]),
+            ibc_sudo_address: Address::from([0; 20]),
+            ibc_relayer_addresses: vec![],
+            native_asset_base_denomination: DEFAULT_NATIVE_ASSET_DENOM.to_string(),
+            fees: default_fees(),
+            allowed_fee_asset_denominations: vec![DEFAULT_NATIVE_ASSET_DENOM.to_string()],
+            ibc_assets: vec![],
+            asset_balances: vec![],
+            asset_metadata: vec![],
+            ibc_params: IbcParameters::default(),
+            validators: vec![],
+            blockspace,
+            mint_caps: MintCaps::default(),
+            minted_supply: 0,
+        }
+    }
+
+    fn signed_sequence_tx(
+        signing_key: &SigningKey,
+        nonce: u32,
+        rollup_id: RollupId,
+        data_len: usize,
+    ) -> (SignedTransaction, bytes::Bytes) {
+        signed_sequence_tx_with_tip(signing_key, nonce, rollup_id, data_len, None)
+    }
+
+    fn signed_sequence_tx_with_tip(
+        signing_key: &SigningKey,
+        nonce: u32,
+        rollup_id: RollupId,
+        data_len: usize,
+        tip: Option<u128>,
+    ) -> (SignedTransaction, bytes::Bytes) {
+        let tx = UnsignedTransaction {
+            nonce,
+            actions: vec![
+                SequenceAction {
+                    rollup_id,
+                    data: vec![0; data_len],
+                    fee_asset_id: get_native_asset().id(),
+                }
+                .into(),
+            ],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip,
+            fee_payer: None,
+        };
+        let signed_tx = tx.into_signed(signing_key);
+        let tx_bytes = signed_tx.to_raw().encode_to_vec().into();
+        (signed_tx, tx_bytes)
+    }
+
+    fn new_prepare_proposal() -> abci::request::PrepareProposal {
+        abci::request::PrepareProposal {
+            txs: vec![],
+            max_tx_bytes: 1_000_000,
+            local_last_commit: None,
+            misbehavior: vec![],
+            height: 1u32.into(),
+            time: Time::now(),
+            next_validators_hash: Hash::default(),
+            proposer_address: account::Id::new([0; 20]),
+        }
+    }
+
+    #[tokio::test]
+    async fn app_prepare_proposal_skips_transactions_exceeding_rollup_quota() {
+        let (alice_signing_key, _) = get_alice_signing_key_and_address();
+        let other_signing_key = SigningKey::from([1; 32]);
+        let busy_rollup_id = RollupId::from_unhashed_bytes(b"busy");
+        let quiet_rollup_id = RollupId::from_unhashed_bytes(b"quiet");
+        let genesis_state = genesis_state_with_blockspace(
+            &other_signing_key,
+            BlockspaceQuotas {
+                default_rollup_quota: None,
+                rollup_quotas: vec![RollupQuota {
+                    rollup_id: busy_rollup_id,
+                    max_sequence_data_bytes: 20,
+                }],
+            },
+        );
+        let (mut app, storage) = initialize_app_with_storage(Some(genesis_state), vec![]).await;
+
+        let (first_busy_tx, first_busy_tx_bytes) =
+            signed_sequence_tx(&alice_signing_key, 0, busy_rollup_id, 15);
+        let (second_busy_tx, second_busy_tx_bytes) =
+            signed_sequence_tx(&alice_signing_key, 1, busy_rollup_id, 15);
+        let (quiet_tx, quiet_tx_bytes) =
+            signed_sequence_tx(&other_signing_key, 0, quiet_rollup_id, 15);
+        for (signed_tx, tx_bytes) in [
+            (first_busy_tx, first_busy_tx_bytes.clone()),
+            (second_busy_tx, second_busy_tx_bytes),
+            (quiet_tx, quiet_tx_bytes.clone()),
+        ] {
+            app.mempool.insert(signed_tx, tx_bytes, 0, 0).await.unwrap();
+        }
+
+        let response = app.prepare_proposal(new_prepare_proposal(), storage).await;
+
+        // the first two transactions are the commitments, and the second transaction
+        // for the busy rollup would exceed its quota
+        assert_eq!(response.txs[2..], [first_busy_tx_bytes, quiet_tx_bytes]);
+    }
+
+    #[tokio::test]
+    async fn app_prepare_proposal_orders_transactions_by_fair_share() {
+        let (alice_signing_key, _) = get_alice_signing_key_and_address();
+        let other_signing_key = SigningKey::from([1; 32]);
+        let busy_rollup_id = RollupId::from_unhashed_bytes(b"busy");
+        let quiet_rollup_id = RollupId::from_unhashed_bytes(b"quiet");
+        let genesis_state =
+            genesis_state_with_blockspace(&other_signing_key, BlockspaceQuotas::default());
+        let (mut app, storage) = initialize_app_with_storage(Some(genesis_state), vec![]).await;
+
+        let mut busy_txs_bytes = vec![];
+        for nonce in 0..3 {
+            let (signed_tx, tx_bytes) =
+                signed_sequence_tx(&alice_signing_key, nonce, busy_rollup_id, 100);
+            busy_txs_bytes.push(tx_bytes.clone());
+            app.mempool.insert(signed_tx, tx_bytes, 0, 0).await.unwrap();
+        }
+        let (quiet_tx, quiet_tx_bytes) =
+            signed_sequence_tx(&other_signing_key, 0, quiet_rollup_id, 100);
+        app.mempool
+            .insert(quiet_tx, quiet_tx_bytes.clone(), 0, 0)
+            .await
+            .unwrap();
+
+        let response = app.prepare_proposal(new_prepare_proposal(), storage).await;
+
+        // the quiet rollup's transaction is not starved by the busy rollup's
+        // earlier transactions
+        assert_eq!(
+            response.txs[2..],
+            [
+                busy_txs_bytes[0].clone(),
+                quiet_tx_bytes,
+                busy_txs_bytes[1].clone(),
+                busy_txs_bytes[2].clone(),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn app_prepare_proposal_includes_transactions_sponsored_by_same_fee_payer() {
+        let (alice_signing_key, alice_address) = get_alice_signing_key_and_address();
+        let rollup_id = RollupId::from_unhashed_bytes(b"testchainid");
+        let (mut app, storage) = initialize_app_with_storage(None, vec![]).await;
+
+        // two unfunded signers, each at nonce 0, are both sponsored by alice
+        let mut txs_bytes = vec![];
+        for signing_key in [SigningKey::from([2; 32]), SigningKey::from([3; 32])] {
+            let tx = UnsignedTransaction {
+                fee_payer: Some(FeePayer {
+                    address: alice_address,
+                }),
+                ..sequence_tx(0, rollup_id)
+            };
+            let signed_tx = tx
+                .into_signed(&signing_key)
+                .with_fee_payer_signature(&alice_signing_key);
+            let tx_bytes: bytes::Bytes = signed_tx.to_raw().encode_to_vec().into();
+            txs_bytes.push(tx_bytes.clone());
+            app.mempool.insert(signed_tx, tx_bytes, 0, 0).await.unwrap();
+        }
+
+        let response = app.prepare_proposal(new_prepare_proposal(), storage).await;
+
+        assert_eq!(response.txs.len(), 4);
+        for tx_bytes in &txs_bytes {
+            assert!(response.txs[2..].contains(tx_bytes));
+        }
+        let sequence_fee = calculate_fee_from_state(b"hello world", &app.state)
+            .await
+            .unwrap();
+        assert_eq!(
+            app.state
+                .get_account_balance(alice_address, get_native_asset().id())
+                .await
+                .unwrap(),
+            10u128.pow(19) - 2 * sequence_fee,
+        );
+    }
+
+    #[tokio::test]
+    async fn app_process_proposal_rejects_transactions_exceeding_rollup_quota() {
+        let signing_key = SigningKey::from([1; 32]);
+        let genesis_state = genesis_state_with_blockspace(
+            &signing_key,
+            BlockspaceQuotas {
+                default_rollup_quota: Some(10),
+                rollup_quotas: vec![],
+            },
+        );
+        let (mut app, storage) = initialize_app_with_storage(Some(genesis_state), vec![]).await;
+
+        let (signed_tx, tx_bytes) = signed_sequence_tx(
+            &signing_key,
+            0,
+            RollupId::from_unhashed_bytes(b"testchainid"),
+            15,
+        );
+        let process_proposal = abci::request::ProcessProposal {
+            txs: generate_sequence_actions_commitment(&[signed_tx])
+                .into_transactions(vec![tx_bytes]),
+            proposed_last_commit: None,
+            misbehavior: vec![],
+            hash: Hash::default(),
+            height: 1u32.into(),
+            next_validators_hash: Hash::default(),
+            time: Time::now(),
+            proposer_address: account::Id::new([0; 20]),
+        };
+
+        app.process_proposal(process_proposal, storage)
+            .await
+            .unwrap_err();
+    }
+
+    #[tokio::test]
+    async fn app_process_proposal_checks_tip_per_byte_order_across_signers() {
+        let (alice_signing_key, _) = get_alice_signing_key_and_address();
+        let other_signing_key = SigningKey::from([1; 32]);
+        let rollup_id = RollupId::from_unhashed_bytes(b"testchainid");
+        let genesis_state =
+            genesis_state_with_blockspace(&other_signing_key, BlockspaceQuotas::default());
+        let (mut app, storage) = initialize_app_with_storage(Some(genesis_state), vec![]).await;
+        let process_proposal = |txs: Vec<(SignedTransaction, bytes::Bytes)>| {
+            let (signed_txs, tx_bytes): (Vec<_>, Vec<_>) = txs.into_iter().unzip();
+            abci::request::ProcessProposal {
+                txs: generate_sequence_actions_commitment(&signed_txs).into_transactions(tx_bytes),
+                proposed_last_commit: None,
+                misbehavior: vec![],
+                hash: Hash::default(),
+                height: 1u32.into(),
+                next_validators_hash: Hash::default(),
+                time: Time::now(),
+                proposer_address: account::Id::new([0; 20]),
+            }
+        };
+
+        // the transactions of a signer stay in nonce order, whatever their tips
+        let low_tip_tx =
+            signed_sequence_tx_with_tip(&alice_signing_key, 0, rollup_id, 100, Some(10));
+        let high_tip_tx =
+            signed_sequence_tx_with_tip(&alice_signing_key, 1, rollup_id, 100, Some(1_000));
+        app.process_proposal(
+            process_proposal(vec![low_tip_tx.clone(), high_tip_tx]),
+            storage.clone(),
+        )
+        .await
+        .unwrap();
+
+        // but a proposer must not order a transaction ahead of a transaction of another
+        // signer paying a higher tip per byte
+        let high_tip_tx =
+            signed_sequence_tx_with_tip(&other_signing_key, 0, rollup_id, 100, Some(1_000));
+        let err = app
+            .process_proposal(process_proposal(vec![low_tip_tx, high_tip_tx]), storage)
+            .await
+            .unwrap_err();
+        assert!(err
+            .chain()
+            .any(|cause| cause.to_string() == "transactions are not in proposal order"));
+    }
+
+    #[tokio::test]
+    async fn app_prepare_proposal_orders_transactions_by_tip_per_byte() {
+        let (alice_signing_key, _) = get_alice_signing_key_and_address();
+        let other_signing_key = SigningKey::from([1; 32]);
+        let rollup_id = RollupId::from_unhashed_bytes(b"testchainid");
+        let genesis_state =
+            genesis_state_with_blockspace(&other_signing_key, BlockspaceQuotas::default());
+        let (mut app, storage) = initialize_app_with_storage(Some(genesis_state), vec![]).await;
+
+        // alice's high tip transaction has to wait for her earlier transaction without a tip
+        let (untipped_tx, untipped_tx_bytes) =
+            signed_sequence_tx_with_tip(&alice_signing_key, 0, rollup_id, 100, None);
+        let (high_tip_tx, high_tip_tx_bytes) =
+            signed_sequence_tx_with_tip(&alice_signing_key, 1, rollup_id, 100, Some(1_000));
+        let (low_tip_tx, low_tip_tx_bytes) =
+            signed_sequence_tx_with_tip(&other_signing_key, 0, rollup_id, 100, Some(10));
+        for (signed_tx, tx_bytes) in [
+            (untipped_tx, untipped_tx_bytes.clone()),
+            (high_tip_tx, high_tip_tx_bytes.clone()),
+            (low_tip_tx, low_tip_tx_bytes.clone()),
+        ] {
+            app.mempool.insert(signed_tx, tx_bytes, 0, 0).await.unwrap();
+        }
+
+        let response = app.prepare_proposal(new_prepare_proposal(), storage).await;
+
+        assert_eq!(
+            response.txs[2..],
+            [low_tip_tx_bytes, untipped_tx_bytes, high_tip_tx_bytes]
+        );
+    }
+
+    fn register_rollup_action(name: &str, owner: Address) -> RegisterRollupAction {
+        use astria_core::sequencer::v1alpha1::rollup::CelestiaNamespaceId;
+
+        RegisterRollupAction {
+            name: name.to_string(),
+            owner,
+            celestia_namespace: CelestiaNamespaceId::from_rollup_id(
+                &RollupId::from_unhashed_bytes(name),
+            ),
+            metadata: "test metadata".to_string(),
+            fee_asset_id: get_native_asset().id(),
+        }
+    }
+
+    #[tokio::test]
+    async fn app_deliver_tx_register_rollup() {
+        use astria_core::sequencer::v1alpha1::RollupRecord;
+
+        use crate::registry::state_ext::StateReadExt as _;
+
+        let mut app = initialize_app(None, vec![]).await;
+        let (alice_signing_key, alice_address) = get_alice_signing_key_and_address();
+
+        let action = register_rollup_action("test-rollup", alice_address);
+        let tx = UnsignedTransaction {
+            nonce: 0,
+            actions: vec![action.clone().into()],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
+        };
+        let events = app
+            .deliver_tx(tx.into_signed(&alice_signing_key))
+            .await
+            .unwrap();
+        assert!(events.contains(
+            &RegisterRollupEvent {
+                rollup_id: RollupId::from_unhashed_bytes("test-rollup"),
+                name: action.name.clone(),
+                owner: alice_address,
+            }
+            .into_abci()
+        ));
+
+        let transfer_fee = app.state.get_transfer_base_fee().await.unwrap();
+        assert_eq!(
+            app.state
+                .get_rollup_record(&RollupId::from_unhashed_bytes("test-rollup"))
+                .await
+                .unwrap(),
+            Some(RollupRecord {
+                name: action.name.clone(),
+                owner: alice_address,
+                celestia_namespace: action.celestia_namespace,
+                metadata: action.metadata.clone(),
+            })
+        );
+        assert_eq!(
+            app.state
+                .get_account_balance(alice_address, get_native_asset().id())
+                .await
+                .unwrap(),
+            10u128.pow(19) - transfer_fee,
+        );
+
+        // a name can only be registered once
+        let tx = UnsignedTransaction {
+            nonce: 1,
+            actions: vec![action.into()],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
+        };
+        let res = app
+            .deliver_tx(tx.into_signed(&alice_signing_key))
+            .await
+            .unwrap_err()
+            .root_cause()
+            .to_string();
+        assert!(res.contains("rollup is already registered"));
+    }
+
+    #[tokio::test]
+    async fn app_deliver_tx_rollup_owner_and_metadata_change() {
+        use crate::registry::state_ext::StateReadExt as _;
+
+        let mut app = initialize_app(None, vec![]).await;
+        let (alice_signing_key, alice_address) = get_alice_signing_key_and_address();
+        let new_owner_signing_key = SigningKey::from([1; 32]);
+        let new_owner = Address::from_verification_key(new_owner_signing_key.verification_key());
+        let rollup_id = RollupId::from_unhashed_bytes("test-rollup");
+
+        let tx = UnsignedTransaction {
+            nonce: 0,
+            actions: vec![
+                register_rollup_action("test-rollup", alice_address).into(),
+                RollupMetadataChangeAction {
+                    rollup_id,
+                    metadata: "new metadata".to_string(),
+                }
+                .into(),
+                RollupOwnerChangeAction {
+                    rollup_id,
+                    new_owner,
+                }
+                .into(),
+            ],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
+        };
+        app.deliver_tx(tx.into_signed(&alice_signing_key))
+            .await
+            .unwrap();
+
+        let record = app
+            .state
+            .get_rollup_record(&rollup_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(record.owner, new_owner);
+        assert_eq!(record.metadata, "new metadata");
+
+        // the previous owner can no longer change the rollup
+        let tx = UnsignedTransaction {
+            nonce: 1,
+            actions: vec![RollupMetadataChangeAction {
+                rollup_id,
+                metadata: "other metadata".to_string(),
+            }
+            .into()],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
+        };
+        let res = app
+            .deliver_tx(tx.into_signed(&alice_signing_key))
+            .await
+            .unwrap_err()
+            .root_cause()
+            .to_string();
+        assert!(res.contains("signer is not the rollup owner"));
+
+        let tx = UnsignedTransaction {
+            nonce: 0,
+            actions: vec![RollupMetadataChangeAction {
+                rollup_id,
+                metadata: "other metadata".to_string(),
+            }
+            .into()],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
+        };
+        app.deliver_tx(tx.into_signed(&new_owner_signing_key))
+            .await
+            .unwrap();
+        assert_eq!(
+            app.state
+                .get_rollup_record(&rollup_id)
+                .await
+                .unwrap()
+                .unwrap()
+                .metadata,
+            "other metadata"
+        );
+    }
+
+    /// Authorizes a session key for `rollup_id` on behalf of alice, returning the
+    /// session key's signing key and address.
+    async fn authorize_session_key(
+        app: &mut App,
+        rollup_id: RollupId,
+        fee_spend_limit: u128,
+    ) -> (SigningKey, Address) {
+        let (alice_signing_key, alice_address) = get_alice_signing_key_and_address();
+        let session_signing_key = SigningKey::from([1; 32]);
+        let session_address =
+            Address::from_verification_key(session_signing_key.verification_key());
+
+        let tx = UnsignedTransaction {
+            nonce: app.state.get_account_nonce(alice_address).await.unwrap(),
+            actions: vec![AuthorizeSessionKeyAction {
+                session_key: session_address,
+                rollup_ids: vec![rollup_id],
+                fee_spend_limit,
+                expiry_height: 100,
+                fee_asset_id: get_native_asset().id(),
+                session_key_signature: SessionKeySignature::new(
+                    &session_signing_key,
+                    alice_address,
+                ),
+            }
+            .into()],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
+        };
+        app.deliver_tx(tx.into_signed(&alice_signing_key))
+            .await
+            .unwrap();
+        (session_signing_key, session_address)
+    }
+
+    fn sequence_tx(nonce: u32, rollup_id: RollupId) -> UnsignedTransaction {
+        UnsignedTransaction {
+            nonce,
+            actions: vec![SequenceAction {
+                rollup_id,
+                data: b"hello world".to_vec(),
+                fee_asset_id: get_native_asset().id(),
+            }
+            .into()],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn app_deliver_tx_session_key_sequence() {
+        use crate::session::state_ext::StateReadExt as _;
+
+        let mut app = initialize_app(None, vec![]).await;
+        let (_, alice_address) = get_alice_signing_key_and_address();
+        let rollup_id = RollupId::from_unhashed_bytes("test-rollup");
+        let (session_signing_key, session_address) =
+            authorize_session_key(&mut app, rollup_id, 1_000_000).await;
+
+        let transfer_fee = app.state.get_transfer_base_fee().await.unwrap();
+        let sequence_fee = calculate_fee_from_state(b"hello world", &app.state)
+            .await
+            .unwrap();
+        app.deliver_tx(sequence_tx(0, rollup_id).into_signed(&session_signing_key))
+            .await
+            .unwrap();
+
+        // the session key's nonce is used, but the fees are paid by alice
+        assert_eq!(
+            app.state.get_account_nonce(session_address).await.unwrap(),
+            1
+        );
+        assert_eq!(app.state.get_account_nonce(alice_address).await.unwrap(), 1);
+        assert_eq!(
+            app.state
+                .get_account_balance(alice_address, get_native_asset().id())
+                .await
+                .unwrap(),
+            10u128.pow(19) - transfer_fee - sequence_fee,
+        );
+        let session_key = app
+            .state
+            .get_session_key(session_address)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(session_key.account, alice_address);
+        assert_eq!(session_key.fees_spent, sequence_fee);
+
+        // session keys can only submit sequence actions
+        let tx = UnsignedTransaction {
+            nonce: 1,
+            actions: vec![TransferAction {
+                to: session_address,
+                amount: 1,
+                asset_id: get_native_asset().id(),
+                fee_asset_id: get_native_asset().id(),
+            }
+            .into()],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
+        };
+        let res = app
+            .deliver_tx(tx.into_signed(&session_signing_key))
+            .await
+            .unwrap_err()
+            .root_cause()
+            .to_string();
+        assert!(res.contains("session keys can only submit sequence actions"));
+
+        // session keys can only submit sequence data for the authorized rollups
+        let res = app
+            .deliver_tx(
+                sequence_tx(1, RollupId::from_unhashed_bytes("other-rollup"))
+                    .into_signed(&session_signing_key),
+            )
+            .await
+            .unwrap_err()
+            .root_cause()
+            .to_string();
+        assert!(res.contains("session key is not authorized for rollup"));
+    }
+
+    #[tokio::test]
+    async fn app_deliver_tx_session_key_budget_and_expiry() {
+        let mut app = initialize_app(None, vec![]).await;
+        let rollup_id = RollupId::from_unhashed_bytes("test-rollup");
+        let sequence_fee = calculate_fee_from_state(b"hello world", &app.state)
+            .await
+            .unwrap();
+        let (session_signing_key, _) =
+            authorize_session_key(&mut app, rollup_id, sequence_fee).await;
+
+        app.deliver_tx(sequence_tx(0, rollup_id).into_signed(&session_signing_key))
+            .await
+            .unwrap();
+
+        // the budget of the session key is spent
+        let res = app
+            .deliver_tx(sequence_tx(1, rollup_id).into_signed(&session_signing_key))
+            .await
+            .unwrap_err()
+            .root_cause()
+            .to_string();
+        assert!(res.contains("exceed the remaining fee budget of the session key"));
+
+        // a new authorization resets the spent budget, but not past the expiry height
+        authorize_session_key(&mut app, rollup_id, sequence_fee).await;
+        let mut state_tx = StateDelta::new(app.state.clone());
+        state_tx.put_block_height(101);
+        app.apply(state_tx);
+        let res = app
+            .deliver_tx(sequence_tx(1, rollup_id).into_signed(&session_signing_key))
+            .await
+            .unwrap_err()
+            .root_cause()
+            .to_string();
+        assert!(res.contains("session key expired at height 100"));
+    }
+
+    #[tokio::test]
+    async fn app_deliver_tx_authorize_session_key_invalid() {
+        let mut app = initialize_app(None, vec![]).await;
+        let (alice_signing_key, alice_address) = get_alice_signing_key_and_address();
+        let rollup_id = RollupId::from_unhashed_bytes("test-rollup");
+        let authorize_tx =
+            |nonce, session_signing_key: &SigningKey, signed_account| UnsignedTransaction {
+                nonce,
+                actions: vec![AuthorizeSessionKeyAction {
+                    session_key: Address::from_verification_key(
+                        session_signing_key.verification_key(),
+                    ),
+                    rollup_ids: vec![rollup_id],
+                    fee_spend_limit: 1_000_000,
+                    expiry_height: 100,
+                    fee_asset_id: get_native_asset().id(),
+                    session_key_signature: SessionKeySignature::new(
+                        session_signing_key,
+                        signed_account,
+                    ),
+                }
+                .into()],
+                valid_until_height: None,
+                valid_until_timestamp: None,
+                tip: None,
+                fee_payer: None,
+            };
+
+        // the session key must have signed the address of the authorizing account
+        let session_signing_key = SigningKey::from([1; 32]);
+        let err = app
+            .deliver_tx(
+                authorize_tx(
+                    0,
+                    &session_signing_key,
+                    address_from_hex_string(BOB_ADDRESS),
+                )
+                .into_signed(&alice_signing_key),
+            )
+            .await
+            .unwrap_err();
+        assert!(err.chain().any(|cause| {
+            cause
+                .to_string()
+                .contains("session key did not sign the address of the authorizing account")
+        }));
+
+        // an address holding funds cannot be claimed as a session key, even with its
+        // holder's signature
+        let funded_signing_key = SigningKey::from([4; 32]);
+        let tx = UnsignedTransaction {
+            nonce: 0,
+            actions: vec![TransferAction {
+                to: Address::from_verification_key(funded_signing_key.verification_key()),
+                amount: 1,
+                asset_id: get_native_asset().id(),
+                fee_asset_id: get_native_asset().id(),
+            }
+            .into()],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
+        };
+        app.deliver_tx(tx.into_signed(&alice_signing_key))
+            .await
+            .unwrap();
+        let res = app
+            .deliver_tx(
+                authorize_tx(1, &funded_signing_key, alice_address).into_signed(&alice_signing_key),
+            )
+            .await
+            .unwrap_err()
+            .root_cause()
+            .to_string();
+        assert!(res.contains("session key must not hold any funds"));
+    }
+
+    #[tokio::test]
+    async fn app_deliver_tx_revoke_session_key() {
+        use crate::session::state_ext::StateReadExt as _;
+
+        let mut app = initialize_app(None, vec![]).await;
+        let (alice_signing_key, _) = get_alice_signing_key_and_address();
+        let rollup_id = RollupId::from_unhashed_bytes("test-rollup");
+        let (session_signing_key, session_address) =
+            authorize_session_key(&mut app, rollup_id, 1_000_000).await;
+
+        // only the authorizing account can revoke the session key
+        let bob_signing_key = SigningKey::from([2; 32]);
+        let tx = UnsignedTransaction {
+            nonce: 0,
+            actions: vec![RevokeSessionKeyAction {
+                session_key: session_address,
+            }
+            .into()],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
+        };
+        let res = app
+            .deliver_tx(tx.clone().into_signed(&bob_signing_key))
+            .await
+            .unwrap_err()
+            .root_cause()
+            .to_string();
+        assert!(res.contains("session key is not authorized by the signer"));
+
+        let tx = UnsignedTransaction {
+            nonce: 1,
+            ..tx
+        };
+        app.deliver_tx(tx.into_signed(&alice_signing_key))
+            .await
+            .unwrap();
+        assert!(app
+            .state
+            .get_session_key(session_address)
+            .await
+            .unwrap()
+            .is_none());
+
+        // the revoked session key pays for its own transactions again
+        let res = app
+            .deliver_tx(sequence_tx(0, rollup_id).into_signed(&session_signing_key))
+            .await
+            .unwrap_err()
+            .root_cause()
+            .to_string();
+        assert!(res.contains("insufficient funds"));
+    }
+
+    #[tokio::test]
+    async fn app_deliver_tx_with_fee_payer() {
+        let mut app = initialize_app(None, vec![]).await;
+        let (alice_signing_key, alice_address) = get_alice_signing_key_and_address();
+        let signing_key = SigningKey::from([2; 32]);
+        let address = Address::from_verification_key(signing_key.verification_key());
+        let rollup_id = RollupId::from_unhashed_bytes("test-rollup");
+
+        // the signer holds no funds, so alice pays the fees of its sequence action
+        let tx = UnsignedTransaction {
+            fee_payer: Some(FeePayer {
+                address: alice_address,
+            }),
+            ..sequence_tx(0, rollup_id)
+        };
+        app.deliver_tx(
+            tx.into_signed(&signing_key)
+                .with_fee_payer_signature(&alice_signing_key),
+        )
+        .await
+        .unwrap();
+
+        let sequence_fee = calculate_fee_from_state(b"hello world", &app.state)
+            .await
+            .unwrap();
+        assert_eq!(app.state.get_account_nonce(address).await.unwrap(), 1);
+        assert_eq!(app.state.get_account_nonce(alice_address).await.unwrap(), 0);
         assert_eq!(
             app.state
-                .get_account_balance(proposer_address, native_asset)
+                .get_account_balance(alice_address, get_native_asset().id())
                 .await
                 .unwrap(),
-            TRANSFER_FEE,
+            10u128.pow(19) - sequence_fee,
         );
-        assert_eq!(app.state.get_block_fees().await.unwrap().len(), 0);
+        assert_eq!(
+            app.state
+                .get_account_balance(address, get_native_asset().id())
+                .await
+                .unwrap(),
+            0,
+        );
+    }
+
+    #[tokio::test]
+    async fn app_deliver_tx_with_fee_payer_invalid() {
+        let mut app = initialize_app(None, vec![]).await;
+        let (alice_signing_key, alice_address) = get_alice_signing_key_and_address();
+        let signing_key = SigningKey::from([2; 32]);
+        let rollup_id = RollupId::from_unhashed_bytes("test-rollup");
+
+        // the fee payer must be able to cover the fees
+        let unfunded_signing_key = SigningKey::from([3; 32]);
+        let tx = UnsignedTransaction {
+            fee_payer: Some(FeePayer {
+                address: Address::from_verification_key(unfunded_signing_key.verification_key()),
+            }),
+            ..sequence_tx(0, rollup_id)
+        };
+        let res = app
+            .deliver_tx(
+                tx.into_signed(&signing_key)
+                    .with_fee_payer_signature(&unfunded_signing_key),
+            )
+            .await
+            .unwrap_err()
+            .root_cause()
+            .to_string();
+        assert!(res.contains("insufficient funds"));
+
+        // the signer cannot name itself as the fee payer
+        let tx = UnsignedTransaction {
+            fee_payer: Some(FeePayer {
+                address: alice_address,
+            }),
+            ..sequence_tx(0, rollup_id)
+        };
+        let res = app
+            .deliver_tx(
+                tx.into_signed(&alice_signing_key)
+                    .with_fee_payer_signature(&alice_signing_key),
+            )
+            .await
+            .unwrap_err()
+            .root_cause()
+            .to_string();
+        assert!(res.contains("fee payer must not be the signer of the transaction"));
     }
 }
@@ -0,0 +1,63 @@
+use anyhow::{
+    ensure,
+    Context as _,
+    Result,
+};
+use astria_core::sequencer::v1alpha1::{
+    transaction::action::AssetMetadataChangeAction,
+    Address,
+};
+use tracing::instrument;
+
+use crate::{
+    asset::{
+        check_metadata,
+        get_native_asset,
+        state_ext::{
+            StateReadExt,
+            StateWriteExt,
+        },
+    },
+    authority::state_ext::StateReadExt as _,
+    transaction::action_handler::ActionHandler,
+};
+
+#[async_trait::async_trait]
+impl ActionHandler for AssetMetadataChangeAction {
+    async fn check_stateless(&self) -> Result<()> {
+        check_metadata(&self.metadata)
+    }
+
+    /// check that the signer of the transaction is the current sudo address,
+    /// as only that address can change the metadata of assets
+    async fn check_stateful<S: StateReadExt + 'static>(
+        &self,
+        state: &S,
+        from: Address,
+    ) -> Result<()> {
+        // ensure signer is the valid `sudo` key in state
+        let sudo_address = state
+            .get_sudo_address()
+            .await
+            .context("failed to get sudo address from state")?;
+        ensure!(sudo_address == from, "signer is not the sudo key");
+
+        ensure!(
+            self.asset_id == get_native_asset().id()
+                || state
+                    .has_ibc_asset(self.asset_id)
+                    .await
+                    .context("failed to check if ibc asset exists in state")?,
+            "asset is neither the native asset nor a registered IBC asset"
+        );
+        Ok(())
+    }
+
+    #[instrument(skip_all, fields(asset_id = %self.asset_id))]
+    async fn execute<S: StateWriteExt>(&self, state: &mut S, _: Address) -> Result<()> {
+        state
+            .put_asset_metadata(self.asset_id, &self.metadata)
+            .context("failed to put asset metadata in state")?;
+        Ok(())
+    }
+}
@@ -1,8 +1,14 @@
+pub(crate) mod action;
+pub(crate) mod query;
 pub(crate) mod state_ext;
 
 use std::sync::OnceLock;
 
-use astria_core::sequencer::v1alpha1::asset::Denom;
+use anyhow::ensure;
+use astria_core::sequencer::v1alpha1::asset::{
+    Denom,
+    Metadata,
+};
 
 pub(crate) static NATIVE_ASSET: OnceLock<Denom> = OnceLock::new();
 
@@ -21,3 +27,29 @@ pub(crate) fn initialize_native_asset(native_asset: &str) {
 pub(crate) fn get_native_asset() -> &'static Denom {
     NATIVE_ASSET.get().expect("native asset should be set")
 }
+
+/// The maximum number of decimals of an asset, as `10^38` is the largest power of ten
+/// fitting into a `u128` balance.
+pub(crate) const MAX_ASSET_DECIMALS: u32 = 38;
+
+/// The maximum length of an asset's symbol and display denomination in bytes.
+pub(crate) const MAX_ASSET_METADATA_FIELD_LENGTH: usize = 64;
+
+/// Checks that `metadata` can be used to display amounts of an asset.
+pub(crate) fn check_metadata(metadata: &Metadata) -> anyhow::Result<()> {
+    ensure!(
+        metadata.decimals <= MAX_ASSET_DECIMALS,
+        "asset decimals must not be greater than {MAX_ASSET_DECIMALS}"
+    );
+    for (field, value) in [
+        ("symbol", &metadata.symbol),
+        ("display denomination", &metadata.display_denom),
+    ] {
+        ensure!(!value.is_empty(), "asset {field} must not be empty");
+        ensure!(
+            value.len() <= MAX_ASSET_METADATA_FIELD_LENGTH,
+            "asset {field} must not be longer than {MAX_ASSET_METADATA_FIELD_LENGTH} bytes"
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,215 @@
+use anyhow::Context as _;
+use astria_core::sequencer::v1alpha1::{
+    asset::{
+        self,
+        Denom,
+    },
+    AbciErrorCode,
+    AssetMetadataResponse,
+    AssetSupplyResponse,
+    DenomResponse,
+};
+use cnidarium::Storage;
+use prost::Message as _;
+use tendermint::abci::{
+    request,
+    response,
+};
+
+use crate::{
+    accounts::query::get_snapshot_and_height,
+    asset::state_ext::StateReadExt as _,
+    state_ext::StateReadExt as _,
+};
+
+pub(crate) async fn native_denom_request(
+    storage: Storage,
+    request: request::Query,
+    _params: Vec<(String, String)>,
+) -> response::Query {
+    let (snapshot, height) = match get_snapshot_and_height(&storage, request.height).await {
+        Ok(tup) => tup,
+        Err(err_rsp) => return err_rsp,
+    };
+
+    let denom = match snapshot.get_native_asset_denom().await {
+        Ok(denom) => denom,
+        Err(err) => {
+            return response::Query {
+                code: AbciErrorCode::INTERNAL_ERROR.into(),
+                info: AbciErrorCode::INTERNAL_ERROR.to_string(),
+                log: format!("failed getting native asset denom: {err:?}"),
+                height,
+                ..response::Query::default()
+            };
+        }
+    };
+
+    denom_response(&request, height, Denom::from_base_denom(&denom))
+}
+
+pub(crate) async fn denom_request(
+    storage: Storage,
+    request: request::Query,
+    params: Vec<(String, String)>,
+) -> response::Query {
+    let asset_id = match preprocess_request(&params) {
+        Ok(asset_id) => asset_id,
+        Err(err_rsp) => return err_rsp,
+    };
+
+    let (snapshot, height) = match get_snapshot_and_height(&storage, request.height).await {
+        Ok(tup) => tup,
+        Err(err_rsp) => return err_rsp,
+    };
+
+    let denom = match snapshot.get_ibc_asset(asset_id).await {
+        Ok(denom) => denom,
+        Err(err) => {
+            return response::Query {
+                code: AbciErrorCode::INTERNAL_ERROR.into(),
+                info: AbciErrorCode::INTERNAL_ERROR.to_string(),
+                log: format!("failed getting denom for provided asset id: {err:?}"),
+                height,
+                ..response::Query::default()
+            };
+        }
+    };
+
+    denom_response(&request, height, denom)
+}
+
+fn denom_response(
+    request: &request::Query,
+    height: tendermint::block::Height,
+    denom: Denom,
+) -> response::Query {
+    let payload = DenomResponse {
+        height: height.value(),
+        denom,
+    }
+    .into_raw()
+    .encode_to_vec()
+    .into();
+    response::Query {
+        code: 0.into(),
+        key: request.path.clone().into_bytes().into(),
+        value: payload,
+        height,
+        ..response::Query::default()
+    }
+}
+
+/// Returns the display metadata of the asset with the given hex encoded ID.
+pub(crate) async fn asset_metadata_request(
+    storage: Storage,
+    request: request::Query,
+    params: Vec<(String, String)>,
+) -> response::Query {
+    let asset_id = match preprocess_request(&params) {
+        Ok(asset_id) => asset_id,
+        Err(err_rsp) => return err_rsp,
+    };
+
+    let (snapshot, height) = match get_snapshot_and_height(&storage, request.height).await {
+        Ok(tup) => tup,
+        Err(err_rsp) => return err_rsp,
+    };
+
+    let metadata = match snapshot.get_asset_metadata(asset_id).await {
+        Ok(metadata) => metadata,
+        Err(err) => {
+            return response::Query {
+                code: AbciErrorCode::INTERNAL_ERROR.into(),
+                info: AbciErrorCode::INTERNAL_ERROR.to_string(),
+                log: format!("failed getting metadata for provided asset id: {err:?}"),
+                height,
+                ..response::Query::default()
+            };
+        }
+    };
+
+    let payload = AssetMetadataResponse {
+        height: height.value(),
+        asset_id,
+        metadata,
+    }
+    .into_raw()
+    .encode_to_vec()
+    .into();
+    response::Query {
+        code: 0.into(),
+        key: request.path.clone().into_bytes().into(),
+        value: payload,
+        height,
+        ..response::Query::default()
+    }
+}
+
+/// Returns the total supply of the asset with the given hex encoded ID.
+pub(crate) async fn asset_supply_request(
+    storage: Storage,
+    request: request::Query,
+    params: Vec<(String, String)>,
+) -> response::Query {
+    let asset_id = match preprocess_request(&params) {
+        Ok(asset_id) => asset_id,
+        Err(err_rsp) => return err_rsp,
+    };
+
+    let (snapshot, height) = match get_snapshot_and_height(&storage, request.height).await {
+        Ok(tup) => tup,
+        Err(err_rsp) => return err_rsp,
+    };
+
+    let supply = match snapshot.get_total_supply(asset_id).await {
+        Ok(supply) => supply,
+        Err(err) => {
+            return response::Query {
+                code: AbciErrorCode::INTERNAL_ERROR.into(),
+                info: AbciErrorCode::INTERNAL_ERROR.to_string(),
+                log: format!("failed getting total supply for provided asset id: {err:?}"),
+                height,
+                ..response::Query::default()
+            };
+        }
+    };
+
+    let payload = AssetSupplyResponse {
+        height: height.value(),
+        asset_id,
+        supply,
+    }
+    .into_raw()
+    .encode_to_vec()
+    .into();
+    response::Query {
+        code: 0.into(),
+        key: request.path.clone().into_bytes().into(),
+        value: payload,
+        height,
+        ..response::Query::default()
+    }
+}
+
+fn preprocess_request(params: &[(String, String)]) -> anyhow::Result<asset::Id, response::Query> {
+    let Some(asset_id) = params.iter().find_map(|(k, v)| (k == "id").then_some(v)) else {
+        return Err(response::Query {
+            code: AbciErrorCode::INVALID_PARAMETER.into(),
+            info: AbciErrorCode::INVALID_PARAMETER.to_string(),
+            log: "path did not contain asset ID parameter".into(),
+            ..response::Query::default()
+        });
+    };
+    hex::decode(asset_id)
+        .context("failed decoding hex encoded bytes")
+        .and_then(|bytes| {
+            asset::Id::try_from_slice(&bytes).context("failed constructing asset ID from bytes")
+        })
+        .map_err(|err| response::Query {
+            code: AbciErrorCode::INVALID_PARAMETER.into(),
+            info: AbciErrorCode::INVALID_PARAMETER.to_string(),
+            log: format!("asset ID could not be constructed from provided parameter: {err:?}"),
+            ..response::Query::default()
+        })
+}
@@ -16,6 +16,7 @@ use cnidarium::{
     StateRead,
     StateWrite,
 };
+use futures::StreamExt as _;
 use hex::ToHex as _;
 use tracing::instrument;
 
@@ -23,8 +24,55 @@ use tracing::instrument;
 #[derive(BorshSerialize, BorshDeserialize, Debug)]
 struct DenominationTrace(String);
 
+/// Newtype wrapper to read and write the total supply of an asset from rocksdb.
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+struct Supply(u128);
+
+/// Wrapper to read and write the metadata of an asset from rocksdb.
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+struct AssetMetadata {
+    decimals: u32,
+    symbol: String,
+    display_denom: String,
+}
+
+impl From<AssetMetadata> for asset::Metadata {
+    fn from(metadata: AssetMetadata) -> Self {
+        let AssetMetadata {
+            decimals,
+            symbol,
+            display_denom,
+        } = metadata;
+        Self {
+            decimals,
+            symbol,
+            display_denom,
+        }
+    }
+}
+
+impl From<&asset::Metadata> for AssetMetadata {
+    fn from(metadata: &asset::Metadata) -> Self {
+        Self {
+            decimals: metadata.decimals,
+            symbol: metadata.symbol.clone(),
+            display_denom: metadata.display_denom.clone(),
+        }
+    }
+}
+
+const ASSET_PREFIX: &str = "asset/";
+
 fn asset_storage_key(asset: asset::Id) -> String {
-    format!("asset/{}", asset.encode_hex::<String>())
+    format!("{ASSET_PREFIX}{}", asset.encode_hex::<String>())
+}
+
+fn metadata_storage_key(asset: asset::Id) -> String {
+    format!("asset_metadata/{}", asset.encode_hex::<String>())
+}
+
+fn supply_storage_key(asset: asset::Id) -> String {
+    format!("asset_supply/{}", asset.encode_hex::<String>())
 }
 
 #[async_trait]
@@ -56,6 +104,50 @@ pub(crate) trait StateReadExt: StateRead {
         let denom: Denom = denom_str.as_str().into();
         Ok(denom)
     }
+
+    /// Returns every IBC asset registered in state.
+    #[instrument(skip(self))]
+    async fn get_ibc_assets(&self) -> Result<Vec<Denom>> {
+        let mut assets = Vec::new();
+
+        let mut stream = std::pin::pin!(self.prefix_raw(ASSET_PREFIX));
+        while let Some(entry) = stream.next().await {
+            let (_, bytes) = entry.context("failed reading raw asset from state")?;
+            let DenominationTrace(denom_str) =
+                DenominationTrace::try_from_slice(&bytes).context("invalid asset bytes")?;
+            assets.push(denom_str.as_str().into());
+        }
+
+        Ok(assets)
+    }
+
+    #[instrument(skip(self))]
+    async fn get_asset_metadata(&self, id: asset::Id) -> Result<Option<asset::Metadata>> {
+        let Some(bytes) = self
+            .get_raw(&metadata_storage_key(id))
+            .await
+            .context("failed reading raw asset metadata from state")?
+        else {
+            return Ok(None);
+        };
+        let metadata =
+            AssetMetadata::try_from_slice(&bytes).context("invalid asset metadata bytes")?;
+        Ok(Some(metadata.into()))
+    }
+
+    /// Returns the total supply of the asset, which is zero for unknown assets.
+    #[instrument(skip(self))]
+    async fn get_total_supply(&self, id: asset::Id) -> Result<u128> {
+        let Some(bytes) = self
+            .get_raw(&supply_storage_key(id))
+            .await
+            .context("failed reading raw asset supply from state")?
+        else {
+            return Ok(0);
+        };
+        let Supply(supply) = Supply::try_from_slice(&bytes).context("invalid supply bytes")?;
+        Ok(supply)
+    }
 }
 
 impl<T: ?Sized + StateRead> StateReadExt for T {}
@@ -70,6 +162,56 @@ pub(crate) trait StateWriteExt: StateWrite {
         self.put_raw(asset_storage_key(id), bytes);
         Ok(())
     }
+
+    #[instrument(skip(self))]
+    fn put_asset_metadata(&mut self, id: asset::Id, metadata: &asset::Metadata) -> Result<()> {
+        let bytes = AssetMetadata::from(metadata)
+            .try_to_vec()
+            .context("failed to serialize asset metadata")?;
+        self.put_raw(metadata_storage_key(id), bytes);
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    fn put_total_supply(&mut self, id: asset::Id, supply: u128) -> Result<()> {
+        let bytes = Supply(supply)
+            .try_to_vec()
+            .context("failed to serialize supply")?;
+        self.put_raw(supply_storage_key(id), bytes);
+        Ok(())
+    }
+
+    /// Adds newly minted or received tokens to the total supply of the asset.
+    #[instrument(skip(self))]
+    async fn increase_total_supply(&mut self, id: asset::Id, amount: u128) -> Result<()> {
+        let supply = self
+            .get_total_supply(id)
+            .await
+            .context("failed to get total supply")?;
+        self.put_total_supply(
+            id,
+            supply
+                .checked_add(amount)
+                .context("failed to update total supply due to overflow")?,
+        )
+        .context("failed to store updated total supply in database")
+    }
+
+    /// Removes burned tokens from the total supply of the asset.
+    #[instrument(skip(self))]
+    async fn decrease_total_supply(&mut self, id: asset::Id, amount: u128) -> Result<()> {
+        let supply = self
+            .get_total_supply(id)
+            .await
+            .context("failed to get total supply")?;
+        self.put_total_supply(
+            id,
+            supply
+                .checked_sub(amount)
+                .context("failed to update total supply due to underflow")?,
+        )
+        .context("failed to store updated total supply in database")
+    }
 }
 
 impl<T: StateWrite> StateWriteExt for T {}
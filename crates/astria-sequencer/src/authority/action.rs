@@ -4,6 +4,10 @@ use anyhow::{
     Result,
 };
 use astria_core::sequencer::v1alpha1::{
+    event::{
+        SudoAddressChangeEvent,
+        ValidatorUpdateEvent,
+    },
     transaction::action::SudoAddressChangeAction,
     Address,
 };
@@ -44,6 +48,14 @@ impl ActionHandler for tendermint::validator::Update {
         state
             .put_validator_updates(validator_updates)
             .context("failed to put validator updates in state")?;
+
+        state.record(
+            ValidatorUpdateEvent {
+                pub_key: self.pub_key,
+                power: self.power.value(),
+            }
+            .into_abci(),
+        );
         Ok(())
     }
 }
@@ -71,6 +83,13 @@ impl ActionHandler for SudoAddressChangeAction {
         state
             .put_sudo_address(self.new_address)
             .context("failed to put sudo address in state")?;
+
+        state.record(
+            SudoAddressChangeEvent {
+                new_address: self.new_address,
+            }
+            .into_abci(),
+        );
         Ok(())
     }
 }
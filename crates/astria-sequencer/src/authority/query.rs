@@ -0,0 +1,94 @@
+use astria_core::sequencer::v1alpha1::{
+    AbciErrorCode,
+    SudoAddressResponse,
+    ValidatorSetResponse,
+};
+use cnidarium::Storage;
+use prost::Message as _;
+use tendermint::abci::{
+    request,
+    response,
+};
+
+use crate::{
+    accounts::query::get_snapshot_and_height,
+    authority::state_ext::StateReadExt as _,
+};
+
+pub(crate) async fn validator_set_request(
+    storage: Storage,
+    request: request::Query,
+    _params: Vec<(String, String)>,
+) -> response::Query {
+    let (snapshot, height) = match get_snapshot_and_height(&storage, request.height).await {
+        Ok(tup) => tup,
+        Err(err_rsp) => return err_rsp,
+    };
+
+    let validator_set = match snapshot.get_validator_set().await {
+        Ok(validator_set) => validator_set,
+        Err(err) => {
+            return response::Query {
+                code: AbciErrorCode::INTERNAL_ERROR.into(),
+                info: AbciErrorCode::INTERNAL_ERROR.to_string(),
+                log: format!("failed getting validator set: {err:?}"),
+                height,
+                ..response::Query::default()
+            };
+        }
+    };
+
+    let payload = ValidatorSetResponse {
+        height: height.value(),
+        validators: validator_set.into_tendermint_validator_updates(),
+    }
+    .into_raw()
+    .encode_to_vec()
+    .into();
+    response::Query {
+        code: 0.into(),
+        key: request.path.clone().into_bytes().into(),
+        value: payload,
+        height,
+        ..response::Query::default()
+    }
+}
+
+pub(crate) async fn sudo_address_request(
+    storage: Storage,
+    request: request::Query,
+    _params: Vec<(String, String)>,
+) -> response::Query {
+    let (snapshot, height) = match get_snapshot_and_height(&storage, request.height).await {
+        Ok(tup) => tup,
+        Err(err_rsp) => return err_rsp,
+    };
+
+    let address = match snapshot.get_sudo_address().await {
+        Ok(address) => address,
+        Err(err) => {
+            return response::Query {
+                code: AbciErrorCode::INTERNAL_ERROR.into(),
+                info: AbciErrorCode::INTERNAL_ERROR.to_string(),
+                log: format!("failed getting authority sudo address: {err:?}"),
+                height,
+                ..response::Query::default()
+            };
+        }
+    };
+
+    let payload = SudoAddressResponse {
+        height: height.value(),
+        address,
+    }
+    .into_raw()
+    .encode_to_vec()
+    .into();
+    response::Query {
+        code: 0.into(),
+        key: request.path.clone().into_bytes().into(),
+        value: payload,
+        height,
+        ..response::Query::default()
+    }
+}
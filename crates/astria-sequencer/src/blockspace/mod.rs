@@ -0,0 +1,341 @@
+//! Allocation of a block's sequence data between rollups.
+//!
+//! Every block can hold at most [`MAX_SEQUENCE_DATA_BYTES_PER_BLOCK`] bytes of sequence
+//! data. Within that limit, each rollup can take at most its quota of bytes, so that a
+//! spike in one rollup's transactions cannot starve all other rollups. Rollups without a
+//! quota of their own are subject to the default rollup quota.
+//!
+//! The quotas are enforced for every proposed block, while [`proposal_order`] is the
+//! policy used by the proposer to order the transactions taken from the mempool. Other
+//! validators cannot know which transactions were in the proposer's mempool, so they
+//! cannot reproduce that order. Instead, every proposal must satisfy the weaker
+//! [`check_proposal_order`], which rejects proposals placing a transaction ahead of a
+//! transaction of another signer that pays a higher tip per byte.
+
+pub(crate) mod state_ext;
+
+use std::{
+    cmp::{
+        Ordering,
+        Reverse,
+    },
+    collections::{
+        BTreeMap,
+        HashMap,
+        VecDeque,
+    },
+    fmt,
+};
+
+use anyhow::{
+    ensure,
+    Context as _,
+};
+use astria_core::sequencer::v1alpha1::{
+    transaction::Action,
+    Address,
+    RollupId,
+    SignedTransaction,
+};
+use cnidarium::StateRead;
+
+use self::state_ext::StateReadExt as _;
+use crate::mempool::MempoolTransaction;
+
+/// The maximum number of bytes allowed in sequencer action data.
+pub(crate) const MAX_SEQUENCE_DATA_BYTES_PER_BLOCK: usize = 256_000;
+
+/// The maximum number of sequence data bytes each rollup can take up in a block.
+#[derive(Debug, Clone)]
+pub(crate) struct RollupQuotas {
+    default: usize,
+    overrides: HashMap<RollupId, usize>,
+}
+
+impl RollupQuotas {
+    /// Reads the rollup quotas from `state`.
+    ///
+    /// If no default quota is set, rollups without a quota of their own can take up
+    /// all of a block's sequence data.
+    pub(crate) async fn from_state<S: StateRead>(state: &S) -> anyhow::Result<Self> {
+        let default = state
+            .get_default_rollup_quota()
+            .await
+            .context("failed to get default rollup quota")?
+            .map_or(MAX_SEQUENCE_DATA_BYTES_PER_BLOCK, quota_to_usize);
+        let overrides = state
+            .get_rollup_quotas()
+            .await
+            .context("failed to get rollup quotas")?
+            .into_iter()
+            .map(|(rollup_id, quota)| (rollup_id, quota_to_usize(quota)))
+            .collect();
+        Ok(Self {
+            default,
+            overrides,
+        })
+    }
+
+    pub(crate) fn quota(&self, rollup_id: &RollupId) -> usize {
+        self.overrides
+            .get(rollup_id)
+            .copied()
+            .unwrap_or(self.default)
+    }
+}
+
+fn quota_to_usize(quota: u64) -> usize {
+    usize::try_from(quota).unwrap_or(usize::MAX)
+}
+
+/// Returns the number of sequence data bytes `tx` contains for each rollup.
+pub(crate) fn sequence_data_bytes(tx: &SignedTransaction) -> BTreeMap<RollupId, usize> {
+    let mut bytes = BTreeMap::new();
+    for sequence in tx
+        .unsigned_transaction()
+        .actions
+        .iter()
+        .filter_map(Action::as_sequence)
+    {
+        *bytes.entry(sequence.rollup_id).or_insert(0) += sequence.data.len();
+    }
+    bytes
+}
+
+/// The sequence data bytes still available in a block, in total and per rollup.
+#[derive(Debug)]
+pub(crate) struct BlockspaceBudget {
+    quotas: RollupQuotas,
+    block_bytes: usize,
+    rollup_bytes: HashMap<RollupId, usize>,
+}
+
+impl BlockspaceBudget {
+    pub(crate) fn new(quotas: RollupQuotas) -> Self {
+        Self {
+            quotas,
+            block_bytes: 0,
+            rollup_bytes: HashMap::new(),
+        }
+    }
+
+    /// Checks that a transaction with the sequence data `tx_bytes` per rollup fits
+    /// into the remaining budget.
+    pub(crate) fn check(
+        &self,
+        tx_bytes: &BTreeMap<RollupId, usize>,
+    ) -> Result<(), BlockspaceExceeded> {
+        let tx_total: usize = tx_bytes.values().sum();
+        if self.block_bytes + tx_total > MAX_SEQUENCE_DATA_BYTES_PER_BLOCK {
+            return Err(BlockspaceExceeded::Block {
+                included: self.block_bytes,
+                tx: tx_total,
+            });
+        }
+        for (rollup_id, tx) in tx_bytes {
+            let included = self.rollup_bytes.get(rollup_id).copied().unwrap_or(0);
+            let quota = self.quotas.quota(rollup_id);
+            if included + tx > quota {
+                return Err(BlockspaceExceeded::Rollup {
+                    rollup_id: *rollup_id,
+                    included,
+                    tx: *tx,
+                    quota,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Takes the sequence data `tx_bytes` per rollup out of the remaining budget.
+    pub(crate) fn consume(&mut self, tx_bytes: &BTreeMap<RollupId, usize>) {
+        for (rollup_id, tx) in tx_bytes {
+            self.block_bytes += tx;
+            *self.rollup_bytes.entry(*rollup_id).or_insert(0) += tx;
+        }
+    }
+}
+
+/// Error returned when a transaction does not fit into a [`BlockspaceBudget`].
+#[derive(Debug)]
+pub(crate) enum BlockspaceExceeded {
+    Block {
+        included: usize,
+        tx: usize,
+    },
+    Rollup {
+        rollup_id: RollupId,
+        included: usize,
+        tx: usize,
+        quota: usize,
+    },
+}
+
+impl fmt::Display for BlockspaceExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Block {
+                included,
+                tx,
+            } => write!(
+                f,
+                "transaction with {tx} sequence data bytes exceeds the block limit of \
+                 {MAX_SEQUENCE_DATA_BYTES_PER_BLOCK} bytes with {included} bytes already included"
+            ),
+            Self::Rollup {
+                rollup_id,
+                included,
+                tx,
+                quota,
+            } => write!(
+                f,
+                "transaction with {tx} sequence data bytes for rollup `{rollup_id}` exceeds its \
+                 quota of {quota} bytes with {included} bytes already included"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for BlockspaceExceeded {}
+
+/// The tip a transaction pays per byte of its encoding.
+///
+/// Compared exactly, without rounding the tip per byte and without overflowing.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct TipPerByte {
+    tip: u128,
+    len: u128,
+}
+
+impl TipPerByte {
+    /// Returns the tip per byte of `tx`, whose encoding is `len` bytes long.
+    pub(crate) fn new(tx: &SignedTransaction, len: usize) -> Self {
+        Self {
+            tip: tx.unsigned_transaction().tip.unwrap_or(0),
+            len: u128::try_from(len).unwrap_or(u128::MAX).max(1),
+        }
+    }
+}
+
+impl Ord for TipPerByte {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // compare the integer parts first; the remainders are less than the lengths,
+        // so cross-multiplying them cannot overflow.
+        (self.tip / self.len)
+            .cmp(&(other.tip / other.len))
+            .then_with(|| {
+                ((self.tip % self.len) * other.len).cmp(&((other.tip % other.len) * self.len))
+            })
+    }
+}
+
+impl PartialOrd for TipPerByte {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for TipPerByte {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for TipPerByte {}
+
+struct QueuedTransaction {
+    position: usize,
+    tip_per_byte: TipPerByte,
+    sequence_data_bytes: BTreeMap<RollupId, usize>,
+    tx: MempoolTransaction,
+}
+
+/// Orders `txs` for inclusion in a proposed block.
+///
+/// Repeatedly takes the next transaction of the signer whose next transaction pays the
+/// highest tip per byte. Among transactions paying the same tip per byte, the one for
+/// the least served rollup is taken first, i.e. the rollup with the fewest bytes ordered
+/// before it, so that rollups get a fair share of the block's sequence data.
+/// Transactions without sequence data are never held back by this. Remaining ties are
+/// broken by the original position in `txs`, and the transactions of each signer stay
+/// in their original (nonce) order.
+pub(crate) fn proposal_order(txs: Vec<MempoolTransaction>) -> Vec<MempoolTransaction> {
+    let mut queues: Vec<VecDeque<QueuedTransaction>> = Vec::new();
+    let mut queue_of_signer: HashMap<Address, usize> = HashMap::new();
+    for (position, tx) in txs.into_iter().enumerate() {
+        let queue = *queue_of_signer
+            .entry(tx.signed_tx().address())
+            .or_insert_with(|| {
+                queues.push(VecDeque::new());
+                queues.len() - 1
+            });
+        queues[queue].push_back(QueuedTransaction {
+            position,
+            tip_per_byte: TipPerByte::new(tx.signed_tx(), tx.tx_bytes().len()),
+            sequence_data_bytes: sequence_data_bytes(tx.signed_tx()),
+            tx,
+        });
+    }
+
+    let mut ordered = Vec::new();
+    let mut ordered_bytes: HashMap<RollupId, usize> = HashMap::new();
+    loop {
+        let next = queues
+            .iter_mut()
+            .filter_map(|queue| {
+                let head = queue.front()?;
+                let served = head
+                    .sequence_data_bytes
+                    .keys()
+                    .map(|rollup_id| ordered_bytes.get(rollup_id).copied().unwrap_or(0))
+                    .min()
+                    .unwrap_or(0);
+                let key = (Reverse(head.tip_per_byte), served, head.position);
+                Some((key, queue))
+            })
+            .min_by_key(|(key, _)| *key)
+            .and_then(|(_, queue)| queue.pop_front());
+        let Some(QueuedTransaction {
+            sequence_data_bytes,
+            tx,
+            ..
+        }) = next
+        else {
+            break;
+        };
+        for (rollup_id, bytes) in sequence_data_bytes {
+            *ordered_bytes.entry(rollup_id).or_insert(0) += bytes;
+        }
+        ordered.push(tx);
+    }
+    ordered
+}
+
+/// Checks that `txs`, given together with the length of their encoding, are ordered as
+/// every proposal must be: no transaction pays a higher tip per byte than the transaction
+/// before it, unless both have the same signer, whose transactions must stay in nonce
+/// order.
+///
+/// [`proposal_order`] always satisfies this, as the next transaction of every other
+/// signer was already queued when a transaction was taken. Skipping transactions does not
+/// break it either, as all later transactions of the skipped transaction's signer are
+/// skipped as well. Ties in the tip per byte are not checked, so the fair share among
+/// rollups remains a policy of the proposer.
+pub(crate) fn check_proposal_order<'a>(
+    txs: impl IntoIterator<Item = (&'a SignedTransaction, usize)>,
+) -> anyhow::Result<()> {
+    let mut previous: Option<(Address, TipPerByte)> = None;
+    for (index, (tx, len)) in txs.into_iter().enumerate() {
+        let signer = tx.address();
+        let tip_per_byte = TipPerByte::new(tx, len);
+        if let Some((previous_signer, previous_tip_per_byte)) = previous {
+            ensure!(
+                signer == previous_signer || tip_per_byte <= previous_tip_per_byte,
+                "transaction at index {index} pays a higher tip per byte than the transaction of \
+                 another signer before it"
+            );
+        }
+        previous = Some((signer, tip_per_byte));
+    }
+    Ok(())
+}
@@ -0,0 +1,95 @@
+use anyhow::{
+    Context,
+    Result,
+};
+use astria_core::sequencer::v1alpha1::RollupId;
+use async_trait::async_trait;
+use borsh::{
+    BorshDeserialize,
+    BorshSerialize,
+};
+use cnidarium::{
+    StateRead,
+    StateWrite,
+};
+use futures::StreamExt as _;
+use hex::ToHex as _;
+use tracing::instrument;
+
+/// Newtype wrapper to read and write a quota of sequence data bytes from rocksdb.
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+struct Quota(u64);
+
+const DEFAULT_ROLLUP_QUOTA_STORAGE_KEY: &str = "blockspace/default_rollup_quota";
+const ROLLUP_QUOTA_PREFIX: &str = "blockspace/rollup_quota/";
+
+fn rollup_quota_storage_key(rollup_id: &RollupId) -> String {
+    format!("{ROLLUP_QUOTA_PREFIX}{}", rollup_id.encode_hex::<String>())
+}
+
+#[async_trait]
+pub(crate) trait StateReadExt: StateRead {
+    /// Returns the quota of rollups without their own quota, or `None` if it was never set.
+    #[instrument(skip(self))]
+    async fn get_default_rollup_quota(&self) -> Result<Option<u64>> {
+        let Some(bytes) = self
+            .get_raw(DEFAULT_ROLLUP_QUOTA_STORAGE_KEY)
+            .await
+            .context("failed reading raw default rollup quota from state")?
+        else {
+            return Ok(None);
+        };
+        let Quota(quota) = Quota::try_from_slice(&bytes).context("invalid quota bytes")?;
+        Ok(Some(quota))
+    }
+
+    /// Returns the rollups which have their own quota, with their quotas.
+    #[instrument(skip(self))]
+    async fn get_rollup_quotas(&self) -> Result<Vec<(RollupId, u64)>> {
+        let mut quotas = Vec::new();
+
+        let mut stream = std::pin::pin!(self.prefix_raw(ROLLUP_QUOTA_PREFIX));
+        while let Some(entry) = stream.next().await {
+            let (key, bytes) = entry.context("failed reading raw rollup quota from state")?;
+            let rollup_id_str = key
+                .strip_prefix(ROLLUP_QUOTA_PREFIX)
+                .context("failed to strip prefix from rollup quota key")?;
+            let rollup_id_bytes = hex::decode(rollup_id_str).context("invalid rollup id bytes")?;
+            let rollup_id = RollupId::try_from_slice(&rollup_id_bytes)
+                .context("failed to parse rollup id from rollup quota key")?;
+            let Quota(quota) = Quota::try_from_slice(&bytes).context("invalid quota bytes")?;
+            quotas.push((rollup_id, quota));
+        }
+
+        Ok(quotas)
+    }
+}
+
+impl<T: StateRead> StateReadExt for T {}
+
+#[async_trait]
+pub(crate) trait StateWriteExt: StateWrite {
+    #[instrument(skip(self))]
+    fn put_default_rollup_quota(&mut self, quota: u64) -> Result<()> {
+        self.put_raw(
+            DEFAULT_ROLLUP_QUOTA_STORAGE_KEY.to_string(),
+            Quota(quota)
+                .try_to_vec()
+                .context("failed to serialize quota")?,
+        );
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    fn put_rollup_quota(&mut self, rollup_id: &RollupId, quota: u64) -> Result<()> {
+        self.put_raw(
+            rollup_quota_storage_key(rollup_id),
+            Quota(quota)
+                .try_to_vec()
+                .context("failed to serialize quota")?,
+        );
+        Ok(())
+    }
+}
+
+impl<T: StateWrite> StateWriteExt for T {}
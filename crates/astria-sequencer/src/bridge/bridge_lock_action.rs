@@ -0,0 +1,70 @@
+use anyhow::{
+    ensure,
+    Context as _,
+    Result,
+};
+use astria_core::sequencer::v1alpha1::{
+    transaction::action::BridgeLockAction,
+    Address,
+};
+use tracing::instrument;
+
+use crate::{
+    accounts::action::{
+        check_transfer,
+        execute_transfer,
+    },
+    bridge::state_ext::{
+        StateReadExt,
+        StateWriteExt,
+    },
+    transaction::action_handler::ActionHandler,
+};
+
+#[async_trait::async_trait]
+impl ActionHandler for BridgeLockAction {
+    async fn check_stateful<S: StateReadExt + 'static>(
+        &self,
+        state: &S,
+        from: Address,
+    ) -> Result<()> {
+        // ensure the recipient is a bridge account registered for the given rollup,
+        // so that the deposit is picked up by that rollup
+        let rollup_id = state
+            .get_bridge_account_rollup_id(&self.to)
+            .await
+            .context("failed to get bridge account rollup ID")?
+            .context("bridge lock must be sent to a bridge account")?;
+        ensure!(
+            rollup_id == self.rollup_id,
+            "bridge account is registered for a different rollup"
+        );
+
+        let allowed_asset_id = state
+            .get_bridge_account_asset_id(&self.to)
+            .await
+            .context("failed to get bridge account asset ID")?;
+        ensure!(
+            allowed_asset_id == self.asset_id,
+            "asset ID is not authorized for transfer to bridge account"
+        );
+
+        // the balance and fee checks are identical to a regular transfer
+        check_transfer(state, from, self.asset_id, self.amount, self.fee_asset_id).await
+    }
+
+    #[instrument(
+        skip_all,
+        fields(
+            to = self.to.to_string(),
+            amount = self.amount,
+        )
+    )]
+    async fn execute<S: StateWriteExt>(&self, state: &mut S, from: Address) -> Result<()> {
+        // the deposit itself is derived from the block data by the rollup,
+        // so executing the lock is only a transfer into the bridge account
+        execute_transfer(state, from, self.to, self.asset_id, self.amount)
+            .await
+            .context("failed to execute bridge lock action as transfer")
+    }
+}
@@ -0,0 +1,107 @@
+use anyhow::{
+    ensure,
+    Context as _,
+    Result,
+};
+use astria_core::sequencer::v1alpha1::{
+    event::BridgeUnlockEvent,
+    transaction::action::BridgeUnlockAction,
+    Address,
+};
+use tracing::instrument;
+
+use crate::{
+    accounts::state_ext::{
+        StateReadExt as _,
+        StateWriteExt as _,
+    },
+    bridge::state_ext::StateReadExt,
+    fees::state_ext::StateReadExt as _,
+    state_ext::StateWriteExt,
+    transaction::action_handler::ActionHandler,
+};
+
+#[async_trait::async_trait]
+impl ActionHandler for BridgeUnlockAction {
+    async fn check_stateful<S: StateReadExt + 'static>(
+        &self,
+        state: &S,
+        from: Address,
+    ) -> Result<()> {
+        ensure!(
+            state.is_allowed_fee_asset(self.fee_asset_id).await?,
+            "invalid fee asset"
+        );
+
+        // only the withdrawer registered for the bridge account can unlock funds from it
+        let withdrawer = state
+            .get_bridge_account_withdrawer_address(&self.bridge_address)
+            .await
+            .context("failed to get bridge account withdrawer address")?;
+        ensure!(
+            withdrawer == from,
+            "signer is not the authorized withdrawer for the bridge account"
+        );
+
+        // funds unlocked into another bridge account would not be deposited to its rollup
+        ensure!(
+            state
+                .get_bridge_account_rollup_id(&self.to)
+                .await
+                .context("failed to get bridge account rollup ID")?
+                .is_none(),
+            "cannot unlock funds to a bridge account"
+        );
+
+        let asset_id = state
+            .get_bridge_account_asset_id(&self.bridge_address)
+            .await
+            .context("failed to get bridge account asset ID")?;
+        let bridge_balance = state
+            .get_account_balance(self.bridge_address, asset_id)
+            .await
+            .context("failed getting bridge account balance")?;
+        ensure!(
+            bridge_balance >= self.amount,
+            "insufficient funds in bridge account for unlock"
+        );
+
+        Ok(())
+    }
+
+    #[instrument(
+        skip_all,
+        fields(
+            to = self.to.to_string(),
+            bridge_address = self.bridge_address.to_string(),
+            amount = self.amount,
+        )
+    )]
+    async fn execute<S: StateWriteExt>(&self, state: &mut S, _: Address) -> Result<()> {
+        let asset_id = state
+            .get_bridge_account_asset_id(&self.bridge_address)
+            .await
+            .context("failed to get bridge account asset ID")?;
+
+        state
+            .decrease_balance(self.bridge_address, asset_id, self.amount)
+            .await
+            .context("failed to deduct unlocked amount from bridge account")?;
+        state
+            .increase_balance(self.to, asset_id, self.amount)
+            .await
+            .context("failed to credit unlocked amount to recipient")?;
+
+        state.record(
+            BridgeUnlockEvent {
+                bridge_address: self.bridge_address,
+                to: self.to,
+                asset_id,
+                amount: self.amount,
+                memo: self.memo.clone(),
+            }
+            .into_abci(),
+        );
+        Ok(())
+    }
+}
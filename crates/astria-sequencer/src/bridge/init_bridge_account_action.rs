@@ -0,0 +1,82 @@
+use anyhow::{
+    bail,
+    ensure,
+    Context as _,
+    Result,
+};
+use astria_core::sequencer::v1alpha1::{
+    event::InitBridgeAccountEvent,
+    transaction::action::InitBridgeAccountAction,
+    Address,
+};
+use tracing::instrument;
+
+use crate::{
+    bridge::state_ext::{
+        StateReadExt,
+        StateWriteExt,
+    },
+    fees::state_ext::StateReadExt as _,
+    transaction::action_handler::ActionHandler,
+};
+
+#[async_trait::async_trait]
+impl ActionHandler for InitBridgeAccountAction {
+    async fn check_stateful<S: StateReadExt + 'static>(
+        &self,
+        state: &S,
+        from: Address,
+    ) -> Result<()> {
+        ensure!(
+            state.is_allowed_fee_asset(self.fee_asset_id).await?,
+            "invalid fee asset"
+        );
+
+        // this prevents the address from being registered as a bridge account
+        // if it's been previously initialized as a bridge account.
+        //
+        // however, there is no prevention of initializing an account as a bridge
+        // account that's already been used as a normal EOA.
+        //
+        // the implication is that the account might already have a balance, nonce, etc.
+        // before being converted into a bridge account.
+        if state
+            .get_bridge_account_rollup_id(&from)
+            .await
+            .context("failed getting rollup ID of bridge account")?
+            .is_some()
+        {
+            bail!("bridge account already exists");
+        }
+
+        Ok(())
+    }
+
+    #[instrument(
+        skip_all,
+        fields(
+            rollup_id = self.rollup_id.to_string(),
+        )
+    )]
+    async fn execute<S: StateWriteExt>(&self, state: &mut S, from: Address) -> Result<()> {
+        state
+            .put_bridge_account_rollup_id(&from, &self.rollup_id)
+            .context("failed to put bridge account rollup ID")?;
+        state.put_bridge_account_asset_id(&from, &self.asset_id);
+        let withdrawer_address = self.withdrawer_address.unwrap_or(from);
+        state
+            .put_bridge_account_withdrawer_address(&from, &withdrawer_address)
+            .context("failed to put bridge account withdrawer address")?;
+
+        state.record(
+            InitBridgeAccountEvent {
+                bridge_address: from,
+                rollup_id: self.rollup_id,
+                asset_id: self.asset_id,
+                withdrawer_address,
+            }
+            .into_abci(),
+        );
+        Ok(())
+    }
+}
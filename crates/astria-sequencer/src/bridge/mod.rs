@@ -0,0 +1,4 @@
+pub(crate) mod bridge_lock_action;
+pub(crate) mod bridge_unlock_action;
+pub(crate) mod init_bridge_account_action;
+pub(crate) mod state_ext;
@@ -0,0 +1,130 @@
+use anyhow::{
+    Context,
+    Result,
+};
+use astria_core::sequencer::v1alpha1::{
+    asset,
+    Address,
+    RollupId,
+    ADDRESS_LEN,
+};
+use async_trait::async_trait;
+use borsh::{
+    BorshDeserialize,
+    BorshSerialize,
+};
+use cnidarium::{
+    StateRead,
+    StateWrite,
+};
+use hex::ToHex as _;
+use tracing::instrument;
+
+/// Newtype wrapper to read and write a rollup ID from rocksdb.
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+struct RollupIdStorage([u8; 32]);
+
+/// Newtype wrapper to read and write an address from rocksdb.
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+struct WithdrawerAddress([u8; ADDRESS_LEN]);
+
+const BRIDGE_ACCOUNT_PREFIX: &str = "bridge_account";
+
+fn storage_key(address: &str) -> String {
+    format!("{BRIDGE_ACCOUNT_PREFIX}/{address}")
+}
+
+fn rollup_id_storage_key(address: &Address) -> String {
+    format!("{}/rollup_id", storage_key(&address.encode_hex::<String>()))
+}
+
+fn asset_id_storage_key(address: &Address) -> String {
+    format!("{}/asset_id", storage_key(&address.encode_hex::<String>()))
+}
+
+fn withdrawer_storage_key(address: &Address) -> String {
+    format!(
+        "{}/withdrawer",
+        storage_key(&address.encode_hex::<String>())
+    )
+}
+
+#[async_trait]
+pub(crate) trait StateReadExt: StateRead {
+    /// Returns the rollup ID the given address is registered as a bridge account for,
+    /// or `None` if the address is not a bridge account.
+    #[instrument(skip(self))]
+    async fn get_bridge_account_rollup_id(&self, address: &Address) -> Result<Option<RollupId>> {
+        let Some(bytes) = self
+            .get_raw(&rollup_id_storage_key(address))
+            .await
+            .context("failed reading raw account rollup ID from state")?
+        else {
+            return Ok(None);
+        };
+
+        let RollupIdStorage(rollup_id) =
+            RollupIdStorage::try_from_slice(&bytes).context("invalid rollup ID bytes")?;
+        Ok(Some(RollupId::new(rollup_id)))
+    }
+
+    #[instrument(skip(self))]
+    async fn get_bridge_account_asset_id(&self, address: &Address) -> Result<asset::Id> {
+        let bytes = self
+            .get_raw(&asset_id_storage_key(address))
+            .await
+            .context("failed reading raw asset ID from state")?
+            .context("asset ID not found")?;
+        asset::Id::try_from_slice(&bytes).context("invalid asset ID bytes")
+    }
+
+    #[instrument(skip(self))]
+    async fn get_bridge_account_withdrawer_address(&self, address: &Address) -> Result<Address> {
+        let bytes = self
+            .get_raw(&withdrawer_storage_key(address))
+            .await
+            .context("failed reading raw withdrawer address from state")?
+            .context("withdrawer address not found")?;
+        let WithdrawerAddress(withdrawer) = WithdrawerAddress::try_from_slice(&bytes)
+            .context("invalid withdrawer address bytes")?;
+        Ok(Address(withdrawer))
+    }
+}
+
+impl<T: StateRead> StateReadExt for T {}
+
+#[async_trait]
+pub(crate) trait StateWriteExt: StateWrite {
+    #[instrument(skip(self))]
+    fn put_bridge_account_rollup_id(
+        &mut self,
+        address: &Address,
+        rollup_id: &RollupId,
+    ) -> Result<()> {
+        let bytes = RollupIdStorage(rollup_id.get())
+            .try_to_vec()
+            .context("failed to serialize rollup ID")?;
+        self.put_raw(rollup_id_storage_key(address), bytes);
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    fn put_bridge_account_asset_id(&mut self, address: &Address, asset_id: &asset::Id) {
+        self.put_raw(asset_id_storage_key(address), asset_id.as_ref().to_vec());
+    }
+
+    #[instrument(skip(self))]
+    fn put_bridge_account_withdrawer_address(
+        &mut self,
+        address: &Address,
+        withdrawer: &Address,
+    ) -> Result<()> {
+        let bytes = WithdrawerAddress(withdrawer.0)
+            .try_to_vec()
+            .context("failed to serialize withdrawer address")?;
+        self.put_raw(withdrawer_storage_key(address), bytes);
+        Ok(())
+    }
+}
+
+impl<T: StateWrite> StateWriteExt for T {}
@@ -22,6 +22,15 @@ pub struct Config {
     pub force_stdout: bool,
     /// Disables writing trace data to an opentelemetry endpoint.
     pub no_otel: bool,
+    /// The number of blocks between state sync snapshots. Set to 0 to disable taking snapshots.
+    pub snapshot_interval: u64,
+    /// The directory in which state sync snapshots are stored.
+    pub snapshot_dir: PathBuf,
+    /// The number of most recent state sync snapshots to keep.
+    pub snapshot_keep_recent: usize,
+    /// The number of most recent sequencer blocks kept in storage to be served over gRPC.
+    /// Set to 0 to keep all blocks.
+    pub sequencer_block_retention: u64,
 }
 
 impl config::Config for Config {
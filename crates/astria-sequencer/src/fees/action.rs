@@ -0,0 +1,112 @@
+use anyhow::{
+    ensure,
+    Context as _,
+    Result,
+};
+use astria_core::sequencer::v1alpha1::{
+    event::FeeChangeEvent,
+    transaction::action::{
+        FeeAssetChangeAction,
+        FeeChange,
+        FeeChangeAction,
+    },
+    Address,
+};
+use tracing::instrument;
+
+use crate::{
+    authority::state_ext::StateReadExt as AuthorityStateReadExt,
+    fees::state_ext::{
+        StateReadExt as _,
+        StateWriteExt as FeesStateWriteExt,
+    },
+    transaction::action_handler::ActionHandler,
+};
+
+#[async_trait::async_trait]
+impl ActionHandler for FeeChangeAction {
+    /// check that the signer of the transaction is the current sudo address,
+    /// as only that address can change the fee schedule
+    async fn check_stateful<S: AuthorityStateReadExt + 'static>(
+        &self,
+        state: &S,
+        from: Address,
+    ) -> Result<()> {
+        // ensure signer is the valid `sudo` key in state
+        let sudo_address = state
+            .get_sudo_address()
+            .await
+            .context("failed to get sudo address from state")?;
+        ensure!(sudo_address == from, "signer is not the sudo key");
+        Ok(())
+    }
+
+    #[instrument(skip_all)]
+    async fn execute<S: FeesStateWriteExt>(&self, state: &mut S, _: Address) -> Result<()> {
+        match self.fee_change {
+            FeeChange::TransferBaseFee => state
+                .put_transfer_base_fee(self.new_value)
+                .context("failed to put transfer base fee in state")?,
+            FeeChange::SequenceBaseFee => state
+                .put_sequence_base_fee(self.new_value)
+                .context("failed to put sequence base fee in state")?,
+            FeeChange::SequenceByteCostMultiplier => state
+                .put_sequence_byte_cost_multiplier(self.new_value)
+                .context("failed to put sequence byte cost multiplier in state")?,
+            FeeChange::Ics20WithdrawalBaseFee => state
+                .put_ics20_withdrawal_base_fee(self.new_value)
+                .context("failed to put ics20 withdrawal base fee in state")?,
+        }
+
+        state.record(
+            FeeChangeEvent {
+                fee_change: self.fee_change,
+                new_value: self.new_value,
+            }
+            .into_abci(),
+        );
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl ActionHandler for FeeAssetChangeAction {
+    /// check that the signer of the transaction is the current sudo address,
+    /// as only that address can change the allowed fee assets
+    async fn check_stateful<S: AuthorityStateReadExt + 'static>(
+        &self,
+        state: &S,
+        from: Address,
+    ) -> Result<()> {
+        // ensure signer is the valid `sudo` key in state
+        let sudo_address = state
+            .get_sudo_address()
+            .await
+            .context("failed to get sudo address from state")?;
+        ensure!(sudo_address == from, "signer is not the sudo key");
+        Ok(())
+    }
+
+    #[instrument(skip_all)]
+    async fn execute<S: FeesStateWriteExt>(&self, state: &mut S, _: Address) -> Result<()> {
+        match self {
+            FeeAssetChangeAction::Addition(asset) => {
+                state.put_allowed_fee_asset(*asset);
+            }
+            FeeAssetChangeAction::Removal(asset) => {
+                state.delete_allowed_fee_asset(*asset);
+
+                // ensure there is always at least one asset that fees can be paid in
+                ensure!(
+                    !state
+                        .get_allowed_fee_assets()
+                        .await
+                        .context("failed to get allowed fee assets from state")?
+                        .is_empty(),
+                    "cannot remove last allowed fee asset"
+                );
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use anyhow::{
+    Context,
+    Result,
+};
+use tendermint::abci::request::{
+    BeginBlock,
+    EndBlock,
+};
+use tracing::instrument;
+
+use super::state_ext::StateWriteExt;
+use crate::{
+    component::Component,
+    genesis::Fees,
+};
+
+#[derive(Default)]
+pub(crate) struct FeesComponent;
+
+#[async_trait::async_trait]
+impl Component for FeesComponent {
+    type AppState = Fees;
+
+    #[instrument(name = "FeesComponent::init_chain", skip(state))]
+    async fn init_chain<S: StateWriteExt>(mut state: S, app_state: &Self::AppState) -> Result<()> {
+        state
+            .put_transfer_base_fee(app_state.transfer_base_fee)
+            .context("failed to put transfer base fee")?;
+        state
+            .put_sequence_base_fee(app_state.sequence_base_fee)
+            .context("failed to put sequence base fee")?;
+        state
+            .put_sequence_byte_cost_multiplier(app_state.sequence_byte_cost_multiplier)
+            .context("failed to put sequence byte cost multiplier")?;
+        state
+            .put_ics20_withdrawal_base_fee(app_state.ics20_withdrawal_base_fee)
+            .context("failed to put ics20 withdrawal base fee")?;
+        Ok(())
+    }
+
+    #[instrument(name = "FeesComponent::begin_block", skip(_state))]
+    async fn begin_block<S: StateWriteExt + 'static>(
+        _state: &mut Arc<S>,
+        _begin_block: &BeginBlock,
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    #[instrument(name = "FeesComponent::end_block", skip(_state))]
+    async fn end_block<S: StateWriteExt + 'static>(
+        _state: &mut Arc<S>,
+        _end_block: &EndBlock,
+    ) -> Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,4 @@
+pub(crate) mod action;
+pub(crate) mod component;
+pub(crate) mod query;
+pub(crate) mod state_ext;
@@ -0,0 +1,155 @@
+use anyhow::Context as _;
+use astria_core::sequencer::v1alpha1::{
+    AbciErrorCode,
+    AllowedFeeAssetsResponse,
+    BlockFeesResponse,
+    FeeScheduleResponse,
+};
+use cnidarium::Storage;
+use prost::Message as _;
+use tendermint::abci::{
+    request,
+    response,
+};
+
+use crate::{
+    accounts::query::get_snapshot_and_height,
+    fees::state_ext::StateReadExt as _,
+    state_ext::StateReadExt as _,
+};
+
+pub(crate) async fn fee_schedule_request(
+    storage: Storage,
+    request: request::Query,
+    _params: Vec<(String, String)>,
+) -> response::Query {
+    let (snapshot, height) = match get_snapshot_and_height(&storage, request.height).await {
+        Ok(tup) => tup,
+        Err(err_rsp) => return err_rsp,
+    };
+
+    let fee_schedule = async {
+        Ok::<_, anyhow::Error>(FeeScheduleResponse {
+            height: height.value(),
+            transfer_base_fee: snapshot
+                .get_transfer_base_fee()
+                .await
+                .context("failed getting transfer base fee")?,
+            sequence_base_fee: snapshot
+                .get_sequence_base_fee()
+                .await
+                .context("failed getting sequence base fee")?,
+            sequence_byte_cost_multiplier: snapshot
+                .get_sequence_byte_cost_multiplier()
+                .await
+                .context("failed getting sequence byte cost multiplier")?,
+            ics20_withdrawal_base_fee: snapshot
+                .get_ics20_withdrawal_base_fee()
+                .await
+                .context("failed getting ics20 withdrawal base fee")?,
+        })
+    }
+    .await;
+    let fee_schedule = match fee_schedule {
+        Ok(fee_schedule) => fee_schedule,
+        Err(err) => {
+            return response::Query {
+                code: AbciErrorCode::INTERNAL_ERROR.into(),
+                info: AbciErrorCode::INTERNAL_ERROR.to_string(),
+                log: format!("failed getting fee schedule: {err:?}"),
+                height,
+                ..response::Query::default()
+            };
+        }
+    };
+
+    let payload = fee_schedule.into_raw().encode_to_vec().into();
+    response::Query {
+        code: 0.into(),
+        key: request.path.clone().into_bytes().into(),
+        value: payload,
+        height,
+        ..response::Query::default()
+    }
+}
+
+/// Returns the fees collected in the block at the requested height and paid out to
+/// its proposer.
+pub(crate) async fn block_fees_request(
+    storage: Storage,
+    request: request::Query,
+    _params: Vec<(String, String)>,
+) -> response::Query {
+    let (snapshot, height) = match get_snapshot_and_height(&storage, request.height).await {
+        Ok(tup) => tup,
+        Err(err_rsp) => return err_rsp,
+    };
+
+    let fees = match snapshot.get_collected_block_fees().await {
+        Ok(fees) => fees,
+        Err(err) => {
+            return response::Query {
+                code: AbciErrorCode::INTERNAL_ERROR.into(),
+                info: AbciErrorCode::INTERNAL_ERROR.to_string(),
+                log: format!("failed getting collected block fees: {err:?}"),
+                height,
+                ..response::Query::default()
+            };
+        }
+    };
+
+    let payload = BlockFeesResponse {
+        height: height.value(),
+        fees,
+    }
+    .into_raw()
+    .encode_to_vec()
+    .into();
+    response::Query {
+        code: 0.into(),
+        key: request.path.clone().into_bytes().into(),
+        value: payload,
+        height,
+        ..response::Query::default()
+    }
+}
+
+/// Returns the assets that fees can be paid in at the requested height.
+pub(crate) async fn allowed_fee_assets_request(
+    storage: Storage,
+    request: request::Query,
+    _params: Vec<(String, String)>,
+) -> response::Query {
+    let (snapshot, height) = match get_snapshot_and_height(&storage, request.height).await {
+        Ok(tup) => tup,
+        Err(err_rsp) => return err_rsp,
+    };
+
+    let fee_asset_ids = match snapshot.get_allowed_fee_assets().await {
+        Ok(fee_asset_ids) => fee_asset_ids,
+        Err(err) => {
+            return response::Query {
+                code: AbciErrorCode::INTERNAL_ERROR.into(),
+                info: AbciErrorCode::INTERNAL_ERROR.to_string(),
+                log: format!("failed getting allowed fee assets: {err:?}"),
+                height,
+                ..response::Query::default()
+            };
+        }
+    };
+
+    let payload = AllowedFeeAssetsResponse {
+        height: height.value(),
+        fee_asset_ids,
+    }
+    .into_raw()
+    .encode_to_vec()
+    .into();
+    response::Query {
+        code: 0.into(),
+        key: request.path.clone().into_bytes().into(),
+        value: payload,
+        height,
+        ..response::Query::default()
+    }
+}
@@ -0,0 +1,166 @@
+use anyhow::{
+    bail,
+    Context,
+    Result,
+};
+use astria_core::sequencer::v1alpha1::asset;
+use async_trait::async_trait;
+use borsh::{
+    BorshDeserialize,
+    BorshSerialize,
+};
+use cnidarium::{
+    StateRead,
+    StateWrite,
+};
+use futures::StreamExt as _;
+use hex::ToHex as _;
+use tracing::instrument;
+
+/// Newtype wrapper to read and write a u128 fee from rocksdb.
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+struct Fee(u128);
+
+const TRANSFER_BASE_FEE_STORAGE_KEY: &str = "fees/transfer_base_fee";
+const SEQUENCE_BASE_FEE_STORAGE_KEY: &str = "fees/sequence_base_fee";
+const SEQUENCE_BYTE_COST_MULTIPLIER_STORAGE_KEY: &str = "fees/sequence_byte_cost_multiplier";
+const ICS20_WITHDRAWAL_BASE_FEE_STORAGE_KEY: &str = "fees/ics20_withdrawal_base_fee";
+const ALLOWED_FEE_ASSET_PREFIX: &str = "fees/allowed_asset/";
+
+fn allowed_fee_asset_storage_key(asset: asset::Id) -> String {
+    format!("{ALLOWED_FEE_ASSET_PREFIX}{}", asset.encode_hex::<String>())
+}
+
+#[async_trait]
+pub(crate) trait StateReadExt: StateRead {
+    #[instrument(skip(self))]
+    async fn get_transfer_base_fee(&self) -> Result<u128> {
+        let Some(bytes) = self
+            .get_raw(TRANSFER_BASE_FEE_STORAGE_KEY)
+            .await
+            .context("failed reading raw transfer base fee from state")?
+        else {
+            // fees must be set at genesis
+            bail!("transfer base fee not found");
+        };
+        let Fee(fee) = Fee::try_from_slice(&bytes).context("invalid fee bytes")?;
+        Ok(fee)
+    }
+
+    #[instrument(skip(self))]
+    async fn get_sequence_base_fee(&self) -> Result<u128> {
+        let Some(bytes) = self
+            .get_raw(SEQUENCE_BASE_FEE_STORAGE_KEY)
+            .await
+            .context("failed reading raw sequence base fee from state")?
+        else {
+            bail!("sequence base fee not found");
+        };
+        let Fee(fee) = Fee::try_from_slice(&bytes).context("invalid fee bytes")?;
+        Ok(fee)
+    }
+
+    #[instrument(skip(self))]
+    async fn get_sequence_byte_cost_multiplier(&self) -> Result<u128> {
+        let Some(bytes) = self
+            .get_raw(SEQUENCE_BYTE_COST_MULTIPLIER_STORAGE_KEY)
+            .await
+            .context("failed reading raw sequence byte cost multiplier from state")?
+        else {
+            bail!("sequence byte cost multiplier not found");
+        };
+        let Fee(fee) = Fee::try_from_slice(&bytes).context("invalid fee bytes")?;
+        Ok(fee)
+    }
+
+    #[instrument(skip(self))]
+    async fn get_ics20_withdrawal_base_fee(&self) -> Result<u128> {
+        let Some(bytes) = self
+            .get_raw(ICS20_WITHDRAWAL_BASE_FEE_STORAGE_KEY)
+            .await
+            .context("failed reading raw ics20 withdrawal base fee from state")?
+        else {
+            bail!("ics20 withdrawal base fee not found");
+        };
+        let Fee(fee) = Fee::try_from_slice(&bytes).context("invalid fee bytes")?;
+        Ok(fee)
+    }
+
+    #[instrument(skip(self))]
+    async fn is_allowed_fee_asset(&self, asset: asset::Id) -> Result<bool> {
+        Ok(self
+            .get_raw(&allowed_fee_asset_storage_key(asset))
+            .await
+            .context("failed to read raw fee asset from state")?
+            .is_some())
+    }
+
+    #[instrument(skip(self))]
+    async fn get_allowed_fee_assets(&self) -> Result<Vec<asset::Id>> {
+        let mut assets = Vec::new();
+
+        let mut stream = std::pin::pin!(self.prefix_keys(ALLOWED_FEE_ASSET_PREFIX));
+        while let Some(key) = stream.next().await {
+            let key = key.context("failed reading allowed fee asset key from state")?;
+            let asset_id_str = key
+                .strip_prefix(ALLOWED_FEE_ASSET_PREFIX)
+                .context("failed to strip prefix from allowed fee asset key")?;
+            let asset_id_bytes = hex::decode(asset_id_str).context("invalid asset id bytes")?;
+            let asset_id = asset::Id::try_from_slice(&asset_id_bytes)
+                .context("failed to parse asset id from allowed fee asset key")?;
+            assets.push(asset_id);
+        }
+
+        Ok(assets)
+    }
+}
+
+impl<T: StateRead> StateReadExt for T {}
+
+#[async_trait]
+pub(crate) trait StateWriteExt: StateWrite {
+    #[instrument(skip(self))]
+    fn put_transfer_base_fee(&mut self, fee: u128) -> Result<()> {
+        let bytes = Fee(fee).try_to_vec().context("failed to serialize fee")?;
+        self.put_raw(TRANSFER_BASE_FEE_STORAGE_KEY.to_string(), bytes);
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    fn put_sequence_base_fee(&mut self, fee: u128) -> Result<()> {
+        let bytes = Fee(fee).try_to_vec().context("failed to serialize fee")?;
+        self.put_raw(SEQUENCE_BASE_FEE_STORAGE_KEY.to_string(), bytes);
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    fn put_sequence_byte_cost_multiplier(&mut self, multiplier: u128) -> Result<()> {
+        let bytes = Fee(multiplier)
+            .try_to_vec()
+            .context("failed to serialize fee")?;
+        self.put_raw(SEQUENCE_BYTE_COST_MULTIPLIER_STORAGE_KEY.to_string(), bytes);
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    fn put_ics20_withdrawal_base_fee(&mut self, fee: u128) -> Result<()> {
+        let bytes = Fee(fee).try_to_vec().context("failed to serialize fee")?;
+        self.put_raw(ICS20_WITHDRAWAL_BASE_FEE_STORAGE_KEY.to_string(), bytes);
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    fn put_allowed_fee_asset(&mut self, asset: asset::Id) {
+        self.put_raw(
+            allowed_fee_asset_storage_key(asset),
+            asset.as_ref().to_vec(),
+        );
+    }
+
+    #[instrument(skip(self))]
+    fn delete_allowed_fee_asset(&mut self, asset: asset::Id) {
+        self.delete(allowed_fee_asset_storage_key(asset));
+    }
+}
+
+impl<T: StateWrite> StateWriteExt for T {}
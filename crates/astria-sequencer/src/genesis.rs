@@ -1,27 +1,394 @@
-use astria_core::sequencer::v1alpha1::Address;
+use std::{
+    collections::{
+        HashMap,
+        HashSet,
+    },
+    fmt,
+};
+
+use astria_core::sequencer::v1alpha1::{
+    asset::{
+        self,
+        Denom,
+    },
+    Address,
+    RollupId,
+};
+use borsh::{
+    BorshDeserialize,
+    BorshSerialize,
+};
 use serde::{
     Deserialize,
     Deserializer,
+    Serialize,
+    Serializer,
 };
+use tendermint::validator;
 
 /// The genesis state for the application.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub(crate) struct GenesisState {
     pub(crate) accounts: Vec<Account>,
-    #[serde(deserialize_with = "deserialize_address")]
+    #[serde(
+        deserialize_with = "deserialize_address",
+        serialize_with = "serialize_address"
+    )]
     pub(crate) authority_sudo_address: Address,
-    #[serde(deserialize_with = "deserialize_address")]
+    #[serde(
+        deserialize_with = "deserialize_address",
+        serialize_with = "serialize_address"
+    )]
     pub(crate) ibc_sudo_address: Address,
+    /// The addresses allowed to relay IBC packets.
+    #[serde(
+        deserialize_with = "deserialize_addresses",
+        serialize_with = "serialize_addresses"
+    )]
+    pub(crate) ibc_relayer_addresses: Vec<Address>,
     pub(crate) native_asset_base_denomination: String,
+    pub(crate) fees: Fees,
+    /// The denominations of the assets that fees can be paid in.
+    pub(crate) allowed_fee_asset_denominations: Vec<String>,
+    /// The IBC assets that exist at genesis, given by their denomination traces,
+    /// eg. `transfer/channel-0/utia`.
+    #[serde(default)]
+    pub(crate) ibc_assets: Vec<String>,
+    /// Account balances in assets other than the native asset.
+    #[serde(default)]
+    pub(crate) asset_balances: Vec<AssetBalance>,
+    /// The display metadata of the native asset and of genesis IBC assets.
+    #[serde(default)]
+    pub(crate) asset_metadata: Vec<AssetMetadata>,
+    #[serde(default)]
+    pub(crate) ibc_params: IbcParameters,
+    /// The initial validator set, which is only used if the cometbft genesis
+    /// does not list any validators.
+    #[serde(default)]
+    pub(crate) validators: Vec<validator::Update>,
+    /// The quotas of sequence data each rollup can take up in a block.
+    #[serde(default)]
+    pub(crate) blockspace: BlockspaceQuotas,
+    /// The caps on minting the native asset with the `mint` feature.
+    #[serde(default)]
+    pub(crate) mint_caps: MintCaps,
+    /// The amount of the native asset minted before genesis, which counts towards the
+    /// total mint cap. Only set when exporting the state of a running chain.
+    #[serde(default)]
+    pub(crate) minted_supply: u128,
 }
 
-#[derive(Debug, Deserialize)]
+impl GenesisState {
+    /// Checks that the genesis state is consistent and can be applied.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error listing every problem found in the genesis state.
+    pub(crate) fn validate(&self) -> Result<(), InvalidGenesis> {
+        let mut problems = Vec::new();
+
+        let native_denom = &self.native_asset_base_denomination;
+        if native_denom.is_empty() {
+            problems.push("native asset base denomination must not be empty".to_string());
+        } else if native_denom.contains('/') {
+            problems.push(format!(
+                "native asset base denomination `{native_denom}` must not contain a `/`"
+            ));
+        }
+
+        let mut account_addresses = HashSet::new();
+        for account in &self.accounts {
+            if !account_addresses.insert(account.address.0) {
+                problems.push(format!(
+                    "account {} is listed more than once",
+                    account.address
+                ));
+            }
+        }
+
+        let mut relayer_addresses = HashSet::new();
+        for address in &self.ibc_relayer_addresses {
+            if !relayer_addresses.insert(address.0) {
+                problems.push(format!("IBC relayer {address} is listed more than once"));
+            }
+        }
+
+        let mut ibc_assets = HashSet::new();
+        for trace in &self.ibc_assets {
+            let denom = Denom::from(trace.as_str());
+            if denom.prefix().is_empty() || denom.base_denom().is_empty() {
+                problems.push(format!(
+                    "IBC asset `{trace}` must be given as `<prefix>/<base denomination>`"
+                ));
+            }
+            if !ibc_assets.insert(trace.as_str()) {
+                problems.push(format!("IBC asset `{trace}` is listed more than once"));
+            }
+        }
+
+        let mut asset_balances = HashSet::new();
+        for AssetBalance {
+            address,
+            denom,
+            ..
+        } in &self.asset_balances
+        {
+            if !ibc_assets.contains(denom.as_str()) {
+                problems.push(format!(
+                    "balance of account {address} is in `{denom}`, which is not a genesis IBC \
+                     asset"
+                ));
+            }
+            if !asset_balances.insert((address.0, denom.as_str())) {
+                problems.push(format!(
+                    "balance of account {address} in `{denom}` is listed more than once"
+                ));
+            }
+        }
+
+        let mut metadata_denoms = HashSet::new();
+        for metadata in &self.asset_metadata {
+            let denom = &metadata.denom;
+            if denom != native_denom && !ibc_assets.contains(denom.as_str()) {
+                problems.push(format!(
+                    "metadata is given for `{denom}`, which is neither the native asset nor a \
+                     genesis IBC asset"
+                ));
+            }
+            if !metadata_denoms.insert(denom.as_str()) {
+                problems.push(format!("metadata of `{denom}` is listed more than once"));
+            }
+            if let Err(err) = crate::asset::check_metadata(&metadata.to_metadata()) {
+                problems.push(format!("metadata of `{denom}` is invalid: {err}"));
+            }
+        }
+
+        let mut supplies: HashMap<&str, u128> = HashMap::new();
+        let balances = self
+            .accounts
+            .iter()
+            .map(|account| (native_denom.as_str(), account.balance))
+            .chain(
+                self.asset_balances
+                    .iter()
+                    .map(|balance| (balance.denom.as_str(), balance.balance)),
+            );
+        for (denom, balance) in balances {
+            let supply = supplies.entry(denom).or_default();
+            let Some(new_supply) = supply.checked_add(balance) else {
+                problems.push(format!("total supply of `{denom}` overflows u128"));
+                continue;
+            };
+            *supply = new_supply;
+        }
+
+        if self.allowed_fee_asset_denominations.is_empty() {
+            problems.push("at least one fee asset must be allowed".to_string());
+        }
+        let mut fee_assets = HashSet::new();
+        for denom in &self.allowed_fee_asset_denominations {
+            if denom != native_denom && !ibc_assets.contains(denom.as_str()) {
+                problems.push(format!(
+                    "allowed fee asset `{denom}` is neither the native asset nor a genesis IBC \
+                     asset"
+                ));
+            }
+            if !fee_assets.insert(denom.as_str()) {
+                problems.push(format!(
+                    "allowed fee asset `{denom}` is listed more than once"
+                ));
+            }
+        }
+
+        let IbcParameters {
+            ibc_enabled,
+            inbound_ics20_transfers_enabled,
+            outbound_ics20_transfers_enabled,
+        } = self.ibc_params;
+        if !ibc_enabled && (inbound_ics20_transfers_enabled || outbound_ics20_transfers_enabled) {
+            problems.push("ICS20 transfers cannot be enabled while IBC is disabled".to_string());
+        }
+
+        let mut validator_keys = HashSet::new();
+        for validator in &self.validators {
+            if !validator_keys.insert(validator.pub_key.to_bytes()) {
+                problems.push(format!(
+                    "validator {} is listed more than once",
+                    hex::encode(validator.pub_key.to_bytes())
+                ));
+            }
+        }
+
+        let mut quota_rollup_ids = HashSet::new();
+        for quota in &self.blockspace.rollup_quotas {
+            if !quota_rollup_ids.insert(quota.rollup_id) {
+                problems.push(format!(
+                    "rollup {} has more than one blockspace quota",
+                    quota.rollup_id
+                ));
+            }
+        }
+
+        if self.minted_supply > self.mint_caps.max_total {
+            problems.push(format!(
+                "minted supply of {} exceeds the total mint cap of {}",
+                self.minted_supply, self.mint_caps.max_total
+            ));
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(InvalidGenesis(problems))
+        }
+    }
+}
+
+/// The problems found when validating a [`GenesisState`].
+#[derive(Debug)]
+pub(crate) struct InvalidGenesis(pub(crate) Vec<String>);
+
+impl fmt::Display for InvalidGenesis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "found {} problem(s) in genesis state: {}",
+            self.0.len(),
+            self.0.join("; ")
+        )
+    }
+}
+
+impl std::error::Error for InvalidGenesis {}
+
+#[derive(Debug, Deserialize, Serialize)]
 pub(crate) struct Account {
-    #[serde(deserialize_with = "deserialize_address")]
+    #[serde(
+        deserialize_with = "deserialize_address",
+        serialize_with = "serialize_address"
+    )]
+    pub(crate) address: Address,
+    pub(crate) balance: u128,
+    #[serde(default)]
+    pub(crate) nonce: u32,
+}
+
+/// The balance of an account in an asset other than the native asset.
+#[derive(Debug, Deserialize, Serialize)]
+pub(crate) struct AssetBalance {
+    #[serde(
+        deserialize_with = "deserialize_address",
+        serialize_with = "serialize_address"
+    )]
     pub(crate) address: Address,
+    /// The denomination trace of the asset.
+    pub(crate) denom: String,
     pub(crate) balance: u128,
 }
 
+/// The display metadata of an asset.
+#[derive(Debug, Deserialize, Serialize)]
+pub(crate) struct AssetMetadata {
+    /// The denomination trace of the asset.
+    pub(crate) denom: String,
+    pub(crate) decimals: u32,
+    pub(crate) symbol: String,
+    pub(crate) display_denom: String,
+}
+
+impl AssetMetadata {
+    pub(crate) fn to_metadata(&self) -> asset::Metadata {
+        asset::Metadata {
+            decimals: self.decimals,
+            symbol: self.symbol.clone(),
+            display_denom: self.display_denom.clone(),
+        }
+    }
+}
+
+/// The IBC parameters of the chain.
+///
+/// All of IBC is enabled if these are not given at genesis.
+#[derive(
+    BorshSerialize, BorshDeserialize, Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq,
+)]
+pub(crate) struct IbcParameters {
+    /// Whether IBC messages are accepted at all.
+    pub(crate) ibc_enabled: bool,
+    /// Whether ICS20 transfers into the sequencer are accepted.
+    pub(crate) inbound_ics20_transfers_enabled: bool,
+    /// Whether ICS20 withdrawals out of the sequencer are allowed.
+    pub(crate) outbound_ics20_transfers_enabled: bool,
+}
+
+impl Default for IbcParameters {
+    fn default() -> Self {
+        Self {
+            ibc_enabled: true,
+            inbound_ics20_transfers_enabled: true,
+            outbound_ics20_transfers_enabled: true,
+        }
+    }
+}
+
+/// The quotas of sequence data bytes rollups can take up in a block.
+///
+/// Rollups can take up all of a block's sequence data if these are not given at genesis.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub(crate) struct BlockspaceQuotas {
+    /// The quota of rollups without a quota of their own.
+    #[serde(default)]
+    pub(crate) default_rollup_quota: Option<u64>,
+    #[serde(default)]
+    pub(crate) rollup_quotas: Vec<RollupQuota>,
+}
+
+/// The quota of sequence data bytes of a single rollup.
+#[derive(Debug, Deserialize, Serialize)]
+pub(crate) struct RollupQuota {
+    #[serde(
+        deserialize_with = "deserialize_rollup_id",
+        serialize_with = "hex::serde::serialize"
+    )]
+    pub(crate) rollup_id: RollupId,
+    pub(crate) max_sequence_data_bytes: u64,
+}
+
+/// The caps on the amount of the native asset the sudo address can mint.
+///
+/// A cap which is not given at genesis is zero, so minting is only possible up to caps
+/// that were set explicitly. The caps can only be set at genesis; changing them on a
+/// running chain requires a state migration run by a scheduled upgrade.
+#[derive(
+    BorshSerialize,
+    BorshDeserialize,
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Deserialize,
+    Serialize,
+    PartialEq,
+    Eq,
+)]
+pub(crate) struct MintCaps {
+    /// The maximum amount that can be minted in a single block.
+    #[serde(default)]
+    pub(crate) max_per_block: u128,
+    /// The maximum amount that can be minted over the lifetime of the chain.
+    #[serde(default)]
+    pub(crate) max_total: u128,
+}
+
+/// The initial fee schedule of the chain.
+#[derive(Debug, Deserialize, Serialize)]
+pub(crate) struct Fees {
+    pub(crate) transfer_base_fee: u128,
+    pub(crate) sequence_base_fee: u128,
+    pub(crate) sequence_byte_cost_multiplier: u128,
+    pub(crate) ics20_withdrawal_base_fee: u128,
+}
+
 fn deserialize_address<'de, D>(deserializer: D) -> Result<Address, D::Error>
 where
     D: Deserializer<'de>,
@@ -31,3 +398,45 @@ where
     Address::try_from_slice(&bytes)
         .map_err(|e| D::Error::custom(format!("failed constructing address from bytes: {e}")))
 }
+
+fn deserialize_addresses<'de, D>(deserializer: D) -> Result<Vec<Address>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    use serde::de::Error as _;
+    let addresses: Vec<String> = Deserialize::deserialize(deserializer)?;
+    addresses
+        .into_iter()
+        .map(|address| {
+            let bytes = hex::decode(address)
+                .map_err(|e| D::Error::custom(format!("failed decoding address from hex: {e}")))?;
+            Address::try_from_slice(&bytes).map_err(|e| {
+                D::Error::custom(format!("failed constructing address from bytes: {e}"))
+            })
+        })
+        .collect()
+}
+
+fn deserialize_rollup_id<'de, D>(deserializer: D) -> Result<RollupId, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    use serde::de::Error as _;
+    let bytes: Vec<u8> = hex::serde::deserialize(deserializer)?;
+    RollupId::try_from_slice(&bytes)
+        .map_err(|e| D::Error::custom(format!("failed constructing rollup id from bytes: {e}")))
+}
+
+fn serialize_address<S>(address: &Address, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    hex::serde::serialize(address, serializer)
+}
+
+fn serialize_addresses<S>(addresses: &[Address], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.collect_seq(addresses.iter().map(hex::encode))
+}
@@ -0,0 +1,360 @@
+//! Exporting the state of a chain as a genesis state.
+//!
+//! This is used to restart a network from its current state after an incident, or to fork
+//! the state of a network into a local test network.
+//!
+//! Only state which can be expressed in a [`GenesisState`] is exported. Notably, bridge
+//! accounts, IBC channel escrow balances, IBC client, connection and channel state, and
+//! the ICS20 channel allowlist and rate limits are not exported. Total asset supplies are
+//! not exported either, but recomputed from the exported balances when the genesis state
+//! is applied.
+
+use std::{
+    collections::HashMap,
+    path::PathBuf,
+};
+
+use anyhow::{
+    ensure,
+    Context as _,
+};
+use astria_core::sequencer::v1alpha1::asset;
+use cnidarium::{
+    StateRead,
+    Storage,
+};
+
+use crate::{
+    accounts::state_ext::StateReadExt as _,
+    asset::state_ext::StateReadExt as _,
+    authority::state_ext::StateReadExt as _,
+    blockspace::state_ext::StateReadExt as _,
+    fees::state_ext::StateReadExt as _,
+    genesis::{
+        Account,
+        AssetBalance,
+        AssetMetadata,
+        BlockspaceQuotas,
+        Fees,
+        GenesisState,
+        RollupQuota,
+    },
+    ibc::state_ext::StateReadExt as _,
+    mint::state_ext::StateReadExt as _,
+    state_ext::StateReadExt as _,
+    state_sync::SUBSTORE_PREFIXES,
+};
+
+/// Exports the state of the sequencer database at `db_path` as a genesis app state.
+///
+/// The state at `height` is exported, or the latest state if `height` is `None`.
+/// The genesis state is returned as pretty-printed JSON.
+///
+/// # Errors
+///
+/// Returns an error if the database cannot be opened, if it has no state at `height`,
+/// or if the state cannot be read.
+pub async fn export_genesis_state(db_path: PathBuf, height: Option<u64>) -> anyhow::Result<String> {
+    let storage = Storage::load(
+        db_path,
+        SUBSTORE_PREFIXES
+            .iter()
+            .map(std::string::ToString::to_string)
+            .collect(),
+    )
+    .await
+    .context("failed to load storage backing chain state")?;
+    // the storage version is u64::MAX until the chain is initialized
+    ensure!(
+        storage.latest_version() != u64::MAX,
+        "database has not been initialized"
+    );
+
+    let snapshot = match height {
+        None => storage.latest_snapshot(),
+        Some(height) => {
+            let version = storage
+                .latest_snapshot()
+                .get_storage_version_by_height(height)
+                .await
+                .context("failed to get storage version from height")?;
+            storage
+                .snapshot(version)
+                .with_context(|| format!("state at height {height} is not available"))?
+        }
+    };
+
+    let genesis_state = genesis_state_from_state(&snapshot)
+        .await
+        .context("failed to read genesis state from state")?;
+    serde_json::to_string_pretty(&genesis_state).context("failed to serialize genesis state")
+}
+
+pub(crate) async fn genesis_state_from_state<S: StateRead>(
+    state: &S,
+) -> anyhow::Result<GenesisState> {
+    let native_asset_base_denomination = state
+        .get_native_asset_denom()
+        .await
+        .context("failed to get native asset denom")?;
+    let native_asset_id = asset::Id::from_denom(&native_asset_base_denomination);
+
+    let ibc_assets: Vec<String> = state
+        .get_ibc_assets()
+        .await
+        .context("failed to get IBC assets")?
+        .iter()
+        .map(asset::Denom::denomination_trace)
+        .collect();
+    let mut denoms: HashMap<asset::Id, &str> = ibc_assets
+        .iter()
+        .map(|trace| (asset::Id::from_denom(trace), trace.as_str()))
+        .collect();
+    denoms.insert(native_asset_id, &native_asset_base_denomination);
+    let denom_of = |id: asset::Id| {
+        denoms
+            .get(&id)
+            .map(ToString::to_string)
+            .with_context(|| format!("asset `{id}` is not registered"))
+    };
+
+    let mut accounts = Vec::new();
+    let mut asset_balances = Vec::new();
+    let stored_accounts = state
+        .get_accounts()
+        .await
+        .context("failed to get accounts")?;
+    for (address, stored_account) in stored_accounts {
+        let mut native_balance = 0;
+        for (asset_id, balance) in stored_account.balances {
+            if asset_id == native_asset_id {
+                native_balance = balance;
+                continue;
+            }
+            asset_balances.push(AssetBalance {
+                address,
+                denom: denom_of(asset_id)?,
+                balance,
+            });
+        }
+        accounts.push(Account {
+            address,
+            balance: native_balance,
+            nonce: stored_account.nonce,
+        });
+    }
+
+    let mut asset_metadata = Vec::new();
+    for denom in std::iter::once(&native_asset_base_denomination).chain(&ibc_assets) {
+        let Some(metadata) = state
+            .get_asset_metadata(asset::Id::from_denom(denom))
+            .await
+            .context("failed to get asset metadata")?
+        else {
+            continue;
+        };
+        asset_metadata.push(AssetMetadata {
+            denom: denom.clone(),
+            decimals: metadata.decimals,
+            symbol: metadata.symbol,
+            display_denom: metadata.display_denom,
+        });
+    }
+
+    let allowed_fee_asset_denominations = state
+        .get_allowed_fee_assets()
+        .await
+        .context("failed to get allowed fee assets")?
+        .into_iter()
+        .map(denom_of)
+        .collect::<anyhow::Result<_>>()?;
+
+    let fees = Fees {
+        transfer_base_fee: state
+            .get_transfer_base_fee()
+            .await
+            .context("failed to get transfer base fee")?,
+        sequence_base_fee: state
+            .get_sequence_base_fee()
+            .await
+            .context("failed to get sequence base fee")?,
+        sequence_byte_cost_multiplier: state
+            .get_sequence_byte_cost_multiplier()
+            .await
+            .context("failed to get sequence byte cost multiplier")?,
+        ics20_withdrawal_base_fee: state
+            .get_ics20_withdrawal_base_fee()
+            .await
+            .context("failed to get ics20 withdrawal base fee")?,
+    };
+
+    let blockspace = BlockspaceQuotas {
+        default_rollup_quota: state
+            .get_default_rollup_quota()
+            .await
+            .context("failed to get default rollup quota")?,
+        rollup_quotas: state
+            .get_rollup_quotas()
+            .await
+            .context("failed to get rollup quotas")?
+            .into_iter()
+            .map(|(rollup_id, max_sequence_data_bytes)| RollupQuota {
+                rollup_id,
+                max_sequence_data_bytes,
+            })
+            .collect(),
+    };
+
+    Ok(GenesisState {
+        accounts,
+        authority_sudo_address: state
+            .get_sudo_address()
+            .await
+            .context("failed to get sudo address")?,
+        ibc_sudo_address: state
+            .get_ibc_sudo_address()
+            .await
+            .context("failed to get IBC sudo address")?,
+        ibc_relayer_addresses: state
+            .get_ibc_relayer_addresses()
+            .await
+            .context("failed to get IBC relayer addresses")?,
+        native_asset_base_denomination,
+        fees,
+        allowed_fee_asset_denominations,
+        ibc_assets,
+        asset_balances,
+        asset_metadata,
+        ibc_params: state
+            .get_ibc_params()
+            .await
+            .context("failed to get IBC params")?,
+        validators: state
+            .get_validator_set()
+            .await
+            .context("failed to get validator set")?
+            .into_tendermint_validator_updates(),
+        blockspace,
+        mint_caps: state
+            .get_mint_caps()
+            .await
+            .context("failed to get mint caps")?,
+        minted_supply: state
+            .get_minted_supply()
+            .await
+            .context("failed to get minted supply")?,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use astria_core::sequencer::v1alpha1::{
+        asset::DEFAULT_NATIVE_ASSET_DENOM,
+        Address,
+        RollupId,
+    };
+    use tendermint::{
+        validator,
+        PublicKey,
+    };
+
+    use super::*;
+    use crate::{
+        app::App,
+        genesis::{
+            IbcParameters,
+            MintCaps,
+        },
+        mempool::Mempool,
+        upgrade::test_consensus_params,
+    };
+
+    #[tokio::test]
+    async fn exported_genesis_state_matches_applied_genesis_state() {
+        let genesis_state = GenesisState {
+            accounts: vec![
+                Account {
+                    address: Address::from([1; 20]),
+                    balance: 10u128.pow(19),
+                    nonce: 0,
+                },
+                Account {
+                    address: Address::from([2; 20]),
+                    balance: 100,
+                    nonce: 5,
+                },
+            ],
+            authority_sudo_address: Address::from([1; 20]),
+            ibc_sudo_address: Address::from([2; 20]),
+            ibc_relayer_addresses: vec![Address::from([3; 20])],
+            native_asset_base_denomination: DEFAULT_NATIVE_ASSET_DENOM.to_string(),
+            fees: Fees {
+                transfer_base_fee: 12,
+                sequence_base_fee: 0,
+                sequence_byte_cost_multiplier: 1,
+                ics20_withdrawal_base_fee: 1,
+            },
+            allowed_fee_asset_denominations: vec![DEFAULT_NATIVE_ASSET_DENOM.to_string()],
+            ibc_assets: vec!["transfer/channel-0/utia".to_string()],
+            asset_balances: vec![AssetBalance {
+                address: Address::from([2; 20]),
+                denom: "transfer/channel-0/utia".to_string(),
+                balance: 50,
+            }],
+            asset_metadata: vec![
+                AssetMetadata {
+                    denom: DEFAULT_NATIVE_ASSET_DENOM.to_string(),
+                    decimals: 9,
+                    symbol: "RIA".to_string(),
+                    display_denom: "ria".to_string(),
+                },
+                AssetMetadata {
+                    denom: "transfer/channel-0/utia".to_string(),
+                    decimals: 6,
+                    symbol: "TIA".to_string(),
+                    display_denom: "tia".to_string(),
+                },
+            ],
+            ibc_params: IbcParameters {
+                ibc_enabled: true,
+                inbound_ics20_transfers_enabled: true,
+                outbound_ics20_transfers_enabled: false,
+            },
+            validators: vec![validator::Update {
+                pub_key: PublicKey::from_raw_ed25519(&[1; 32]).unwrap(),
+                power: 10u32.into(),
+            }],
+            blockspace: BlockspaceQuotas {
+                default_rollup_quota: Some(100_000),
+                rollup_quotas: vec![RollupQuota {
+                    rollup_id: RollupId::new([1; 32]),
+                    max_sequence_data_bytes: 200_000,
+                }],
+            },
+            mint_caps: MintCaps {
+                max_per_block: 1_000,
+                max_total: 1_000_000,
+            },
+            minted_supply: 5_000,
+        };
+        let expected = serde_json::to_string_pretty(&genesis_state).unwrap();
+
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut app = App::new(storage.latest_snapshot(), Mempool::new(), 0);
+        let validators = genesis_state.validators.clone();
+        app.init_chain(
+            genesis_state,
+            validators,
+            "test".to_string(),
+            test_consensus_params(),
+        )
+        .await
+        .unwrap();
+        app.commit((*storage).clone()).await;
+
+        let exported = genesis_state_from_state(&storage.latest_snapshot())
+            .await
+            .unwrap();
+        assert_eq!(serde_json::to_string_pretty(&exported).unwrap(), expected);
+    }
+}
@@ -0,0 +1,2 @@
+pub(crate) mod sequencer;
+pub(crate) mod state_ext;
@@ -0,0 +1,171 @@
+use astria_core::{
+    generated::sequencer::v1alpha1::{
+        sequencer_service_server::SequencerService,
+        GetRollupTransactionsRequest,
+        GetSequencerBlockRequest,
+        RollupTransactionsWithProof as RawRollupTransactionsWithProof,
+        SequencerBlock as RawSequencerBlock,
+    },
+    sequencer::v1alpha1::{
+        RollupId,
+        SequencerBlock,
+    },
+};
+use cnidarium::Storage;
+use tonic::{
+    Request,
+    Response,
+    Status,
+};
+use tracing::instrument;
+
+use super::state_ext::StateReadExt as _;
+use crate::state_ext::StateReadExt as _;
+
+/// Serves sequencer blocks and the rollup data contained in them over gRPC.
+pub(crate) struct SequencerServer {
+    storage: Storage,
+}
+
+impl SequencerServer {
+    pub(crate) fn new(storage: Storage) -> Self {
+        Self {
+            storage,
+        }
+    }
+
+    /// Returns the sequencer block stored at `height`.
+    ///
+    /// Returns a `NOT_FOUND` status if the height has not been reached yet or no
+    /// sequencer block was stored for it.
+    async fn sequencer_block_at_height(&self, height: u64) -> Result<SequencerBlock, Status> {
+        let snapshot = self.storage.latest_snapshot();
+
+        let current_height = snapshot.get_block_height().await.map_err(|e| {
+            Status::internal(format!("failed to get block height from storage: {e:#}"))
+        })?;
+        if height > current_height {
+            return Err(Status::not_found(format!(
+                "requested height `{height}` is greater than the current block height \
+                 `{current_height}`"
+            )));
+        }
+
+        snapshot
+            .get_sequencer_block_by_height(height)
+            .await
+            .map_err(|e| {
+                Status::internal(format!("failed to get sequencer block from storage: {e:#}"))
+            })?
+            .ok_or_else(|| {
+                Status::not_found(format!("no sequencer block stored for height `{height}`"))
+            })
+    }
+}
+
+#[tonic::async_trait]
+impl SequencerService for SequencerServer {
+    #[instrument(skip_all, fields(height = request.get_ref().height))]
+    async fn get_sequencer_block(
+        &self,
+        request: Request<GetSequencerBlockRequest>,
+    ) -> Result<Response<RawSequencerBlock>, Status> {
+        let GetSequencerBlockRequest {
+            height,
+        } = request.into_inner();
+        if height == 0 {
+            return Err(Status::invalid_argument("height cannot be 0"));
+        }
+
+        let block = self.sequencer_block_at_height(height).await?;
+        Ok(Response::new(block.into_raw()))
+    }
+
+    #[instrument(skip_all, fields(height = request.get_ref().height))]
+    async fn get_rollup_transactions(
+        &self,
+        request: Request<GetRollupTransactionsRequest>,
+    ) -> Result<Response<RawRollupTransactionsWithProof>, Status> {
+        let GetRollupTransactionsRequest {
+            height,
+            rollup_id,
+        } = request.into_inner();
+        if height == 0 {
+            return Err(Status::invalid_argument("height cannot be 0"));
+        }
+        let rollup_id = RollupId::try_from_slice(&rollup_id)
+            .map_err(|e| Status::invalid_argument(format!("invalid rollup ID: {e}")))?;
+
+        let block = self.sequencer_block_at_height(height).await?;
+        let Some(rollup_transactions) = block.rollup_transactions_with_proof(rollup_id) else {
+            return Err(Status::not_found(format!(
+                "no transactions for rollup `{rollup_id}` at height `{height}`"
+            )));
+        };
+        Ok(Response::new(rollup_transactions.into_raw()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use astria_core::sequencer::v1alpha1::{
+        test_utils::make_cometbft_block,
+        RollupTransactionsWithProof,
+    };
+    use cnidarium::StateDelta;
+
+    use super::*;
+    use crate::{
+        grpc::state_ext::StateWriteExt as _,
+        state_ext::StateWriteExt as _,
+    };
+
+    #[tokio::test]
+    async fn get_sequencer_block_and_rollup_transactions() {
+        let block = SequencerBlock::try_from_cometbft(make_cometbft_block()).unwrap();
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_block_height(1);
+        state.put_sequencer_block(block.clone());
+        storage.commit(state).await.unwrap();
+
+        let server = SequencerServer::new(storage.clone());
+        let request = GetSequencerBlockRequest {
+            height: 1,
+        };
+        let response = server
+            .get_sequencer_block(Request::new(request))
+            .await
+            .unwrap();
+        assert_eq!(
+            SequencerBlock::try_from_raw(response.into_inner()).unwrap(),
+            block
+        );
+
+        let rollup_id = RollupId::from_unhashed_bytes(b"test_chain_id_1");
+        let request = GetRollupTransactionsRequest {
+            height: 1,
+            rollup_id: rollup_id.to_vec(),
+        };
+        let response = server
+            .get_rollup_transactions(Request::new(request))
+            .await
+            .unwrap();
+        let rollup_transactions =
+            RollupTransactionsWithProof::try_from_raw(response.into_inner()).unwrap();
+        assert_eq!(
+            rollup_transactions.transactions(),
+            block.rollup_transactions()[&rollup_id].as_slice(),
+        );
+        assert!(rollup_transactions.is_included());
+
+        let request = GetSequencerBlockRequest {
+            height: 2,
+        };
+        let status = server
+            .get_sequencer_block(Request::new(request))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::NotFound);
+    }
+}
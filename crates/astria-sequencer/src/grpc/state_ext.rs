@@ -0,0 +1,174 @@
+use anyhow::{
+    bail,
+    Context as _,
+    Result,
+};
+use astria_core::{
+    generated::sequencer::v1alpha1 as raw,
+    sequencer::v1alpha1::SequencerBlock,
+};
+use async_trait::async_trait;
+use cnidarium::{
+    StateRead,
+    StateWrite,
+};
+use prost::Message as _;
+use tracing::instrument;
+
+const SEQUENCER_BLOCK_PREFIX: &str = "sequencer_block/";
+const OLDEST_SEQUENCER_BLOCK_HEIGHT_KEY: &[u8] = b"oldest_sequencer_block_height";
+
+fn sequencer_block_key(height: u64) -> Vec<u8> {
+    format!("{SEQUENCER_BLOCK_PREFIX}{height}").into()
+}
+
+#[async_trait]
+pub(crate) trait StateReadExt: StateRead {
+    /// Returns the sequencer block at `height`, or `None` if no block was stored
+    /// for that height.
+    #[instrument(skip(self))]
+    async fn get_sequencer_block_by_height(&self, height: u64) -> Result<Option<SequencerBlock>> {
+        let Some(bytes) = self
+            .nonverifiable_get_raw(&sequencer_block_key(height))
+            .await
+            .context("failed to read raw sequencer block from state")?
+        else {
+            return Ok(None);
+        };
+        let raw = raw::SequencerBlock::decode(&*bytes).context("invalid sequencer block bytes")?;
+        let block = SequencerBlock::try_from_raw(raw)
+            .context("failed converting raw sequencer block to native sequencer block")?;
+        Ok(Some(block))
+    }
+
+    /// Returns the height from which on sequencer blocks are retained, or `None` if no
+    /// sequencer block was stored yet.
+    #[instrument(skip(self))]
+    async fn get_oldest_sequencer_block_height(&self) -> Result<Option<u64>> {
+        let Some(bytes) = self
+            .nonverifiable_get_raw(OLDEST_SEQUENCER_BLOCK_HEIGHT_KEY)
+            .await
+            .context("failed to read raw oldest sequencer block height from state")?
+        else {
+            return Ok(None);
+        };
+        let Ok(bytes): Result<[u8; 8], _> = bytes.try_into() else {
+            bail!("failed turning raw oldest sequencer block height bytes into u64; not 8 bytes?");
+        };
+        Ok(Some(u64::from_be_bytes(bytes)))
+    }
+}
+
+impl<T: StateRead> StateReadExt for T {}
+
+#[async_trait]
+pub(crate) trait StateWriteExt: StateWrite {
+    #[instrument(skip_all)]
+    fn put_sequencer_block(&mut self, block: SequencerBlock) {
+        let key = sequencer_block_key(block.height().value());
+        self.nonverifiable_put_raw(key, block.into_raw().encode_to_vec());
+    }
+
+    /// Deletes the stored sequencer blocks which are not among the `retention` most recent
+    /// blocks up to and including `height`. All blocks are kept if `retention` is 0.
+    ///
+    /// Sequencer blocks are local to a node and are not part of state sync snapshots, so
+    /// pruning them does not affect consensus.
+    #[instrument(skip(self))]
+    async fn prune_sequencer_blocks(&mut self, height: u64, retention: u64) -> Result<()> {
+        // this is called after every stored block, so no block below `height` was stored
+        // if no height was recorded yet
+        let oldest = self
+            .get_oldest_sequencer_block_height()
+            .await
+            .context("failed to get oldest sequencer block height")?
+            .unwrap_or(height);
+        let keep_from = match retention.checked_sub(1) {
+            Some(older_blocks) => height.saturating_sub(older_blocks).max(oldest),
+            None => oldest,
+        };
+        for pruned_height in oldest..keep_from {
+            self.nonverifiable_delete(sequencer_block_key(pruned_height));
+        }
+        self.nonverifiable_put_raw(
+            OLDEST_SEQUENCER_BLOCK_HEIGHT_KEY.to_vec(),
+            keep_from.to_be_bytes().to_vec(),
+        );
+        Ok(())
+    }
+}
+
+impl<T: StateWrite> StateWriteExt for T {}
+
+#[cfg(test)]
+mod test {
+    use astria_core::sequencer::v1alpha1::test_utils::make_cometbft_block;
+    use cnidarium::StateDelta;
+
+    use super::{
+        StateReadExt as _,
+        StateWriteExt as _,
+    };
+
+    #[tokio::test]
+    async fn put_sequencer_block() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let block = astria_core::sequencer::v1alpha1::SequencerBlock::try_from_cometbft(
+            make_cometbft_block(),
+        )
+        .unwrap();
+        let height = block.height().value();
+
+        assert!(state
+            .get_sequencer_block_by_height(height)
+            .await
+            .unwrap()
+            .is_none());
+        state.put_sequencer_block(block.clone());
+        assert_eq!(
+            state
+                .get_sequencer_block_by_height(height)
+                .await
+                .unwrap()
+                .unwrap(),
+            block,
+        );
+    }
+
+    #[tokio::test]
+    async fn prune_sequencer_blocks() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let block = astria_core::sequencer::v1alpha1::SequencerBlock::try_from_cometbft(
+            make_cometbft_block(),
+        )
+        .unwrap();
+        let height = block.height().value();
+        state.put_sequencer_block(block);
+
+        // the block is kept while it is among the 2 most recent blocks
+        state.prune_sequencer_blocks(height, 2).await.unwrap();
+        state.prune_sequencer_blocks(height + 1, 2).await.unwrap();
+        assert!(state
+            .get_sequencer_block_by_height(height)
+            .await
+            .unwrap()
+            .is_some());
+
+        state.prune_sequencer_blocks(height + 2, 2).await.unwrap();
+        assert!(state
+            .get_sequencer_block_by_height(height)
+            .await
+            .unwrap()
+            .is_none());
+        assert_eq!(
+            state.get_oldest_sequencer_block_height().await.unwrap(),
+            Some(height + 1),
+        );
+    }
+}
@@ -0,0 +1,59 @@
+use anyhow::{
+    ensure,
+    Context as _,
+    Result,
+};
+use astria_core::sequencer::v1alpha1::{
+    event::IbcRelayerChangeEvent,
+    transaction::action::IbcRelayerChangeAction,
+    Address,
+};
+use tracing::instrument;
+
+use crate::{
+    ibc::state_ext::{
+        StateReadExt,
+        StateWriteExt,
+    },
+    transaction::action_handler::ActionHandler,
+};
+
+#[async_trait::async_trait]
+impl ActionHandler for IbcRelayerChangeAction {
+    /// check that the signer of the transaction is the current IBC sudo address,
+    /// as only that address can change the set of IBC relayers
+    async fn check_stateful<S: StateReadExt + 'static>(
+        &self,
+        state: &S,
+        from: Address,
+    ) -> Result<()> {
+        let ibc_sudo_address = state
+            .get_ibc_sudo_address()
+            .await
+            .context("failed to get IBC sudo address from state")?;
+        ensure!(ibc_sudo_address == from, "signer is not the IBC sudo key");
+        Ok(())
+    }
+
+    #[instrument(skip_all)]
+    async fn execute<S: StateWriteExt>(&self, state: &mut S, _: Address) -> Result<()> {
+        let event = match self {
+            IbcRelayerChangeAction::Addition(address) => {
+                state.put_ibc_relayer_address(address);
+                IbcRelayerChangeEvent {
+                    address: *address,
+                    added: true,
+                }
+            }
+            IbcRelayerChangeAction::Removal(address) => {
+                state.delete_ibc_relayer_address(address);
+                IbcRelayerChangeEvent {
+                    address: *address,
+                    added: false,
+                }
+            }
+        };
+        state.record(event.into_abci());
+        Ok(())
+    }
+}
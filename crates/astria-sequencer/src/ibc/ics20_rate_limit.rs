@@ -0,0 +1,158 @@
+//! Enforcement of the rate limits on ICS20 transfers.
+//!
+//! A rate limit bounds the net amount of an asset transferred over a channel within
+//! fixed windows of blocks. Transfers out of the sequencer count as outflow and
+//! transfers into it as inflow, so that a transfer in one direction frees up capacity
+//! in the other. Refunds of failed outbound transfers are counted as inflow but are
+//! never rejected, as they return funds which already left the sender's account.
+
+use anyhow::{
+    ensure,
+    Context as _,
+    Result,
+};
+use astria_core::sequencer::v1alpha1::{
+    asset,
+    Ics20RateLimit,
+};
+use cnidarium::{
+    StateRead,
+    StateWrite,
+};
+use ibc_types::core::channel::ChannelId;
+
+use crate::{
+    ibc::state_ext::{
+        Ics20RateLimitUsage,
+        StateReadExt as _,
+        StateWriteExt as _,
+    },
+    state_ext::StateReadExt as _,
+};
+
+/// The direction of an ICS20 transfer.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Ics20Flow {
+    Inflow,
+    Outflow,
+    Refund,
+}
+
+/// Returns the usage of `rate_limit` in the window containing `height`, given the usage
+/// as of the last recorded transfer.
+fn usage_in_window(
+    rate_limit: Ics20RateLimit,
+    usage: Ics20RateLimitUsage,
+    height: u64,
+) -> Ics20RateLimitUsage {
+    let window_start_height = height
+        .checked_rem(rate_limit.window_blocks)
+        .map_or(0, |offset| height - offset);
+    if usage.window_start_height == window_start_height {
+        usage
+    } else {
+        Ics20RateLimitUsage {
+            window_start_height,
+            ..Ics20RateLimitUsage::default()
+        }
+    }
+}
+
+/// Returns the rate limit on `channel` and `asset` and its usage in the current window,
+/// which is empty if transfers are not rate limited.
+pub(crate) async fn get_ics20_rate_limit_and_usage<S: StateRead>(
+    state: &S,
+    channel: &ChannelId,
+    asset: asset::Id,
+) -> Result<(Option<Ics20RateLimit>, Ics20RateLimitUsage)> {
+    let Some(rate_limit) = state
+        .get_ics20_rate_limit(channel, asset)
+        .await
+        .context("failed to get ics20 rate limit")?
+    else {
+        return Ok((None, Ics20RateLimitUsage::default()));
+    };
+    let height = state
+        .get_block_height()
+        .await
+        .context("failed to get block height")?;
+    let usage = state
+        .get_ics20_rate_limit_usage(channel, asset)
+        .await
+        .context("failed to get ics20 rate limit usage")?;
+    Ok((Some(rate_limit), usage_in_window(rate_limit, usage, height)))
+}
+
+/// Returns the rate limit usage on `channel` and `asset` after transferring `amount` in the
+/// direction of `flow`, checking that it stays within the rate limit.
+///
+/// Returns `None` if transfers of `asset` over `channel` are not rate limited.
+pub(crate) async fn check_ics20_flow<S: StateRead>(
+    state: &S,
+    channel: &ChannelId,
+    asset: asset::Id,
+    amount: u128,
+    flow: Ics20Flow,
+) -> Result<Option<Ics20RateLimitUsage>> {
+    let (Some(rate_limit), mut usage) =
+        get_ics20_rate_limit_and_usage(state, channel, asset).await?
+    else {
+        return Ok(None);
+    };
+
+    match flow {
+        Ics20Flow::Inflow | Ics20Flow::Refund => {
+            usage.inflow = usage
+                .inflow
+                .checked_add(amount)
+                .context("ics20 inflow overflowed u128")?;
+        }
+        Ics20Flow::Outflow => {
+            usage.outflow = usage
+                .outflow
+                .checked_add(amount)
+                .context("ics20 outflow overflowed u128")?;
+        }
+    }
+
+    match flow {
+        Ics20Flow::Inflow => {
+            let net_inflow = usage.inflow.saturating_sub(usage.outflow);
+            ensure!(
+                net_inflow <= rate_limit.max_inflow,
+                "transfer of {amount} into channel `{channel}` exceeds the maximum net inflow of \
+                 {} in the current window",
+                rate_limit.max_inflow,
+            );
+        }
+        Ics20Flow::Outflow => {
+            let net_outflow = usage.outflow.saturating_sub(usage.inflow);
+            ensure!(
+                net_outflow <= rate_limit.max_outflow,
+                "transfer of {amount} out of channel `{channel}` exceeds the maximum net outflow \
+                 of {} in the current window",
+                rate_limit.max_outflow,
+            );
+        }
+        Ics20Flow::Refund => {}
+    }
+
+    Ok(Some(usage))
+}
+
+/// Checks the transfer of `amount` in the direction of `flow` against the rate limit on
+/// `channel` and `asset`, and records it in the rate limit usage.
+pub(crate) async fn record_ics20_flow<S: StateWrite>(
+    state: &mut S,
+    channel: &ChannelId,
+    asset: asset::Id,
+    amount: u128,
+    flow: Ics20Flow,
+) -> Result<()> {
+    if let Some(usage) = check_ics20_flow(&*state, channel, asset, amount, flow).await? {
+        state
+            .put_ics20_rate_limit_usage(channel, asset, usage)
+            .context("failed to put ics20 rate limit usage")?;
+    }
+    Ok(())
+}
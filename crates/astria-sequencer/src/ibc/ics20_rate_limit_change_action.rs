@@ -0,0 +1,60 @@
+use anyhow::{
+    ensure,
+    Context as _,
+    Result,
+};
+use astria_core::sequencer::v1alpha1::{
+    transaction::action::Ics20RateLimitChangeAction,
+    Address,
+};
+use tracing::instrument;
+
+use crate::{
+    ibc::state_ext::{
+        StateReadExt,
+        StateWriteExt,
+    },
+    transaction::action_handler::ActionHandler,
+};
+
+#[async_trait::async_trait]
+impl ActionHandler for Ics20RateLimitChangeAction {
+    async fn check_stateless(&self) -> Result<()> {
+        if let Some(rate_limit) = &self.rate_limit {
+            ensure!(
+                rate_limit.window_blocks > 0,
+                "rate limit window must be at least one block"
+            );
+        }
+        Ok(())
+    }
+
+    /// check that the signer of the transaction is the current IBC sudo address,
+    /// as only that address can change the rate limits on ICS20 transfers
+    async fn check_stateful<S: StateReadExt + 'static>(
+        &self,
+        state: &S,
+        from: Address,
+    ) -> Result<()> {
+        let ibc_sudo_address = state
+            .get_ibc_sudo_address()
+            .await
+            .context("failed to get IBC sudo address from state")?;
+        ensure!(ibc_sudo_address == from, "signer is not the IBC sudo key");
+        Ok(())
+    }
+
+    #[instrument(skip_all)]
+    async fn execute<S: StateWriteExt>(&self, state: &mut S, _: Address) -> Result<()> {
+        let asset_id = self.denom.id();
+        match self.rate_limit {
+            // the usage in the current window is kept, so that raising or lowering the
+            // limit does not reset it
+            Some(rate_limit) => state
+                .put_ics20_rate_limit(&self.channel, asset_id, rate_limit)
+                .context("failed to put ics20 rate limit")?,
+            None => state.delete_ics20_rate_limit(&self.channel, asset_id),
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,7 @@
+pub(crate) mod ibc_relayer_change_action;
+pub(crate) mod ibc_sudo_change_action;
+pub(crate) mod ics20_channel_change_action;
+pub(crate) mod ics20_rate_limit;
+pub(crate) mod ics20_rate_limit_change_action;
+pub(crate) mod query;
+pub(crate) mod state_ext;
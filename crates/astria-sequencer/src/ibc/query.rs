@@ -0,0 +1,193 @@
+use anyhow::Context as _;
+use astria_core::sequencer::v1alpha1::{
+    asset,
+    AbciErrorCode,
+    IbcRelayerAddressesResponse,
+    Ics20RateLimitResponse,
+    SudoAddressResponse,
+};
+use cnidarium::Storage;
+use ibc_types::core::channel::ChannelId;
+use prost::Message as _;
+use tendermint::abci::{
+    request,
+    response,
+};
+
+use crate::{
+    accounts::query::get_snapshot_and_height,
+    ibc::{
+        ics20_rate_limit::get_ics20_rate_limit_and_usage,
+        state_ext::StateReadExt as _,
+    },
+};
+
+/// Returns the addresses allowed to relay IBC packets at the requested height.
+pub(crate) async fn ibc_relayer_addresses_request(
+    storage: Storage,
+    request: request::Query,
+    _params: Vec<(String, String)>,
+) -> response::Query {
+    let (snapshot, height) = match get_snapshot_and_height(&storage, request.height).await {
+        Ok(tup) => tup,
+        Err(err_rsp) => return err_rsp,
+    };
+
+    let addresses = match snapshot.get_ibc_relayer_addresses().await {
+        Ok(addresses) => addresses,
+        Err(err) => {
+            return response::Query {
+                code: AbciErrorCode::INTERNAL_ERROR.into(),
+                info: AbciErrorCode::INTERNAL_ERROR.to_string(),
+                log: format!("failed getting IBC relayer addresses: {err:?}"),
+                height,
+                ..response::Query::default()
+            };
+        }
+    };
+
+    let payload = IbcRelayerAddressesResponse {
+        height: height.value(),
+        addresses,
+    }
+    .into_raw()
+    .encode_to_vec()
+    .into();
+    response::Query {
+        code: 0.into(),
+        key: request.path.clone().into_bytes().into(),
+        value: payload,
+        height,
+        ..response::Query::default()
+    }
+}
+
+/// Returns the IBC sudo address at the requested height.
+pub(crate) async fn ibc_sudo_address_request(
+    storage: Storage,
+    request: request::Query,
+    _params: Vec<(String, String)>,
+) -> response::Query {
+    let (snapshot, height) = match get_snapshot_and_height(&storage, request.height).await {
+        Ok(tup) => tup,
+        Err(err_rsp) => return err_rsp,
+    };
+
+    let address = match snapshot.get_ibc_sudo_address().await {
+        Ok(address) => address,
+        Err(err) => {
+            return response::Query {
+                code: AbciErrorCode::INTERNAL_ERROR.into(),
+                info: AbciErrorCode::INTERNAL_ERROR.to_string(),
+                log: format!("failed getting IBC sudo address: {err:?}"),
+                height,
+                ..response::Query::default()
+            };
+        }
+    };
+
+    let payload = SudoAddressResponse {
+        height: height.value(),
+        address,
+    }
+    .into_raw()
+    .encode_to_vec()
+    .into();
+    response::Query {
+        code: 0.into(),
+        key: request.path.clone().into_bytes().into(),
+        value: payload,
+        height,
+        ..response::Query::default()
+    }
+}
+
+/// Returns the rate limit on the ICS20 transfers of the asset with the given hex encoded
+/// ID over the given channel, and its usage in the window containing the requested height.
+pub(crate) async fn ics20_rate_limit_request(
+    storage: Storage,
+    request: request::Query,
+    params: Vec<(String, String)>,
+) -> response::Query {
+    let (channel, asset_id) = match preprocess_ics20_rate_limit_request(&params) {
+        Ok(tup) => tup,
+        Err(err_rsp) => return err_rsp,
+    };
+
+    let (snapshot, height) = match get_snapshot_and_height(&storage, request.height).await {
+        Ok(tup) => tup,
+        Err(err_rsp) => return err_rsp,
+    };
+
+    let (rate_limit, usage) =
+        match get_ics20_rate_limit_and_usage(&snapshot, &channel, asset_id).await {
+            Ok(tup) => tup,
+            Err(err) => {
+                return response::Query {
+                    code: AbciErrorCode::INTERNAL_ERROR.into(),
+                    info: AbciErrorCode::INTERNAL_ERROR.to_string(),
+                    log: format!("failed getting ICS20 rate limit: {err:?}"),
+                    height,
+                    ..response::Query::default()
+                };
+            }
+        };
+
+    let payload = Ics20RateLimitResponse {
+        height: height.value(),
+        channel,
+        asset_id,
+        rate_limit,
+        window_start_height: usage.window_start_height,
+        inflow: usage.inflow,
+        outflow: usage.outflow,
+    }
+    .into_raw()
+    .encode_to_vec()
+    .into();
+    response::Query {
+        code: 0.into(),
+        key: request.path.clone().into_bytes().into(),
+        value: payload,
+        height,
+        ..response::Query::default()
+    }
+}
+
+fn preprocess_ics20_rate_limit_request(
+    params: &[(String, String)],
+) -> Result<(ChannelId, asset::Id), response::Query> {
+    let (Some(channel), Some(asset_id)) = (
+        params
+            .iter()
+            .find_map(|(k, v)| (k == "channel").then_some(v)),
+        params
+            .iter()
+            .find_map(|(k, v)| (k == "asset_id").then_some(v)),
+    ) else {
+        return Err(response::Query {
+            code: AbciErrorCode::INVALID_PARAMETER.into(),
+            info: AbciErrorCode::INVALID_PARAMETER.to_string(),
+            log: "path did not contain channel and asset ID parameters".into(),
+            ..response::Query::default()
+        });
+    };
+    let channel = channel.parse().map_err(|err| response::Query {
+        code: AbciErrorCode::INVALID_PARAMETER.into(),
+        info: AbciErrorCode::INVALID_PARAMETER.to_string(),
+        log: format!("channel ID could not be constructed from provided parameter: {err:?}"),
+        ..response::Query::default()
+    })?;
+    let asset_id = hex::decode(asset_id)
+        .context("failed decoding hex encoded bytes")
+        .and_then(|bytes| {
+            asset::Id::try_from_slice(&bytes).context("failed constructing asset ID from bytes")
+        })
+        .map_err(|err| response::Query {
+            code: AbciErrorCode::INVALID_PARAMETER.into(),
+            info: AbciErrorCode::INVALID_PARAMETER.to_string(),
+            log: format!("asset ID could not be constructed from provided parameter: {err:?}"),
+            ..response::Query::default()
+        })?;
+    Ok((channel, asset_id))
+}
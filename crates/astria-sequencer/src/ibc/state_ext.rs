@@ -0,0 +1,302 @@
+use anyhow::{
+    bail,
+    Context,
+    Result,
+};
+use astria_core::sequencer::v1alpha1::{
+    asset,
+    Address,
+    Ics20RateLimit,
+    ADDRESS_LEN,
+};
+use async_trait::async_trait;
+use borsh::{
+    BorshDeserialize,
+    BorshSerialize,
+};
+use cnidarium::{
+    StateRead,
+    StateWrite,
+};
+use futures::StreamExt as _;
+use hex::ToHex as _;
+use ibc_types::core::channel::ChannelId;
+use tracing::instrument;
+
+use crate::genesis::IbcParameters;
+
+/// Newtype wrapper to read and write an address from rocksdb.
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+struct SudoAddress([u8; ADDRESS_LEN]);
+
+/// Newtype wrapper to read and write an ICS20 rate limit from rocksdb.
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+struct Ics20RateLimitStorage {
+    max_inflow: u128,
+    max_outflow: u128,
+    window_blocks: u64,
+}
+
+impl From<Ics20RateLimit> for Ics20RateLimitStorage {
+    fn from(rate_limit: Ics20RateLimit) -> Self {
+        let Ics20RateLimit {
+            max_inflow,
+            max_outflow,
+            window_blocks,
+        } = rate_limit;
+        Self {
+            max_inflow,
+            max_outflow,
+            window_blocks,
+        }
+    }
+}
+
+impl From<Ics20RateLimitStorage> for Ics20RateLimit {
+    fn from(storage: Ics20RateLimitStorage) -> Self {
+        let Ics20RateLimitStorage {
+            max_inflow,
+            max_outflow,
+            window_blocks,
+        } = storage;
+        Self {
+            max_inflow,
+            max_outflow,
+            window_blocks,
+        }
+    }
+}
+
+/// The amounts of an asset transferred over a channel in the rate limit window
+/// starting at `window_start_height`.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct Ics20RateLimitUsage {
+    pub(crate) window_start_height: u64,
+    pub(crate) inflow: u128,
+    pub(crate) outflow: u128,
+}
+
+const IBC_SUDO_STORAGE_KEY: &str = "ibcsudo";
+const IBC_RELAYER_PREFIX: &str = "ibc-relayer/";
+const IBC_PARAMS_STORAGE_KEY: &str = "ibc-params";
+const ICS20_CHANNEL_PREFIX: &str = "ics20-channel/";
+const ICS20_RATE_LIMIT_PREFIX: &str = "ics20-rate-limit/";
+const ICS20_RATE_LIMIT_USAGE_PREFIX: &str = "ics20-rate-limit-usage/";
+
+fn ibc_relayer_storage_key(address: &Address) -> String {
+    format!("{IBC_RELAYER_PREFIX}{}", address.encode_hex::<String>())
+}
+
+fn ics20_channel_storage_key(channel: &ChannelId) -> String {
+    format!("{ICS20_CHANNEL_PREFIX}{channel}")
+}
+
+fn ics20_rate_limit_storage_key(channel: &ChannelId, asset: asset::Id) -> String {
+    format!(
+        "{ICS20_RATE_LIMIT_PREFIX}{channel}/{}",
+        asset.encode_hex::<String>()
+    )
+}
+
+fn ics20_rate_limit_usage_storage_key(channel: &ChannelId, asset: asset::Id) -> String {
+    format!(
+        "{ICS20_RATE_LIMIT_USAGE_PREFIX}{channel}/{}",
+        asset.encode_hex::<String>()
+    )
+}
+
+#[async_trait]
+pub(crate) trait StateReadExt: StateRead {
+    #[instrument(skip(self))]
+    async fn get_ibc_sudo_address(&self) -> Result<Address> {
+        let Some(bytes) = self
+            .get_raw(IBC_SUDO_STORAGE_KEY)
+            .await
+            .context("failed reading raw ibc sudo key from state")?
+        else {
+            // ibc sudo key must be set
+            bail!("ibc sudo key not found");
+        };
+        let SudoAddress(address) =
+            SudoAddress::try_from_slice(&bytes).context("invalid ibc sudo key bytes")?;
+        Ok(Address(address))
+    }
+
+    #[instrument(skip(self))]
+    async fn is_ibc_relayer(&self, address: &Address) -> Result<bool> {
+        Ok(self
+            .get_raw(&ibc_relayer_storage_key(address))
+            .await
+            .context("failed to read raw ibc relayer address from state")?
+            .is_some())
+    }
+
+    #[instrument(skip(self))]
+    async fn get_ibc_relayer_addresses(&self) -> Result<Vec<Address>> {
+        let mut addresses = Vec::new();
+
+        let mut stream = std::pin::pin!(self.prefix_keys(IBC_RELAYER_PREFIX));
+        while let Some(key) = stream.next().await {
+            let key = key.context("failed reading ibc relayer key from state")?;
+            let address_str = key
+                .strip_prefix(IBC_RELAYER_PREFIX)
+                .context("failed to strip prefix from ibc relayer key")?;
+            let address_bytes = hex::decode(address_str).context("invalid address bytes")?;
+            let address = Address::try_from_slice(&address_bytes)
+                .context("failed to parse address from ibc relayer key")?;
+            addresses.push(address);
+        }
+
+        Ok(addresses)
+    }
+
+    /// Returns the IBC parameters of the chain, which default to all of IBC
+    /// being enabled if they were never set.
+    #[instrument(skip(self))]
+    async fn get_ibc_params(&self) -> Result<IbcParameters> {
+        let Some(bytes) = self
+            .get_raw(IBC_PARAMS_STORAGE_KEY)
+            .await
+            .context("failed reading raw ibc params from state")?
+        else {
+            return Ok(IbcParameters::default());
+        };
+        IbcParameters::try_from_slice(&bytes).context("invalid ibc params bytes")
+    }
+
+    /// Returns whether ICS20 transfers can be made over `channel`.
+    #[instrument(skip(self))]
+    async fn is_ics20_channel_allowed(&self, channel: &ChannelId) -> Result<bool> {
+        Ok(self
+            .get_raw(&ics20_channel_storage_key(channel))
+            .await
+            .context("failed to read raw ics20 channel from state")?
+            .is_some())
+    }
+
+    #[instrument(skip(self))]
+    async fn get_ics20_rate_limit(
+        &self,
+        channel: &ChannelId,
+        asset: asset::Id,
+    ) -> Result<Option<Ics20RateLimit>> {
+        let Some(bytes) = self
+            .get_raw(&ics20_rate_limit_storage_key(channel, asset))
+            .await
+            .context("failed reading raw ics20 rate limit from state")?
+        else {
+            return Ok(None);
+        };
+        let rate_limit = Ics20RateLimitStorage::try_from_slice(&bytes)
+            .context("invalid ics20 rate limit bytes")?;
+        Ok(Some(rate_limit.into()))
+    }
+
+    /// Returns the usage of the rate limit on `channel` and `asset` as of the last
+    /// transfer that was recorded, which may be in an earlier window.
+    #[instrument(skip(self))]
+    async fn get_ics20_rate_limit_usage(
+        &self,
+        channel: &ChannelId,
+        asset: asset::Id,
+    ) -> Result<Ics20RateLimitUsage> {
+        let Some(bytes) = self
+            .get_raw(&ics20_rate_limit_usage_storage_key(channel, asset))
+            .await
+            .context("failed reading raw ics20 rate limit usage from state")?
+        else {
+            return Ok(Ics20RateLimitUsage::default());
+        };
+        Ics20RateLimitUsage::try_from_slice(&bytes).context("invalid ics20 rate limit usage bytes")
+    }
+}
+
+impl<T: StateRead> StateReadExt for T {}
+
+#[async_trait]
+pub(crate) trait StateWriteExt: StateWrite {
+    #[instrument(skip(self))]
+    fn put_ibc_sudo_address(&mut self, address: Address) -> Result<()> {
+        self.put_raw(
+            IBC_SUDO_STORAGE_KEY.to_string(),
+            SudoAddress(address.0)
+                .try_to_vec()
+                .context("failed to convert sudo address to vec")?,
+        );
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    fn put_ibc_params(&mut self, params: &IbcParameters) -> Result<()> {
+        self.put_raw(
+            IBC_PARAMS_STORAGE_KEY.to_string(),
+            params
+                .try_to_vec()
+                .context("failed to serialize ibc params")?,
+        );
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    fn put_ibc_relayer_address(&mut self, address: &Address) {
+        self.put_raw(ibc_relayer_storage_key(address), address.to_vec());
+    }
+
+    #[instrument(skip(self))]
+    fn delete_ibc_relayer_address(&mut self, address: &Address) {
+        self.delete(ibc_relayer_storage_key(address));
+    }
+
+    #[instrument(skip(self))]
+    fn put_ics20_channel(&mut self, channel: &ChannelId) {
+        self.put_raw(
+            ics20_channel_storage_key(channel),
+            channel.to_string().into_bytes(),
+        );
+    }
+
+    #[instrument(skip(self))]
+    fn delete_ics20_channel(&mut self, channel: &ChannelId) {
+        self.delete(ics20_channel_storage_key(channel));
+    }
+
+    #[instrument(skip(self))]
+    fn put_ics20_rate_limit(
+        &mut self,
+        channel: &ChannelId,
+        asset: asset::Id,
+        rate_limit: Ics20RateLimit,
+    ) -> Result<()> {
+        self.put_raw(
+            ics20_rate_limit_storage_key(channel, asset),
+            Ics20RateLimitStorage::from(rate_limit)
+                .try_to_vec()
+                .context("failed to serialize ics20 rate limit")?,
+        );
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    fn delete_ics20_rate_limit(&mut self, channel: &ChannelId, asset: asset::Id) {
+        self.delete(ics20_rate_limit_storage_key(channel, asset));
+        self.delete(ics20_rate_limit_usage_storage_key(channel, asset));
+    }
+
+    #[instrument(skip(self))]
+    fn put_ics20_rate_limit_usage(
+        &mut self,
+        channel: &ChannelId,
+        asset: asset::Id,
+        usage: Ics20RateLimitUsage,
+    ) -> Result<()> {
+        self.put_raw(
+            ics20_rate_limit_usage_storage_key(channel, asset),
+            usage
+                .try_to_vec()
+                .context("failed to serialize ics20 rate limit usage")?,
+        );
+        Ok(())
+    }
+}
+
+impl<T: StateWrite> StateWriteExt for T {}
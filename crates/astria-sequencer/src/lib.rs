@@ -2,19 +2,31 @@ pub(crate) mod accounts;
 pub(crate) mod app;
 pub(crate) mod asset;
 pub(crate) mod authority;
+pub(crate) mod blockspace;
+pub(crate) mod bridge;
 pub(crate) mod component;
+pub(crate) mod fees;
 pub mod config;
 pub(crate) mod genesis;
+mod genesis_export;
+pub(crate) mod grpc;
 pub(crate) mod host_interface;
-#[cfg(feature = "mint")]
+pub(crate) mod ibc;
+pub(crate) mod mempool;
 pub(crate) mod mint;
 pub(crate) mod proposal;
+pub(crate) mod query;
+pub(crate) mod registry;
 pub(crate) mod sequence;
 mod sequencer;
 pub(crate) mod service;
+pub(crate) mod session;
 pub(crate) mod state_ext;
+pub(crate) mod state_sync;
 pub(crate) mod transaction;
+pub(crate) mod upgrade;
 
 pub use config::Config;
+pub use genesis_export::export_genesis_state;
 pub use sequencer::Sequencer;
 pub use telemetry;
@@ -0,0 +1,696 @@
+//! The app-side mempool.
+//!
+//! Transactions which pass `CheckTx` are tracked here per signer, ordered by nonce.
+//! Only transactions whose nonces form a contiguous sequence starting at the signer's
+//! current account nonce are released to `prepare_proposal`, so that a gap in a signer's
+//! nonces does not cause their later transactions to be repeatedly executed and excluded.
+//!
+//! The mempool is bounded by [`Limits`]: in its total size, in the number of pending
+//! transactions of each signer, and in how far ahead of the signer's current nonce a
+//! transaction may be. When it is full, transactions are evicted in the reverse of the
+//! tip-priority order used for proposals, and only ever from the end of a signer's
+//! nonce sequence, so that eviction never opens a gap in the nonces.
+
+use std::{
+    cmp::Reverse,
+    collections::{
+        BTreeMap,
+        HashMap,
+    },
+    sync::Arc,
+};
+
+use anyhow::{
+    bail,
+    ensure,
+    Context as _,
+};
+use astria_core::sequencer::v1alpha1::{
+    Address,
+    SignedTransaction,
+};
+use bytes::Bytes;
+use sha2::{
+    Digest as _,
+    Sha256,
+};
+use tokio::sync::RwLock;
+use tracing::{
+    debug,
+    instrument,
+};
+
+use crate::{
+    accounts::state_ext::StateReadExt,
+    blockspace::TipPerByte,
+};
+
+/// The maximum total size of the encoded transactions in the mempool.
+pub(crate) const MAX_TOTAL_BYTES: usize = 64 * 1024 * 1024;
+
+/// The maximum number of pending transactions of a single signer.
+pub(crate) const MAX_PENDING_PER_ACCOUNT: usize = 64;
+
+/// The maximum number by which a transaction's nonce may exceed its signer's current nonce.
+pub(crate) const MAX_NONCE_GAP: u32 = 64;
+
+/// The limits of the mempool.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Limits {
+    pub(crate) max_total_bytes: usize,
+    pub(crate) max_pending_per_account: usize,
+    pub(crate) max_nonce_gap: u32,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Self {
+            max_total_bytes: MAX_TOTAL_BYTES,
+            max_pending_per_account: MAX_PENDING_PER_ACCOUNT,
+            max_nonce_gap: MAX_NONCE_GAP,
+        }
+    }
+}
+
+/// A transaction tracked by the mempool.
+#[derive(Clone, Debug)]
+pub(crate) struct MempoolTransaction {
+    signed_tx: SignedTransaction,
+    tx_bytes: Bytes,
+    tx_hash: [u8; 32],
+    fee: u128,
+    inserted_at: u64,
+}
+
+impl MempoolTransaction {
+    pub(crate) fn signed_tx(&self) -> &SignedTransaction {
+        &self.signed_tx
+    }
+
+    pub(crate) fn tx_bytes(&self) -> &Bytes {
+        &self.tx_bytes
+    }
+
+    /// The eviction priority of the transaction: the lowest is evicted first.
+    ///
+    /// This is the reverse of the order in which [`crate::blockspace::proposal_order`]
+    /// takes transactions: by tip per byte, with ties going to the earlier inserted.
+    fn priority(&self) -> (TipPerByte, Reverse<u64>) {
+        (
+            TipPerByte::new(&self.signed_tx, self.tx_bytes.len()),
+            Reverse(self.inserted_at),
+        )
+    }
+}
+
+#[derive(Debug, Default)]
+struct Inner {
+    /// The pending transactions of each signer, keyed by nonce.
+    accounts: HashMap<Address, BTreeMap<u32, MempoolTransaction>>,
+    /// Maps the hashes of all pending transactions to their signer and nonce.
+    hashes: HashMap<[u8; 32], (Address, u32)>,
+    /// The total size of the encoded pending transactions.
+    total_bytes: usize,
+    /// Incremented on every insertion; used to order transactions of different signers.
+    next_insertion_index: u64,
+}
+
+impl Inner {
+    fn remove(&mut self, tx_hash: &[u8; 32]) -> Option<MempoolTransaction> {
+        let (address, nonce) = self.hashes.remove(tx_hash)?;
+        let txs = self.accounts.get_mut(&address)?;
+        let removed = txs.remove(&nonce);
+        if txs.is_empty() {
+            self.accounts.remove(&address);
+        }
+        if let Some(removed) = &removed {
+            self.total_bytes -= removed.tx_bytes.len();
+        }
+        removed
+    }
+
+    /// Returns the hashes of the transactions to evict so that `needed_bytes` more fit
+    /// into `max_total_bytes`, each of which has a lower priority than `priority`.
+    ///
+    /// Only the last pending transactions of signers other than `signer` are evicted.
+    /// Transactions in `already_removed` are counted as evicted.
+    fn plan_eviction(
+        &self,
+        signer: &Address,
+        priority: TipPerByte,
+        needed_bytes: usize,
+        max_total_bytes: usize,
+        already_removed: &[[u8; 32]],
+    ) -> anyhow::Result<Vec<[u8; 32]>> {
+        ensure!(
+            needed_bytes <= max_total_bytes,
+            "transaction of {needed_bytes} bytes exceeds the mempool size limit of \
+             {max_total_bytes} bytes"
+        );
+        let mut total_bytes = self.total_bytes;
+        for tx_hash in already_removed {
+            let (address, nonce) = &self.hashes[tx_hash];
+            total_bytes -= self.accounts[address][nonce].tx_bytes.len();
+        }
+        let mut evicted_per_account: HashMap<Address, usize> = HashMap::new();
+        let mut evicted = Vec::new();
+        while total_bytes + needed_bytes > max_total_bytes {
+            let lowest = self
+                .accounts
+                .iter()
+                .filter(|(address, _)| *address != signer)
+                .filter_map(|(address, txs)| {
+                    let evicted = evicted_per_account.get(address).copied().unwrap_or(0);
+                    Some((address, txs.values().rev().nth(evicted)?))
+                })
+                .min_by_key(|(_, tx)| tx.priority());
+            let Some((address, tx)) = lowest else {
+                bail!("mempool is full");
+            };
+            ensure!(
+                tx.priority().0 < priority,
+                "mempool is full and the transaction does not pay a higher tip per byte than the \
+                 pending transactions"
+            );
+            *evicted_per_account.entry(*address).or_insert(0) += 1;
+            total_bytes -= tx.tx_bytes.len();
+            evicted.push(tx.tx_hash);
+        }
+        Ok(evicted)
+    }
+}
+
+/// The sequencer's mempool, shared between the mempool service and the [`crate::app::App`].
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Mempool {
+    inner: Arc<RwLock<Inner>>,
+    limits: Limits,
+}
+
+impl Mempool {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    #[cfg(test)]
+    pub(crate) fn with_limits(limits: Limits) -> Self {
+        Self {
+            inner: Arc::default(),
+            limits,
+        }
+    }
+
+    /// Returns the number of transactions in the mempool.
+    pub(crate) async fn len(&self) -> usize {
+        self.inner.read().await.hashes.len()
+    }
+
+    /// Returns whether a transaction with the given hash is in the mempool.
+    pub(crate) async fn contains(&self, tx_hash: &[u8; 32]) -> bool {
+        self.inner.read().await.hashes.contains_key(tx_hash)
+    }
+
+    /// Inserts a transaction into the mempool.
+    ///
+    /// `fee` is the total fee paid by the transaction and `current_account_nonce` the
+    /// signer's nonce in the latest committed state.
+    ///
+    /// If the signer already has a pending transaction with the same nonce, it is
+    /// replaced only if `fee` is higher than the fee of the pending transaction.
+    /// In this case, the hash of the replaced transaction is returned.
+    ///
+    /// The transaction is rejected if its nonce is more than the maximum nonce gap ahead
+    /// of `current_account_nonce`. If the signer already has the maximum number of pending
+    /// transactions, the one with the highest nonce is evicted if it comes after the
+    /// transaction, otherwise the transaction is rejected. If the mempool is full, the
+    /// transactions of other signers with the lowest priority are evicted, as long as they
+    /// pay a lower tip per byte than the transaction.
+    ///
+    /// Inserting a transaction which is already in the mempool is a no-op.
+    #[instrument(skip_all)]
+    pub(crate) async fn insert(
+        &self,
+        signed_tx: SignedTransaction,
+        tx_bytes: Bytes,
+        fee: u128,
+        current_account_nonce: u32,
+    ) -> anyhow::Result<Option<[u8; 32]>> {
+        let tx_hash: [u8; 32] = Sha256::digest(&tx_bytes).into();
+        let address = signed_tx.address();
+        let nonce = signed_tx.unsigned_transaction().nonce;
+        ensure!(
+            nonce >= current_account_nonce,
+            "nonce already used by account"
+        );
+        ensure!(
+            nonce - current_account_nonce <= self.limits.max_nonce_gap,
+            "nonce is more than {} ahead of the account nonce {current_account_nonce}",
+            self.limits.max_nonce_gap,
+        );
+
+        let mut inner = self.inner.write().await;
+        if inner.hashes.contains_key(&tx_hash) {
+            return Ok(None);
+        }
+
+        let pending_txs = inner.accounts.get(&address);
+        let replaced = match pending_txs.and_then(|txs| txs.get(&nonce)) {
+            Some(pending) if fee <= pending.fee => bail!(
+                "a transaction with nonce {nonce} is already pending for the account; a \
+                 replacement must pay a higher fee than {}",
+                pending.fee,
+            ),
+            Some(pending) => Some(pending.tx_hash),
+            None => None,
+        };
+        let mut evicted = Vec::new();
+        let account_is_full =
+            pending_txs.is_some_and(|txs| txs.len() >= self.limits.max_pending_per_account);
+        if replaced.is_none() && account_is_full {
+            let (last_nonce, last) = pending_txs
+                .and_then(BTreeMap::last_key_value)
+                .expect("accounts without pending transactions are removed");
+            ensure!(
+                *last_nonce > nonce,
+                "account already has the maximum of {} pending transactions",
+                self.limits.max_pending_per_account,
+            );
+            evicted.push(last.tx_hash);
+        }
+        let already_removed: Vec<[u8; 32]> = replaced.iter().chain(&evicted).copied().collect();
+        evicted.extend(inner.plan_eviction(
+            &address,
+            TipPerByte::new(&signed_tx, tx_bytes.len()),
+            tx_bytes.len(),
+            self.limits.max_total_bytes,
+            &already_removed,
+        )?);
+
+        for evicted_hash in &evicted {
+            inner.remove(evicted_hash);
+            debug!(
+                evicted_tx_hash = %telemetry::display::hex(evicted_hash),
+                "evicted transaction from full mempool"
+            );
+        }
+        if let Some(replaced_hash) = replaced {
+            inner.remove(&replaced_hash);
+            debug!(
+                replaced_tx_hash = %telemetry::display::hex(&replaced_hash),
+                nonce,
+                "replacing pending transaction with higher fee transaction"
+            );
+        }
+
+        let inserted_at = inner.next_insertion_index;
+        inner.next_insertion_index = inner.next_insertion_index.wrapping_add(1);
+        inner.hashes.insert(tx_hash, (address, nonce));
+        inner.total_bytes += tx_bytes.len();
+        inner.accounts.entry(address).or_default().insert(
+            nonce,
+            MempoolTransaction {
+                signed_tx,
+                tx_bytes,
+                tx_hash,
+                fee,
+                inserted_at,
+            },
+        );
+        Ok(replaced)
+    }
+
+    /// Removes the transaction with the given hash from the mempool, returning it if present.
+    pub(crate) async fn remove(&self, tx_hash: &[u8; 32]) -> Option<MempoolTransaction> {
+        self.inner.write().await.remove(tx_hash)
+    }
+
+    /// Returns the pending transactions of `address` with nonces lower than `nonce`,
+    /// which are executed before a transaction with `nonce`.
+    pub(crate) async fn pending_transactions_before(
+        &self,
+        address: &Address,
+        nonce: u32,
+    ) -> Vec<SignedTransaction> {
+        self.inner
+            .read()
+            .await
+            .accounts
+            .get(address)
+            .map(|txs| {
+                txs.range(..nonce)
+                    .map(|(_, tx)| tx.signed_tx.clone())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Returns the transactions which are ready for execution given `state`.
+    ///
+    /// For each signer, these are the pending transactions with contiguous nonces
+    /// starting at the signer's current account nonce. Transactions of different
+    /// signers are interleaved in the order they were inserted, while the transactions
+    /// of each signer are always returned in nonce order.
+    #[instrument(skip_all)]
+    pub(crate) async fn ready_transactions<S: StateReadExt>(
+        &self,
+        state: &S,
+    ) -> anyhow::Result<Vec<MempoolTransaction>> {
+        let inner = self.inner.read().await;
+
+        let mut ready_per_account = Vec::with_capacity(inner.accounts.len());
+        for (address, txs) in &inner.accounts {
+            let mut expected_nonce = state
+                .get_account_nonce(*address)
+                .await
+                .context("failed to get account nonce")?;
+            let ready: Vec<_> = txs
+                .range(expected_nonce..)
+                .map_while(|(nonce, tx)| {
+                    if *nonce != expected_nonce {
+                        return None;
+                    }
+                    expected_nonce = expected_nonce.wrapping_add(1);
+                    Some(tx)
+                })
+                .collect();
+            if !ready.is_empty() {
+                ready_per_account.push(ready.into_iter().peekable());
+            }
+        }
+
+        // repeatedly take the earliest inserted transaction among the next ready
+        // transaction of each signer.
+        let mut ready_txs = Vec::new();
+        loop {
+            let next = ready_per_account
+                .iter_mut()
+                .filter_map(|txs| Some((txs.peek()?.inserted_at, txs)))
+                .min_by_key(|(inserted_at, _)| *inserted_at)
+                .and_then(|(_, txs)| txs.next());
+            let Some(tx) = next else {
+                break;
+            };
+            ready_txs.push(tx.clone());
+        }
+        Ok(ready_txs)
+    }
+
+    /// Removes all transactions whose nonces have already been used according to `state`.
+    ///
+    /// This should be called after every commit, which evicts both the transactions
+    /// included in the committed block and those which can never be included anymore.
+    #[instrument(skip_all)]
+    pub(crate) async fn remove_stale<S: StateReadExt>(&self, state: &S) -> anyhow::Result<()> {
+        let mut inner = self.inner.write().await;
+        let addresses: Vec<Address> = inner.accounts.keys().copied().collect();
+        let mut removed_count: usize = 0;
+        for address in addresses {
+            let current_nonce = state
+                .get_account_nonce(address)
+                .await
+                .context("failed to get account nonce")?;
+            let stale_hashes: Vec<[u8; 32]> = inner.accounts[&address]
+                .range(..current_nonce)
+                .map(|(_, tx)| tx.tx_hash)
+                .collect();
+            for tx_hash in &stale_hashes {
+                inner.remove(tx_hash);
+            }
+            removed_count += stale_hashes.len();
+        }
+        debug!(
+            removed_count,
+            remaining_count = inner.hashes.len(),
+            "removed stale transactions from mempool"
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use astria_core::sequencer::v1alpha1::{
+        asset::default_native_asset_id,
+        transaction::action::SequenceAction,
+        RollupId,
+        UnsignedTransaction,
+    };
+    use cnidarium::StateDelta;
+    use ed25519_consensus::SigningKey;
+    use prost::Message as _;
+
+    use super::*;
+    use crate::accounts::state_ext::StateWriteExt as _;
+
+    fn signed_tx(signing_key: &SigningKey, nonce: u32, data: &[u8]) -> (SignedTransaction, Bytes) {
+        signed_tx_with_tip(signing_key, nonce, data, None)
+    }
+
+    fn signed_tx_with_tip(
+        signing_key: &SigningKey,
+        nonce: u32,
+        data: &[u8],
+        tip: Option<u128>,
+    ) -> (SignedTransaction, Bytes) {
+        let tx = UnsignedTransaction {
+            nonce,
+            actions: vec![
+                SequenceAction {
+                    rollup_id: RollupId::from_unhashed_bytes(b"testchainid"),
+                    data: data.to_vec(),
+                    fee_asset_id: default_native_asset_id(),
+                }
+                .into(),
+            ],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip,
+            fee_payer: None,
+        }
+        .into_signed(signing_key);
+        let bytes = tx.to_raw().encode_to_vec().into();
+        (tx, bytes)
+    }
+
+    fn nonces(txs: &[MempoolTransaction]) -> Vec<u32> {
+        txs.iter()
+            .map(|tx| tx.signed_tx().unsigned_transaction().nonce)
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn only_contiguous_nonces_are_ready() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let state = StateDelta::new(storage.latest_snapshot());
+        let mempool = Mempool::new();
+        let signing_key = SigningKey::from([1; 32]);
+
+        for nonce in [0, 1, 3] {
+            let (tx, bytes) = signed_tx(&signing_key, nonce, b"data");
+            mempool.insert(tx, bytes, 1, 0).await.unwrap();
+        }
+        assert_eq!(mempool.len().await, 3);
+
+        let ready = mempool.ready_transactions(&state).await.unwrap();
+        assert_eq!(nonces(&ready), vec![0, 1]);
+
+        let (tx, bytes) = signed_tx(&signing_key, 2, b"data");
+        mempool.insert(tx, bytes, 1, 0).await.unwrap();
+        let ready = mempool.ready_transactions(&state).await.unwrap();
+        assert_eq!(nonces(&ready), vec![0, 1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn ready_transactions_are_ordered_by_insertion_across_signers() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let state = StateDelta::new(storage.latest_snapshot());
+        let mempool = Mempool::new();
+        let alice = SigningKey::from([1; 32]);
+        let bob = SigningKey::from([2; 32]);
+
+        let (tx, bytes) = signed_tx(&alice, 1, b"data");
+        mempool.insert(tx, bytes, 1, 0).await.unwrap();
+        let (tx, bytes) = signed_tx(&bob, 0, b"data");
+        let bob_hash: [u8; 32] = Sha256::digest(&bytes).into();
+        mempool.insert(tx, bytes, 1, 0).await.unwrap();
+        let (tx, bytes) = signed_tx(&alice, 0, b"data");
+        let alice_hash: [u8; 32] = Sha256::digest(&bytes).into();
+        mempool.insert(tx, bytes, 1, 0).await.unwrap();
+
+        // alice's nonce 1 must not precede her nonce 0, even though it was inserted first
+        let ready = mempool.ready_transactions(&state).await.unwrap();
+        let hashes: Vec<[u8; 32]> = ready
+            .iter()
+            .map(|tx| Sha256::digest(tx.tx_bytes()).into())
+            .collect();
+        assert_eq!(hashes[0], bob_hash);
+        assert_eq!(hashes[1], alice_hash);
+        assert_eq!(nonces(&ready[2..]), vec![1]);
+    }
+
+    #[tokio::test]
+    async fn same_nonce_replacement_requires_higher_fee() {
+        let mempool = Mempool::new();
+        let signing_key = SigningKey::from([1; 32]);
+
+        let (tx, bytes) = signed_tx(&signing_key, 0, b"first");
+        let first_hash: [u8; 32] = Sha256::digest(&bytes).into();
+        assert_eq!(mempool.insert(tx, bytes, 10, 0).await.unwrap(), None);
+
+        // re-inserting the same transaction is a no-op
+        let (tx, bytes) = signed_tx(&signing_key, 0, b"first");
+        assert_eq!(mempool.insert(tx, bytes, 10, 0).await.unwrap(), None);
+
+        let (tx, bytes) = signed_tx(&signing_key, 0, b"second");
+        let _ = mempool.insert(tx, bytes, 10, 0).await.unwrap_err();
+
+        let (tx, bytes) = signed_tx(&signing_key, 0, b"third");
+        let third_hash: [u8; 32] = Sha256::digest(&bytes).into();
+        assert_eq!(
+            mempool.insert(tx, bytes, 11, 0).await.unwrap(),
+            Some(first_hash)
+        );
+        assert!(!mempool.contains(&first_hash).await);
+        assert!(mempool.contains(&third_hash).await);
+        assert_eq!(mempool.len().await, 1);
+    }
+
+    #[tokio::test]
+    async fn insert_with_used_nonce_fails() {
+        let mempool = Mempool::new();
+        let signing_key = SigningKey::from([1; 32]);
+        let (tx, bytes) = signed_tx(&signing_key, 0, b"data");
+        let _ = mempool.insert(tx, bytes, 1, 1).await.unwrap_err();
+        assert_eq!(mempool.len().await, 0);
+    }
+
+    #[tokio::test]
+    async fn pending_transactions_before_returns_lower_nonces() {
+        let mempool = Mempool::new();
+        let signing_key = SigningKey::from([1; 32]);
+        let address = Address::from_verification_key(signing_key.verification_key());
+
+        for nonce in [0, 1, 3] {
+            let (tx, bytes) = signed_tx(&signing_key, nonce, b"data");
+            mempool.insert(tx, bytes, 1, 0).await.unwrap();
+        }
+
+        let pending = mempool.pending_transactions_before(&address, 3).await;
+        let pending_nonces: Vec<u32> = pending
+            .iter()
+            .map(|tx| tx.unsigned_transaction().nonce)
+            .collect();
+        assert_eq!(pending_nonces, vec![0, 1]);
+        assert!(
+            mempool
+                .pending_transactions_before(&address, 0)
+                .await
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn remove_stale_evicts_used_nonces() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        let mempool = Mempool::new();
+        let signing_key = SigningKey::from([1; 32]);
+        let address = Address::from_verification_key(signing_key.verification_key());
+
+        for nonce in [0, 1, 2] {
+            let (tx, bytes) = signed_tx(&signing_key, nonce, b"data");
+            mempool.insert(tx, bytes, 1, 0).await.unwrap();
+        }
+
+        state.put_account_nonce(address, 2).unwrap();
+        mempool.remove_stale(&state).await.unwrap();
+        assert_eq!(mempool.len().await, 1);
+        let ready = mempool.ready_transactions(&state).await.unwrap();
+        assert_eq!(nonces(&ready), vec![2]);
+
+        state.put_account_nonce(address, 3).unwrap();
+        mempool.remove_stale(&state).await.unwrap();
+        assert_eq!(mempool.len().await, 0);
+    }
+
+    #[tokio::test]
+    async fn insert_with_nonce_too_far_ahead_fails() {
+        let mempool = Mempool::new();
+        let signing_key = SigningKey::from([1; 32]);
+
+        let (tx, bytes) = signed_tx(&signing_key, MAX_NONCE_GAP + 1, b"data");
+        let err = mempool.insert(tx, bytes, 1, 0).await.unwrap_err();
+        assert!(err.to_string().contains("ahead of the account nonce"));
+
+        let (tx, bytes) = signed_tx(&signing_key, MAX_NONCE_GAP + 1, b"data");
+        mempool.insert(tx, bytes, 1, 1).await.unwrap();
+        assert_eq!(mempool.len().await, 1);
+    }
+
+    #[tokio::test]
+    async fn full_account_evicts_its_highest_nonce() {
+        let mempool = Mempool::with_limits(Limits {
+            max_pending_per_account: 2,
+            ..Limits::default()
+        });
+        let signing_key = SigningKey::from([1; 32]);
+        let address = Address::from_verification_key(signing_key.verification_key());
+
+        for nonce in [0, 2] {
+            let (tx, bytes) = signed_tx(&signing_key, nonce, b"data");
+            mempool.insert(tx, bytes, 1, 0).await.unwrap();
+        }
+
+        // a transaction after all pending transactions is rejected
+        let (tx, bytes) = signed_tx(&signing_key, 3, b"data");
+        let err = mempool.insert(tx, bytes, 1, 0).await.unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("maximum of 2 pending transactions"));
+
+        // a transaction filling the gap evicts the transaction with the highest nonce
+        let (tx, bytes) = signed_tx(&signing_key, 1, b"data");
+        mempool.insert(tx, bytes, 1, 0).await.unwrap();
+        let pending = mempool
+            .pending_transactions_before(&address, u32::MAX)
+            .await;
+        let pending_nonces: Vec<u32> = pending
+            .iter()
+            .map(|tx| tx.unsigned_transaction().nonce)
+            .collect();
+        assert_eq!(pending_nonces, vec![0, 1]);
+    }
+
+    #[tokio::test]
+    async fn full_mempool_evicts_lowest_tip_per_byte() {
+        let alice = SigningKey::from([1; 32]);
+        let bob = SigningKey::from([2; 32]);
+        let carol = SigningKey::from([3; 32]);
+        let dave = SigningKey::from([4; 32]);
+
+        let (alice_tx, alice_bytes) = signed_tx_with_tip(&alice, 0, b"data", Some(10));
+        let alice_hash: [u8; 32] = Sha256::digest(&alice_bytes).into();
+        let (bob_tx, bob_bytes) = signed_tx_with_tip(&bob, 0, b"data", Some(100));
+        let bob_hash: [u8; 32] = Sha256::digest(&bob_bytes).into();
+        let mempool = Mempool::with_limits(Limits {
+            max_total_bytes: alice_bytes.len() + bob_bytes.len(),
+            ..Limits::default()
+        });
+        mempool.insert(alice_tx, alice_bytes, 1, 0).await.unwrap();
+        mempool.insert(bob_tx, bob_bytes, 1, 0).await.unwrap();
+
+        // a transaction paying less than all pending transactions is rejected
+        let (tx, bytes) = signed_tx_with_tip(&dave, 0, b"data", Some(1));
+        let err = mempool.insert(tx, bytes, 1, 0).await.unwrap_err();
+        assert!(err.to_string().contains("mempool is full"));
+
+        // a transaction paying more than alice's evicts it
+        let (tx, bytes) = signed_tx_with_tip(&carol, 0, b"data", Some(50));
+        let carol_hash: [u8; 32] = Sha256::digest(&bytes).into();
+        mempool.insert(tx, bytes, 1, 0).await.unwrap();
+        assert!(!mempool.contains(&alice_hash).await);
+        assert!(mempool.contains(&bob_hash).await);
+        assert!(mempool.contains(&carol_hash).await);
+        assert_eq!(mempool.len().await, 2);
+    }
+}
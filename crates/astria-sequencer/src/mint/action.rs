@@ -4,9 +4,11 @@ use anyhow::{
     Result,
 };
 use astria_core::sequencer::v1alpha1::{
+    event::MintEvent,
     transaction::action::MintAction,
     Address,
 };
+use cnidarium::StateRead;
 use tracing::instrument;
 
 use crate::{
@@ -14,11 +16,72 @@ use crate::{
         StateReadExt as AccountStateReadExt,
         StateWriteExt as AccountStateWriteExt,
     },
-    asset::get_native_asset,
+    asset::{
+        get_native_asset,
+        state_ext::StateWriteExt as _,
+    },
     authority::state_ext::StateReadExt as AuthorityStateReadExt,
+    mint::state_ext::{
+        StateReadExt as _,
+        StateWriteExt as _,
+    },
+    state_ext::StateReadExt as _,
     transaction::action_handler::ActionHandler,
 };
 
+/// The amounts minted in the current block and since genesis after a mint.
+struct Minted {
+    height: u64,
+    block: u128,
+    supply: u128,
+}
+
+/// Returns the amounts minted after minting `amount`, checking that they stay within
+/// the mint caps.
+async fn check_mint_caps<S: StateRead>(state: &S, amount: u128) -> Result<Minted> {
+    let caps = state
+        .get_mint_caps()
+        .await
+        .context("failed to get mint caps")?;
+    let height = state
+        .get_block_height()
+        .await
+        .context("failed to get block height")?;
+
+    let block_minted = state
+        .get_block_minted(height)
+        .await
+        .context("failed to get amount minted in block")?;
+    let block = block_minted
+        .checked_add(amount)
+        .context("amount minted in block overflowed u128")?;
+    ensure!(
+        block <= caps.max_per_block,
+        "mint of {amount} exceeds the per-block mint cap of {} with {block_minted} already minted \
+         in this block",
+        caps.max_per_block,
+    );
+
+    let minted_supply = state
+        .get_minted_supply()
+        .await
+        .context("failed to get minted supply")?;
+    let supply = minted_supply
+        .checked_add(amount)
+        .context("minted supply overflowed u128")?;
+    ensure!(
+        supply <= caps.max_total,
+        "mint of {amount} exceeds the total mint cap of {} with {minted_supply} already minted",
+        caps.max_total,
+    );
+
+    Ok(Minted {
+        height,
+        block,
+        supply,
+    })
+}
+
 #[async_trait::async_trait]
 impl ActionHandler for MintAction {
     async fn check_stateful<S: AuthorityStateReadExt>(
@@ -32,6 +95,7 @@ impl ActionHandler for MintAction {
             .await
             .context("failed to get sudo address from state")?;
         ensure!(sudo_address == from, "signer is not the sudo key");
+        check_mint_caps(state, self.amount).await?;
         Ok(())
     }
 
@@ -39,10 +103,23 @@ impl ActionHandler for MintAction {
     async fn execute<S: AccountStateWriteExt + AccountStateReadExt>(
         &self,
         state: &mut S,
-        _: Address,
+        from: Address,
     ) -> Result<()> {
         let native_asset = get_native_asset().id();
 
+        // the caps are checked again, as a transaction can contain several mints
+        let Minted {
+            height,
+            block,
+            supply,
+        } = check_mint_caps(&*state, self.amount).await?;
+        state
+            .put_block_minted(height, block)
+            .context("failed to put amount minted in block")?;
+        state
+            .put_minted_supply(supply)
+            .context("failed to put minted supply")?;
+
         let to_balance = state
             .get_account_balance(self.to, native_asset)
             .await
@@ -50,6 +127,20 @@ impl ActionHandler for MintAction {
         state
             .put_account_balance(self.to, native_asset, to_balance + self.amount)
             .context("failed updating `to` account balance")?;
+        state
+            .increase_total_supply(native_asset, self.amount)
+            .await
+            .context("failed to increase total supply")?;
+
+        state.record(
+            MintEvent {
+                minter: from,
+                to: self.to,
+                amount: self.amount,
+                minted_supply: supply,
+            }
+            .into_abci(),
+        );
         Ok(())
     }
 }
@@ -1 +1,18 @@
+//! Minting of the native asset by the sudo address, which is only possible with the
+//! `mint` feature.
+//!
+//! Mints are limited by the [`MintCaps`] set at genesis, which cap the amount minted in
+//! a single block and over the lifetime of the chain. Caps which are not set are zero, so
+//! nothing can be minted on a chain without caps. No action changes the caps, so that
+//! a leaked sudo key cannot inflate the supply without bound. Setting new caps on a running
+//! chain requires a state migration shipped with a binary and run by a scheduled
+//! [upgrade](crate::upgrade).
+//!
+//! Only the mint action is gated by the feature. The mint state and its accessors are
+//! compiled regardless, so that the state is the same on all nodes.
+//!
+//! [`MintCaps`]: crate::genesis::MintCaps
+
+#[cfg(feature = "mint")]
 pub(crate) mod action;
+pub(crate) mod state_ext;
@@ -0,0 +1,144 @@
+use std::cmp::Ordering;
+
+use anyhow::{
+    bail,
+    Context,
+    Result,
+};
+use async_trait::async_trait;
+use borsh::{
+    BorshDeserialize,
+    BorshSerialize,
+};
+use cnidarium::{
+    StateRead,
+    StateWrite,
+};
+use tracing::instrument;
+
+use crate::genesis::MintCaps;
+
+/// Newtype wrapper to read and write the minted supply from rocksdb.
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+struct MintedSupply(u128);
+
+/// The amount minted in the block at `height`.
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+struct BlockMinted {
+    height: u64,
+    amount: u128,
+}
+
+const MINT_CAPS_STORAGE_KEY: &str = "mint/caps";
+const MINTED_SUPPLY_STORAGE_KEY: &str = "mint/minted_supply";
+const BLOCK_MINTED_STORAGE_KEY: &str = "mint/block_minted";
+
+#[async_trait]
+pub(crate) trait StateReadExt: StateRead {
+    /// Returns the mint caps of the chain, which default to zero if they were never set,
+    /// so that nothing can be minted.
+    #[instrument(skip(self))]
+    async fn get_mint_caps(&self) -> Result<MintCaps> {
+        let Some(bytes) = self
+            .get_raw(MINT_CAPS_STORAGE_KEY)
+            .await
+            .context("failed reading raw mint caps from state")?
+        else {
+            return Ok(MintCaps::default());
+        };
+        MintCaps::try_from_slice(&bytes).context("invalid mint caps bytes")
+    }
+
+    /// Returns the total amount of the native asset minted since genesis.
+    #[instrument(skip(self))]
+    async fn get_minted_supply(&self) -> Result<u128> {
+        let Some(bytes) = self
+            .get_raw(MINTED_SUPPLY_STORAGE_KEY)
+            .await
+            .context("failed reading raw minted supply from state")?
+        else {
+            return Ok(0);
+        };
+        let MintedSupply(supply) =
+            MintedSupply::try_from_slice(&bytes).context("invalid minted supply bytes")?;
+        Ok(supply)
+    }
+
+    /// Returns the amount of the native asset minted in the block at `height`.
+    ///
+    /// Only the amount of the latest block that minted anything is stored, and it is
+    /// overwritten by the first mint of a later block. The amount is 0 if the stored amount
+    /// belongs to an earlier block, as nothing was minted at `height` yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the stored amount belongs to a later block than `height`, as
+    /// the amount minted at `height` is no longer known.
+    #[instrument(skip(self))]
+    async fn get_block_minted(&self, height: u64) -> Result<u128> {
+        let Some(bytes) = self
+            .get_raw(BLOCK_MINTED_STORAGE_KEY)
+            .await
+            .context("failed reading raw block minted amount from state")?
+        else {
+            return Ok(0);
+        };
+        let BlockMinted {
+            height: minted_height,
+            amount,
+        } = BlockMinted::try_from_slice(&bytes).context("invalid block minted amount bytes")?;
+        match minted_height.cmp(&height) {
+            Ordering::Less => Ok(0),
+            Ordering::Equal => Ok(amount),
+            Ordering::Greater => bail!(
+                "amount minted in block {height} is no longer known, last mint was in block \
+                 {minted_height}"
+            ),
+        }
+    }
+}
+
+impl<T: StateRead> StateReadExt for T {}
+
+#[async_trait]
+pub(crate) trait StateWriteExt: StateWrite {
+    /// Puts the mint caps of the chain.
+    ///
+    /// This is only called at genesis; changing the caps of a running chain requires a
+    /// state migration run by a scheduled upgrade.
+    #[instrument(skip(self))]
+    fn put_mint_caps(&mut self, caps: &MintCaps) -> Result<()> {
+        self.put_raw(
+            MINT_CAPS_STORAGE_KEY.to_string(),
+            caps.try_to_vec().context("failed to serialize mint caps")?,
+        );
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    fn put_minted_supply(&mut self, supply: u128) -> Result<()> {
+        self.put_raw(
+            MINTED_SUPPLY_STORAGE_KEY.to_string(),
+            MintedSupply(supply)
+                .try_to_vec()
+                .context("failed to serialize minted supply")?,
+        );
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    fn put_block_minted(&mut self, height: u64, amount: u128) -> Result<()> {
+        self.put_raw(
+            BLOCK_MINTED_STORAGE_KEY.to_string(),
+            BlockMinted {
+                height,
+                amount,
+            }
+            .try_to_vec()
+            .context("failed to serialize block minted amount")?,
+        );
+        Ok(())
+    }
+}
+
+impl<T: StateWrite> StateWriteExt for T {}
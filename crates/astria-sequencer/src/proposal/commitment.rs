@@ -28,10 +28,11 @@ impl GeneratedCommitments {
 /// In the case of `ProcessProposal`, we use this function to generate and verify the
 /// `commitment_tx` expected at the start of the block.
 ///
-/// This function sorts the block's `sequence::Action`s contained within the transactions
-/// using their `rollup_id`. It then returns the merkle root of the tree where each leaf is
-/// a commitment of `sequence::Action`s with the same `rollup_id`. The leaves are ordered
-/// by `rollup_id` in ascending order.
+/// This function sorts the block's `sequence::Action`s, `BridgeLockAction`s and received ICS20
+/// transfers routed to rollups contained within the transactions using their `rollup_id`,
+/// encoding each as a `RollupData`. It then returns the
+/// merkle root of the tree where each leaf is a commitment of `sequence::Action`s with the same
+/// `rollup_id`. The leaves are ordered by `rollup_id` in ascending order.
 /// This structure can be referred to as the "action tree".
 ///
 /// The leaf, which contains a commitment to every action with the same `rollup_id`, is currently
@@ -40,7 +41,10 @@ impl GeneratedCommitments {
 pub(crate) fn generate_sequence_actions_commitment(
     signed_txs: &[SignedTransaction],
 ) -> GeneratedCommitments {
-    let rollup_ids_to_txs = astria_core::sequencer::v1alpha1::group_sequence_actions_in_signed_transaction_transactions_by_rollup_id(signed_txs);
+    let rollup_ids_to_txs =
+        astria_core::sequencer::v1alpha1::group_rollup_data_in_signed_transactions_by_rollup_id(
+            signed_txs,
+        );
     let rollup_ids_root = merkle::Tree::from_leaves(rollup_ids_to_txs.keys()).root();
 
     // each leaf of the action tree is the root of a merkle tree of the `sequence::Action`s
@@ -99,6 +103,10 @@ mod test {
         let tx = UnsignedTransaction {
             nonce: 0,
             actions: vec![sequence_action.clone().into(), transfer_action.into()],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
         };
 
         let signed_tx = tx.into_signed(&signing_key);
@@ -112,6 +120,10 @@ mod test {
         let tx = UnsignedTransaction {
             nonce: 0,
             actions: vec![sequence_action.into()],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
         };
 
         let signed_tx = tx.into_signed(&signing_key);
@@ -148,6 +160,10 @@ mod test {
         let tx = UnsignedTransaction {
             nonce: 0,
             actions: vec![sequence_action.into(), transfer_action.into()],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
         };
 
         let signed_tx = tx.into_signed(&signing_key);
@@ -158,8 +174,8 @@ mod test {
         } = generate_sequence_actions_commitment(&txs);
 
         let expected: [u8; 32] = [
-            74, 113, 242, 162, 39, 84, 89, 175, 130, 76, 171, 61, 17, 189, 247, 101, 151, 181, 174,
-            181, 52, 122, 131, 245, 56, 22, 11, 80, 217, 112, 44, 31,
+            189, 156, 127, 228, 51, 249, 64, 237, 150, 91, 219, 216, 1, 99, 135, 28, 235, 15, 249,
+            129, 3, 59, 231, 75, 92, 72, 103, 106, 173, 167, 251, 238,
         ];
         assert_eq!(expected, actual);
     }
@@ -0,0 +1,54 @@
+use astria_core::sequencer::v1alpha1::{
+    AbciErrorCode,
+    ChainIdResponse,
+};
+use cnidarium::Storage;
+use prost::Message as _;
+use tendermint::abci::{
+    request,
+    response,
+};
+
+use crate::{
+    accounts::query::get_snapshot_and_height,
+    state_ext::StateReadExt as _,
+};
+
+pub(crate) async fn chain_id_request(
+    storage: Storage,
+    request: request::Query,
+    _params: Vec<(String, String)>,
+) -> response::Query {
+    let (snapshot, height) = match get_snapshot_and_height(&storage, request.height).await {
+        Ok(tup) => tup,
+        Err(err_rsp) => return err_rsp,
+    };
+
+    let chain_id = match snapshot.get_chain_id().await {
+        Ok(chain_id) => chain_id,
+        Err(err) => {
+            return response::Query {
+                code: AbciErrorCode::INTERNAL_ERROR.into(),
+                info: AbciErrorCode::INTERNAL_ERROR.to_string(),
+                log: format!("failed getting chain id: {err:?}"),
+                height,
+                ..response::Query::default()
+            };
+        }
+    };
+
+    let payload = ChainIdResponse {
+        height: height.value(),
+        chain_id,
+    }
+    .into_raw()
+    .encode_to_vec()
+    .into();
+    response::Query {
+        code: 0.into(),
+        key: request.path.clone().into_bytes().into(),
+        value: payload,
+        height,
+        ..response::Query::default()
+    }
+}
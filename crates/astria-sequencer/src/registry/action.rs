@@ -0,0 +1,171 @@
+use anyhow::{
+    bail,
+    ensure,
+    Context as _,
+    Result,
+};
+use astria_core::sequencer::v1alpha1::{
+    event::RegisterRollupEvent,
+    transaction::action::{
+        RegisterRollupAction,
+        RollupMetadataChangeAction,
+        RollupOwnerChangeAction,
+    },
+    Address,
+    RollupId,
+    RollupRecord,
+};
+use tracing::instrument;
+
+use crate::{
+    fees::state_ext::StateReadExt as _,
+    registry::state_ext::{
+        StateReadExt,
+        StateWriteExt,
+    },
+    transaction::action_handler::ActionHandler,
+};
+
+/// The maximum length of a rollup name in bytes.
+pub(crate) const MAX_ROLLUP_NAME_LENGTH: usize = 64;
+
+/// The maximum length of a rollup's metadata in bytes.
+pub(crate) const MAX_ROLLUP_METADATA_LENGTH: usize = 2048;
+
+fn check_metadata(metadata: &str) -> Result<()> {
+    ensure!(
+        metadata.len() <= MAX_ROLLUP_METADATA_LENGTH,
+        "rollup metadata must not be longer than {MAX_ROLLUP_METADATA_LENGTH} bytes"
+    );
+    Ok(())
+}
+
+/// Returns the registry record of `rollup_id` after checking that `from` is its owner.
+async fn get_owned_rollup_record<S: StateReadExt>(
+    state: &S,
+    rollup_id: &RollupId,
+    from: Address,
+) -> Result<RollupRecord> {
+    let Some(record) = state
+        .get_rollup_record(rollup_id)
+        .await
+        .context("failed to get rollup record")?
+    else {
+        bail!("rollup is not registered");
+    };
+    ensure!(record.owner == from, "signer is not the rollup owner");
+    Ok(record)
+}
+
+#[async_trait::async_trait]
+impl ActionHandler for RegisterRollupAction {
+    async fn check_stateless(&self) -> Result<()> {
+        ensure!(!self.name.is_empty(), "rollup name must not be empty");
+        ensure!(
+            self.name.len() <= MAX_ROLLUP_NAME_LENGTH,
+            "rollup name must not be longer than {MAX_ROLLUP_NAME_LENGTH} bytes"
+        );
+        // names are part of the query paths used to look up rollups
+        ensure!(
+            self.name
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_' | b'.')),
+            "rollup name must only contain ascii alphanumeric characters, `-`, `_` and `.`"
+        );
+        check_metadata(&self.metadata)
+    }
+
+    async fn check_stateful<S: StateReadExt + 'static>(&self, state: &S, _: Address) -> Result<()> {
+        ensure!(
+            state.is_allowed_fee_asset(self.fee_asset_id).await?,
+            "invalid fee asset"
+        );
+        ensure!(
+            state
+                .get_rollup_record(&self.rollup_id())
+                .await
+                .context("failed to get rollup record")?
+                .is_none(),
+            "rollup is already registered"
+        );
+        Ok(())
+    }
+
+    #[instrument(skip_all, fields(name = %self.name))]
+    async fn execute<S: StateWriteExt>(&self, state: &mut S, _: Address) -> Result<()> {
+        let rollup_id = self.rollup_id();
+        state
+            .put_rollup_record(
+                &rollup_id,
+                &RollupRecord {
+                    name: self.name.clone(),
+                    owner: self.owner,
+                    celestia_namespace: self.celestia_namespace,
+                    metadata: self.metadata.clone(),
+                },
+            )
+            .context("failed to put rollup record")?;
+
+        state.record(
+            RegisterRollupEvent {
+                rollup_id,
+                name: self.name.clone(),
+                owner: self.owner,
+            }
+            .into_abci(),
+        );
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl ActionHandler for RollupOwnerChangeAction {
+    /// check that the signer of the transaction is the rollup's current owner,
+    /// as only the owner can transfer the rollup's ownership
+    async fn check_stateful<S: StateReadExt + 'static>(
+        &self,
+        state: &S,
+        from: Address,
+    ) -> Result<()> {
+        get_owned_rollup_record(state, &self.rollup_id, from).await?;
+        Ok(())
+    }
+
+    #[instrument(skip_all, fields(rollup_id = %self.rollup_id, new_owner = %self.new_owner))]
+    async fn execute<S: StateWriteExt>(&self, state: &mut S, from: Address) -> Result<()> {
+        let mut record = get_owned_rollup_record(&*state, &self.rollup_id, from).await?;
+        record.owner = self.new_owner;
+        state
+            .put_rollup_record(&self.rollup_id, &record)
+            .context("failed to put rollup record")?;
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl ActionHandler for RollupMetadataChangeAction {
+    async fn check_stateless(&self) -> Result<()> {
+        check_metadata(&self.metadata)
+    }
+
+    /// check that the signer of the transaction is the rollup's owner,
+    /// as only the owner can change the rollup's metadata
+    async fn check_stateful<S: StateReadExt + 'static>(
+        &self,
+        state: &S,
+        from: Address,
+    ) -> Result<()> {
+        get_owned_rollup_record(state, &self.rollup_id, from).await?;
+        Ok(())
+    }
+
+    #[instrument(skip_all, fields(rollup_id = %self.rollup_id))]
+    async fn execute<S: StateWriteExt>(&self, state: &mut S, from: Address) -> Result<()> {
+        let mut record = get_owned_rollup_record(&*state, &self.rollup_id, from).await?;
+        record.metadata.clone_from(&self.metadata);
+        state
+            .put_rollup_record(&self.rollup_id, &record)
+            .context("failed to put rollup record")?;
+        Ok(())
+    }
+}
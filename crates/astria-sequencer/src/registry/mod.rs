@@ -0,0 +1,14 @@
+//! The rollup registry, which records the name, owner, celestia namespace and metadata
+//! of rollups.
+//!
+//! A rollup is registered under the rollup ID derived from its name with a
+//! [`RegisterRollupAction`], after which only its owner can transfer its ownership
+//! ([`RollupOwnerChangeAction`]) or replace its metadata ([`RollupMetadataChangeAction`]).
+//!
+//! [`RegisterRollupAction`]: astria_core::sequencer::v1alpha1::transaction::action::RegisterRollupAction
+//! [`RollupOwnerChangeAction`]: astria_core::sequencer::v1alpha1::transaction::action::RollupOwnerChangeAction
+//! [`RollupMetadataChangeAction`]: astria_core::sequencer::v1alpha1::transaction::action::RollupMetadataChangeAction
+
+pub(crate) mod action;
+pub(crate) mod query;
+pub(crate) mod state_ext;
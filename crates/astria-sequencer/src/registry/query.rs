@@ -0,0 +1,111 @@
+use anyhow::Context as _;
+use astria_core::sequencer::v1alpha1::{
+    AbciErrorCode,
+    RollupId,
+    RollupResponse,
+};
+use cnidarium::Storage;
+use prost::Message as _;
+use tendermint::abci::{
+    request,
+    response,
+};
+
+use crate::{
+    accounts::query::get_snapshot_and_height,
+    registry::state_ext::StateReadExt as _,
+};
+
+/// Returns the registry record of the rollup with the given hex encoded ID at the
+/// requested height.
+pub(crate) async fn rollup_by_id_request(
+    storage: Storage,
+    request: request::Query,
+    params: Vec<(String, String)>,
+) -> response::Query {
+    let rollup_id = match preprocess_request(&params) {
+        Ok(rollup_id) => rollup_id,
+        Err(err_rsp) => return err_rsp,
+    };
+    rollup_response(storage, request, rollup_id).await
+}
+
+fn preprocess_request(params: &[(String, String)]) -> anyhow::Result<RollupId, response::Query> {
+    let Some(rollup_id) = params.iter().find_map(|(k, v)| (k == "id").then_some(v)) else {
+        return Err(response::Query {
+            code: AbciErrorCode::INVALID_PARAMETER.into(),
+            info: AbciErrorCode::INVALID_PARAMETER.to_string(),
+            log: "path did not contain rollup ID parameter".into(),
+            ..response::Query::default()
+        });
+    };
+    hex::decode(rollup_id)
+        .context("failed decoding hex encoded bytes")
+        .and_then(|bytes| {
+            RollupId::try_from_vec(bytes).context("failed constructing rollup ID from bytes")
+        })
+        .map_err(|err| response::Query {
+            code: AbciErrorCode::INVALID_PARAMETER.into(),
+            info: AbciErrorCode::INVALID_PARAMETER.to_string(),
+            log: format!("rollup ID could not be constructed from provided parameter: {err:?}"),
+            ..response::Query::default()
+        })
+}
+
+/// Returns the registry record of the rollup with the given name at the requested height.
+pub(crate) async fn rollup_by_name_request(
+    storage: Storage,
+    request: request::Query,
+    params: Vec<(String, String)>,
+) -> response::Query {
+    let Some(name) = params.iter().find_map(|(k, v)| (k == "name").then_some(v)) else {
+        return response::Query {
+            code: AbciErrorCode::INVALID_PARAMETER.into(),
+            info: AbciErrorCode::INVALID_PARAMETER.to_string(),
+            log: "path did not contain rollup name parameter".into(),
+            ..response::Query::default()
+        };
+    };
+    let rollup_id = RollupId::from_unhashed_bytes(name);
+    rollup_response(storage, request, rollup_id).await
+}
+
+async fn rollup_response(
+    storage: Storage,
+    request: request::Query,
+    rollup_id: RollupId,
+) -> response::Query {
+    let (snapshot, height) = match get_snapshot_and_height(&storage, request.height).await {
+        Ok(tup) => tup,
+        Err(err_rsp) => return err_rsp,
+    };
+
+    let rollup = match snapshot.get_rollup_record(&rollup_id).await {
+        Ok(rollup) => rollup,
+        Err(err) => {
+            return response::Query {
+                code: AbciErrorCode::INTERNAL_ERROR.into(),
+                info: AbciErrorCode::INTERNAL_ERROR.to_string(),
+                log: format!("failed getting rollup record: {err:?}"),
+                height,
+                ..response::Query::default()
+            };
+        }
+    };
+
+    let payload = RollupResponse {
+        height: height.value(),
+        rollup_id,
+        rollup,
+    }
+    .into_raw()
+    .encode_to_vec()
+    .into();
+    response::Query {
+        code: 0.into(),
+        key: request.path.clone().into_bytes().into(),
+        value: payload,
+        height,
+        ..response::Query::default()
+    }
+}
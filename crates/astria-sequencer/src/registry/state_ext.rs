@@ -0,0 +1,108 @@
+use anyhow::{
+    Context,
+    Result,
+};
+use astria_core::sequencer::v1alpha1::{
+    rollup::{
+        CelestiaNamespaceId,
+        CELESTIA_NAMESPACE_ID_LEN,
+    },
+    Address,
+    RollupId,
+    RollupRecord,
+    ADDRESS_LEN,
+};
+use async_trait::async_trait;
+use borsh::{
+    BorshDeserialize,
+    BorshSerialize,
+};
+use cnidarium::{
+    StateRead,
+    StateWrite,
+};
+use hex::ToHex as _;
+use tracing::instrument;
+
+/// Newtype wrapper to read and write a rollup record from rocksdb.
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+struct RollupRecordStorage {
+    name: String,
+    owner: [u8; ADDRESS_LEN],
+    celestia_namespace: [u8; CELESTIA_NAMESPACE_ID_LEN],
+    metadata: String,
+}
+
+impl From<&RollupRecord> for RollupRecordStorage {
+    fn from(record: &RollupRecord) -> Self {
+        Self {
+            name: record.name.clone(),
+            owner: record.owner.0,
+            celestia_namespace: record.celestia_namespace.get(),
+            metadata: record.metadata.clone(),
+        }
+    }
+}
+
+impl From<RollupRecordStorage> for RollupRecord {
+    fn from(storage: RollupRecordStorage) -> Self {
+        let RollupRecordStorage {
+            name,
+            owner,
+            celestia_namespace,
+            metadata,
+        } = storage;
+        Self {
+            name,
+            owner: Address::from_array(owner),
+            celestia_namespace: CelestiaNamespaceId::new(celestia_namespace),
+            metadata,
+        }
+    }
+}
+
+const ROLLUP_REGISTRY_PREFIX: &str = "rollup_registry";
+
+fn rollup_record_storage_key(rollup_id: &RollupId) -> String {
+    format!(
+        "{ROLLUP_REGISTRY_PREFIX}/{}",
+        rollup_id.encode_hex::<String>()
+    )
+}
+
+#[async_trait]
+pub(crate) trait StateReadExt: StateRead {
+    /// Returns the registry record of the given rollup, or `None` if the rollup is
+    /// not registered.
+    #[instrument(skip(self))]
+    async fn get_rollup_record(&self, rollup_id: &RollupId) -> Result<Option<RollupRecord>> {
+        let Some(bytes) = self
+            .get_raw(&rollup_record_storage_key(rollup_id))
+            .await
+            .context("failed reading raw rollup record from state")?
+        else {
+            return Ok(None);
+        };
+        let record =
+            RollupRecordStorage::try_from_slice(&bytes).context("invalid rollup record bytes")?;
+        Ok(Some(record.into()))
+    }
+}
+
+impl<T: StateRead> StateReadExt for T {}
+
+#[async_trait]
+pub(crate) trait StateWriteExt: StateWrite {
+    #[instrument(skip(self))]
+    fn put_rollup_record(&mut self, rollup_id: &RollupId, record: &RollupRecord) -> Result<()> {
+        self.put_raw(
+            rollup_record_storage_key(rollup_id),
+            RollupRecordStorage::from(record)
+                .try_to_vec()
+                .context("failed to serialize rollup record")?,
+        );
+        Ok(())
+    }
+}
+
+impl<T: StateWrite> StateWriteExt for T {}
@@ -4,6 +4,7 @@ use anyhow::{
     Result,
 };
 use astria_core::sequencer::v1alpha1::{
+    event::SequenceEvent,
     transaction::action::SequenceAction,
     Address,
 };
@@ -14,26 +15,17 @@ use crate::{
         StateReadExt,
         StateWriteExt,
     },
-    state_ext::StateWriteExt as _,
+    fees::state_ext::StateReadExt as FeesStateReadExt,
     transaction::action_handler::ActionHandler,
 };
 
-/// Fee charged for a sequence `Action` per byte of `data` included.
-const SEQUENCE_ACTION_FEE_PER_BYTE: u128 = 1;
-
 #[async_trait::async_trait]
 impl ActionHandler for SequenceAction {
-    async fn check_stateful<S: StateReadExt + 'static>(
-        &self,
-        state: &S,
-        from: Address,
-    ) -> Result<()> {
-        let curr_balance = state
-            .get_account_balance(from, self.fee_asset_id)
-            .await
-            .context("failed getting `from` account balance for fee payment")?;
-        let fee = calculate_fee(&self.data).context("calculated fee overflows u128")?;
-        ensure!(curr_balance >= fee, "insufficient funds");
+    async fn check_stateful<S: StateReadExt + 'static>(&self, state: &S, _: Address) -> Result<()> {
+        ensure!(
+            state.is_allowed_fee_asset(self.fee_asset_id).await?,
+            "invalid fee asset"
+        );
         Ok(())
     }
 
@@ -54,30 +46,43 @@ impl ActionHandler for SequenceAction {
         )
     )]
     async fn execute<S: StateWriteExt>(&self, state: &mut S, from: Address) -> Result<()> {
-        let fee = calculate_fee(&self.data).context("failed to calculate fee")?;
-        state
-            .get_and_increase_block_fees(self.fee_asset_id, fee)
-            .await
-            .context("failed to add to block fees")?;
-
-        let from_balance = state
-            .get_account_balance(from, self.fee_asset_id)
-            .await
-            .context("failed getting `from` account balance")?;
-        state
-            .put_account_balance(from, self.fee_asset_id, from_balance - fee)
-            .context("failed updating `from` account balance")?;
+        state.record(
+            SequenceEvent {
+                from,
+                rollup_id: self.rollup_id,
+                data_len: self.data.len() as u64,
+            }
+            .into_abci(),
+        );
         Ok(())
     }
 }
 
+/// Calculates the fee for a sequence `Action` using the fee schedule stored in state.
+pub(crate) async fn calculate_fee_from_state<S: FeesStateReadExt>(
+    data: &[u8],
+    state: &S,
+) -> Result<u128> {
+    let base_fee = state
+        .get_sequence_base_fee()
+        .await
+        .context("failed to get sequence base fee")?;
+    let fee_per_byte = state
+        .get_sequence_byte_cost_multiplier()
+        .await
+        .context("failed to get sequence byte cost multiplier")?;
+    calculate_fee(data, fee_per_byte, base_fee).context("calculated fee overflows u128")
+}
+
 /// Calculates the fee for a sequence `Action` based on the length of the `data`.
 /// Returns `None` if the fee overflows `u128`.
-pub(crate) fn calculate_fee(data: &[u8]) -> Option<u128> {
-    SEQUENCE_ACTION_FEE_PER_BYTE.checked_mul(
-        data.len()
-            .try_into()
-            .expect("a usize should always convert to a u128"),
+fn calculate_fee(data: &[u8], fee_per_byte: u128, base_fee: u128) -> Option<u128> {
+    base_fee.checked_add(
+        fee_per_byte.checked_mul(
+            data.len()
+                .try_into()
+                .expect("a usize should always convert to a u128"),
+        )?,
     )
 }
 
@@ -87,8 +92,13 @@ mod test {
 
     #[test]
     fn calculate_fee_ok() {
-        assert_eq!(calculate_fee(&[]), Some(0));
-        assert_eq!(calculate_fee(&[0]), Some(1));
-        assert_eq!(calculate_fee(&[0u8; 10]), Some(10));
+        assert_eq!(calculate_fee(&[], 1, 0), Some(0));
+        assert_eq!(calculate_fee(&[0], 1, 0), Some(1));
+        assert_eq!(calculate_fee(&[0u8; 10], 1, 0), Some(10));
+
+        assert_eq!(calculate_fee(&[], 1, 42), Some(42));
+        assert_eq!(calculate_fee(&[0u8; 10], 2, 42), Some(62));
+        assert_eq!(calculate_fee(&[0u8; 10], u128::MAX, 0), None);
+        assert_eq!(calculate_fee(&[], 0, u128::MAX), Some(u128::MAX));
     }
 }
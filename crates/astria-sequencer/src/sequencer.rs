@@ -30,9 +30,12 @@ use tracing::{
 use crate::{
     app::App,
     config::Config,
+    grpc::sequencer::SequencerServer,
     host_interface::AstriaHost,
+    mempool::Mempool,
     service,
     state_ext::StateReadExt as _,
+    state_sync,
 };
 
 pub struct Sequencer;
@@ -58,12 +61,10 @@ impl Sequencer {
 
         let mut signals = spawn_signal_handler();
 
-        let substore_prefixes = vec![penumbra_ibc::IBC_SUBSTORE_PREFIX];
-
         let storage = cnidarium::Storage::load(
             config.db_filepath.clone(),
-            substore_prefixes
-                .into_iter()
+            state_sync::SUBSTORE_PREFIXES
+                .iter()
                 .map(std::string::ToString::to_string)
                 .collect(),
         )
@@ -84,7 +85,8 @@ impl Sequencer {
             crate::asset::initialize_native_asset(&native_asset);
         }
 
-        let app = App::new(snapshot);
+        let mempool = Mempool::new();
+        let app = App::new(snapshot, mempool.clone(), config.sequencer_block_retention);
         let consensus_service = tower::ServiceBuilder::new()
             .layer(request_span::layer(|req: &ConsensusRequest| {
                 req.create_span()
@@ -93,10 +95,25 @@ impl Sequencer {
                 let storage = storage.clone();
                 async move { service::Consensus::new(storage, app, queue).run().await }
             }));
-        let mempool_service = service::Mempool::new(storage.clone());
+        let mempool_service = service::Mempool::new(storage.clone(), mempool);
         let info_service =
             service::Info::new(storage.clone()).context("failed initializing info service")?;
-        let snapshot_service = service::Snapshot;
+        let snapshot_store = state_sync::SnapshotStore::new(
+            config.snapshot_dir.clone(),
+            config.snapshot_keep_recent,
+        );
+        if config.snapshot_interval > 0 {
+            info!(
+                interval = config.snapshot_interval,
+                "taking state sync snapshots periodically"
+            );
+            tokio::spawn(state_sync::run_periodic_snapshots(
+                storage.clone(),
+                snapshot_store.clone(),
+                config.snapshot_interval,
+            ));
+        }
+        let snapshot_service = service::Snapshot::new(storage.clone(), snapshot_store);
 
         let server = Server::builder()
             .consensus(consensus_service)
@@ -113,7 +130,7 @@ impl Sequencer {
             .grpc_addr
             .parse()
             .context("failed to parse grpc_addr address")?;
-        let grpc_server_handle = start_ibc_grpc_server(&storage, grpc_addr, shutdown_rx);
+        let grpc_server_handle = start_grpc_server(&storage, grpc_addr, shutdown_rx);
 
         info!(config.listen_addr, "starting sequencer");
         let server_handle = tokio::spawn(async move {
@@ -151,11 +168,12 @@ impl Sequencer {
     }
 }
 
-fn start_ibc_grpc_server(
+fn start_grpc_server(
     storage: &cnidarium::Storage,
     grpc_addr: std::net::SocketAddr,
     shutdown_rx: oneshot::Receiver<()>,
 ) -> JoinHandle<Result<(), tonic::transport::Error>> {
+    use astria_core::generated::sequencer::v1alpha1::sequencer_service_server::SequencerServiceServer;
     use futures::TryFutureExt as _;
     use ibc_proto::ibc::core::{
         channel::v1::query_server::QueryServer as ChannelQueryServer,
@@ -166,6 +184,7 @@ fn start_ibc_grpc_server(
     use tower_http::cors::CorsLayer;
 
     let ibc = penumbra_ibc::component::rpc::IbcQuery::<AstriaHost>::new(storage.clone());
+    let sequencer_api = SequencerServer::new(storage.clone());
     let cors_layer = CorsLayer::permissive();
 
     // TODO: setup HTTPS?
@@ -188,7 +207,8 @@ fn start_ibc_grpc_server(
         .layer(cors_layer)
         .add_service(ClientQueryServer::new(ibc.clone()))
         .add_service(ChannelQueryServer::new(ibc.clone()))
-        .add_service(ConnectionQueryServer::new(ibc.clone()));
+        .add_service(ConnectionQueryServer::new(ibc.clone()))
+        .add_service(SequencerServiceServer::new(sequencer_api));
 
     info!(grpc_addr = grpc_addr.to_string(), "starting grpc server");
     tokio::task::spawn(
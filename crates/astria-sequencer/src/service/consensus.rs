@@ -141,11 +141,20 @@ impl Consensus {
 
         let genesis_state: GenesisState = serde_json::from_slice(&init_chain.app_state_bytes)
             .context("failed to parse app_state in genesis file")?;
-        self.app
+        // the validators listed in the cometbft genesis take precedence. if there are none,
+        // the validators from the app state are used and returned to cometbft.
+        let validators = if init_chain.validators.is_empty() {
+            genesis_state.validators.clone()
+        } else {
+            init_chain.validators
+        };
+        let consensus_params = self
+            .app
             .init_chain(
                 genesis_state,
-                init_chain.validators.clone(),
+                validators.clone(),
                 init_chain.chain_id,
+                init_chain.consensus_params,
             )
             .await
             .context("failed to call init_chain")?;
@@ -158,8 +167,8 @@ impl Consensus {
                 .to_vec()
                 .try_into()
                 .context("failed to convert app hash")?,
-            consensus_params: Some(init_chain.consensus_params),
-            validators: init_chain.validators,
+            consensus_params: Some(consensus_params),
+            validators,
         })
     }
 
@@ -295,7 +304,9 @@ mod test {
     use super::*;
     use crate::{
         asset::get_native_asset,
+        mempool::Mempool,
         proposal::commitment::generate_sequence_actions_commitment,
+        upgrade::test_consensus_params,
     };
 
     fn make_unsigned_tx() -> UnsignedTransaction {
@@ -309,6 +320,10 @@ mod test {
                 }
                 .into(),
             ],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
         }
     }
 
@@ -341,12 +356,16 @@ mod test {
     #[tokio::test]
     async fn prepare_and_process_proposal() {
         let signing_key = SigningKey::new(OsRng);
-        let mut consensus_service =
+        let (mut consensus_service, mempool) =
             new_consensus_service(Some(signing_key.verification_key())).await;
         let tx = make_unsigned_tx();
         let signed_tx = tx.into_signed(&signing_key);
-        let tx_bytes = signed_tx.clone().into_raw().encode_to_vec();
-        let txs = vec![tx_bytes.into()];
+        let tx_bytes: Bytes = signed_tx.clone().into_raw().encode_to_vec().into();
+        let txs = vec![tx_bytes.clone()];
+        mempool
+            .insert(signed_tx.clone(), tx_bytes, 0, 0)
+            .await
+            .unwrap();
 
         let res = generate_sequence_actions_commitment(&vec![signed_tx]);
 
@@ -361,7 +380,7 @@ mod test {
             }
         );
 
-        let mut consensus_service =
+        let (mut consensus_service, _) =
             new_consensus_service(Some(signing_key.verification_key())).await;
         let process_proposal = new_process_proposal_request(prepare_proposal_response.txs);
         consensus_service
@@ -373,7 +392,7 @@ mod test {
     #[tokio::test]
     async fn process_proposal_ok() {
         let signing_key = SigningKey::new(OsRng);
-        let mut consensus_service =
+        let (mut consensus_service, _) =
             new_consensus_service(Some(signing_key.verification_key())).await;
         let tx = make_unsigned_tx();
         let signed_tx = tx.into_signed(&signing_key);
@@ -389,7 +408,7 @@ mod test {
 
     #[tokio::test]
     async fn process_proposal_fail_missing_action_commitment() {
-        let mut consensus_service = new_consensus_service(None).await;
+        let (mut consensus_service, _) = new_consensus_service(None).await;
         let process_proposal = new_process_proposal_request(vec![]);
         assert!(
             consensus_service
@@ -404,7 +423,7 @@ mod test {
 
     #[tokio::test]
     async fn process_proposal_fail_wrong_commitment_length() {
-        let mut consensus_service = new_consensus_service(None).await;
+        let (mut consensus_service, _) = new_consensus_service(None).await;
         let process_proposal = new_process_proposal_request(vec![[0u8; 16].to_vec().into()]);
         assert!(
             consensus_service
@@ -419,7 +438,7 @@ mod test {
 
     #[tokio::test]
     async fn process_proposal_fail_wrong_commitment_value() {
-        let mut consensus_service = new_consensus_service(None).await;
+        let (mut consensus_service, _) = new_consensus_service(None).await;
         let process_proposal = new_process_proposal_request(vec![
             [99u8; 32].to_vec().into(),
             [99u8; 32].to_vec().into(),
@@ -437,7 +456,7 @@ mod test {
 
     #[tokio::test]
     async fn prepare_proposal_empty_block() {
-        let mut consensus_service = new_consensus_service(None).await;
+        let (mut consensus_service, _) = new_consensus_service(None).await;
         let txs = vec![];
         let res = generate_sequence_actions_commitment(&txs.clone());
         let prepare_proposal = new_prepare_proposal_request(vec![]);
@@ -455,7 +474,7 @@ mod test {
 
     #[tokio::test]
     async fn process_proposal_ok_empty_block() {
-        let mut consensus_service = new_consensus_service(None).await;
+        let (mut consensus_service, _) = new_consensus_service(None).await;
         let txs = vec![];
         let res = generate_sequence_actions_commitment(&txs);
         let process_proposal = new_process_proposal_request(res.into_transactions(vec![]));
@@ -504,16 +523,33 @@ mod test {
                 accounts: vec![],
                 authority_sudo_address: Address::from([0; 20]),
                 ibc_sudo_address: Address::from([0; 20]),
+                ibc_relayer_addresses: vec![],
                 native_asset_base_denomination: DEFAULT_NATIVE_ASSET_DENOM.to_string(),
+                fees: crate::genesis::Fees {
+                    transfer_base_fee: 12,
+                    sequence_base_fee: 0,
+                    sequence_byte_cost_multiplier: 1,
+                    ics20_withdrawal_base_fee: 1,
+                },
+                allowed_fee_asset_denominations: vec![DEFAULT_NATIVE_ASSET_DENOM.to_string()],
+                ibc_assets: vec![],
+                asset_balances: vec![],
+                asset_metadata: vec![],
+                ibc_params: crate::genesis::IbcParameters::default(),
+                validators: vec![],
+                blockspace: crate::genesis::BlockspaceQuotas::default(),
+                mint_caps: crate::genesis::MintCaps::default(),
+                minted_supply: 0,
             }
         }
     }
 
-    async fn new_consensus_service(funded_key: Option<VerificationKey>) -> Consensus {
+    async fn new_consensus_service(funded_key: Option<VerificationKey>) -> (Consensus, Mempool) {
         let accounts = if funded_key.is_some() {
             vec![crate::genesis::Account {
                 address: Address::from_verification_key(funded_key.unwrap()),
                 balance: 10u128.pow(19),
+                nonce: 0,
             }]
         } else {
             vec![]
@@ -525,20 +561,26 @@ mod test {
 
         let storage = cnidarium::TempStorage::new().await.unwrap();
         let snapshot = storage.latest_snapshot();
-        let mut app = App::new(snapshot);
-        app.init_chain(genesis_state, vec![], "test".to_string())
-            .await
-            .unwrap();
+        let mempool = Mempool::new();
+        let mut app = App::new(snapshot, mempool.clone(), 0);
+        app.init_chain(
+            genesis_state,
+            vec![],
+            "test".to_string(),
+            test_consensus_params(),
+        )
+        .await
+        .unwrap();
         app.commit(storage.clone()).await;
 
         let (_tx, rx) = mpsc::channel(1);
-        Consensus::new(storage.clone(), app, rx)
+        (Consensus::new(storage.clone(), app, rx), mempool)
     }
 
     #[tokio::test]
     async fn block_lifecycle() {
         let signing_key = SigningKey::new(OsRng);
-        let mut consensus_service =
+        let (mut consensus_service, _) =
             new_consensus_service(Some(signing_key.verification_key())).await;
 
         let tx = make_unsigned_tx();
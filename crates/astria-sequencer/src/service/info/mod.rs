@@ -32,7 +32,10 @@ use tracing::{
 
 mod abci_query_router;
 
-use crate::state_ext::StateReadExt;
+use crate::{
+    state_ext::StateReadExt,
+    upgrade::state_ext::StateReadExt as _,
+};
 
 #[derive(Clone)]
 pub(crate) struct Info {
@@ -55,6 +58,84 @@ impl Info {
                 crate::accounts::query::nonce_request,
             )
             .context("invalid path: `accounts/nonce/:account`")?;
+        query_router
+            .insert("fees/schedule", crate::fees::query::fee_schedule_request)
+            .context("invalid path: `fees/schedule`")?;
+        query_router
+            .insert("fees/block", crate::fees::query::block_fees_request)
+            .context("invalid path: `fees/block`")?;
+        query_router
+            .insert(
+                "fees/allowed_assets",
+                crate::fees::query::allowed_fee_assets_request,
+            )
+            .context("invalid path: `fees/allowed_assets`")?;
+        query_router
+            .insert(
+                "authority/validator_set",
+                crate::authority::query::validator_set_request,
+            )
+            .context("invalid path: `authority/validator_set`")?;
+        query_router
+            .insert(
+                "authority/sudo_address",
+                crate::authority::query::sudo_address_request,
+            )
+            .context("invalid path: `authority/sudo_address`")?;
+        query_router
+            .insert(
+                "asset/native_denom",
+                crate::asset::query::native_denom_request,
+            )
+            .context("invalid path: `asset/native_denom`")?;
+        query_router
+            .insert("asset/denom/:id", crate::asset::query::denom_request)
+            .context("invalid path: `asset/denom/:id`")?;
+        query_router
+            .insert(
+                "asset/metadata/:id",
+                crate::asset::query::asset_metadata_request,
+            )
+            .context("invalid path: `asset/metadata/:id`")?;
+        query_router
+            .insert(
+                "asset/supply/:id",
+                crate::asset::query::asset_supply_request,
+            )
+            .context("invalid path: `asset/supply/:id`")?;
+        query_router
+            .insert(
+                "ibc/relayer_addresses",
+                crate::ibc::query::ibc_relayer_addresses_request,
+            )
+            .context("invalid path: `ibc/relayer_addresses`")?;
+        query_router
+            .insert(
+                "ibc/sudo_address",
+                crate::ibc::query::ibc_sudo_address_request,
+            )
+            .context("invalid path: `ibc/sudo_address`")?;
+        query_router
+            .insert(
+                "ibc/ics20_rate_limit/:channel/:asset_id",
+                crate::ibc::query::ics20_rate_limit_request,
+            )
+            .context("invalid path: `ibc/ics20_rate_limit/:channel/:asset_id`")?;
+        query_router
+            .insert("chain/id", crate::query::chain_id_request)
+            .context("invalid path: `chain/id`")?;
+        query_router
+            .insert(
+                "rollup/id/:id",
+                crate::registry::query::rollup_by_id_request,
+            )
+            .context("invalid path: `rollup/id/:id`")?;
+        query_router
+            .insert(
+                "rollup/name/:name",
+                crate::registry::query::rollup_by_name_request,
+            )
+            .context("invalid path: `rollup/name/:name`")?;
         Ok(Self {
             storage,
             query_router,
@@ -77,10 +158,16 @@ impl Info {
                     .root_hash()
                     .await
                     .context("failed to get app hash")?;
+                let app_version = self
+                    .storage
+                    .latest_snapshot()
+                    .get_app_version()
+                    .await
+                    .context("failed to get app version")?;
 
                 let response = InfoResponse::Info(response::Info {
                     version: env!("CARGO_PKG_VERSION").to_string(),
-                    app_version: 1,
+                    app_version,
                     last_block_height: u32::try_from(block_height)
                         .expect("block height must fit into u32")
                         .into(),
@@ -150,6 +237,7 @@ mod test {
             Denom,
             DEFAULT_NATIVE_ASSET_DENOM,
         },
+        AbciErrorCode,
         Address,
     };
     use cnidarium::StateDelta;
@@ -164,6 +252,7 @@ mod test {
         accounts::state_ext::StateWriteExt as _,
         asset::{
             get_native_asset,
+            state_ext::StateWriteExt as _,
             NATIVE_ASSET,
         },
         state_ext::StateWriteExt as _,
@@ -212,4 +301,178 @@ mod test {
         };
         assert!(query_response.code.is_ok());
     }
+
+    async fn query_balance_at_height(
+        storage: cnidarium::Storage,
+        height: u32,
+    ) -> tendermint::abci::response::Query {
+        let info_request = InfoRequest::Query(request::Query {
+            path: "accounts/balance/a034c743bed8f26cb8ee7b8db2230fd8347ae131".to_string(),
+            data: vec![].into(),
+            height: height.into(),
+            prove: false,
+        });
+        let info_service = Info::new(storage).unwrap();
+        match info_service
+            .handle_info_request(info_request)
+            .await
+            .unwrap()
+        {
+            InfoResponse::Query(query) => query,
+            other => panic!("expected InfoResponse::Query, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn handle_query_above_latest_height() {
+        let storage = cnidarium::TempStorage::new()
+            .await
+            .expect("failed to create temp storage backing chain state");
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_block_height(99);
+        storage.commit(state).await.unwrap();
+
+        let query_response = query_balance_at_height((*storage).clone(), 100).await;
+        assert_eq!(
+            query_response.code,
+            tendermint::abci::Code::from(AbciErrorCode::INVALID_PARAMETER)
+        );
+    }
+
+    /// Commits the blocks at heights `1..=latest_height` to `storage`, with the balance
+    /// of the queried account being the height of the block.
+    async fn commit_blocks(storage: &cnidarium::Storage, latest_height: u64) {
+        let address = Address::try_from_slice(
+            &hex::decode("a034c743bed8f26cb8ee7b8db2230fd8347ae131").unwrap(),
+        )
+        .unwrap();
+        let _ = NATIVE_ASSET.set(Denom::from_base_denom(DEFAULT_NATIVE_ASSET_DENOM));
+        for height in 1..=latest_height {
+            let mut state = StateDelta::new(storage.latest_snapshot());
+            state.put_storage_version_by_height(height, storage.latest_version().wrapping_add(1));
+            state.put_block_height(height);
+            state
+                .put_account_balance(address, get_native_asset().id(), height.into())
+                .unwrap();
+            storage.commit(state).await.unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn handle_query_at_past_height() {
+        use astria_core::{
+            generated::sequencer::v1alpha1 as raw,
+            sequencer::v1alpha1::BalanceResponse,
+        };
+        use prost::Message as _;
+
+        let storage = cnidarium::TempStorage::new()
+            .await
+            .expect("failed to create temp storage backing chain state");
+        commit_blocks(&storage, 5).await;
+
+        let query_response = query_balance_at_height((*storage).clone(), 4).await;
+        assert!(query_response.code.is_ok());
+        let response =
+            BalanceResponse::from_raw(&raw::BalanceResponse::decode(query_response.value).unwrap());
+        assert_eq!(response.height, 4);
+        assert_eq!(response.balances[0].balance, 4);
+    }
+
+    #[tokio::test]
+    async fn handle_query_at_uncached_height() {
+        let storage = cnidarium::TempStorage::new()
+            .await
+            .expect("failed to create temp storage backing chain state");
+        // the state of height 1 is stored, but only the most recent snapshots are cached
+        commit_blocks(&storage, 50).await;
+
+        let query_response = query_balance_at_height((*storage).clone(), 1).await;
+        assert_eq!(
+            query_response.code,
+            tendermint::abci::Code::from(AbciErrorCode::HEIGHT_NOT_CACHED)
+        );
+    }
+
+    #[tokio::test]
+    async fn handle_query_at_pruned_height() {
+        use crate::state_sync::{
+            export,
+            restore,
+        };
+
+        let storage = cnidarium::TempStorage::new()
+            .await
+            .expect("failed to create temp storage backing chain state");
+        commit_blocks(&storage, 5).await;
+
+        // a node restored from a snapshot of height 5 has no state of earlier heights
+        let (metadata, chunks) = export(&storage.latest_snapshot()).await.unwrap();
+        let restored_storage = cnidarium::TempStorage::new()
+            .await
+            .expect("failed to create temp storage backing chain state");
+        restore(&restored_storage, metadata.height, &chunks)
+            .await
+            .unwrap();
+
+        let query_response = query_balance_at_height((*restored_storage).clone(), 4).await;
+        assert_eq!(
+            query_response.code,
+            tendermint::abci::Code::from(AbciErrorCode::HEIGHT_PRUNED)
+        );
+        let query_response = query_balance_at_height((*restored_storage).clone(), 5).await;
+        assert!(query_response.code.is_ok());
+    }
+
+    #[tokio::test]
+    async fn handle_asset_supply_query() {
+        use astria_core::{
+            generated::sequencer::v1alpha1 as raw,
+            sequencer::v1alpha1::AssetSupplyResponse,
+        };
+        use prost::Message as _;
+
+        let storage = cnidarium::TempStorage::new()
+            .await
+            .expect("failed to create temp storage backing chain state");
+        let height = 99;
+        let version = storage.latest_version().wrapping_add(1);
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_storage_version_by_height(height, version);
+        state.put_block_height(height);
+
+        let asset_id = Denom::from_base_denom(DEFAULT_NATIVE_ASSET_DENOM).id();
+        state.put_total_supply(asset_id, 1000).unwrap();
+        storage.commit(state).await.unwrap();
+
+        let info_request = InfoRequest::Query(request::Query {
+            path: format!("asset/supply/{asset_id}"),
+            data: vec![].into(),
+            height: u32::try_from(height).unwrap().into(),
+            prove: false,
+        });
+        let info_service = Info::new((*storage).clone()).unwrap();
+        let query_response = match info_service
+            .handle_info_request(info_request)
+            .await
+            .unwrap()
+        {
+            InfoResponse::Query(query) => query,
+            other => panic!("expected InfoResponse::Query, got {other:?}"),
+        };
+        assert!(query_response.code.is_ok());
+
+        let response = raw::AssetSupplyResponse::decode(query_response.value)
+            .unwrap()
+            .try_into_native()
+            .unwrap();
+        assert_eq!(
+            response,
+            AssetSupplyResponse {
+                height,
+                asset_id,
+                supply: 1000,
+            }
+        );
+    }
 }
@@ -1,4 +1,5 @@
 use std::{
+    collections::HashMap,
     pin::Pin,
     task::{
         Context,
@@ -6,7 +7,18 @@ use std::{
     },
 };
 
-use cnidarium::Storage;
+use anyhow::Context as _;
+use astria_core::sequencer::v1alpha1::{
+    asset,
+    Address,
+    SignedTransaction,
+};
+use bytes::Bytes;
+use cnidarium::{
+    Snapshot,
+    StateDelta,
+    Storage,
+};
 use futures::{
     Future,
     FutureExt,
@@ -21,23 +33,42 @@ use tower::Service;
 use tower_abci::BoxError;
 use tracing::Instrument;
 
-use crate::accounts::state_ext::StateReadExt;
+use crate::{
+    accounts::state_ext::{
+        StateReadExt,
+        StateWriteExt as _,
+    },
+    mempool::Mempool as AppMempool,
+    session::state_ext::{
+        StateReadExt as _,
+        StateWriteExt as _,
+    },
+    transaction,
+    upgrade,
+};
 
 const MAX_TX_SIZE: usize = 256_000; // 256 KB
 
 /// Mempool handles [`request::CheckTx`] abci requests.
 //
-/// It performs a stateless check of the given transaction,
+/// It performs a stateless and a stateful check of the given transaction,
 /// returning a [`tendermint::v0_37::abci::response::CheckTx`].
+///
+/// Transactions passing the checks are inserted into the app-side mempool.
+/// When rechecked, transactions which are no longer in the app-side mempool
+/// or which no longer pass the checks are rejected, evicting them from both
+/// cometbft's and the app-side mempool.
 #[derive(Clone)]
 pub(crate) struct Mempool {
     storage: Storage,
+    mempool: AppMempool,
 }
 
 impl Mempool {
-    pub(crate) fn new(storage: Storage) -> Self {
+    pub(crate) fn new(storage: Storage, mempool: AppMempool) -> Self {
         Self {
             storage,
+            mempool,
         }
     }
 }
@@ -55,11 +86,12 @@ impl Service<MempoolRequest> for Mempool {
         use penumbra_tower_trace::v037::RequestExt as _;
         let span = req.create_span();
         let storage = self.storage.clone();
+        let mempool = self.mempool.clone();
         async move {
             let rsp = match req {
-                MempoolRequest::CheckTx(req) => {
-                    MempoolResponse::CheckTx(handle_check_tx(req, storage.latest_snapshot()).await)
-                }
+                MempoolRequest::CheckTx(req) => MempoolResponse::CheckTx(
+                    handle_check_tx(req, storage.latest_snapshot(), mempool).await,
+                ),
             };
             Ok(rsp)
         }
@@ -68,23 +100,25 @@ impl Service<MempoolRequest> for Mempool {
     }
 }
 
-async fn handle_check_tx<S: StateReadExt + 'static>(
+async fn handle_check_tx(
     req: request::CheckTx,
-    state: S,
+    state: Snapshot,
+    mempool: AppMempool,
 ) -> response::CheckTx {
     use astria_core::{
         generated::sequencer::v1alpha1 as raw,
-        sequencer::v1alpha1::{
-            AbciErrorCode,
-            SignedTransaction,
-        },
+        sequencer::v1alpha1::AbciErrorCode,
     };
     use prost::Message as _;
-
-    use crate::transaction;
+    use sha2::{
+        Digest as _,
+        Sha256,
+    };
+    use tendermint::abci::request::CheckTxKind;
 
     let request::CheckTx {
-        tx, ..
+        tx,
+        kind,
     } = req;
     if tx.len() > MAX_TX_SIZE {
         return response::CheckTx {
@@ -98,7 +132,7 @@ async fn handle_check_tx<S: StateReadExt + 'static>(
         };
     }
 
-    let raw_signed_tx = match raw::SignedTransaction::decode(tx) {
+    let raw_signed_tx = match raw::SignedTransaction::decode(tx.clone()) {
         Ok(tx) => tx,
         Err(e) => {
             return response::CheckTx {
@@ -136,13 +170,338 @@ async fn handle_check_tx<S: StateReadExt + 'static>(
         };
     };
 
-    match transaction::check_stateless(&signed_tx).await {
-        Ok(()) => response::CheckTx::default(),
-        Err(e) => response::CheckTx {
+    if let Err(e) = transaction::check_stateless(&signed_tx).await {
+        return response::CheckTx {
             code: AbciErrorCode::INVALID_PARAMETER.into(),
             info: "transaction failed stateless check".into(),
             log: format!("{e:?}"),
             ..response::CheckTx::default()
+        };
+    };
+
+    if let Err(e) = upgrade::check_upgrades_known(&signed_tx) {
+        return response::CheckTx {
+            code: AbciErrorCode::INVALID_PARAMETER.into(),
+            info: "transaction schedules an upgrade unknown to this node".into(),
+            log: format!("{e:?}"),
+            ..response::CheckTx::default()
+        };
+    }
+
+    // a rechecked transaction was inserted into the app-side mempool when it was
+    // first checked; if it is gone, it was replaced or its nonce was used.
+    let is_recheck = matches!(kind, CheckTxKind::Recheck);
+    let tx_hash: [u8; 32] = Sha256::digest(&tx).into();
+    if is_recheck && !mempool.contains(&tx_hash).await {
+        return response::CheckTx {
+            code: AbciErrorCode::INVALID_NONCE.into(),
+            info: "transaction was removed from the mempool".into(),
+            log: "transaction was replaced by a higher fee transaction or its nonce was already \
+                  used"
+                .into(),
+            ..response::CheckTx::default()
+        };
+    }
+
+    if let Err(e) = check_stateful_with_pending(&signed_tx, &state, &mempool).await {
+        if is_recheck {
+            mempool.remove(&tx_hash).await;
+        }
+        return response::CheckTx {
+            code: AbciErrorCode::INVALID_PARAMETER.into(),
+            info: "transaction failed stateful check".into(),
+            log: format!("{e:?}"),
+            ..response::CheckTx::default()
+        };
+    }
+
+    // the tip is reported as the transaction's priority; cometbft only uses it if
+    // its mempool is configured to prioritize transactions.
+    let priority = signed_tx
+        .unsigned_transaction()
+        .tip
+        .map_or(0, |tip| i64::try_from(tip).unwrap_or(i64::MAX));
+
+    if is_recheck {
+        return response::CheckTx {
+            priority,
+            ..response::CheckTx::default()
+        };
+    }
+
+    if let Err(e) = insert_into_mempool(signed_tx, tx, &state, &mempool).await {
+        return response::CheckTx {
+            code: AbciErrorCode::INVALID_NONCE.into(),
+            info: "failed inserting transaction into the mempool".into(),
+            log: format!("{e:?}"),
+            ..response::CheckTx::default()
+        };
+    }
+
+    response::CheckTx {
+        priority,
+        ..response::CheckTx::default()
+    }
+}
+
+/// Runs the stateful checks of `signed_tx` against `state`, as if the signer's
+/// pending transactions preceding it in the mempool had already been executed.
+///
+/// The pending transactions are not executed; instead their combined cost is
+/// deducted from the balances of the accounts paying for them before the checks are run.
+async fn check_stateful_with_pending(
+    signed_tx: &SignedTransaction,
+    state: &Snapshot,
+    mempool: &AppMempool,
+) -> anyhow::Result<()> {
+    let address = signed_tx.address();
+    let nonce = signed_tx.unsigned_transaction().nonce;
+
+    // transactions signed by a session key are paid for by the account that authorized it
+    let mut session_key = state
+        .get_session_key(address)
+        .await
+        .context("failed to get session key")?;
+    let account = session_key
+        .as_ref()
+        .map_or(address, |session_key| session_key.account);
+
+    let mut pending_cost: HashMap<(Address, asset::Id), u128> = HashMap::new();
+    for pending_tx in mempool.pending_transactions_before(&address, nonce).await {
+        let cost = transaction::get_total_transaction_cost(
+            pending_tx.unsigned_transaction(),
+            state,
+            account,
+        )
+        .await
+        .context("failed to get cost of pending transaction")?;
+        for (key, amount) in cost {
+            let total = pending_cost.entry(key).or_default();
+            *total = total.saturating_add(amount);
+        }
+        if let Some(session_key) = &mut session_key {
+            let fees = transaction::get_session_key_fees(pending_tx.unsigned_transaction(), state)
+                .await
+                .context("failed to get fees of pending transaction")?;
+            session_key.fees_spent = session_key.fees_spent.saturating_add(fees);
+        }
+    }
+
+    let mut state_tx = StateDelta::new(state.clone());
+    state_tx
+        .put_account_nonce(address, nonce)
+        .context("failed to put account nonce")?;
+    if let Some(session_key) = &session_key {
+        state_tx
+            .put_session_key(address, session_key)
+            .context("failed to put session key")?;
+    }
+    for ((address, asset_id), amount) in pending_cost {
+        let balance = state_tx
+            .get_account_balance(address, asset_id)
+            .await
+            .context("failed to get account balance")?;
+        let remaining = balance.checked_sub(amount).with_context(|| {
+            format!("insufficient balance of asset `{asset_id}` to cover pending transactions")
+        })?;
+        state_tx
+            .put_account_balance(address, asset_id, remaining)
+            .context("failed to put account balance")?;
+    }
+
+    transaction::check_stateful(signed_tx, &state_tx).await
+}
+
+/// Inserts a transaction which passed `CheckTx` into the app-side mempool,
+/// prioritized by the total fee it pays.
+async fn insert_into_mempool<S: StateReadExt + 'static>(
+    signed_tx: SignedTransaction,
+    tx_bytes: Bytes,
+    state: &S,
+    mempool: &AppMempool,
+) -> anyhow::Result<()> {
+    let fee = transaction::get_fees_for_transaction(signed_tx.unsigned_transaction(), state)
+        .await
+        .context("failed to calculate transaction fees")?
+        .values()
+        .fold(0u128, |acc, fee| acc.saturating_add(*fee));
+    let address = signed_tx.address();
+    let current_account_nonce = state
+        .get_account_nonce(address)
+        .await
+        .context("failed to get account nonce")?;
+    mempool
+        .insert(signed_tx, tx_bytes, fee, current_account_nonce)
+        .await
+        .context("failed to insert transaction into mempool")?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use astria_core::sequencer::v1alpha1::{
+        asset::{
+            default_native_asset_id,
+            DEFAULT_NATIVE_ASSET_DENOM,
+        },
+        transaction::action::{
+            ScheduleUpgradeAction,
+            TransferAction,
         },
+        AbciErrorCode,
+        Address,
+        UnsignedTransaction,
+    };
+    use ed25519_consensus::SigningKey;
+    use prost::Message as _;
+
+    use super::*;
+    use crate::fees::state_ext::StateWriteExt as _;
+
+    fn transfer_tx(signing_key: &SigningKey, nonce: u32, amount: u128) -> SignedTransaction {
+        transfer_tx_with_tip(signing_key, nonce, amount, None)
+    }
+
+    fn transfer_tx_with_tip(
+        signing_key: &SigningKey,
+        nonce: u32,
+        amount: u128,
+        tip: Option<u128>,
+    ) -> SignedTransaction {
+        UnsignedTransaction {
+            nonce,
+            actions: vec![
+                TransferAction {
+                    to: Address::from([1; 20]),
+                    amount,
+                    asset_id: default_native_asset_id(),
+                    fee_asset_id: default_native_asset_id(),
+                }
+                .into(),
+            ],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip,
+            fee_payer: None,
+        }
+        .into_signed(signing_key)
+    }
+
+    #[tokio::test]
+    async fn stateful_check_includes_cost_of_pending_transactions() {
+        let signing_key = SigningKey::from([1; 32]);
+        let address = Address::from_verification_key(signing_key.verification_key());
+
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_transfer_base_fee(10).unwrap();
+        state.put_sequence_base_fee(0).unwrap();
+        state.put_sequence_byte_cost_multiplier(0).unwrap();
+        state.put_ics20_withdrawal_base_fee(0).unwrap();
+        state.put_allowed_fee_asset(default_native_asset_id());
+        state
+            .put_account_balance(address, default_native_asset_id(), 100)
+            .unwrap();
+        storage.commit(state).await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mempool = AppMempool::new();
+
+        // the first transaction costs 60 of the 100 available
+        let first_tx = transfer_tx(&signing_key, 0, 50);
+        check_stateful_with_pending(&first_tx, &snapshot, &mempool)
+            .await
+            .unwrap();
+        let tx_bytes: Bytes = first_tx.to_raw().encode_to_vec().into();
+        mempool.insert(first_tx, tx_bytes, 10, 0).await.unwrap();
+
+        // the second transaction is affordable on its own, but not after the first
+        let second_tx = transfer_tx(&signing_key, 1, 50);
+        let _ = check_stateful_with_pending(&second_tx, &snapshot, &mempool)
+            .await
+            .unwrap_err();
+        let second_tx = transfer_tx(&signing_key, 1, 30);
+        check_stateful_with_pending(&second_tx, &snapshot, &mempool)
+            .await
+            .unwrap();
+
+        // a replacement for the first transaction is not charged for the transaction it replaces
+        let replacement_tx = transfer_tx(&signing_key, 0, 90);
+        check_stateful_with_pending(&replacement_tx, &snapshot, &mempool)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn check_tx_reports_tip_as_priority() {
+        crate::asset::initialize_native_asset(DEFAULT_NATIVE_ASSET_DENOM);
+        let signing_key = SigningKey::from([1; 32]);
+        let address = Address::from_verification_key(signing_key.verification_key());
+
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_transfer_base_fee(10).unwrap();
+        state.put_sequence_base_fee(0).unwrap();
+        state.put_sequence_byte_cost_multiplier(0).unwrap();
+        state.put_ics20_withdrawal_base_fee(0).unwrap();
+        state.put_allowed_fee_asset(default_native_asset_id());
+        state
+            .put_account_balance(address, default_native_asset_id(), 1_000)
+            .unwrap();
+        storage.commit(state).await.unwrap();
+
+        let tx = transfer_tx_with_tip(&signing_key, 0, 50, Some(100));
+        let response = handle_check_tx(
+            request::CheckTx {
+                tx: tx.to_raw().encode_to_vec().into(),
+                kind: tendermint::abci::request::CheckTxKind::New,
+            },
+            storage.latest_snapshot(),
+            AppMempool::new(),
+        )
+        .await;
+
+        assert!(response.code.is_ok(), "{}", response.log);
+        assert_eq!(response.priority, 100);
+    }
+
+    #[tokio::test]
+    async fn check_tx_rejects_unknown_upgrade() {
+        let signing_key = SigningKey::from([1; 32]);
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+
+        let tx = UnsignedTransaction {
+            nonce: 0,
+            actions: vec![
+                ScheduleUpgradeAction {
+                    name: "unknown-upgrade".to_string(),
+                    height: 10,
+                }
+                .into(),
+            ],
+            valid_until_height: None,
+            valid_until_timestamp: None,
+            tip: None,
+            fee_payer: None,
+        }
+        .into_signed(&signing_key);
+        let response = handle_check_tx(
+            request::CheckTx {
+                tx: tx.to_raw().encode_to_vec().into(),
+                kind: tendermint::abci::request::CheckTxKind::New,
+            },
+            storage.latest_snapshot(),
+            AppMempool::new(),
+        )
+        .await;
+
+        assert_eq!(
+            response.code,
+            tendermint::abci::Code::from(AbciErrorCode::INVALID_PARAMETER),
+            "{}",
+            response.log
+        );
+        assert!(response
+            .log
+            .contains("upgrade `unknown-upgrade` is not known to this binary"));
     }
 }
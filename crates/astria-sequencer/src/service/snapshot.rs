@@ -1,32 +1,284 @@
 use std::{
     pin::Pin,
+    sync::Arc,
     task::{
         Context,
         Poll,
     },
 };
 
+use anyhow::Context as _;
+use cnidarium::Storage;
 use futures::{
     Future,
     FutureExt,
 };
 use penumbra_tower_trace::v037::RequestExt as _;
-use tendermint::v0_37::abci::{
-    response::{
-        ApplySnapshotChunk,
-        ListSnapshots,
-        LoadSnapshotChunk,
-        OfferSnapshot,
+use sha2::{
+    Digest as _,
+    Sha256,
+};
+use tendermint::{
+    v0_37::abci::{
+        request,
+        response::{
+            ApplySnapshotChunk,
+            ApplySnapshotChunkResult,
+            ListSnapshots,
+            LoadSnapshotChunk,
+            OfferSnapshot,
+        },
+        SnapshotRequest,
+        SnapshotResponse,
     },
-    SnapshotRequest,
-    SnapshotResponse,
+    AppHash,
 };
+use tokio::sync::Mutex;
 use tower::Service;
 use tower_abci::BoxError;
-use tracing::Instrument as _;
+use tracing::{
+    error,
+    info,
+    instrument,
+    warn,
+    Instrument as _,
+};
+
+use crate::{
+    state_ext::StateReadExt as _,
+    state_sync::{
+        self,
+        SnapshotStore,
+    },
+};
+
+/// A snapshot offered by cometbft which is being restored.
+struct Restore {
+    height: u64,
+    app_hash: AppHash,
+    hash: Vec<u8>,
+    chunk_hashes: Vec<[u8; 32]>,
+    chunks: Vec<Option<Vec<u8>>>,
+}
+
+#[derive(Clone)]
+pub(crate) struct Snapshot {
+    storage: Storage,
+    store: SnapshotStore,
+    restore: Arc<Mutex<Option<Restore>>>,
+}
+
+impl Snapshot {
+    pub(crate) fn new(storage: Storage, store: SnapshotStore) -> Self {
+        Self {
+            storage,
+            store,
+            restore: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    #[instrument(skip_all)]
+    async fn handle_list_snapshots(self) -> ListSnapshots {
+        let snapshots = match self.store.list().await {
+            Ok(snapshots) => snapshots,
+            Err(e) => {
+                warn!(
+                    error = AsRef::<dyn std::error::Error>::as_ref(&e),
+                    "failed to list snapshots"
+                );
+                return ListSnapshots::default();
+            }
+        };
+        ListSnapshots {
+            snapshots: snapshots
+                .iter()
+                .filter_map(|metadata| metadata.to_abci().ok())
+                .collect(),
+        }
+    }
+
+    #[instrument(skip_all, fields(height = %offer.snapshot.height))]
+    async fn handle_offer_snapshot(self, offer: request::OfferSnapshot) -> OfferSnapshot {
+        let request::OfferSnapshot {
+            snapshot,
+            app_hash,
+        } = offer;
+
+        if snapshot.format != state_sync::SNAPSHOT_FORMAT {
+            return OfferSnapshot::RejectFormat;
+        }
+        if self.storage.latest_version() != u64::MAX {
+            warn!("rejecting snapshot as storage is not empty");
+            return OfferSnapshot::Reject;
+        }
+        let chunk_hashes = match state_sync::chunk_hashes_from_abci_metadata(&snapshot.metadata) {
+            Ok(chunk_hashes) if chunk_hashes.len() == snapshot.chunks as usize => chunk_hashes,
+            _ => return OfferSnapshot::Reject,
+        };
+
+        *self.restore.lock().await = Some(Restore {
+            height: snapshot.height.value(),
+            app_hash,
+            hash: snapshot.hash.to_vec(),
+            chunks: vec![None; chunk_hashes.len()],
+            chunk_hashes,
+        });
+        OfferSnapshot::Accept
+    }
+
+    #[instrument(skip_all, fields(height = %request.height, chunk = request.chunk))]
+    async fn handle_load_snapshot_chunk(
+        self,
+        request: request::LoadSnapshotChunk,
+    ) -> LoadSnapshotChunk {
+        match self
+            .store
+            .load_chunk(request.height.value(), request.format, request.chunk)
+            .await
+        {
+            Ok(chunk) => LoadSnapshotChunk {
+                chunk: chunk.into(),
+            },
+            Err(e) => {
+                warn!(
+                    error = AsRef::<dyn std::error::Error>::as_ref(&e),
+                    "failed to load snapshot chunk"
+                );
+                LoadSnapshotChunk::default()
+            }
+        }
+    }
+
+    #[instrument(skip_all, fields(index = request.index))]
+    async fn handle_apply_snapshot_chunk(
+        self,
+        request: request::ApplySnapshotChunk,
+    ) -> ApplySnapshotChunk {
+        let request::ApplySnapshotChunk {
+            index,
+            chunk,
+            sender,
+        } = request;
+
+        let mut restore_guard = self.restore.lock().await;
+        let Some(restore) = restore_guard.as_mut() else {
+            error!("received snapshot chunk without an accepted snapshot offer");
+            return apply_snapshot_chunk_result(ApplySnapshotChunkResult::Abort);
+        };
+
+        // the chunk hashes are taken from the offered snapshot, so they only guard against
+        // corrupted chunks. the restored state is verified against the app hash below.
+        let Some(expected_hash) = restore.chunk_hashes.get(index as usize) else {
+            return apply_snapshot_chunk_result(ApplySnapshotChunkResult::RejectSnapshot);
+        };
+        if Sha256::digest(&chunk).as_slice() != expected_hash.as_slice() {
+            warn!(
+                sender,
+                "received snapshot chunk with invalid hash; refetching"
+            );
+            return ApplySnapshotChunk {
+                result: ApplySnapshotChunkResult::Retry,
+                refetch_chunks: vec![index],
+                reject_senders: vec![sender],
+            };
+        }
+        restore.chunks[index as usize] = Some(chunk.to_vec());
+
+        if restore.chunks.iter().any(Option::is_none) {
+            return apply_snapshot_chunk_result(ApplySnapshotChunkResult::Accept);
+        }
+
+        let restore = restore_guard
+            .take()
+            .expect("restore was checked to be set above");
+        let chunks: Vec<Vec<u8>> = restore.chunks.into_iter().flatten().collect();
+        if Sha256::digest(chunks.concat()).as_slice() != restore.hash.as_slice() {
+            warn!("snapshot payload does not match snapshot hash");
+            return apply_snapshot_chunk_result(ApplySnapshotChunkResult::RejectSnapshot);
+        }
 
-#[derive(Clone, Default)]
-pub(crate) struct Snapshot;
+        // the snapshot is restored into scratch storage first, so that a snapshot whose
+        // state does not match the app hash is rejected without touching our storage and
+        // cometbft can try another one.
+        let scratch_root_hash = match state_sync::restored_root_hash(
+            &self.store.restore_scratch_dir(),
+            restore.height,
+            &chunks,
+        )
+        .await
+        {
+            Ok(root_hash) => root_hash,
+            Err(e) => {
+                warn!(
+                    error = AsRef::<dyn std::error::Error>::as_ref(&e),
+                    "failed to restore snapshot into scratch storage; rejecting snapshot"
+                );
+                return apply_snapshot_chunk_result(ApplySnapshotChunkResult::RejectSnapshot);
+            }
+        };
+        if scratch_root_hash.0.as_slice() != restore.app_hash.as_bytes() {
+            warn!(
+                root_hash = %telemetry::display::hex(&scratch_root_hash.0),
+                app_hash = %telemetry::display::hex(&restore.app_hash),
+                "root hash of snapshot state does not match app hash; rejecting snapshot",
+            );
+            return apply_snapshot_chunk_result(ApplySnapshotChunkResult::RejectSnapshot);
+        }
+
+        let root_hash = match state_sync::restore(&self.storage, restore.height, &chunks).await {
+            Ok(root_hash) => root_hash,
+            Err(e) => {
+                error!(
+                    error = AsRef::<dyn std::error::Error>::as_ref(&e),
+                    "failed to restore state from snapshot"
+                );
+                return apply_snapshot_chunk_result(ApplySnapshotChunkResult::Abort);
+            }
+        };
+        // the state was verified above, so this only fails if restoring is not
+        // deterministic. the state has been committed at this point, so retrying
+        // another snapshot is not possible.
+        if root_hash.0.as_slice() != restore.app_hash.as_bytes() {
+            error!(
+                root_hash = %telemetry::display::hex(&root_hash.0),
+                app_hash = %telemetry::display::hex(&restore.app_hash),
+                "root hash of restored state does not match app hash; storage must be wiped \
+                 before syncing again",
+            );
+            return apply_snapshot_chunk_result(ApplySnapshotChunkResult::Abort);
+        }
+
+        // the native asset is read from the verified state
+        if let Err(e) = initialize_native_asset(&self.storage).await {
+            error!(
+                error = AsRef::<dyn std::error::Error>::as_ref(&e),
+                "failed to initialize native asset from restored state"
+            );
+            return apply_snapshot_chunk_result(ApplySnapshotChunkResult::Abort);
+        }
+
+        info!(height = restore.height, "restored state from snapshot");
+        apply_snapshot_chunk_result(ApplySnapshotChunkResult::Accept)
+    }
+}
+
+fn apply_snapshot_chunk_result(result: ApplySnapshotChunkResult) -> ApplySnapshotChunk {
+    ApplySnapshotChunk {
+        result,
+        refetch_chunks: vec![],
+        reject_senders: vec![],
+    }
+}
+
+async fn initialize_native_asset(storage: &Storage) -> anyhow::Result<()> {
+    let native_asset = storage
+        .latest_snapshot()
+        .get_native_asset_denom()
+        .await
+        .context("failed to get native asset from storage")?;
+    crate::asset::initialize_native_asset(&native_asset);
+    Ok(())
+}
 
 impl Service<SnapshotRequest> for Snapshot {
     type Error = BoxError;
@@ -40,22 +292,25 @@ impl Service<SnapshotRequest> for Snapshot {
 
     fn call(&mut self, req: SnapshotRequest) -> Self::Future {
         let span = req.create_span();
+        let this = self.clone();
         async move {
             Ok(match req {
                 SnapshotRequest::ListSnapshots => {
-                    SnapshotResponse::ListSnapshots(ListSnapshots::default())
+                    SnapshotResponse::ListSnapshots(this.handle_list_snapshots().await)
                 }
 
-                SnapshotRequest::OfferSnapshot(_) => {
-                    SnapshotResponse::OfferSnapshot(OfferSnapshot::default())
+                SnapshotRequest::OfferSnapshot(offer) => {
+                    SnapshotResponse::OfferSnapshot(this.handle_offer_snapshot(offer).await)
                 }
 
-                SnapshotRequest::LoadSnapshotChunk(_) => {
-                    SnapshotResponse::LoadSnapshotChunk(LoadSnapshotChunk::default())
-                }
+                SnapshotRequest::LoadSnapshotChunk(request) => SnapshotResponse::LoadSnapshotChunk(
+                    this.handle_load_snapshot_chunk(request).await,
+                ),
 
-                SnapshotRequest::ApplySnapshotChunk(_) => {
-                    SnapshotResponse::ApplySnapshotChunk(ApplySnapshotChunk::default())
+                SnapshotRequest::ApplySnapshotChunk(request) => {
+                    SnapshotResponse::ApplySnapshotChunk(
+                        this.handle_apply_snapshot_chunk(request).await,
+                    )
                 }
             })
         }
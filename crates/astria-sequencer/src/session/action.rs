@@ -0,0 +1,219 @@
+use std::collections::HashSet;
+
+use anyhow::{
+    bail,
+    ensure,
+    Context as _,
+    Result,
+};
+use astria_core::sequencer::v1alpha1::{
+    transaction::action::{
+        AuthorizeSessionKeyAction,
+        RevokeSessionKeyAction,
+    },
+    Address,
+};
+use tracing::instrument;
+
+use crate::{
+    accounts::state_ext::StateReadExt as _,
+    authority::state_ext::StateReadExt as _,
+    bridge::state_ext::StateReadExt as _,
+    fees::state_ext::StateReadExt as _,
+    ibc::state_ext::StateReadExt as _,
+    session::state_ext::{
+        SessionKey,
+        StateReadExt,
+        StateWriteExt,
+    },
+    state_ext::StateReadExt as _,
+    transaction::action_handler::ActionHandler,
+};
+
+/// The maximum number of rollups a session key can be authorized for.
+pub(crate) const MAX_SESSION_KEY_ROLLUP_IDS: usize = 32;
+
+#[async_trait::async_trait]
+impl ActionHandler for AuthorizeSessionKeyAction {
+    async fn check_stateless(&self) -> Result<()> {
+        ensure!(
+            !self.rollup_ids.is_empty(),
+            "session key must be authorized for at least one rollup"
+        );
+        ensure!(
+            self.rollup_ids.len() <= MAX_SESSION_KEY_ROLLUP_IDS,
+            "session key must not be authorized for more than {MAX_SESSION_KEY_ROLLUP_IDS} rollups"
+        );
+        let mut seen = HashSet::with_capacity(self.rollup_ids.len());
+        for rollup_id in &self.rollup_ids {
+            ensure!(seen.insert(rollup_id), "duplicate rollup ID `{rollup_id}`");
+        }
+        Ok(())
+    }
+
+    async fn check_stateful<S: StateReadExt + 'static>(
+        &self,
+        state: &S,
+        from: Address,
+    ) -> Result<()> {
+        ensure!(
+            state.is_allowed_fee_asset(self.fee_asset_id).await?,
+            "invalid fee asset"
+        );
+        check_session_key_claimable(state, self, from).await?;
+
+        let height = state
+            .get_block_height()
+            .await
+            .context("failed to get block height")?;
+        ensure!(
+            self.expiry_height > height,
+            "session key expiry height must be after the current block height"
+        );
+        Ok(())
+    }
+
+    #[instrument(skip_all, fields(session_key = %self.session_key, account = %from))]
+    async fn execute<S: StateWriteExt>(&self, state: &mut S, from: Address) -> Result<()> {
+        // checked again, as earlier actions of the transaction can change the session
+        // key's account
+        check_session_key_claimable(&*state, self, from).await?;
+        state
+            .put_session_key(
+                self.session_key,
+                &SessionKey {
+                    account: from,
+                    rollup_ids: self.rollup_ids.clone(),
+                    fee_spend_limit: self.fee_spend_limit,
+                    fees_spent: 0,
+                    expiry_height: self.expiry_height,
+                },
+            )
+            .context("failed to put session key")?;
+        Ok(())
+    }
+}
+
+/// Checks that `from` can authorize the session key of `action`.
+///
+/// The session key's holder must have signed the address of `from`, so that no account
+/// can claim another account's address as its session key. An account can replace the
+/// scope of its own session key, but any other session key must be an unused address:
+/// it must not have a nonce or a balance, or a special role on the chain.
+async fn check_session_key_claimable<S: StateReadExt>(
+    state: &S,
+    action: &AuthorizeSessionKeyAction,
+    from: Address,
+) -> Result<()> {
+    let session_key = action.session_key;
+    ensure!(
+        session_key != from,
+        "an account cannot authorize itself as a session key"
+    );
+    action
+        .session_key_signature
+        .verify(from)
+        .context("session key did not sign the address of the authorizing account")?;
+
+    if let Some(existing) = state
+        .get_session_key(session_key)
+        .await
+        .context("failed to get session key")?
+    {
+        ensure!(
+            existing.account == from,
+            "session key is already authorized by another account"
+        );
+        return Ok(());
+    }
+
+    ensure!(
+        state
+            .get_account_nonce(session_key)
+            .await
+            .context("failed to get session key nonce")?
+            == 0,
+        "session key must not have sent transactions"
+    );
+    ensure!(
+        state
+            .get_account_balances(session_key)
+            .await
+            .context("failed to get session key balances")?
+            .iter()
+            .all(|balance| balance.balance == 0),
+        "session key must not hold any funds"
+    );
+    ensure!(
+        state
+            .get_sudo_address()
+            .await
+            .context("failed to get sudo address")?
+            != session_key,
+        "session key must not be the sudo address"
+    );
+    ensure!(
+        state
+            .get_ibc_sudo_address()
+            .await
+            .context("failed to get ibc sudo address")?
+            != session_key,
+        "session key must not be the ibc sudo address"
+    );
+    ensure!(
+        !state
+            .is_ibc_relayer(&session_key)
+            .await
+            .context("failed to check if session key is an ibc relayer")?,
+        "session key must not be an ibc relayer"
+    );
+    ensure!(
+        state
+            .get_bridge_account_rollup_id(&session_key)
+            .await
+            .context("failed to get bridge account rollup id")?
+            .is_none(),
+        "session key must not be a bridge account"
+    );
+    Ok(())
+}
+
+/// Checks that `session_key` is authorized by `from`.
+async fn check_session_key_owner<S: StateReadExt>(
+    state: &S,
+    session_key: Address,
+    from: Address,
+) -> Result<()> {
+    let Some(session_key) = state
+        .get_session_key(session_key)
+        .await
+        .context("failed to get session key")?
+    else {
+        bail!("session key is not authorized");
+    };
+    ensure!(
+        session_key.account == from,
+        "session key is not authorized by the signer"
+    );
+    Ok(())
+}
+
+#[async_trait::async_trait]
+impl ActionHandler for RevokeSessionKeyAction {
+    /// check that the session key was authorized by the signer of the transaction,
+    /// as only the authorizing account can revoke it
+    async fn check_stateful<S: StateReadExt + 'static>(
+        &self,
+        state: &S,
+        from: Address,
+    ) -> Result<()> {
+        check_session_key_owner(state, self.session_key, from).await
+    }
+
+    #[instrument(skip_all, fields(session_key = %self.session_key))]
+    async fn execute<S: StateWriteExt>(&self, state: &mut S, from: Address) -> Result<()> {
+        check_session_key_owner(&*state, self.session_key, from).await?;
+        state.delete_session_key(self.session_key);
+        Ok(())
+    }
+}
@@ -0,0 +1,18 @@
+//! Session keys, which submit transactions on behalf of another account.
+//!
+//! An account authorizes a session key with an [`AuthorizeSessionKeyAction`], which carries
+//! the session key's signature over the account's address. Only unused addresses can be
+//! authorized: a session key must not have a nonce, a balance or a special role such as a
+//! sudo address, an IBC relayer or a bridge account. Transactions
+//! signed by the session key keep using the session key's own nonce, but their fees, tips
+//! and actions are charged to and executed for the authorizing account. They can only
+//! contain sequence actions for the authorized rollups paying fees in the native asset,
+//! and are rejected once the session key's fee budget is spent or its expiry height has
+//! passed. Fees paid by a separate fee payer are not counted against the budget. The
+//! account can remove the authorization with a [`RevokeSessionKeyAction`].
+//!
+//! [`AuthorizeSessionKeyAction`]: astria_core::sequencer::v1alpha1::transaction::action::AuthorizeSessionKeyAction
+//! [`RevokeSessionKeyAction`]: astria_core::sequencer::v1alpha1::transaction::action::RevokeSessionKeyAction
+
+pub(crate) mod action;
+pub(crate) mod state_ext;
@@ -0,0 +1,138 @@
+use anyhow::{
+    Context,
+    Result,
+};
+use astria_core::sequencer::v1alpha1::{
+    Address,
+    RollupId,
+    ADDRESS_LEN,
+};
+use async_trait::async_trait;
+use borsh::{
+    BorshDeserialize,
+    BorshSerialize,
+};
+use cnidarium::{
+    StateRead,
+    StateWrite,
+};
+use hex::ToHex as _;
+use tracing::instrument;
+
+/// The scope and spent budget of a session key.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct SessionKey {
+    /// The account the session key acts for.
+    pub(crate) account: Address,
+    /// The rollups the session key can submit sequence data for.
+    pub(crate) rollup_ids: Vec<RollupId>,
+    /// The maximum amount of fees and tips the session key can spend.
+    pub(crate) fee_spend_limit: u128,
+    /// The amount of fees and tips the session key has spent so far.
+    pub(crate) fees_spent: u128,
+    /// The last block height at which the session key can be used.
+    pub(crate) expiry_height: u64,
+}
+
+impl SessionKey {
+    /// Returns the amount of fees and tips the session key can still spend.
+    pub(crate) fn remaining_budget(&self) -> u128 {
+        self.fee_spend_limit.saturating_sub(self.fees_spent)
+    }
+}
+
+/// Newtype wrapper to read and write a session key from rocksdb.
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+struct SessionKeyStorage {
+    account: [u8; ADDRESS_LEN],
+    rollup_ids: Vec<[u8; 32]>,
+    fee_spend_limit: u128,
+    fees_spent: u128,
+    expiry_height: u64,
+}
+
+impl From<&SessionKey> for SessionKeyStorage {
+    fn from(session_key: &SessionKey) -> Self {
+        Self {
+            account: session_key.account.0,
+            rollup_ids: session_key
+                .rollup_ids
+                .iter()
+                .map(|rollup_id| rollup_id.get())
+                .collect(),
+            fee_spend_limit: session_key.fee_spend_limit,
+            fees_spent: session_key.fees_spent,
+            expiry_height: session_key.expiry_height,
+        }
+    }
+}
+
+impl From<SessionKeyStorage> for SessionKey {
+    fn from(storage: SessionKeyStorage) -> Self {
+        let SessionKeyStorage {
+            account,
+            rollup_ids,
+            fee_spend_limit,
+            fees_spent,
+            expiry_height,
+        } = storage;
+        Self {
+            account: Address::from_array(account),
+            rollup_ids: rollup_ids.into_iter().map(RollupId::new).collect(),
+            fee_spend_limit,
+            fees_spent,
+            expiry_height,
+        }
+    }
+}
+
+const SESSION_KEY_PREFIX: &str = "session_key";
+
+fn session_key_storage_key(session_key: Address) -> String {
+    format!(
+        "{SESSION_KEY_PREFIX}/{}",
+        session_key.encode_hex::<String>()
+    )
+}
+
+#[async_trait]
+pub(crate) trait StateReadExt: StateRead {
+    /// Returns the scope of the given session key, or `None` if the address is not an
+    /// authorized session key.
+    #[instrument(skip(self))]
+    async fn get_session_key(&self, session_key: Address) -> Result<Option<SessionKey>> {
+        let Some(bytes) = self
+            .get_raw(&session_key_storage_key(session_key))
+            .await
+            .context("failed reading raw session key from state")?
+        else {
+            return Ok(None);
+        };
+        let session_key =
+            SessionKeyStorage::try_from_slice(&bytes).context("invalid session key bytes")?;
+        Ok(Some(session_key.into()))
+    }
+}
+
+impl<T: StateRead> StateReadExt for T {}
+
+#[async_trait]
+pub(crate) trait StateWriteExt: StateWrite {
+    #[instrument(skip(self))]
+    fn put_session_key(&mut self, session_key: Address, scope: &SessionKey) -> Result<()> {
+        self.put_raw(
+            session_key_storage_key(session_key),
+            SessionKeyStorage::from(scope)
+                .try_to_vec()
+                .context("failed to serialize session key")?,
+        );
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    fn delete_session_key(&mut self, session_key: Address) {
+        self.delete(session_key_storage_key(session_key));
+    }
+}
+
+impl<T: StateWrite> StateWriteExt for T {}
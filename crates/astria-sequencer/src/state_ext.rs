@@ -5,16 +5,26 @@ use anyhow::{
 };
 use astria_core::sequencer::v1alpha1::asset;
 use async_trait::async_trait;
+use borsh::{
+    BorshDeserialize,
+    BorshSerialize,
+};
 use cnidarium::{
     StateRead,
     StateWrite,
 };
 use futures::StreamExt as _;
+use ibc_types::core::connection::ChainId;
 use tendermint::Time;
 use tracing::instrument;
 
-const NATIVE_ASSET_KEY: &[u8] = b"nativeasset";
+const NATIVE_ASSET_KEY: &str = "nativeasset";
+/// The key the native asset denomination was kept under in the nonverifiable store by
+/// chains initialized before it became part of the verifiable state.
+const LEGACY_NATIVE_ASSET_KEY: &[u8] = b"nativeasset";
 const BLOCK_FEES_PREFIX: &str = "block_fees/";
+const COLLECTED_BLOCK_FEES_KEY: &[u8] = b"collected_block_fees";
+const EARLIEST_RETAINED_HEIGHT_KEY: &[u8] = b"earliest_retained_height";
 
 fn storage_version_by_height_key(height: u64) -> Vec<u8> {
     format!("storage_version/{height}").into()
@@ -24,6 +34,10 @@ fn block_fees_key(asset: asset::Id) -> Vec<u8> {
     format!("{BLOCK_FEES_PREFIX}{asset}").into()
 }
 
+/// Newtype wrapper to read and write the fees collected in a block from rocksdb.
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+struct CollectedBlockFees(Vec<([u8; 32], u128)>);
+
 #[async_trait]
 pub(crate) trait StateReadExt: StateRead {
     #[instrument(skip(self))]
@@ -41,8 +55,13 @@ pub(crate) trait StateReadExt: StateRead {
 
     #[instrument(skip(self))]
     async fn get_revision_number(&self) -> Result<u64> {
-        // this is used for chain upgrades, which we do not currently have.
-        Ok(0)
+        // the revision number is the epoch of the chain id, eg. 1 for `astria-1`, and
+        // only changes when the chain id does; app upgrades leave the chain id untouched.
+        let chain_id = self
+            .get_chain_id()
+            .await
+            .context("failed to get chain id")?;
+        Ok(ChainId::chain_version(&chain_id))
     }
 
     #[instrument(skip(self))]
@@ -90,13 +109,45 @@ pub(crate) trait StateReadExt: StateRead {
         Ok(u64::from_be_bytes(bytes))
     }
 
+    /// Returns the earliest height whose state is stored by this node.
+    ///
+    /// This is 0 unless the node was restored from a state sync snapshot, in which
+    /// case the state of all heights before the snapshot's is missing.
     #[instrument(skip(self))]
-    async fn get_native_asset_denom(&self) -> Result<String> {
+    async fn get_earliest_retained_height(&self) -> Result<u64> {
         let Some(bytes) = self
-            .nonverifiable_get_raw(NATIVE_ASSET_KEY)
+            .nonverifiable_get_raw(EARLIEST_RETAINED_HEIGHT_KEY)
             .await
-            .context("failed to read raw native_asset_denom from state")?
+            .context("failed to read raw earliest retained height from state")?
         else {
+            return Ok(0);
+        };
+        let Ok(bytes): Result<[u8; 8], _> = bytes.try_into() else {
+            bail!("failed turning raw earliest retained height bytes into u64; not 8 bytes?");
+        };
+        Ok(u64::from_be_bytes(bytes))
+    }
+
+    /// Returns the base denomination of the native asset.
+    ///
+    /// The denomination is part of the verifiable state, as it is restored from state
+    /// sync snapshots, which only contain verifiable state. Chains initialized before
+    /// that keep it in the nonverifiable store until the `native-asset-in-verifiable-state`
+    /// upgrade is applied, so it is read from there if it is not in the verifiable state.
+    #[instrument(skip(self))]
+    async fn get_native_asset_denom(&self) -> Result<String> {
+        let bytes = match self
+            .get_raw(NATIVE_ASSET_KEY)
+            .await
+            .context("failed to read raw native_asset_denom from state")?
+        {
+            Some(bytes) => Some(bytes),
+            None => self
+                .nonverifiable_get_raw(LEGACY_NATIVE_ASSET_KEY)
+                .await
+                .context("failed to read raw legacy native_asset_denom from state")?,
+        };
+        let Some(bytes) = bytes else {
             bail!("native asset denom not found");
         };
 
@@ -128,6 +179,30 @@ pub(crate) trait StateReadExt: StateRead {
 
         Ok(fees)
     }
+
+    /// Returns the fees which were collected in the last executed block and
+    /// paid out to its proposer.
+    #[instrument(skip(self))]
+    async fn get_collected_block_fees(&self) -> Result<Vec<(asset::Id, u128)>> {
+        let Some(bytes) = self
+            .nonverifiable_get_raw(COLLECTED_BLOCK_FEES_KEY)
+            .await
+            .context("failed to read raw collected block fees from state")?
+        else {
+            return Ok(vec![]);
+        };
+        let CollectedBlockFees(fees) = CollectedBlockFees::try_from_slice(&bytes)
+            .context("invalid collected block fees bytes")?;
+        Ok(fees
+            .into_iter()
+            .map(|(id, amount)| {
+                (
+                    asset::Id::try_from_slice(&id).expect("32 byte array is a valid asset id"),
+                    amount,
+                )
+            })
+            .collect())
+    }
 }
 
 impl<T: StateRead> StateReadExt for T {}
@@ -157,9 +232,17 @@ pub(crate) trait StateWriteExt: StateWrite {
         );
     }
 
+    #[instrument(skip(self))]
+    fn put_earliest_retained_height(&mut self, height: u64) {
+        self.nonverifiable_put_raw(
+            EARLIEST_RETAINED_HEIGHT_KEY.to_vec(),
+            height.to_be_bytes().to_vec(),
+        );
+    }
+
     #[instrument(skip(self))]
     fn put_native_asset_denom(&mut self, denom: &str) {
-        self.nonverifiable_put_raw(NATIVE_ASSET_KEY.to_vec(), denom.as_bytes().to_vec());
+        self.put_raw(NATIVE_ASSET_KEY.to_string(), denom.as_bytes().to_vec());
     }
 
     /// Adds `amount` to the block fees for `asset`.
@@ -187,6 +270,19 @@ pub(crate) trait StateWriteExt: StateWrite {
         Ok(())
     }
 
+    #[instrument(skip_all)]
+    fn put_collected_block_fees(&mut self, fees: &[(asset::Id, u128)]) -> Result<()> {
+        let bytes = CollectedBlockFees(
+            fees.iter()
+                .map(|(id, amount)| (*id.as_bytes(), *amount))
+                .collect(),
+        )
+        .try_to_vec()
+        .context("failed to serialize collected block fees")?;
+        self.nonverifiable_put_raw(COLLECTED_BLOCK_FEES_KEY.to_vec(), bytes);
+        Ok(())
+    }
+
     #[instrument(skip(self))]
     async fn clear_block_fees(&mut self) {
         let mut stream =
@@ -0,0 +1,397 @@
+//! Snapshots of the sequencer's state used for ABCI state sync.
+//!
+//! A snapshot is an export of all key-value pairs in the verifiable store at a
+//! given block height. The export is borsh-encoded and split into chunks, which
+//! are served to syncing nodes and used by them to rebuild their storage. As the
+//! verifiable store is a merkle tree, the root hash of the rebuilt storage must
+//! match the app hash of the chain at the snapshot height.
+//!
+//! The nonverifiable store is not part of snapshots, as its contents could not be
+//! checked against the app hash. It only holds data which is local to a node
+//! (storage versions and stored sequencer blocks) or which only lives for the
+//! duration of a block (block fees and validator updates). The storage version of
+//! the snapshot height is rebuilt on restore; the remaining data is rebuilt as
+//! blocks are executed after the restore.
+
+pub(crate) mod store;
+
+use std::{
+    collections::BTreeMap,
+    path::Path,
+};
+
+use anyhow::{
+    ensure,
+    Context as _,
+    Result,
+};
+use borsh::{
+    BorshDeserialize,
+    BorshSerialize,
+};
+use cnidarium::{
+    RootHash,
+    StateDelta,
+    StateRead as _,
+    StateWrite as _,
+    Storage,
+};
+use futures::StreamExt as _;
+use sha2::{
+    Digest as _,
+    Sha256,
+};
+use tendermint::abci::types;
+use tracing::{
+    info,
+    instrument,
+    warn,
+};
+
+pub(crate) use self::store::SnapshotStore;
+use crate::state_ext::{
+    StateReadExt as _,
+    StateWriteExt as _,
+};
+
+/// The prefixes of the substores of the sequencer's storage.
+pub(crate) const SUBSTORE_PREFIXES: &[&str] = &[penumbra_ibc::IBC_SUBSTORE_PREFIX];
+
+/// The snapshot format produced and understood by this version of the sequencer.
+pub(crate) const SNAPSHOT_FORMAT: u32 = 2;
+
+/// The maximum size of a snapshot chunk in bytes; cometbft rejects chunks larger than 16MB.
+const CHUNK_SIZE: usize = 10 * 1024 * 1024;
+
+/// The contents of a snapshot.
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+struct SnapshotPayload {
+    verifiable: Vec<(String, Vec<u8>)>,
+}
+
+/// Describes a snapshot taken at a given height.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct SnapshotMetadata {
+    pub(crate) height: u64,
+    pub(crate) format: u32,
+    /// The sha256 hash of the entire snapshot payload.
+    pub(crate) hash: [u8; 32],
+    /// The sha256 hashes of each chunk of the payload.
+    pub(crate) chunk_hashes: Vec<[u8; 32]>,
+}
+
+impl SnapshotMetadata {
+    /// Converts the metadata into the snapshot description sent to cometbft.
+    ///
+    /// The chunk hashes are sent as the snapshot's metadata, so that a syncing
+    /// node can verify each chunk as it is received.
+    pub(crate) fn to_abci(&self) -> Result<types::Snapshot> {
+        Ok(types::Snapshot {
+            height: self
+                .height
+                .try_into()
+                .context("snapshot height does not fit into tendermint height")?,
+            format: self.format,
+            chunks: self
+                .chunk_hashes
+                .len()
+                .try_into()
+                .context("number of chunks does not fit into u32")?,
+            hash: self.hash.to_vec().into(),
+            metadata: self
+                .chunk_hashes
+                .try_to_vec()
+                .context("failed to serialize chunk hashes")?
+                .into(),
+        })
+    }
+}
+
+/// Decodes the chunk hashes sent as the metadata of an offered snapshot.
+pub(crate) fn chunk_hashes_from_abci_metadata(metadata: &[u8]) -> Result<Vec<[u8; 32]>> {
+    Vec::<[u8; 32]>::try_from_slice(metadata).context("invalid snapshot metadata bytes")
+}
+
+/// Exports the state at `snapshot` and splits it into chunks.
+#[instrument(skip_all)]
+pub(crate) async fn export(
+    snapshot: &cnidarium::Snapshot,
+) -> Result<(SnapshotMetadata, Vec<Vec<u8>>)> {
+    let height = snapshot
+        .get_block_height()
+        .await
+        .context("failed to get block height from snapshot")?;
+
+    // the main store is read with the empty prefix, the substores with their own prefixes.
+    let prefixes = std::iter::once(String::new())
+        .chain(SUBSTORE_PREFIXES.iter().map(|prefix| format!("{prefix}/")));
+
+    let mut verifiable = BTreeMap::new();
+    for prefix in prefixes {
+        let mut stream = std::pin::pin!(snapshot.prefix_raw(&prefix));
+        while let Some(item) = stream.next().await {
+            let (key, value) = item.context("failed reading verifiable state")?;
+            // the root hashes of the substores are written to the main store on commit
+            if SUBSTORE_PREFIXES.contains(&key.as_str()) {
+                continue;
+            }
+            verifiable.insert(key, value);
+        }
+    }
+
+    let payload = SnapshotPayload {
+        verifiable: verifiable.into_iter().collect(),
+    }
+    .try_to_vec()
+    .context("failed to serialize snapshot payload")?;
+
+    let chunks: Vec<Vec<u8>> = payload.chunks(CHUNK_SIZE).map(<[u8]>::to_vec).collect();
+    let metadata = SnapshotMetadata {
+        height,
+        format: SNAPSHOT_FORMAT,
+        hash: Sha256::digest(&payload).into(),
+        chunk_hashes: chunks
+            .iter()
+            .map(|chunk| Sha256::digest(chunk).into())
+            .collect(),
+    };
+    Ok((metadata, chunks))
+}
+
+/// Writes the state contained in `chunks` to `storage` and commits it.
+///
+/// `storage` must be empty. The state is committed as the first version of
+/// the storage, which is recorded as the storage version for `height`. As the
+/// state of earlier heights is not restored, `height` is recorded as the earliest
+/// height retained by the node.
+///
+/// Returns the root hash of the restored state, which must match the app hash of
+/// the chain at `height`. As the state is committed, it should first be checked
+/// with [`restored_root_hash`]. As only verifiable state is restored, nothing in
+/// the restored storage escapes this check.
+#[instrument(skip(storage, chunks))]
+pub(crate) async fn restore(
+    storage: &Storage,
+    height: u64,
+    chunks: &[Vec<u8>],
+) -> Result<RootHash> {
+    ensure!(
+        storage.latest_version() == u64::MAX,
+        "storage must be empty to be restored from a snapshot"
+    );
+
+    // this rejects payloads with trailing data, such as nonverifiable state
+    let payload = SnapshotPayload::try_from_slice(&chunks.concat())
+        .context("failed to deserialize snapshot payload")?;
+
+    let mut state = StateDelta::new(storage.latest_snapshot());
+    for (key, value) in payload.verifiable {
+        state.put_raw(key, value);
+    }
+    state.put_storage_version_by_height(height, storage.latest_version().wrapping_add(1));
+    state.put_earliest_retained_height(height);
+
+    storage
+        .commit(state)
+        .await
+        .context("failed to commit restored state")
+}
+
+/// Restores the state contained in `chunks` into a scratch storage at `scratch_dir`,
+/// returning its root hash.
+///
+/// The node's own storage is left untouched, so that the state of a snapshot offered by
+/// a peer can be checked against the app hash of the chain before it is committed with
+/// [`restore`]. The scratch storage is removed afterwards.
+///
+/// Snapshots which do not contain the native asset denom, such as snapshots of chains
+/// which have not applied the `native-asset-in-verifiable-state` upgrade, are rejected.
+#[instrument(skip(chunks))]
+pub(crate) async fn restored_root_hash(
+    scratch_dir: &Path,
+    height: u64,
+    chunks: &[Vec<u8>],
+) -> Result<RootHash> {
+    remove_scratch_dir(scratch_dir).await?;
+    tokio::fs::create_dir_all(scratch_dir)
+        .await
+        .context("failed to create scratch storage directory")?;
+    let scratch = Storage::load(
+        scratch_dir.to_path_buf(),
+        SUBSTORE_PREFIXES
+            .iter()
+            .map(std::string::ToString::to_string)
+            .collect(),
+    )
+    .await
+    .context("failed to create scratch storage")?;
+    let root_hash = async {
+        let root_hash = restore(&scratch, height, chunks).await?;
+        // the scratch storage has no nonverifiable state, so this only succeeds if the
+        // snapshot carries the native asset denom in its verifiable state
+        scratch
+            .latest_snapshot()
+            .get_native_asset_denom()
+            .await
+            .context("snapshot does not contain the native asset denom")?;
+        Ok(root_hash)
+    }
+    .await;
+    scratch.release().await;
+    remove_scratch_dir(scratch_dir).await?;
+    root_hash
+}
+
+async fn remove_scratch_dir(scratch_dir: &Path) -> Result<()> {
+    if tokio::fs::try_exists(scratch_dir).await.unwrap_or(false) {
+        tokio::fs::remove_dir_all(scratch_dir)
+            .await
+            .context("failed to remove scratch storage directory")?;
+    }
+    Ok(())
+}
+
+/// Takes a snapshot every `interval` blocks, as new state is committed to `storage`.
+pub(crate) async fn run_periodic_snapshots(storage: Storage, store: SnapshotStore, interval: u64) {
+    let mut snapshots = storage.subscribe();
+    while snapshots.changed().await.is_ok() {
+        let snapshot = snapshots.borrow_and_update().clone();
+        let height = match snapshot.get_block_height().await {
+            Ok(height) => height,
+            Err(e) => {
+                warn!(
+                    error = AsRef::<dyn std::error::Error>::as_ref(&e),
+                    "failed to get block height of committed state; skipping snapshot"
+                );
+                continue;
+            }
+        };
+        if height == 0 || height % interval != 0 {
+            continue;
+        }
+
+        let res = async {
+            let (metadata, chunks) = export(&snapshot).await.context("failed to export state")?;
+            store
+                .save(&metadata, &chunks)
+                .await
+                .context("failed to save snapshot")
+        }
+        .await;
+        match res {
+            Ok(()) => info!(height, "took state sync snapshot"),
+            Err(e) => warn!(
+                height,
+                error = AsRef::<dyn std::error::Error>::as_ref(&e),
+                "failed to take state sync snapshot"
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use cnidarium::{
+        StateDelta,
+        StateRead as _,
+        StateWrite as _,
+    };
+
+    use super::{
+        export,
+        restore,
+        restored_root_hash,
+    };
+    use crate::state_ext::{
+        StateReadExt as _,
+        StateWriteExt as _,
+    };
+
+    #[tokio::test]
+    async fn export_and_restore_yields_same_root_hash() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_block_height(7);
+        state.put_raw("some/key".to_string(), b"value".to_vec());
+        state.put_raw("other/key".to_string(), b"another value".to_vec());
+        state.nonverifiable_put_raw(b"nonverifiable".to_vec(), b"data".to_vec());
+        state.put_storage_version_by_height(7, 0);
+        let root_hash = storage.commit(state).await.unwrap();
+
+        let (metadata, chunks) = export(&storage.latest_snapshot()).await.unwrap();
+        assert_eq!(metadata.height, 7);
+        assert_eq!(metadata.chunk_hashes.len(), chunks.len());
+
+        let restored_storage = cnidarium::TempStorage::new().await.unwrap();
+        let restored_root_hash = restore(&restored_storage, metadata.height, &chunks)
+            .await
+            .unwrap();
+        assert_eq!(root_hash, restored_root_hash);
+
+        let snapshot = restored_storage.latest_snapshot();
+        assert_eq!(snapshot.get_block_height().await.unwrap(), 7);
+        // nonverifiable state is not part of snapshots
+        assert!(snapshot
+            .nonverifiable_get_raw(b"nonverifiable")
+            .await
+            .unwrap()
+            .is_none());
+        assert_eq!(snapshot.get_storage_version_by_height(7).await.unwrap(), 0);
+        assert_eq!(snapshot.get_earliest_retained_height().await.unwrap(), 7);
+    }
+
+    #[tokio::test]
+    async fn restored_root_hash_leaves_no_state_behind() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_block_height(7);
+        state.put_native_asset_denom("nria");
+        let root_hash = storage.commit(state).await.unwrap();
+
+        let (metadata, chunks) = export(&storage.latest_snapshot()).await.unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let scratch_dir = dir.path().join("scratch");
+        let scratch_root_hash = restored_root_hash(&scratch_dir, metadata.height, &chunks)
+            .await
+            .unwrap();
+        assert_eq!(root_hash, scratch_root_hash);
+        assert!(!scratch_dir.exists());
+    }
+
+    #[tokio::test]
+    async fn restore_of_snapshot_with_nonverifiable_state_fails() {
+        use borsh::BorshSerialize as _;
+
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_block_height(7);
+        state.put_native_asset_denom("nria");
+        storage.commit(state).await.unwrap();
+
+        // a peer appends a tampered nonverifiable entry to an honest payload
+        let (metadata, chunks) = export(&storage.latest_snapshot()).await.unwrap();
+        let mut payload = chunks.concat();
+        vec![(b"nativeasset".to_vec(), b"tampered".to_vec())]
+            .serialize(&mut payload)
+            .unwrap();
+
+        let restored_storage = cnidarium::TempStorage::new().await.unwrap();
+        let _ = restore(&restored_storage, metadata.height, &[payload])
+            .await
+            .unwrap_err();
+        assert_eq!(restored_storage.latest_version(), u64::MAX);
+    }
+
+    #[tokio::test]
+    async fn restore_into_non_empty_storage_fails() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_block_height(1);
+        storage.commit(state).await.unwrap();
+
+        let (metadata, chunks) = export(&storage.latest_snapshot()).await.unwrap();
+        let _ = restore(&storage, metadata.height, &chunks)
+            .await
+            .unwrap_err();
+    }
+}
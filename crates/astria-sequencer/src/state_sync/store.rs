@@ -0,0 +1,233 @@
+use std::path::{
+    Path,
+    PathBuf,
+};
+
+use anyhow::{
+    ensure,
+    Context as _,
+    Result,
+};
+use borsh::{
+    BorshDeserialize as _,
+    BorshSerialize as _,
+};
+use tracing::{
+    debug,
+    instrument,
+    warn,
+};
+
+use super::SnapshotMetadata;
+
+const METADATA_FILE_NAME: &str = "metadata";
+const TEMP_DIR_SUFFIX: &str = ".tmp";
+const RESTORE_SCRATCH_DIR_NAME: &str = "restore.tmp";
+
+/// Stores snapshots on disk.
+///
+/// Each snapshot is stored in a directory named after its height, which
+/// contains its metadata and one file per chunk.
+#[derive(Clone, Debug)]
+pub(crate) struct SnapshotStore {
+    dir: PathBuf,
+    keep_recent: usize,
+}
+
+impl SnapshotStore {
+    /// Creates a new store in `dir`, which retains the `keep_recent` most
+    /// recent snapshots.
+    pub(crate) fn new(dir: PathBuf, keep_recent: usize) -> Self {
+        Self {
+            dir,
+            keep_recent,
+        }
+    }
+
+    fn snapshot_dir(&self, height: u64) -> PathBuf {
+        self.dir.join(height.to_string())
+    }
+
+    /// Returns the directory in which snapshots offered by peers are restored to be
+    /// verified, before they are restored into the node's storage.
+    pub(crate) fn restore_scratch_dir(&self) -> PathBuf {
+        self.dir.join(RESTORE_SCRATCH_DIR_NAME)
+    }
+
+    /// Writes a snapshot to disk and removes the oldest snapshots exceeding `keep_recent`.
+    ///
+    /// The snapshot is first written to a temporary directory which is then
+    /// renamed, so that partially written snapshots are never served.
+    #[instrument(skip_all, fields(height = metadata.height))]
+    pub(crate) async fn save(&self, metadata: &SnapshotMetadata, chunks: &[Vec<u8>]) -> Result<()> {
+        let final_dir = self.snapshot_dir(metadata.height);
+        let temp_dir = self
+            .dir
+            .join(format!("{}{TEMP_DIR_SUFFIX}", metadata.height));
+        if tokio::fs::try_exists(&temp_dir).await.unwrap_or(false) {
+            tokio::fs::remove_dir_all(&temp_dir)
+                .await
+                .context("failed to remove stale temporary snapshot directory")?;
+        }
+        tokio::fs::create_dir_all(&temp_dir)
+            .await
+            .context("failed to create temporary snapshot directory")?;
+
+        for (index, chunk) in chunks.iter().enumerate() {
+            tokio::fs::write(temp_dir.join(index.to_string()), chunk)
+                .await
+                .context("failed to write snapshot chunk")?;
+        }
+        let metadata_bytes = metadata
+            .try_to_vec()
+            .context("failed to serialize snapshot metadata")?;
+        tokio::fs::write(temp_dir.join(METADATA_FILE_NAME), metadata_bytes)
+            .await
+            .context("failed to write snapshot metadata")?;
+
+        if tokio::fs::try_exists(&final_dir).await.unwrap_or(false) {
+            tokio::fs::remove_dir_all(&final_dir)
+                .await
+                .context("failed to remove existing snapshot directory")?;
+        }
+        tokio::fs::rename(&temp_dir, &final_dir)
+            .await
+            .context("failed to move snapshot into place")?;
+
+        self.prune().await.context("failed to prune old snapshots")
+    }
+
+    /// Returns the metadata of all stored snapshots, ordered by ascending height.
+    #[instrument(skip_all)]
+    pub(crate) async fn list(&self) -> Result<Vec<SnapshotMetadata>> {
+        let mut snapshots = Vec::new();
+        for height in self.heights().await? {
+            let path = self.snapshot_dir(height).join(METADATA_FILE_NAME);
+            match read_metadata(&path).await {
+                Ok(metadata) => snapshots.push(metadata),
+                Err(e) => warn!(
+                    height,
+                    error = AsRef::<dyn std::error::Error>::as_ref(&e),
+                    "failed to read snapshot metadata; skipping snapshot"
+                ),
+            }
+        }
+        Ok(snapshots)
+    }
+
+    /// Reads the chunk at `index` of the snapshot taken at `height`.
+    #[instrument(skip(self))]
+    pub(crate) async fn load_chunk(&self, height: u64, format: u32, index: u32) -> Result<Vec<u8>> {
+        let dir = self.snapshot_dir(height);
+        let metadata = read_metadata(&dir.join(METADATA_FILE_NAME)).await?;
+        ensure!(
+            metadata.format == format,
+            "snapshot at height `{height}` has format `{}`, not `{format}`",
+            metadata.format,
+        );
+        tokio::fs::read(dir.join(index.to_string()))
+            .await
+            .context("failed to read snapshot chunk")
+    }
+
+    /// Returns the heights of all stored snapshots in ascending order.
+    async fn heights(&self) -> Result<Vec<u64>> {
+        if !tokio::fs::try_exists(&self.dir).await.unwrap_or(false) {
+            return Ok(vec![]);
+        }
+        let mut heights = Vec::new();
+        let mut entries = tokio::fs::read_dir(&self.dir)
+            .await
+            .context("failed to read snapshot directory")?;
+        while let Some(entry) = entries
+            .next_entry()
+            .await
+            .context("failed to read snapshot directory entry")?
+        {
+            // temporary directories and unrelated files are skipped
+            if let Some(height) = entry
+                .file_name()
+                .to_str()
+                .and_then(|name| name.parse::<u64>().ok())
+            {
+                heights.push(height);
+            }
+        }
+        heights.sort_unstable();
+        Ok(heights)
+    }
+
+    async fn prune(&self) -> Result<()> {
+        let heights = self.heights().await?;
+        let num_to_remove = heights.len().saturating_sub(self.keep_recent);
+        for height in &heights[..num_to_remove] {
+            debug!(height, "removing old snapshot");
+            tokio::fs::remove_dir_all(self.snapshot_dir(*height))
+                .await
+                .context("failed to remove snapshot directory")?;
+        }
+        Ok(())
+    }
+}
+
+async fn read_metadata(path: &Path) -> Result<SnapshotMetadata> {
+    let bytes = tokio::fs::read(path)
+        .await
+        .context("failed to read snapshot metadata")?;
+    SnapshotMetadata::try_from_slice(&bytes).context("invalid snapshot metadata bytes")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn metadata(height: u64) -> SnapshotMetadata {
+        SnapshotMetadata {
+            height,
+            format: super::super::SNAPSHOT_FORMAT,
+            hash: [0; 32],
+            chunk_hashes: vec![[1; 32], [2; 32]],
+        }
+    }
+
+    #[tokio::test]
+    async fn save_list_and_load() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = SnapshotStore::new(dir.path().to_path_buf(), 2);
+
+        let chunks = vec![b"chunk 0".to_vec(), b"chunk 1".to_vec()];
+        store.save(&metadata(10), &chunks).await.unwrap();
+
+        assert_eq!(store.list().await.unwrap(), vec![metadata(10)]);
+        assert_eq!(
+            store
+                .load_chunk(10, super::super::SNAPSHOT_FORMAT, 1)
+                .await
+                .unwrap(),
+            b"chunk 1".to_vec(),
+        );
+        let _ = store
+            .load_chunk(10, super::super::SNAPSHOT_FORMAT + 1, 1)
+            .await
+            .unwrap_err();
+        let _ = store
+            .load_chunk(20, super::super::SNAPSHOT_FORMAT, 0)
+            .await
+            .unwrap_err();
+    }
+
+    #[tokio::test]
+    async fn old_snapshots_are_pruned() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = SnapshotStore::new(dir.path().to_path_buf(), 2);
+
+        for height in [10, 20, 30] {
+            store.save(&metadata(height), &[vec![0]]).await.unwrap();
+        }
+
+        assert_eq!(
+            store.list().await.unwrap(),
+            vec![metadata(20), metadata(30)]
+        );
+    }
+}
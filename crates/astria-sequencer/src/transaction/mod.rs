@@ -1,20 +1,25 @@
 pub(crate) mod action_handler;
 
-use std::fmt;
+use std::{
+    collections::HashMap,
+    fmt,
+};
 
 pub(crate) use action_handler::ActionHandler;
-#[cfg(not(feature = "mint"))]
-use anyhow::bail;
 use anyhow::{
+    bail,
     ensure,
     Context as _,
 };
 use astria_core::sequencer::v1alpha1::{
+    asset,
+    event::FeePaymentEvent,
     transaction::action::Action,
     Address,
     SignedTransaction,
     UnsignedTransaction,
 };
+use tendermint::Time;
 use tracing::instrument;
 
 use crate::{
@@ -22,14 +27,28 @@ use crate::{
         StateReadExt,
         StateWriteExt,
     },
+    asset::get_native_asset,
+    bridge::state_ext::StateReadExt as _,
+    fees::state_ext::StateReadExt as _,
     host_interface::AstriaHost,
+    ibc::state_ext::StateReadExt as _,
+    sequence::calculate_fee_from_state,
+    session::state_ext::{
+        SessionKey,
+        StateReadExt as _,
+        StateWriteExt as _,
+    },
+    state_ext::{
+        StateReadExt as _,
+        StateWriteExt as _,
+    },
 };
 
 pub(crate) async fn check_nonce_mempool<S: StateReadExt + 'static>(
     tx: &SignedTransaction,
     state: &S,
 ) -> anyhow::Result<()> {
-    let signer_address = Address::from_verification_key(tx.verification_key());
+    let signer_address = tx.address();
     let curr_nonce = state
         .get_account_nonce(signer_address)
         .await
@@ -41,6 +60,193 @@ pub(crate) async fn check_nonce_mempool<S: StateReadExt + 'static>(
     Ok(())
 }
 
+/// Checks that `tx` has not expired given the block height and timestamp in state.
+async fn check_expiry<S: StateReadExt + 'static>(
+    tx: &UnsignedTransaction,
+    state: &S,
+) -> anyhow::Result<()> {
+    if let Some(valid_until_height) = tx.valid_until_height {
+        let height = state
+            .get_block_height()
+            .await
+            .context("failed to get block height")?;
+        ensure!(
+            height <= valid_until_height,
+            "transaction expired at height {valid_until_height}"
+        );
+    }
+
+    if let Some(valid_until_timestamp) = &tx.valid_until_timestamp {
+        let valid_until = Time::try_from(tendermint_proto::google::protobuf::Timestamp {
+            seconds: valid_until_timestamp.seconds,
+            nanos: valid_until_timestamp.nanos,
+        })
+        .context("invalid `valid_until_timestamp`")?;
+        let timestamp = state
+            .get_block_timestamp()
+            .await
+            .context("failed to get block timestamp")?;
+        ensure!(
+            timestamp <= valid_until,
+            "transaction expired at time {valid_until}"
+        );
+    }
+
+    Ok(())
+}
+
+/// Checks that `tx`, signed by a session key, stays within the scope of `session_key`.
+async fn check_session_key_scope<S: StateReadExt + 'static>(
+    tx: &UnsignedTransaction,
+    state: &S,
+    session_key: &SessionKey,
+) -> anyhow::Result<()> {
+    let height = state
+        .get_block_height()
+        .await
+        .context("failed to get block height")?;
+    ensure!(
+        height <= session_key.expiry_height,
+        "session key expired at height {}",
+        session_key.expiry_height
+    );
+
+    let native_asset_id = get_native_asset().id();
+    for action in &tx.actions {
+        let Action::Sequence(act) = action else {
+            bail!("session keys can only submit sequence actions");
+        };
+        ensure!(
+            session_key.rollup_ids.contains(&act.rollup_id),
+            "session key is not authorized for rollup `{}`",
+            act.rollup_id
+        );
+        ensure!(
+            act.fee_asset_id == native_asset_id,
+            "session keys can only pay fees in the native asset"
+        );
+    }
+
+    let fees = get_session_key_fees(tx, state)
+        .await
+        .context("failed to get fees for transaction")?;
+    ensure!(
+        fees <= session_key.remaining_budget(),
+        "transaction fees exceed the remaining fee budget of the session key"
+    );
+    Ok(())
+}
+
+/// Returns the fees and tip charged against the budget of the session key signing `tx`.
+///
+/// Session keys can only pay fees in the native asset, so these are the native asset fees.
+/// Fees paid by a fee payer are not charged against the budget.
+pub(crate) async fn get_session_key_fees<S: StateReadExt>(
+    tx: &UnsignedTransaction,
+    state: &S,
+) -> anyhow::Result<u128> {
+    if tx.fee_payer.is_some() {
+        return Ok(0);
+    }
+    let fees = get_fees_for_transaction(tx, state).await?;
+    Ok(fees
+        .get(&get_native_asset().id())
+        .copied()
+        .unwrap_or_default())
+}
+
+/// Returns the fees charged for executing `tx`, keyed by the asset they are paid in.
+///
+/// This includes the transaction's tip, which is paid in the native asset.
+pub(crate) async fn get_fees_for_transaction<S: StateReadExt>(
+    tx: &UnsignedTransaction,
+    state: &S,
+) -> anyhow::Result<HashMap<asset::Id, u128>> {
+    let transfer_fee = state
+        .get_transfer_base_fee()
+        .await
+        .context("failed to get transfer base fee")?;
+    let ics20_withdrawal_fee = state
+        .get_ics20_withdrawal_base_fee()
+        .await
+        .context("failed to get ics20 withdrawal base fee")?;
+
+    let mut fees: HashMap<asset::Id, u128> = HashMap::new();
+    for action in &tx.actions {
+        let (fee_asset_id, fee) = match action {
+            Action::Transfer(act) => (act.fee_asset_id, transfer_fee),
+            Action::Sequence(act) => (
+                act.fee_asset_id,
+                calculate_fee_from_state(&act.data, state)
+                    .await
+                    .context("failed to calculate sequence action fee")?,
+            ),
+            Action::Ics20Withdrawal(act) => (*act.fee_asset_id(), ics20_withdrawal_fee),
+            Action::InitBridgeAccount(act) => (act.fee_asset_id, transfer_fee),
+            Action::BridgeLock(act) => (act.fee_asset_id, transfer_fee),
+            Action::BridgeUnlock(act) => (act.fee_asset_id, transfer_fee),
+            Action::RegisterRollup(act) => (act.fee_asset_id, transfer_fee),
+            Action::AuthorizeSessionKey(act) => (act.fee_asset_id, transfer_fee),
+            _ => continue,
+        };
+        let total = fees.entry(fee_asset_id).or_default();
+        *total = total
+            .checked_add(fee)
+            .context("transaction fees overflowed u128")?;
+    }
+    if let Some(tip) = tx.tip {
+        let total = fees.entry(get_native_asset().id()).or_default();
+        *total = total
+            .checked_add(tip)
+            .context("transaction fees overflowed u128")?;
+    }
+    Ok(fees)
+}
+
+/// Returns the account paying the fees and tip of `tx`, which is its fee payer if the
+/// transaction is sponsored, or `from` otherwise.
+pub(crate) fn get_fee_payer(tx: &UnsignedTransaction, from: Address) -> Address {
+    tx.fee_payer.map_or(from, |fee_payer| fee_payer.address)
+}
+
+/// Returns the total amount of each asset `tx` deducts from the balances of the accounts
+/// it touches, which are the fees deducted from the fee payer plus any amounts transferred
+/// out of `from` or unlocked from bridge accounts.
+pub(crate) async fn get_total_transaction_cost<S: StateReadExt>(
+    tx: &UnsignedTransaction,
+    state: &S,
+    from: Address,
+) -> anyhow::Result<HashMap<(Address, asset::Id), u128>> {
+    let fee_payer = get_fee_payer(tx, from);
+    let mut cost: HashMap<(Address, asset::Id), u128> = get_fees_for_transaction(tx, state)
+        .await
+        .context("failed to get fees for transaction")?
+        .into_iter()
+        .map(|(asset_id, fee)| ((fee_payer, asset_id), fee))
+        .collect();
+    for action in &tx.actions {
+        let (address, asset_id, amount) = match action {
+            Action::Transfer(act) => (from, act.asset_id, act.amount),
+            Action::Ics20Withdrawal(act) => (from, act.denom().id(), act.amount()),
+            Action::BridgeLock(act) => (from, act.asset_id, act.amount),
+            Action::BridgeUnlock(act) => (
+                act.bridge_address,
+                state
+                    .get_bridge_account_asset_id(&act.bridge_address)
+                    .await
+                    .context("failed to get bridge account asset ID")?,
+                act.amount,
+            ),
+            _ => continue,
+        };
+        let total = cost.entry((address, asset_id)).or_default();
+        *total = total
+            .checked_add(amount)
+            .context("transaction cost overflowed u128")?;
+    }
+    Ok(cost)
+}
+
 pub(crate) async fn check_stateless(tx: &SignedTransaction) -> anyhow::Result<()> {
     tx.unsigned_transaction()
         .check_stateless()
@@ -52,7 +258,7 @@ pub(crate) async fn check_stateful<S: StateReadExt + 'static>(
     tx: &SignedTransaction,
     state: &S,
 ) -> anyhow::Result<()> {
-    let signer_address = Address::from_verification_key(tx.verification_key());
+    let signer_address = tx.address();
     tx.unsigned_transaction()
         .check_stateful(state, signer_address)
         .await
@@ -62,7 +268,7 @@ pub(crate) async fn execute<S: StateWriteExt>(
     tx: &SignedTransaction,
     state: &mut S,
 ) -> anyhow::Result<()> {
-    let signer_address = Address::from_verification_key(tx.verification_key());
+    let signer_address = tx.address();
     tx.unsigned_transaction()
         .execute(state, signer_address)
         .await
@@ -120,6 +326,70 @@ impl ActionHandler for UnsignedTransaction {
                     .check_stateless()
                     .await
                     .context("stateless check failed for Ics20WithdrawalAction")?,
+                Action::FeeChange(act) => act
+                    .check_stateless()
+                    .await
+                    .context("stateless check failed for FeeChangeAction")?,
+                Action::FeeAssetChange(act) => act
+                    .check_stateless()
+                    .await
+                    .context("stateless check failed for FeeAssetChangeAction")?,
+                Action::InitBridgeAccount(act) => act
+                    .check_stateless()
+                    .await
+                    .context("stateless check failed for InitBridgeAccountAction")?,
+                Action::BridgeLock(act) => act
+                    .check_stateless()
+                    .await
+                    .context("stateless check failed for BridgeLockAction")?,
+                Action::BridgeUnlock(act) => act
+                    .check_stateless()
+                    .await
+                    .context("stateless check failed for BridgeUnlockAction")?,
+                Action::IbcRelayerChange(act) => act
+                    .check_stateless()
+                    .await
+                    .context("stateless check failed for IbcRelayerChangeAction")?,
+                Action::IbcSudoChange(act) => act
+                    .check_stateless()
+                    .await
+                    .context("stateless check failed for IbcSudoChangeAction")?,
+                Action::Ics20ChannelChange(act) => act
+                    .check_stateless()
+                    .await
+                    .context("stateless check failed for Ics20ChannelChangeAction")?,
+                Action::Ics20RateLimitChange(act) => act
+                    .check_stateless()
+                    .await
+                    .context("stateless check failed for Ics20RateLimitChangeAction")?,
+                Action::ScheduleUpgrade(act) => act
+                    .check_stateless()
+                    .await
+                    .context("stateless check failed for ScheduleUpgradeAction")?,
+                Action::RegisterRollup(act) => act
+                    .check_stateless()
+                    .await
+                    .context("stateless check failed for RegisterRollupAction")?,
+                Action::RollupOwnerChange(act) => act
+                    .check_stateless()
+                    .await
+                    .context("stateless check failed for RollupOwnerChangeAction")?,
+                Action::RollupMetadataChange(act) => act
+                    .check_stateless()
+                    .await
+                    .context("stateless check failed for RollupMetadataChangeAction")?,
+                Action::AssetMetadataChange(act) => act
+                    .check_stateless()
+                    .await
+                    .context("stateless check failed for AssetMetadataChangeAction")?,
+                Action::AuthorizeSessionKey(act) => act
+                    .check_stateless()
+                    .await
+                    .context("stateless check failed for AuthorizeSessionKeyAction")?,
+                Action::RevokeSessionKey(act) => act
+                    .check_stateless()
+                    .await
+                    .context("stateless check failed for RevokeSessionKeyAction")?,
                 #[cfg(feature = "mint")]
                 Action::Mint(act) => act
                     .check_stateless()
@@ -142,6 +412,32 @@ impl ActionHandler for UnsignedTransaction {
         let curr_nonce = state.get_account_nonce(from).await?;
         ensure!(curr_nonce == self.nonce, InvalidNonce(self.nonce));
 
+        check_expiry(self, state)
+            .await
+            .context("transaction expiry check failed")?;
+
+        if let Some(fee_payer) = self.fee_payer {
+            ensure!(
+                fee_payer.address != from,
+                "fee payer must not be the signer of the transaction"
+            );
+        }
+
+        // transactions signed by a session key act for the account that authorized it
+        let from = match state
+            .get_session_key(from)
+            .await
+            .context("failed to get session key")?
+        {
+            Some(session_key) => {
+                check_session_key_scope(self, state, &session_key)
+                    .await
+                    .context("session key scope check failed")?;
+                session_key.account
+            }
+            None => from,
+        };
+
         for action in &self.actions {
             match action {
                 Action::Transfer(act) => act
@@ -161,19 +457,90 @@ impl ActionHandler for UnsignedTransaction {
                     .await
                     .context("stateful check failed for SudoAddressChangeAction")?,
                 Action::Ibc(_) => {
-                    let ibc_sudo_address = state
-                        .get_ibc_sudo_address()
-                        .await
-                        .context("failed to get IBC sudo address")?;
                     ensure!(
-                        from == ibc_sudo_address,
-                        "only IBC sudo address can execute IBC actions"
+                        state
+                            .get_ibc_params()
+                            .await
+                            .context("failed to get IBC params")?
+                            .ibc_enabled,
+                        "IBC is disabled"
+                    );
+                    ensure!(
+                        state
+                            .is_ibc_relayer(&from)
+                            .await
+                            .context("failed to check if signer is an IBC relayer")?,
+                        "only IBC relayers can execute IBC actions"
                     );
                 }
                 Action::Ics20Withdrawal(act) => act
                     .check_stateful(state, from)
                     .await
                     .context("stateful check failed for Ics20WithdrawalAction")?,
+                Action::FeeChange(act) => act
+                    .check_stateful(state, from)
+                    .await
+                    .context("stateful check failed for FeeChangeAction")?,
+                Action::FeeAssetChange(act) => act
+                    .check_stateful(state, from)
+                    .await
+                    .context("stateful check failed for FeeAssetChangeAction")?,
+                Action::InitBridgeAccount(act) => act
+                    .check_stateful(state, from)
+                    .await
+                    .context("stateful check failed for InitBridgeAccountAction")?,
+                Action::BridgeLock(act) => act
+                    .check_stateful(state, from)
+                    .await
+                    .context("stateful check failed for BridgeLockAction")?,
+                Action::BridgeUnlock(act) => act
+                    .check_stateful(state, from)
+                    .await
+                    .context("stateful check failed for BridgeUnlockAction")?,
+                Action::IbcRelayerChange(act) => act
+                    .check_stateful(state, from)
+                    .await
+                    .context("stateful check failed for IbcRelayerChangeAction")?,
+                Action::IbcSudoChange(act) => act
+                    .check_stateful(state, from)
+                    .await
+                    .context("stateful check failed for IbcSudoChangeAction")?,
+                Action::Ics20ChannelChange(act) => act
+                    .check_stateful(state, from)
+                    .await
+                    .context("stateful check failed for Ics20ChannelChangeAction")?,
+                Action::Ics20RateLimitChange(act) => act
+                    .check_stateful(state, from)
+                    .await
+                    .context("stateful check failed for Ics20RateLimitChangeAction")?,
+                Action::ScheduleUpgrade(act) => act
+                    .check_stateful(state, from)
+                    .await
+                    .context("stateful check failed for ScheduleUpgradeAction")?,
+                Action::RegisterRollup(act) => act
+                    .check_stateful(state, from)
+                    .await
+                    .context("stateful check failed for RegisterRollupAction")?,
+                Action::RollupOwnerChange(act) => act
+                    .check_stateful(state, from)
+                    .await
+                    .context("stateful check failed for RollupOwnerChangeAction")?,
+                Action::RollupMetadataChange(act) => act
+                    .check_stateful(state, from)
+                    .await
+                    .context("stateful check failed for RollupMetadataChangeAction")?,
+                Action::AssetMetadataChange(act) => act
+                    .check_stateful(state, from)
+                    .await
+                    .context("stateful check failed for AssetMetadataChangeAction")?,
+                Action::AuthorizeSessionKey(act) => act
+                    .check_stateful(state, from)
+                    .await
+                    .context("stateful check failed for AuthorizeSessionKeyAction")?,
+                Action::RevokeSessionKey(act) => act
+                    .check_stateful(state, from)
+                    .await
+                    .context("stateful check failed for RevokeSessionKeyAction")?,
                 #[cfg(feature = "mint")]
                 Action::Mint(act) => act
                     .check_stateful(state, from)
@@ -184,6 +551,35 @@ impl ActionHandler for UnsignedTransaction {
             }
         }
 
+        // funds only leave bridge accounts through unlocks signed by their withdrawer, so
+        // bridge accounts cannot pay fees or tips. transactions signed by a bridge account
+        // must be sponsored by a fee payer.
+        ensure!(
+            state
+                .get_bridge_account_rollup_id(&get_fee_payer(self, from))
+                .await
+                .context("failed to get bridge account rollup ID")?
+                .is_none(),
+            "bridge accounts cannot pay transaction fees or tips"
+        );
+
+        // the fees and tip are charged before the actions are executed, so the fee payer
+        // must be able to cover them together with any amounts transferred out of its
+        // account by the actions
+        let cost = get_total_transaction_cost(self, state, from)
+            .await
+            .context("failed to get transaction cost")?;
+        for ((address, asset_id), amount) in cost {
+            let balance = state
+                .get_account_balance(address, asset_id)
+                .await
+                .context("failed to get account balance")?;
+            ensure!(
+                balance >= amount,
+                "insufficient funds of asset `{asset_id}` to cover the transaction cost"
+            );
+        }
+
         Ok(())
     }
 
@@ -206,6 +602,58 @@ impl ActionHandler for UnsignedTransaction {
             .put_account_nonce(from, next_nonce)
             .context("failed updating `from` nonce")?;
 
+        // transactions signed by a session key act for the account that authorized it,
+        // and their fees are charged against the session key's budget
+        let from = match state
+            .get_session_key(from)
+            .await
+            .context("failed to get session key")?
+        {
+            Some(mut session_key) => {
+                let fees = get_session_key_fees(self, state)
+                    .await
+                    .context("failed to get fees for transaction")?;
+                session_key.fees_spent = session_key
+                    .fees_spent
+                    .checked_add(fees)
+                    .context("session key fees spent overflowed u128")?;
+                state
+                    .put_session_key(from, &session_key)
+                    .context("failed to put session key")?;
+                session_key.account
+            }
+            None => from,
+        };
+
+        let fee_payer = get_fee_payer(self, from);
+
+        // the fees and tip are charged before any action can change the fee schedule,
+        // and are paid to the proposer together with the other fees of the block
+        let mut fees: Vec<_> = get_fees_for_transaction(self, state)
+            .await
+            .context("failed to get fees for transaction")?
+            .into_iter()
+            .collect();
+        fees.sort_unstable_by_key(|(asset_id, _)| *asset_id);
+        for (asset_id, amount) in fees {
+            state
+                .decrease_balance(fee_payer, asset_id, amount)
+                .await
+                .context("failed to deduct fees from fee payer balance")?;
+            state
+                .get_and_increase_block_fees(asset_id, amount)
+                .await
+                .context("failed to add to block fees")?;
+            state.record(
+                FeePaymentEvent {
+                    from: fee_payer,
+                    asset_id,
+                    amount,
+                }
+                .into_abci(),
+            );
+        }
+
         for action in &self.actions {
             match action {
                 Action::Transfer(act) => {
@@ -243,6 +691,86 @@ impl ActionHandler for UnsignedTransaction {
                         .await
                         .context("execution failed for Ics20WithdrawalAction")?;
                 }
+                Action::FeeChange(act) => {
+                    act.execute(state, from)
+                        .await
+                        .context("execution failed for FeeChangeAction")?;
+                }
+                Action::FeeAssetChange(act) => {
+                    act.execute(state, from)
+                        .await
+                        .context("execution failed for FeeAssetChangeAction")?;
+                }
+                Action::InitBridgeAccount(act) => {
+                    act.execute(state, from)
+                        .await
+                        .context("execution failed for InitBridgeAccountAction")?;
+                }
+                Action::BridgeLock(act) => {
+                    act.execute(state, from)
+                        .await
+                        .context("execution failed for BridgeLockAction")?;
+                }
+                Action::BridgeUnlock(act) => {
+                    act.execute(state, from)
+                        .await
+                        .context("execution failed for BridgeUnlockAction")?;
+                }
+                Action::IbcRelayerChange(act) => {
+                    act.execute(state, from)
+                        .await
+                        .context("execution failed for IbcRelayerChangeAction")?;
+                }
+                Action::IbcSudoChange(act) => {
+                    act.execute(state, from)
+                        .await
+                        .context("execution failed for IbcSudoChangeAction")?;
+                }
+                Action::Ics20ChannelChange(act) => {
+                    act.execute(state, from)
+                        .await
+                        .context("execution failed for Ics20ChannelChangeAction")?;
+                }
+                Action::Ics20RateLimitChange(act) => {
+                    act.execute(state, from)
+                        .await
+                        .context("execution failed for Ics20RateLimitChangeAction")?;
+                }
+                Action::ScheduleUpgrade(act) => {
+                    act.execute(state, from)
+                        .await
+                        .context("execution failed for ScheduleUpgradeAction")?;
+                }
+                Action::RegisterRollup(act) => {
+                    act.execute(state, from)
+                        .await
+                        .context("execution failed for RegisterRollupAction")?;
+                }
+                Action::RollupOwnerChange(act) => {
+                    act.execute(state, from)
+                        .await
+                        .context("execution failed for RollupOwnerChangeAction")?;
+                }
+                Action::RollupMetadataChange(act) => {
+                    act.execute(state, from)
+                        .await
+                        .context("execution failed for RollupMetadataChangeAction")?;
+                }
+                Action::AssetMetadataChange(act) => {
+                    act.execute(state, from)
+                        .await
+                        .context("execution failed for AssetMetadataChangeAction")?;
+                }
+                Action::AuthorizeSessionKey(act) => {
+                    act.execute(state, from)
+                        .await
+                        .context("execution failed for AuthorizeSessionKeyAction")?;
+                }
+                Action::RevokeSessionKey(act) => {
+                    act.execute(state, from)
+                        .await
+                        .context("execution failed for RevokeSessionKeyAction")?;
+                }
                 #[cfg(feature = "mint")]
                 Action::Mint(act) => {
                     act.execute(state, from)
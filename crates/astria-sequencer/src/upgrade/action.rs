@@ -0,0 +1,77 @@
+use anyhow::{
+    ensure,
+    Context as _,
+    Result,
+};
+use astria_core::sequencer::v1alpha1::{
+    transaction::action::ScheduleUpgradeAction,
+    Address,
+};
+use tracing::instrument;
+
+use crate::{
+    authority::state_ext::StateReadExt as _,
+    state_ext::StateReadExt as _,
+    transaction::action_handler::ActionHandler,
+    upgrade::state_ext::{
+        ScheduledUpgrade,
+        StateReadExt,
+        StateWriteExt,
+    },
+};
+
+#[async_trait::async_trait]
+impl ActionHandler for ScheduleUpgradeAction {
+    /// whether the upgrade is known to this binary is not checked here, as this also
+    /// runs when executing blocks, which must not depend on the binary executing them.
+    /// see [`crate::upgrade::check_upgrades_known`].
+    async fn check_stateless(&self) -> Result<()> {
+        ensure!(!self.name.is_empty(), "upgrade name must not be empty");
+        Ok(())
+    }
+
+    /// check that the signer of the transaction is the current sudo address,
+    /// as only that address can schedule upgrades
+    async fn check_stateful<S: StateReadExt + 'static>(
+        &self,
+        state: &S,
+        from: Address,
+    ) -> Result<()> {
+        let sudo_address = state
+            .get_sudo_address()
+            .await
+            .context("failed to get sudo address from state")?;
+        ensure!(sudo_address == from, "signer is not the sudo key");
+
+        let block_height = state
+            .get_block_height()
+            .await
+            .context("failed to get block height")?;
+        ensure!(
+            self.height > block_height,
+            "upgrade height must be greater than the current block height"
+        );
+
+        ensure!(
+            state
+                .get_upgrade_applied_height(&self.name)
+                .await
+                .context("failed to get applied upgrade height")?
+                .is_none(),
+            "upgrade has already been applied"
+        );
+        Ok(())
+    }
+
+    #[instrument(skip_all, fields(name = %self.name, height = self.height))]
+    async fn execute<S: StateWriteExt>(&self, state: &mut S, _: Address) -> Result<()> {
+        // scheduling an upgrade replaces any previously scheduled upgrade
+        state
+            .put_scheduled_upgrade(&ScheduledUpgrade {
+                name: self.name.clone(),
+                height: self.height,
+            })
+            .context("failed to put scheduled upgrade in state")?;
+        Ok(())
+    }
+}
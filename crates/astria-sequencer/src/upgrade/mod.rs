@@ -0,0 +1,298 @@
+//! Height-gated chain upgrades.
+//!
+//! An upgrade is scheduled by the sudo address with a [`ScheduleUpgradeAction`], naming the
+//! upgrade and the height at which it is applied. A node only admits a transaction
+//! scheduling an upgrade into its mempool if the upgrade is one of the [`Upgrade`]s known
+//! to its binary, so validators should run a binary which knows an upgrade before it is
+//! scheduled. This is not checked when executing blocks, so that their execution never
+//! depends on the binary executing them, and history can be replayed by binaries which
+//! no longer know old upgrades. When the upgrade height is reached, the upgrade's state
+//! migration is run and the app version is bumped, which is sent to cometbft as a consensus
+//! params update at the end of the block. A node whose binary does not know the scheduled
+//! upgrade halts at the upgrade height and must be restarted with a binary that does.
+//!
+//! [`ScheduleUpgradeAction`]: astria_core::sequencer::v1alpha1::transaction::action::ScheduleUpgradeAction
+
+pub(crate) mod action;
+pub(crate) mod state_ext;
+
+use std::sync::Arc;
+
+use anyhow::{
+    ensure,
+    Context as _,
+    Result,
+};
+use astria_core::sequencer::v1alpha1::{
+    transaction::Action,
+    SignedTransaction,
+};
+use cnidarium::{
+    Snapshot,
+    StateDelta,
+    StateWrite,
+};
+use futures::{
+    future::BoxFuture,
+    FutureExt as _,
+};
+use tendermint::consensus::{
+    self,
+    params::VersionParams,
+};
+use tracing::info;
+
+use self::state_ext::{
+    StateReadExt as _,
+    StateWriteExt as _,
+};
+use crate::state_ext::{
+    StateReadExt as _,
+    StateWriteExt as _,
+};
+
+/// The state an upgrade's migration is run against.
+pub(crate) type MigrationState = StateDelta<Arc<StateDelta<Snapshot>>>;
+
+/// A state migration, run when the upgrade it belongs to is applied.
+pub(crate) type Migration = fn(&mut MigrationState) -> BoxFuture<'_, Result<()>>;
+
+/// An upgrade known to this binary.
+#[derive(Clone, Copy)]
+pub(crate) struct Upgrade {
+    /// The name the upgrade is scheduled under.
+    pub(crate) name: &'static str,
+    /// The app version of the chain after the upgrade is applied.
+    pub(crate) app_version: u64,
+    pub(crate) migration: Migration,
+}
+
+impl std::fmt::Debug for Upgrade {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Upgrade")
+            .field("name", &self.name)
+            .field("app_version", &self.app_version)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Moves the native asset denomination into the verifiable state on chains initialized
+/// before it was kept there, so that it is part of state sync snapshots.
+const NATIVE_ASSET_IN_VERIFIABLE_STATE: Upgrade = Upgrade {
+    name: "native-asset-in-verifiable-state",
+    app_version: 2,
+    migration: migrate_native_asset_to_verifiable_state,
+};
+
+/// The upgrades this binary knows how to apply, ordered by app version.
+#[cfg(not(test))]
+pub(crate) const KNOWN_UPGRADES: &[Upgrade] = &[NATIVE_ASSET_IN_VERIFIABLE_STATE];
+
+/// The upgrades known in tests, whose migration marks the state as migrated.
+#[cfg(test)]
+pub(crate) const KNOWN_UPGRADES: &[Upgrade] = &[Upgrade {
+    name: "test-upgrade",
+    app_version: 2,
+    migration: test_migration,
+}];
+
+#[cfg(test)]
+fn test_migration(state: &mut MigrationState) -> BoxFuture<'_, Result<()>> {
+    use cnidarium::StateWrite as _;
+
+    async move {
+        state.put_raw("test/migrated".to_string(), vec![1]);
+        Ok(())
+    }
+    .boxed()
+}
+
+fn migrate_native_asset_to_verifiable_state(
+    state: &mut MigrationState,
+) -> BoxFuture<'_, Result<()>> {
+    async move {
+        // falls back to the nonverifiable store if the denom is not in the verifiable state
+        let native_asset = state
+            .get_native_asset_denom()
+            .await
+            .context("failed to get native asset denom")?;
+        state.put_native_asset_denom(&native_asset);
+        Ok(())
+    }
+    .boxed()
+}
+
+/// Returns cometbft's default consensus params, as passed to `init_chain` in tests.
+#[cfg(test)]
+pub(crate) fn test_consensus_params() -> consensus::Params {
+    use tendermint_proto::{
+        google::protobuf::Duration,
+        v0_37::types::{
+            BlockParams,
+            ConsensusParams,
+            EvidenceParams,
+            ValidatorParams,
+            VersionParams,
+        },
+    };
+
+    ConsensusParams {
+        block: Some(BlockParams {
+            max_bytes: 22_020_096,
+            max_gas: -1,
+        }),
+        evidence: Some(EvidenceParams {
+            max_age_num_blocks: 100_000,
+            max_age_duration: Some(Duration {
+                seconds: 172_800,
+                nanos: 0,
+            }),
+            max_bytes: 1_048_576,
+        }),
+        validator: Some(ValidatorParams {
+            pub_key_types: vec!["ed25519".to_string()],
+        }),
+        version: Some(VersionParams {
+            app: 0,
+        }),
+    }
+    .try_into()
+    .unwrap()
+}
+
+/// Returns the upgrade with the given name if it is known to this binary.
+pub(crate) fn find_known_upgrade(name: &str) -> Option<&'static Upgrade> {
+    KNOWN_UPGRADES.iter().find(|upgrade| upgrade.name == name)
+}
+
+/// Checks that all upgrades scheduled by `tx` are known to this binary.
+///
+/// This is only checked for transactions entering the mempool, as an unknown upgrade would
+/// halt this node at its height. It must not be checked when executing transactions.
+///
+/// # Errors
+///
+/// Returns an error if `tx` schedules an upgrade which is not known to this binary.
+pub(crate) fn check_upgrades_known(tx: &SignedTransaction) -> Result<()> {
+    for action in tx.actions() {
+        if let Action::ScheduleUpgrade(act) = action {
+            ensure!(
+                find_known_upgrade(&act.name).is_some(),
+                "upgrade `{}` is not known to this binary",
+                act.name,
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Applies the upgrade scheduled for `height`, if there is one.
+///
+/// Once applied, the scheduled upgrade is removed from state, so calling this again
+/// for the same height is a no-op.
+///
+/// # Errors
+///
+/// Returns an error if an upgrade is scheduled for `height` which is not known to this
+/// binary, or if its migration fails. The node cannot make progress past `height` in
+/// either case.
+pub(crate) async fn apply_scheduled_upgrade(state: &mut MigrationState, height: u64) -> Result<()> {
+    let Some(scheduled) = state
+        .get_scheduled_upgrade()
+        .await
+        .context("failed to get scheduled upgrade")?
+    else {
+        return Ok(());
+    };
+    if scheduled.height != height {
+        return Ok(());
+    }
+
+    let upgrade = find_known_upgrade(&scheduled.name).with_context(|| {
+        format!(
+            "upgrade `{}` scheduled for height {height} is not known to this binary; halting \
+             until restarted with a binary that knows the upgrade",
+            scheduled.name,
+        )
+    })?;
+
+    (upgrade.migration)(state)
+        .await
+        .with_context(|| format!("failed running migration of upgrade `{}`", upgrade.name))?;
+    state.put_app_version(upgrade.app_version);
+    state.put_upgrade_applied_height(upgrade.name, height);
+    state.delete_scheduled_upgrade();
+
+    info!(
+        name = upgrade.name,
+        height,
+        app_version = upgrade.app_version,
+        "applied upgrade"
+    );
+    Ok(())
+}
+
+/// Returns the consensus params to send to cometbft at the end of the block if the app
+/// version changed since they were last sent, storing them as the latest params sent.
+///
+/// Returns `None` if the app version is unchanged, or if the chain was initialized before
+/// consensus params were stored; the migration of the first upgrade of such a chain must
+/// store them.
+pub(crate) async fn consensus_params_update<S: StateWrite>(
+    state: &mut S,
+) -> Result<Option<consensus::Params>> {
+    let Some(mut params) = state
+        .get_consensus_params()
+        .await
+        .context("failed to get consensus params")?
+    else {
+        return Ok(None);
+    };
+    let app_version = state
+        .get_app_version()
+        .await
+        .context("failed to get app version")?;
+    if params.version.as_ref().map(|version| version.app) == Some(app_version) {
+        return Ok(None);
+    }
+    params.version = Some(VersionParams {
+        app: app_version,
+    });
+    state.put_consensus_params(params.clone());
+    Ok(Some(params))
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use cnidarium::{
+        StateDelta,
+        StateRead as _,
+        StateWrite as _,
+    };
+
+    use super::NATIVE_ASSET_IN_VERIFIABLE_STATE;
+    use crate::state_ext::StateReadExt as _;
+
+    #[tokio::test]
+    async fn native_asset_is_moved_into_verifiable_state() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        // chains initialized before the upgrade keep the denom in the nonverifiable store
+        state.nonverifiable_put_raw(b"nativeasset".to_vec(), b"nria".to_vec());
+        storage.commit(state).await.unwrap();
+
+        let mut state = StateDelta::new(Arc::new(StateDelta::new(storage.latest_snapshot())));
+        assert_eq!(state.get_native_asset_denom().await.unwrap(), "nria");
+        assert!(state.get_raw("nativeasset").await.unwrap().is_none());
+
+        (NATIVE_ASSET_IN_VERIFIABLE_STATE.migration)(&mut state)
+            .await
+            .unwrap();
+        assert_eq!(
+            state.get_raw("nativeasset").await.unwrap(),
+            Some(b"nria".to_vec())
+        );
+    }
+}
@@ -0,0 +1,149 @@
+use anyhow::{
+    bail,
+    Context,
+    Result,
+};
+use async_trait::async_trait;
+use borsh::{
+    BorshDeserialize,
+    BorshSerialize,
+};
+use cnidarium::{
+    StateRead,
+    StateWrite,
+};
+use prost::Message as _;
+use tendermint::consensus;
+use tendermint_proto::v0_37::types::ConsensusParams as RawConsensusParams;
+use tracing::instrument;
+
+/// The app version of the chain before any upgrade was applied.
+pub(crate) const GENESIS_APP_VERSION: u64 = 1;
+
+const SCHEDULED_UPGRADE_STORAGE_KEY: &str = "upgrade/scheduled";
+const APP_VERSION_STORAGE_KEY: &str = "upgrade/app_version";
+const CONSENSUS_PARAMS_STORAGE_KEY: &str = "upgrade/consensus_params";
+
+fn applied_upgrade_storage_key(name: &str) -> String {
+    format!("upgrade/applied/{name}")
+}
+
+/// An upgrade scheduled by the sudo address, to be applied at `height`.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct ScheduledUpgrade {
+    pub(crate) name: String,
+    pub(crate) height: u64,
+}
+
+#[async_trait]
+pub(crate) trait StateReadExt: StateRead {
+    #[instrument(skip(self))]
+    async fn get_scheduled_upgrade(&self) -> Result<Option<ScheduledUpgrade>> {
+        let Some(bytes) = self
+            .get_raw(SCHEDULED_UPGRADE_STORAGE_KEY)
+            .await
+            .context("failed reading raw scheduled upgrade from state")?
+        else {
+            return Ok(None);
+        };
+        let upgrade =
+            ScheduledUpgrade::try_from_slice(&bytes).context("invalid scheduled upgrade bytes")?;
+        Ok(Some(upgrade))
+    }
+
+    /// Returns the height at which the upgrade with the given name was applied,
+    /// or `None` if it was never applied.
+    #[instrument(skip(self))]
+    async fn get_upgrade_applied_height(&self, name: &str) -> Result<Option<u64>> {
+        let Some(bytes) = self
+            .get_raw(&applied_upgrade_storage_key(name))
+            .await
+            .context("failed reading raw applied upgrade height from state")?
+        else {
+            return Ok(None);
+        };
+        let Ok(bytes): Result<[u8; 8], _> = bytes.try_into() else {
+            bail!("failed turning raw applied upgrade height bytes into u64; not 8 bytes?");
+        };
+        Ok(Some(u64::from_be_bytes(bytes)))
+    }
+
+    /// Returns the app version of the chain, which changes whenever an upgrade is applied.
+    #[instrument(skip(self))]
+    async fn get_app_version(&self) -> Result<u64> {
+        let Some(bytes) = self
+            .get_raw(APP_VERSION_STORAGE_KEY)
+            .await
+            .context("failed reading raw app version from state")?
+        else {
+            return Ok(GENESIS_APP_VERSION);
+        };
+        let Ok(bytes): Result<[u8; 8], _> = bytes.try_into() else {
+            bail!("failed turning raw app version bytes into u64; not 8 bytes?");
+        };
+        Ok(u64::from_be_bytes(bytes))
+    }
+
+    /// Returns the consensus params last sent to cometbft, or `None` if the chain was
+    /// initialized before they were stored.
+    #[instrument(skip(self))]
+    async fn get_consensus_params(&self) -> Result<Option<consensus::Params>> {
+        let Some(bytes) = self
+            .get_raw(CONSENSUS_PARAMS_STORAGE_KEY)
+            .await
+            .context("failed reading raw consensus params from state")?
+        else {
+            return Ok(None);
+        };
+        let raw = RawConsensusParams::decode(bytes.as_slice())
+            .context("invalid consensus params bytes")?;
+        let params = consensus::Params::try_from(raw)
+            .context("failed converting raw consensus params to native type")?;
+        Ok(Some(params))
+    }
+}
+
+impl<T: StateRead> StateReadExt for T {}
+
+#[async_trait]
+pub(crate) trait StateWriteExt: StateWrite {
+    #[instrument(skip(self))]
+    fn put_scheduled_upgrade(&mut self, upgrade: &ScheduledUpgrade) -> Result<()> {
+        let bytes = upgrade
+            .try_to_vec()
+            .context("failed to serialize scheduled upgrade")?;
+        self.put_raw(SCHEDULED_UPGRADE_STORAGE_KEY.to_string(), bytes);
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    fn delete_scheduled_upgrade(&mut self) {
+        self.delete(SCHEDULED_UPGRADE_STORAGE_KEY.to_string());
+    }
+
+    #[instrument(skip(self))]
+    fn put_upgrade_applied_height(&mut self, name: &str, height: u64) {
+        self.put_raw(
+            applied_upgrade_storage_key(name),
+            height.to_be_bytes().to_vec(),
+        );
+    }
+
+    #[instrument(skip(self))]
+    fn put_app_version(&mut self, app_version: u64) {
+        self.put_raw(
+            APP_VERSION_STORAGE_KEY.to_string(),
+            app_version.to_be_bytes().to_vec(),
+        );
+    }
+
+    #[instrument(skip_all)]
+    fn put_consensus_params(&mut self, params: consensus::Params) {
+        self.put_raw(
+            CONSENSUS_PARAMS_STORAGE_KEY.to_string(),
+            RawConsensusParams::from(params).encode_to_vec(),
+        );
+    }
+}
+
+impl<T: StateWrite> StateWriteExt for T {}